@@ -1,16 +1,18 @@
 use criterion::{BatchSize, Criterion};
 use criterion::{criterion_group, criterion_main};
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 use serde_json;
 
 use f1_data::{
     jolpica::{
-        agent::{Agent, AgentConfigs, MultiPageOption, RateLimiterOption},
+        agent::{Agent, AgentConfigs, CacheExpiry, CacheOption, MultiPageOption, RateLimiterOption},
         api::{JOLPICA_API_BASE_URL, JOLPICA_API_RATE_LIMIT_QUOTA},
+        backend::HttpBackend,
         resource::{Filters, Page, Resource},
         response::Response,
     },
@@ -60,8 +62,17 @@ static JOLPICA_SP: LazyLock<Agent> = LazyLock::new(|| {
     // Multi-page requests and HTTP retries would interfere with benchmarking individual calls.
     Agent::new(AgentConfigs {
         base_url: get_base_url().to_string(),
+        backend: Arc::new(HttpBackend),
+        user_agent: None,
+        extra_headers: BTreeMap::new(),
         multi_page: MultiPageOption::Disabled,
+        multi_page_concurrency: 1,
         http_retries: None,
+        retry_predicate: None,
+        conditional_requests: false,
+        verify_table_variant: false,
+        cache: CacheOption::None,
+        cache_expiry: CacheExpiry::Never,
         rate_limiter: RateLimiterOption::None,
     })
 });
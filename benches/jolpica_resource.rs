@@ -10,6 +10,7 @@ static FILTERS_NONE: LazyLock<Filters> = LazyLock::new(|| Filters::none());
 
 static FILTERS_MANY: LazyLock<Filters> = LazyLock::new(|| Filters {
     season: Some(2023),
+    season_range: None,
     round: Some(1),
     driver_id: Some("alonso".into()),
     constructor_id: Some("aston_martin".into()),
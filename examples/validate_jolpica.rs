@@ -260,7 +260,7 @@ where
         }
         // We ignore NotFound errors because the .qualifying_pos/sprint_pos/finish_pos filters do
         // not work for non-fishing positions, i.e. those where position_text is not a number.
-        else if !matches!(race, Err(Error::NotFound)) {
+        else if !matches!(race, Err(Error::NotFound(_))) {
             error!("P{pos} failed");
             log_error(race);
         }
@@ -407,7 +407,7 @@ fn validate_driver_laps(configs: Configurations) -> anyhow::Result<()> {
                 }
                 // We ignore NotFound errors because those are valid for seasons prior to 1996,
                 // where Resource::LapTimes were not available; an overall message is still logged.
-                else if let Err(Error::NotFound) = laps {
+                else if let Err(Error::NotFound(_)) = laps {
                     continue 'driver_loop;
                 } else {
                     let msg =
@@ -478,7 +478,7 @@ fn validate_lap_timings(configs: Configurations) -> anyhow::Result<()> {
                     lap_timings
                 }
                 // Stop loop once we find the first lap for which there are no lap timings.
-                else if let Err(Error::NotFound) = lap_timings {
+                else if let Err(Error::NotFound(_)) = lap_timings {
                     if lap == 1 {
                         debug!(">> No lap timings found");
                     }
@@ -542,7 +542,7 @@ fn validate_pit_stops(_: Configurations) -> anyhow::Result<()> {
                 pit_stops
             }
             // Stop loop once we find the first lap for which there are no lap timings.
-            else if let Err(Error::NotFound) = pit_stops {
+            else if let Err(Error::NotFound(_)) = pit_stops {
                 debug!(">> No pit stops found");
                 continue 'race_loop;
             } else {
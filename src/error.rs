@@ -6,13 +6,17 @@ use ureq;
 #[cfg(feature = "fantasy")]
 use serde_yaml;
 
-use crate::jolpica::response::{Payload, Table};
+use crate::jolpica::response::{Payload, TableKind};
 
+#[cfg(all(doc, feature = "async"))]
+use crate::jolpica::async_agent::AsyncAgent;
 #[cfg(doc)]
 use crate::jolpica::{
+    agent::{Agent, AgentConfigs},
     concat::PageVerify,
-    resource::Resource,
-    response::{self, Response},
+    get::{RetryPolicy, RetryPredicate},
+    resource::{Filters, Resource},
+    response::{self, Response, Standings, Table},
 };
 
 /// An error that may occur while processing a [`Resource`] HTTP request from the jolpica-f1 API,
@@ -40,16 +44,42 @@ pub enum Error {
     #[cfg(feature = "fantasy")]
     YamlParse(serde_yaml::Error),
 
+    /// Error writing CSV data, passing through the [`csv::Error`] from [`csv::Writer`] methods,
+    /// e.g. from [`Race::to_csv`](response::Race::to_csv).
+    #[cfg(feature = "csv")]
+    Csv(csv::Error),
+
+    /// Underlying HTTP error of an [`AsyncAgent`] request, passing through [`reqwest::Error`] from
+    /// [`reqwest::RequestBuilder::send`], e.g. a connection failure. HTTP `4xx` and `5xx` statuses
+    /// are instead returned as [`Error::Http`] with [`ureq::Error::StatusCode`], as for [`Agent`].
+    #[cfg(feature = "async")]
+    AsyncHttp(reqwest::Error),
+
     /// A request has exceeded the maximum number of allowed retries on HTTP errors.
     HttpRetries((usize /* retries */, ureq::Error)),
+    /// The jolpica-f1 API responded with HTTP `429 Too Many Requests` and a `Retry-After` header,
+    /// holding the requested delay before retrying. Without the header, an [`Error::Http`] with
+    /// [`ureq::Error::StatusCode`] is returned instead. See [`RetryPolicy`].
+    RetryAfter(std::time::Duration),
     /// A request by a method supporting only single-page responses resulted in a multi-page one.
     MultiPage,
+    /// A request for a [`Resource`] with [`Filters::season_range`] set was made via a method that
+    /// only supports a single URL, e.g. [`Agent::get_response_page`], since a range of seasons has
+    /// to be split into separate requests. The contained string is the requested endpoint.
+    UnsupportedSeasonRange(String),
     /// A request resulted in a response that did not contain the expected [`Table`] variant.
-    BadTableVariant,
-    /// A request resulted in a response that did not contain the expected [`Payload`] variant.
+    BadTableVariant {
+        /// The expected [`Table`] variant.
+        expected: TableKind,
+        /// The actual [`Table`] variant.
+        actual: TableKind,
+    },
+    /// A request resulted in a response that did not contain the expected [`Payload`] variant, or
+    /// [`Standings`] variant.
     BadPayloadVariant,
     /// A request resulted in a response that did not contain any of the expected elements.
-    NotFound,
+    /// The contained string describes what was not found, e.g. the requested [`Resource`] endpoint.
+    NotFound(String),
     /// A request resulted in a response that contained more than the expected number of elements.
     TooMany,
     /// A request for multiple pages would or has exceeded the maximum allowed number of pages.
@@ -63,6 +93,224 @@ pub enum Error {
     EmptyResponseList,
     /// A generic error for when unexpected data was found during processing of a response.
     UnexpectedData(String),
+    /// A request was cancelled by the caller, e.g. via [`Agent::get_response_cancellable`], before
+    /// all of its pages had been retrieved.
+    Cancelled,
+}
+
+/// A field-less discriminant of [`Error`], as returned by [`Error::kind`].
+///
+/// This allows callers to match on the kind of error that occurred, e.g. to handle
+/// [`ErrorKind::NotFound`] differently from other errors, without having to match on, or import
+/// the types of, the data held by each [`Error`] variant, e.g. [`ureq::Error`].
+/// Each variant corresponds to the [`Error`] variant of the same name.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ErrorKind {
+    /// See [`Error::Http`].
+    Http,
+    /// See [`Error::Io`].
+    Io,
+    /// See [`Error::Parse`].
+    Parse,
+    /// See [`Error::YamlParse`].
+    #[cfg(feature = "fantasy")]
+    YamlParse,
+    /// See [`Error::Csv`].
+    #[cfg(feature = "csv")]
+    Csv,
+    /// See [`Error::AsyncHttp`].
+    #[cfg(feature = "async")]
+    AsyncHttp,
+    /// See [`Error::HttpRetries`].
+    HttpRetries,
+    /// See [`Error::RetryAfter`].
+    RetryAfter,
+    /// See [`Error::MultiPage`].
+    MultiPage,
+    /// See [`Error::UnsupportedSeasonRange`].
+    UnsupportedSeasonRange,
+    /// See [`Error::BadTableVariant`].
+    BadTableVariant,
+    /// See [`Error::BadPayloadVariant`].
+    BadPayloadVariant,
+    /// See [`Error::NotFound`].
+    NotFound,
+    /// See [`Error::TooMany`].
+    TooMany,
+    /// See [`Error::ExceededMaxPageCount`].
+    ExceededMaxPageCount,
+    /// See [`Error::BadResponseInfo`].
+    BadResponseInfo,
+    /// See [`Error::BadPagination`].
+    BadPagination,
+    /// See [`Error::EmptyResponseList`].
+    EmptyResponseList,
+    /// See [`Error::UnexpectedData`].
+    UnexpectedData,
+    /// See [`Error::Cancelled`].
+    Cancelled,
+}
+
+impl Error {
+    /// Returns the [`ErrorKind`] discriminant of this error, for matching on the kind of error
+    /// without needing to match on, or import the types of, the data held by each variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::error::{Error, ErrorKind};
+    /// #
+    /// let error = Error::Http(ureq::Error::StatusCode(404));
+    ///
+    /// match error.kind() {
+    ///     ErrorKind::NotFound | ErrorKind::TooMany => panic!("unexpected result count"),
+    ///     ErrorKind::Http => {}
+    ///     _ => panic!("unexpected error"),
+    /// }
+    ///
+    /// assert_eq!(error.kind(), ErrorKind::Http);
+    /// assert_eq!(Error::NotFound(String::new()).kind(), ErrorKind::NotFound);
+    /// ```
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Http(_) => ErrorKind::Http,
+            Self::Io(_) => ErrorKind::Io,
+            Self::Parse(_) => ErrorKind::Parse,
+            #[cfg(feature = "fantasy")]
+            Self::YamlParse(_) => ErrorKind::YamlParse,
+            #[cfg(feature = "csv")]
+            Self::Csv(_) => ErrorKind::Csv,
+            #[cfg(feature = "async")]
+            Self::AsyncHttp(_) => ErrorKind::AsyncHttp,
+            Self::HttpRetries(_) => ErrorKind::HttpRetries,
+            Self::RetryAfter(_) => ErrorKind::RetryAfter,
+            Self::MultiPage => ErrorKind::MultiPage,
+            Self::UnsupportedSeasonRange(_) => ErrorKind::UnsupportedSeasonRange,
+            Self::BadTableVariant { .. } => ErrorKind::BadTableVariant,
+            Self::BadPayloadVariant => ErrorKind::BadPayloadVariant,
+            Self::NotFound(_) => ErrorKind::NotFound,
+            Self::TooMany => ErrorKind::TooMany,
+            Self::ExceededMaxPageCount(_) => ErrorKind::ExceededMaxPageCount,
+            Self::BadResponseInfo(_) => ErrorKind::BadResponseInfo,
+            Self::BadPagination(_) => ErrorKind::BadPagination,
+            Self::EmptyResponseList => ErrorKind::EmptyResponseList,
+            Self::UnexpectedData(_) => ErrorKind::UnexpectedData,
+            Self::Cancelled => ErrorKind::Cancelled,
+        }
+    }
+
+    /// Returns `true` if this error is likely to be transient, i.e. worth retrying later, or
+    /// `false` if retrying the same request is expected to fail in the same way.
+    ///
+    /// Transient errors are [`Error::Http`] errors for server errors, i.e. HTTP `5xx`, rate
+    /// limiting, i.e. HTTP `429 Too Many Requests`, timeouts, and connection failures, as well as
+    /// [`Error::HttpRetries`] for any of those, [`Error::RetryAfter`], and [`Error::Io`] errors
+    /// that indicate an interrupted connection, and [`Error::AsyncHttp`] timeouts and connection
+    /// failures. All other errors, e.g. [`Error::NotFound`], [`Error::Parse`], or
+    /// [`Error::BadTableVariant`], are not transient.
+    ///
+    /// This can be used directly as a [`RetryPredicate`], via [`AgentConfigs::retry_predicate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::error::Error;
+    /// #
+    /// assert!(Error::Http(ureq::Error::StatusCode(503)).is_transient());
+    /// assert!(Error::Http(ureq::Error::StatusCode(429)).is_transient());
+    /// assert!(!Error::Http(ureq::Error::StatusCode(404)).is_transient());
+    /// assert!(!Error::NotFound(String::new()).is_transient());
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Http(error) | Self::HttpRetries((_, error)) => is_transient_http_error(error),
+            Self::RetryAfter(_) => true,
+            Self::Io(error) => is_transient_io_error(error),
+            #[cfg(feature = "async")]
+            Self::AsyncHttp(error) => error.is_timeout() || error.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Returns a copy of this error, e.g. for each duplicate [`Resource`] of a failed request in
+    /// [`Agent::get_batch`], since [`Error`] cannot implement [`Clone`].
+    ///
+    /// Variants holding errors that cannot be cloned, e.g. [`Error::Parse`], are rebuilt from the
+    /// kind and message of the original error. The copy has the same [`Error::kind`], except for
+    /// [`Error::AsyncHttp`], which is copied as an [`Error::Io`], and the same
+    /// [`Error::is_transient`], but the original source of the error is not preserved.
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Self::Http(error) => Self::Http(duplicate_http_error(error)),
+            Self::Io(error) => Self::Io(duplicate_io_error(error)),
+            Self::Parse(error) => Self::Parse(serde::de::Error::custom(error)),
+            #[cfg(feature = "fantasy")]
+            Self::YamlParse(error) => Self::YamlParse(serde::de::Error::custom(error)),
+            #[cfg(feature = "csv")]
+            Self::Csv(error) => Self::Csv(std::io::Error::other(error.to_string()).into()),
+            #[cfg(feature = "async")]
+            Self::AsyncHttp(error) => Self::Io(std::io::Error::other(error.to_string())),
+            Self::HttpRetries((retries, error)) => Self::HttpRetries((*retries, duplicate_http_error(error))),
+            Self::RetryAfter(delay) => Self::RetryAfter(*delay),
+            Self::MultiPage => Self::MultiPage,
+            Self::UnsupportedSeasonRange(endpoint) => Self::UnsupportedSeasonRange(endpoint.clone()),
+            Self::BadTableVariant { expected, actual } => Self::BadTableVariant {
+                expected: *expected,
+                actual: *actual,
+            },
+            Self::BadPayloadVariant => Self::BadPayloadVariant,
+            Self::NotFound(context) => Self::NotFound(context.clone()),
+            Self::TooMany => Self::TooMany,
+            Self::ExceededMaxPageCount(counts) => Self::ExceededMaxPageCount(*counts),
+            Self::BadResponseInfo(message) => Self::BadResponseInfo(message.clone()),
+            Self::BadPagination(message) => Self::BadPagination(message.clone()),
+            Self::EmptyResponseList => Self::EmptyResponseList,
+            Self::UnexpectedData(message) => Self::UnexpectedData(message.clone()),
+            Self::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+/// Returns `true` if the [`ureq::Error`] is transient, as per [`Error::is_transient`].
+fn is_transient_http_error(error: &ureq::Error) -> bool {
+    match error {
+        ureq::Error::StatusCode(status) => *status >= 500 || *status == 429,
+        ureq::Error::Timeout(_) | ureq::Error::ConnectionFailed | ureq::Error::BodyStalled => true,
+        ureq::Error::Io(error) => is_transient_io_error(error),
+        _ => false,
+    }
+}
+
+/// Returns a copy of the [`ureq::Error`], as per [`Error::duplicate`].
+fn duplicate_http_error(error: &ureq::Error) -> ureq::Error {
+    match error {
+        ureq::Error::StatusCode(status) => ureq::Error::StatusCode(*status),
+        ureq::Error::Timeout(timeout) => ureq::Error::Timeout(*timeout),
+        ureq::Error::ConnectionFailed => ureq::Error::ConnectionFailed,
+        ureq::Error::BodyStalled => ureq::Error::BodyStalled,
+        ureq::Error::Io(error) => ureq::Error::Io(duplicate_io_error(error)),
+        _ => ureq::Error::Other(error.to_string().into()),
+    }
+}
+
+/// Returns a copy of the [`std::io::Error`], with the same kind and message.
+fn duplicate_io_error(error: &std::io::Error) -> std::io::Error {
+    std::io::Error::new(error.kind(), error.to_string())
+}
+
+/// Returns `true` if the [`std::io::Error`] indicates an interrupted connection or a timeout.
+fn is_transient_io_error(error: &std::io::Error) -> bool {
+    use std::io::ErrorKind;
+
+    matches!(
+        error.kind(),
+        ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::Interrupted
+            | ErrorKind::TimedOut
+            | ErrorKind::UnexpectedEof
+    )
 }
 
 impl std::fmt::Display for Error {
@@ -98,9 +346,17 @@ impl From<serde_yaml::Error> for Error {
     }
 }
 
-impl From<Table> for Error {
-    fn from(_: Table) -> Self {
-        Self::BadTableVariant
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+    fn from(error: csv::Error) -> Self {
+        Self::Csv(error)
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Self::AsyncHttp(error)
     }
 }
 
@@ -112,3 +368,107 @@ impl From<Payload> for Error {
 
 /// Convenience type alias for [`Result<T, f1_data::error::Error>`].
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use crate::tests::asserts::*;
+    use shadow_asserts::{assert_eq, assert_ne};
+
+    use super::*;
+
+    #[test]
+    fn error_is_transient() {
+        let io_error = |kind| std::io::Error::new(kind, "error");
+
+        assert_true!(Error::Http(ureq::Error::StatusCode(500)).is_transient());
+        assert_true!(Error::Http(ureq::Error::StatusCode(503)).is_transient());
+        assert_true!(Error::Http(ureq::Error::StatusCode(429)).is_transient());
+        assert_true!(Error::Http(ureq::Error::Timeout(ureq::Timeout::Global)).is_transient());
+        assert_true!(Error::Http(ureq::Error::ConnectionFailed).is_transient());
+        assert_true!(Error::Http(ureq::Error::Io(io_error(std::io::ErrorKind::ConnectionReset))).is_transient());
+        assert_true!(Error::HttpRetries((2, ureq::Error::StatusCode(502))).is_transient());
+        assert_true!(Error::Io(io_error(std::io::ErrorKind::TimedOut)).is_transient());
+        assert_true!(Error::RetryAfter(std::time::Duration::from_secs(1)).is_transient());
+
+        assert_false!(Error::Http(ureq::Error::StatusCode(400)).is_transient());
+        assert_false!(Error::Http(ureq::Error::StatusCode(404)).is_transient());
+        assert_false!(Error::Http(ureq::Error::HostNotFound).is_transient());
+        assert_false!(Error::HttpRetries((2, ureq::Error::StatusCode(404))).is_transient());
+        assert_false!(Error::Io(io_error(std::io::ErrorKind::NotFound)).is_transient());
+        assert_false!(Error::Parse(serde_json::from_str::<u32>("").unwrap_err()).is_transient());
+        assert_false!(Error::NotFound(String::new()).is_transient());
+        assert_false!(Error::MultiPage.is_transient());
+        assert_false!(Error::UnsupportedSeasonRange(String::new()).is_transient());
+        assert_false!(Error::BadPayloadVariant.is_transient());
+        assert_false!(Error::Cancelled.is_transient());
+    }
+
+    #[test]
+    fn error_duplicate() {
+        let io_error = |kind| std::io::Error::new(kind, "error");
+
+        let errors = [
+            Error::Http(ureq::Error::StatusCode(503)),
+            Error::Http(ureq::Error::StatusCode(404)),
+            Error::Http(ureq::Error::Timeout(ureq::Timeout::Global)),
+            Error::Http(ureq::Error::Io(io_error(std::io::ErrorKind::ConnectionReset))),
+            Error::Http(ureq::Error::HostNotFound),
+            Error::HttpRetries((2, ureq::Error::StatusCode(502))),
+            Error::Io(io_error(std::io::ErrorKind::TimedOut)),
+            Error::Io(io_error(std::io::ErrorKind::NotFound)),
+            Error::Parse(serde_json::from_str::<u32>("").unwrap_err()),
+            Error::RetryAfter(std::time::Duration::from_secs(1)),
+            Error::NotFound(String::from("/2023/drivers")),
+            Error::ExceededMaxPageCount((2, 1)),
+            Error::Cancelled,
+        ];
+
+        for error in &errors {
+            let duplicate = error.duplicate();
+            assert_eq!(duplicate.kind(), error.kind());
+            assert_eq!(duplicate.is_transient(), error.is_transient());
+        }
+
+        assert!(matches!(
+            Error::Http(ureq::Error::StatusCode(404)).duplicate(),
+            Error::Http(ureq::Error::StatusCode(404))
+        ));
+        assert!(matches!(
+            Error::NotFound(String::from("/2023/drivers")).duplicate(),
+            Error::NotFound(context) if context == "/2023/drivers"
+        ));
+    }
+
+    #[test]
+    fn error_kind() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "error");
+
+        assert_eq!(Error::Http(ureq::Error::StatusCode(404)).kind(), ErrorKind::Http);
+        assert_eq!(Error::Io(io_error).kind(), ErrorKind::Io);
+        assert_eq!(Error::Parse(serde_json::from_str::<u32>("").unwrap_err()).kind(), ErrorKind::Parse);
+        assert_eq!(Error::HttpRetries((2, ureq::Error::StatusCode(503))).kind(), ErrorKind::HttpRetries);
+        assert_eq!(Error::RetryAfter(std::time::Duration::from_secs(1)).kind(), ErrorKind::RetryAfter);
+        assert_eq!(Error::MultiPage.kind(), ErrorKind::MultiPage);
+        assert_eq!(Error::UnsupportedSeasonRange(String::new()).kind(), ErrorKind::UnsupportedSeasonRange);
+        assert_eq!(
+            Error::BadTableVariant {
+                expected: TableKind::Drivers,
+                actual: TableKind::Seasons
+            }
+            .kind(),
+            ErrorKind::BadTableVariant
+        );
+        assert_eq!(Error::BadPayloadVariant.kind(), ErrorKind::BadPayloadVariant);
+        assert_eq!(Error::NotFound(String::new()).kind(), ErrorKind::NotFound);
+        assert_eq!(Error::TooMany.kind(), ErrorKind::TooMany);
+        assert_eq!(Error::ExceededMaxPageCount((2, 1)).kind(), ErrorKind::ExceededMaxPageCount);
+        assert_eq!(Error::BadResponseInfo(String::new()).kind(), ErrorKind::BadResponseInfo);
+        assert_eq!(Error::BadPagination(String::new()).kind(), ErrorKind::BadPagination);
+        assert_eq!(Error::EmptyResponseList.kind(), ErrorKind::EmptyResponseList);
+        assert_eq!(Error::UnexpectedData(String::new()).kind(), ErrorKind::UnexpectedData);
+        assert_eq!(Error::Cancelled.kind(), ErrorKind::Cancelled);
+
+        assert_ne!(Error::NotFound(String::new()).kind(), ErrorKind::TooMany);
+    }
+}
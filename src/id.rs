@@ -3,9 +3,13 @@
 //! These types are used in interfaces throughout the [`f1_data`](crate) crate to refer to these
 //! entities in a consistent manner, regardless of the wrapper module or underlying data source.
 
+use std::{fmt::Display, str::FromStr};
+
 use serde::Deserialize;
 use serde_with::{DisplayFromStr, serde_as};
 
+use crate::error::{Error, Result};
+
 /// Uniquely identifies a driver by a string, e.g. `"max_verstappen"` for _Max Verstappen_
 pub type DriverID = String;
 
@@ -41,6 +45,10 @@ pub type RoundID = u32;
 /// let race_id = RaceID::from(2023, 1);
 /// assert_eq!(race_id.season, 2023);
 /// assert_eq!(race_id.round, 1);
+///
+/// assert_eq!("2023/1".parse::<RaceID>().unwrap(), race_id);
+/// assert_eq!("2023-1".parse::<RaceID>().unwrap(), race_id);
+/// assert_eq!(race_id.to_string(), "2023/1");
 /// ```
 #[serde_as]
 #[derive(Deserialize, Hash, Clone, Copy, PartialEq, Eq, Debug)]
@@ -60,6 +68,34 @@ impl RaceID {
     }
 }
 
+impl Display for RaceID {
+    /// Formats the [`RaceID`] as `"season/round"`, e.g. `"2023/1"`, the inverse of [`FromStr`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.season, self.round)
+    }
+}
+
+impl FromStr for RaceID {
+    type Err = Error;
+
+    /// Parses a [`RaceID`] from a `"season/round"` or `"season-round"` string, e.g. `"2023/1"` or
+    /// `"2023-1"`. Returns [`Error::UnexpectedData`] if `s` is not of either form, or if the season
+    /// or round is not a `u32` number.
+    fn from_str(s: &str) -> Result<Self> {
+        let (season, round) = s.split_once(['/', '-']).ok_or_else(|| {
+            Error::UnexpectedData(format!("Invalid race ID \"{s}\": expected \"season/round\" or \"season-round\""))
+        })?;
+
+        let parse = |field: &str, value: &str| {
+            value
+                .parse::<u32>()
+                .map_err(|err| Error::UnexpectedData(format!("Invalid {field} \"{value}\" in race ID \"{s}\": {err}")))
+        };
+
+        Ok(Self::from(parse("season", season)?, parse("round", round)?))
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage, coverage(off))]
 mod tests {
@@ -93,4 +129,31 @@ mod tests {
             RaceID::from(2023, 4)
         );
     }
+
+    #[test]
+    fn race_id_display_and_from_str() {
+        assert_eq!(RaceID::from(2021, 22).to_string(), "2021/22");
+        assert_eq!("2021/22".parse::<RaceID>().unwrap(), RaceID::from(2021, 22));
+        assert_eq!("2021-22".parse::<RaceID>().unwrap(), RaceID::from(2021, 22));
+        assert_eq!("1950/1".parse::<RaceID>().unwrap(), RaceID::from(1950, 1));
+
+        for id in [RaceID::from(2023, 1), RaceID::from(2024, 24)] {
+            assert_eq!(id.to_string().parse::<RaceID>().unwrap(), id);
+        }
+
+        for str in [
+            "",
+            "2021",
+            "2021/",
+            "/22",
+            "2021 22",
+            "2021/22/1",
+            "2021-22-1",
+            "a/22",
+            "2021/b",
+            "-1/22",
+        ] {
+            assert!(matches!(str.parse::<RaceID>(), Err(Error::UnexpectedData(_))), "{str}");
+        }
+    }
 }
@@ -1,30 +1,47 @@
 //! An [`Agent`], and associated configuration options and utilities, for accessing the
 //! [jolpica-f1](https://github.com/jolpica/jolpica-f1) API for querying Formula 1 data.
 
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+
 use crate::{
     error::{Error, Result},
-    id::{CircuitID, ConstructorID, DriverID, RaceID, SeasonID, StatusID},
+    id::{CircuitID, ConstructorID, DriverID, RaceID, RoundID, SeasonID, StatusID},
     jolpica::{
-        api::{JOLPICA_API_BASE_URL, JOLPICA_API_RATE_LIMIT_QUOTA},
-        concat::{PageVerify, concat_response_multi_pages},
-        get,
+        api::{JOLPICA_API_BASE_URL, JOLPICA_API_PAGINATION, JOLPICA_API_RATE_LIMIT_QUOTA, constructor_lineage},
+        backend::{HttpBackend, ResponseSource},
+        concat::{PageVerify, concat_response_multi_pages, concat_response_seasons},
+        get::{self, CacheValidators, ConditionalResponse, RetryPolicy, RetryPredicate},
         resource::{Filters, LapTimeFilters, Page, PitStopFilters, Resource},
         response::{
-            Circuit, Constructor, Driver, DriverLap, PayloadInnerList, PitStop, QualifyingResult, Race, RaceResult,
-            Response, Schedule, Season, SprintResult, Status, TableInnerList, Timing,
+            Circuit, Constructor, ConstructorStanding, Driver, DriverLap, DriverRound, DriverSeason, DriverStanding,
+            Lap, PayloadInnerList, PitStop, Points, QualifyingResult, Race, RaceResult, Response, Schedule, Season,
+            SprintResult, Standings, StandingsList, Status, TableInnerList, Timing, Weekend, WeekendSummary,
+            fastest_pit_stop, fastest_timing, verify_has_one_element_and_extract,
         },
+        stats::{self, LapMatrix},
+        time::Duration,
     },
     rate_limiter::RateLimiter,
 };
 
 #[cfg(doc)]
 use crate::jolpica::{
-    api::{JOLPICA_API_PAGINATION, JOLPICA_API_RATE_LIMIT},
-    response::{Lap, Pagination, Payload, Table},
+    api::{CONSTRUCTOR_LINEAGES, JOLPICA_API_RATE_LIMIT},
+    backend::MockBackend,
+    response::{FastestLap, Pagination, Payload, Table},
+    time::RaceTime,
 };
 
 /// Options to configure the behavior of an [`Agent`], e.g. rate limiting, multi-page handling, etc.
-#[derive(Debug)]
 pub struct AgentConfigs<'a> {
     /// Configuration for the base URL at which to make requests to the jolpica-f1 API.
     ///
@@ -32,39 +49,163 @@ pub struct AgentConfigs<'a> {
     /// can be overridden, for example, to point to a local instance of the jolpica-f1 API server.
     pub base_url: String,
 
+    /// Configuration for the [`ResponseSource`] from which every page of every [`Resource`] is
+    /// requested, e.g. [`HttpBackend`] to make GET requests to the jolpica-f1 API at
+    /// [`base_url`](Self::base_url), or [`MockBackend`] to serve canned responses.
+    ///
+    /// All other configurations, e.g. rate limiting, retries, and caching, apply regardless of the
+    /// backend, so [`RateLimiterOption::None`] is usually desirable for backends other than
+    /// [`HttpBackend`], e.g. in tests. See the [`backend`](crate::jolpica::backend) module.
+    pub backend: Arc<dyn ResponseSource>,
+
+    /// Configuration for the `User-Agent` header sent with every GET request, if [`Some`].
+    ///
+    /// The jolpica-f1 API's [terms of service](https://github.com/jolpica/jolpica-f1/blob/main/TERMS.md)
+    /// encourage identifying the client, e.g. `Some("my-f1-app/1.0".into())`, so that the API
+    /// maintainers can identify its traffic. If [`None`], the HTTP client's default is sent.
+    pub user_agent: Option<String>,
+
+    /// Configuration for additional HTTP headers sent with every GET request, as `name -> value`.
+    ///
+    /// These are set after [`user_agent`](Self::user_agent), so a `User-Agent` entry here takes
+    /// precedence. Headers set by the [`Agent`] itself, e.g. `If-None-Match` for
+    /// [`conditional_requests`](Self::conditional_requests), take precedence over these.
+    pub extra_headers: BTreeMap<String, String>,
+
     /// Configuration for handling multi-page responses from the jolpica-f1 API.
     pub multi_page: MultiPageOption,
 
+    /// Configuration for the maximum number of pages of a multi-page response to request
+    /// concurrently, if [`multi_page`](Self::multi_page) is [`MultiPageOption::Enabled`].
+    ///
+    /// The first page is always requested on its own, since its [`Pagination::total`] is needed to
+    /// determine the remaining pages. If this is greater than `1`, the remaining pages are then
+    /// requested from up to this many threads at once, otherwise they are requested sequentially.
+    /// Either way, the [`Response`]s are concatenated in page order.
+    ///
+    /// **Note:** Concurrent requests are still subject to the configured
+    /// [`rate_limiter`](Self::rate_limiter), which is shared by all threads, so the speedup is
+    /// bounded by its quota, e.g. the burst size of [`JOLPICA_API_RATE_LIMIT`].
+    pub multi_page_concurrency: usize,
+
     /// Configuration to enable retrying GET calls if they return [`Error::Http`].
     ///
-    /// If [`Some(n)`](Some) where `n > 0`, and if any GET requests made to the jolpica-f1 API
-    /// return [`Error::Http`], then the call will be repeated until [`Ok`] or some non-HTTP error
-    /// is returned, up to `n` times. If all attempts result in [`Error::Http`], then an
-    /// [`Error::HttpRetries`] is returned. If [`None`] or [`Some(0)`](Some), no retries are
-    /// performed.
+    /// If [`Some(policy)`](Some) where [`RetryPolicy::max_retries`] is `n > 0`, and if any GET
+    /// requests made to the jolpica-f1 API return [`Error::Http`] or [`Error::RetryAfter`], then
+    /// the call will be repeated until [`Ok`] or some non-HTTP error is returned, up to `n` times,
+    /// waiting for the [`RetryPolicy::delay`] or the requested `Retry-After` before each retry. If
+    /// all attempts result in [`Error::Http`], then an [`Error::HttpRetries`] is returned. If
+    /// [`None`] or `n == 0`, no retries are performed. See [`get::retry_with_policy`].
     ///
     /// **Note:**: If enabled, the maximum number of retries applies to each individual GET request
     /// made, including each one made as part of handling multi-page responses, so the total number
     /// of retries may exceed this configured value.
-    pub http_retries: Option<usize>,
+    pub http_retries: Option<RetryPolicy>,
+
+    /// Configuration to customize which errors are retried, if [`http_retries`](Self::http_retries)
+    /// is enabled.
+    ///
+    /// If [`Some`], the [`RetryPredicate`] is consulted before each retry, and the GET request is
+    /// only retried if it returns `true` for the encountered [`Error`], e.g. to retry on server
+    /// errors, HTTP `5xx`, but not on client errors, HTTP `4xx`, as per [`Error::is_transient`]. If
+    /// [`None`], only [`Error::Http`] and [`Error::RetryAfter`] errors are retried. See
+    /// [`get::retry_with_policy`] for more details.
+    pub retry_predicate: Option<Arc<RetryPredicate>>,
+
+    /// Configuration to enable conditional GET requests, to save bandwidth when polling data.
+    ///
+    /// If `true`, the [`Agent`] caches each [`Response`] along with its `ETag` and `Last-Modified`
+    /// headers, if any, and sends them via `If-None-Match` and `If-Modified-Since` on subsequent
+    /// identical requests. If the jolpica-f1 API responds with `304 Not Modified`, the cached
+    /// [`Response`] is returned instead. See [`Agent::get_response_page_with_freshness`].
+    ///
+    /// **Note:** Conditional requests are still subject to the configured rate limiting, and the
+    /// cache grows with every distinct request made, until [`Agent::clear_response_cache`].
+    pub conditional_requests: bool,
+
+    /// Configuration to verify that the [`Table`] variant of every [`Response`] corresponds to the
+    /// requested [`Resource`], via [`Response::verify_matches`].
+    ///
+    /// If `true`, a mismatch, e.g. due to a server bug, results in an [`Error::BadTableVariant`]
+    /// naming the expected and actual variants, as soon as the response is received, rather than
+    /// at extraction time. This is mostly useful when pointing the [`Agent`] at alternate or
+    /// experimental servers, via [`base_url`](Self::base_url).
+    pub verify_table_variant: bool,
+
+    /// Configuration for a filesystem-backed cache of [`Response`]s, to avoid repeated requests.
+    ///
+    /// If [`CacheOption::Directory`], the raw JSON of each successful response is written to a file
+    /// in the given directory, named after the full request URL, and subsequent identical requests
+    /// are served from that file, without any network requests or rate limiting, until it expires
+    /// as per [`cache_expiry`](Self::cache_expiry). Unlike the in-memory cache used for
+    /// [`conditional_requests`](Self::conditional_requests), this cache is shared across [`Agent`]
+    /// instances and program runs. See [`Agent::get_response_page_with_freshness`].
+    ///
+    /// **Note:** If enabled, this cache takes precedence over
+    /// [`conditional_requests`](Self::conditional_requests), i.e. requests made after a cache miss
+    /// are never conditional. Cache entries are not validated beyond parsing, so a directory
+    /// shared by agents with different [`base_url`](Self::base_url)s is fine, but one containing
+    /// foreign or corrupted files may result in [`Error::Parse`].
+    pub cache: CacheOption,
+
+    /// Configuration for when entries of the [`cache`](Self::cache) expire, if it is enabled.
+    pub cache_expiry: CacheExpiry,
 
     /// Configuration for rate limiting of GET requests to the jolpica-f1 API.
     pub rate_limiter: RateLimiterOption<'a>,
 }
 
+impl std::fmt::Debug for AgentConfigs<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AgentConfigs")
+            .field("base_url", &self.base_url)
+            .field("backend", &self.backend)
+            .field("user_agent", &self.user_agent)
+            .field("extra_headers", &self.extra_headers)
+            .field("multi_page", &self.multi_page)
+            .field("multi_page_concurrency", &self.multi_page_concurrency)
+            .field("http_retries", &self.http_retries)
+            .field("retry_predicate", &self.retry_predicate.as_ref().map(|_| "Fn(&Error) -> bool"))
+            .field("conditional_requests", &self.conditional_requests)
+            .field("verify_table_variant", &self.verify_table_variant)
+            .field("cache", &self.cache)
+            .field("cache_expiry", &self.cache_expiry)
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
+}
+
 impl Default for AgentConfigs<'_> {
     /// Creates a new [`AgentConfigs`] with default settings.
     ///
     /// The default settings are:
     ///  - Base URL set to [`JOLPICA_API_BASE_URL`]
+    ///  - Requests made to the jolpica-f1 API via [`HttpBackend`]
+    ///  - No custom `User-Agent` or additional headers, i.e. the HTTP client's defaults
     ///  - Multi-page response handling [`MultiPageOption::Enabled`] with no max page count limit
-    ///  - Retries on HTTP errors enabled with `2` maximum retries per individual GET request
+    ///  - Pages of multi-page responses requested sequentially, i.e. no concurrency
+    ///  - Retries on HTTP errors enabled with `2` maximum retries per individual GET request, with
+    ///    [`RetryPolicy::exponential`] backoff starting from `500ms`
+    ///  - No custom retry predicate, i.e. all [`Error::Http`] and [`Error::RetryAfter`] are retried
+    ///  - Conditional requests disabled, i.e. no responses are cached
+    ///  - No verification of the [`Table`] variant of responses against the requested [`Resource`]
+    ///  - No filesystem cache, [`CacheOption::None`], and if enabled, entries for past seasons never
+    ///    expire, while all others expire after an hour, [`CacheExpiry::ImmutablePastSeasons`]
     ///  - Enabled rate limiting [`RateLimiterOption::Internal`] with [`JOLPICA_API_RATE_LIMIT`]
     fn default() -> Self {
         Self {
             base_url: JOLPICA_API_BASE_URL.to_string(),
+            backend: Arc::new(HttpBackend),
+            user_agent: None,
+            extra_headers: BTreeMap::new(),
             multi_page: MultiPageOption::Enabled(None),
-            http_retries: Some(2),
+            multi_page_concurrency: 1,
+            http_retries: Some(RetryPolicy::exponential(2, std::time::Duration::from_millis(500))),
+            retry_predicate: None,
+            conditional_requests: false,
+            verify_table_variant: false,
+            cache: CacheOption::None,
+            cache_expiry: CacheExpiry::ImmutablePastSeasons(std::time::Duration::from_hours(1)),
             rate_limiter: RateLimiterOption::Internal(RateLimiter::new(JOLPICA_API_RATE_LIMIT_QUOTA)),
         }
     }
@@ -92,6 +233,67 @@ impl RateLimiterOption<'_> {
     }
 }
 
+/// Options for configuring a filesystem-backed cache of [`Response`]s, see [`AgentConfigs::cache`].
+#[derive(Clone, Debug)]
+pub enum CacheOption {
+    /// No filesystem caching is performed, i.e. every request is made to the jolpica-f1 API.
+    None,
+    /// Responses are cached as JSON files in the given directory, one per distinct request URL.
+    /// The directory, and any missing parents, are created on the first write to the cache. Files
+    /// are written atomically, and any that cannot be read or parsed are requested again.
+    Directory(PathBuf),
+}
+
+/// Options for configuring when entries of a [`CacheOption::Directory`] cache expire, based on the
+/// time since they were written, see [`AgentConfigs::cache_expiry`].
+///
+/// Expired entries are not removed, but they are overwritten by the next identical request.
+#[derive(Copy, Clone, Debug)]
+pub enum CacheExpiry {
+    /// Cached responses never expire, e.g. for purely historical analysis. Stale entries must be
+    /// removed manually, by deleting files from the cache directory.
+    Never,
+    /// Cached responses expire once they are older than the given duration.
+    After(std::time::Duration),
+    /// Cached responses for [`Resource`]s filtered by a past season, as per [`Resource::season`],
+    /// never expire, since historical data does not change. All others, i.e. those for the current
+    /// season or not filtered by season, expire once they are older than the given duration.
+    ImmutablePastSeasons(std::time::Duration),
+}
+
+impl CacheExpiry {
+    /// Returns `true` if a cached response for the given [`Resource`], written `age` ago, has
+    /// expired and should be requested again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use f1_data::jolpica::{agent::CacheExpiry, resource::{Filters, Resource}};
+    /// #
+    /// let expiry = CacheExpiry::ImmutablePastSeasons(Duration::from_mins(1));
+    /// let (hour, second) = (Duration::from_hours(1), Duration::from_secs(1));
+    ///
+    /// assert!(!expiry.is_expired(&Resource::RaceResults(Filters::new().season(1950)), hour));
+    /// assert!(expiry.is_expired(&Resource::SeasonList(Filters::none()), hour));
+    /// assert!(!expiry.is_expired(&Resource::SeasonList(Filters::none()), second));
+    /// ```
+    pub fn is_expired(&self, resource: &Resource, age: std::time::Duration) -> bool {
+        match self {
+            Self::Never => false,
+            Self::After(max_age) => age >= *max_age,
+            Self::ImmutablePastSeasons(max_age) => {
+                let current_season = time::OffsetDateTime::now_utc().year();
+                let is_past_season = resource
+                    .season()
+                    .is_some_and(|season| i32::try_from(season).is_ok_and(|season| season < current_season));
+
+                !is_past_season && age >= *max_age
+            }
+        }
+    }
+}
+
 /// Options for configuring multi-page response handling from the jolpica-f1 API.
 ///
 /// The jolpica-f1 API supports a maximum of
@@ -145,10 +347,74 @@ impl From<MultiPageOption> for Option<usize> {
 ///
 /// The jolpica-f1 API is a drop-in replacement for the now defunct
 /// [Ergast API](https://github.com/jolpica/jolpica-f1/blob/main/docs/ergast_differences.md).
+///
+/// # Generic fetching
+///
+/// Most `get_*` methods are thin aliases of a few generic methods, which can be used directly by
+/// generic code, e.g. libraries building on top of this crate:
+///  - [`get_table_list::<T>`](Self::get_table_list) requests all elements of a [`TableInnerList`],
+///    e.g. [`Season`] or [`Driver`], and [`get_first_element::<T>`](Self::get_first_element)
+///    requests only the first one, if any.
+///  - [`get_table_list_single_element::<T>`](Self::get_table_list_single_element) requests
+///    exactly one element of a [`TableInnerList`] by its [`IdFilter::ID`], e.g. a [`SeasonID`].
+///  - [`get_session_results::<T>`](Self::get_session_results) and its variants, e.g.
+///    [`get_session_result_for_events::<T>`](Self::get_session_result_for_events), request the
+///    results of a [`SessionResult`], e.g. [`QualifyingResult`], [`SprintResult`], or
+///    [`RaceResult`], with different expectations about the number of races and results.
+///
+/// The [`Resource`] to request is derived from `T` via [`ToResource`].
+///
+/// # Thread safety
+///
+/// [`Agent`] is [`Send`] and [`Sync`], so a single instance can be shared across threads, e.g. via
+/// an [`Arc<Agent>`](Arc) in a concurrent server, and all of its methods take `&self`. Its interior
+/// state, i.e. the response cache used for [`AgentConfigs::conditional_requests`], is guarded by a
+/// [`Mutex`], the [`RetryPredicate`] is required to be [`Send`] + [`Sync`], and the configured
+/// [`RateLimiter`] is itself thread-safe, so concurrent requests share the same rate limit quota.
+/// Requests made concurrently from multiple threads are not otherwise serialized.
+///
+/// ```no_run
+/// # use std::{sync::Arc, thread};
+/// # use f1_data::jolpica::agent::Agent;
+/// let jolpica = Arc::new(Agent::default());
+///
+/// let handles: Vec<_> = [2022, 2023]
+///     .into_iter()
+///     .map(|season| {
+///         let jolpica = Arc::clone(&jolpica);
+///         thread::spawn(move || jolpica.get_round_winners(season).unwrap())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert!(!handle.join().unwrap().is_empty());
+/// }
+/// ```
 #[allow(missing_copy_implementations)]
 #[derive(Debug)]
 pub struct Agent<'a> {
     configs: AgentConfigs<'a>,
+    /// Cached [`Response`]s and their [`CacheValidators`], keyed by request URL, used if
+    /// [`AgentConfigs::conditional_requests`] is enabled.
+    response_cache: Mutex<BTreeMap<String, (CacheValidators, Response)>>,
+}
+
+/// Indicates whether a [`Response`] was freshly returned by the jolpica-f1 API, or was cached.
+///
+/// A cached [`Response`] is returned either if the API responded with `304 Not Modified`, or if it
+/// was read from the filesystem cache, without making any requests.
+///
+/// See [`AgentConfigs::conditional_requests`], [`AgentConfigs::cache`], and
+/// [`Agent::get_response_page_with_freshness`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Freshness {
+    /// The [`Response`] was freshly returned by the jolpica-f1 API.
+    Fresh,
+    /// The jolpica-f1 API responded with `304 Not Modified`, and a cached [`Response`] was used.
+    NotModified,
+    /// The [`Response`] was read from the filesystem cache, as per [`AgentConfigs::cache`], and no
+    /// request was made to the jolpica-f1 API.
+    Cached,
 }
 
 impl Default for Agent<'_> {
@@ -161,7 +427,10 @@ impl Default for Agent<'_> {
 impl<'a> Agent<'a> {
     /// Creates a new [`Agent`] with the given [`AgentConfigs`].
     pub const fn new(configs: AgentConfigs<'a>) -> Self {
-        Self { configs }
+        Self {
+            configs,
+            response_cache: Mutex::new(BTreeMap::new()),
+        }
     }
 
     /// Performs a GET request to the jolpica-f1 API for a specific page of the specified
@@ -178,6 +447,11 @@ impl<'a> Agent<'a> {
     /// use one of the other convenience `get_*` methods, e.g. [`get_seasons`][Self::get_seasons],
     /// in almost all cases, but this method is provided for maximum flexibility.
     ///
+    /// # Errors
+    ///
+    /// If the [`Resource`] has a [`Filters::season_range`], which cannot be requested via a single
+    /// page, an [`Error::UnsupportedSeasonRange`] is returned without making any requests.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -207,13 +481,198 @@ impl<'a> Agent<'a> {
     /// assert!(resp.pagination.is_last_page());
     /// ```
     pub fn get_response_page(&self, resource: &Resource, page: Page) -> Result<Response> {
-        get::retry_on_http_error(
-            || get::get_response_page(&self.configs.base_url, resource, Some(page)),
+        self.get_response_opt_page(resource, Some(page))
+            .map(|(response, _)| response)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for a specific page of the specified
+    /// [`Resource`], also returning the [`Freshness`] of the resulting [`Response`].
+    ///
+    /// This behaves like [`get_response_page`](Self::get_response_page), but additionally
+    /// indicates whether the [`Response`] was freshly returned by the jolpica-f1 API, or whether a
+    /// cached [`Response`] was used, either after a `304 Not Modified`, if
+    /// [`AgentConfigs::conditional_requests`] is enabled, or from the filesystem cache, if
+    /// [`AgentConfigs::cache`] is enabled. Otherwise, [`Freshness::Fresh`] is always returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{
+    /// #     agent::{Agent, AgentConfigs, Freshness},
+    /// #     resource::{Filters, Page, Resource},
+    /// # };
+    /// let jolpica = Agent::new(AgentConfigs {
+    ///     conditional_requests: true,
+    ///     ..AgentConfigs::default()
+    /// });
+    ///
+    /// let resource = Resource::SeasonList(Filters::none());
+    ///
+    /// let (_, freshness) = jolpica
+    ///     .get_response_page_with_freshness(&resource, Page::default())
+    ///     .unwrap();
+    /// assert_eq!(freshness, Freshness::Fresh);
+    ///
+    /// // If the jolpica-f1 API supports conditional requests, this may be `NotModified`
+    /// let (resp, freshness) = jolpica
+    ///     .get_response_page_with_freshness(&resource, Page::default())
+    ///     .unwrap();
+    /// println!("{freshness:?}: {resp:?}");
+    /// ```
+    pub fn get_response_page_with_freshness(&self, resource: &Resource, page: Page) -> Result<(Response, Freshness)> {
+        self.get_response_opt_page(resource, Some(page))
+    }
+
+    /// Clears all [`Response`]s cached due to [`AgentConfigs::conditional_requests`], if any.
+    pub fn clear_response_cache(&self) {
+        self.lock_response_cache().clear();
+    }
+
+    /// Returns the [`RateLimiter`] configured via [`AgentConfigs::rate_limiter`], if any, e.g. to
+    /// check [`RateLimiter::available`] before making a large number of requests.
+    pub const fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.configs.rate_limiter.get()
+    }
+
+    /// Performs a GET request for an optional page of the specified [`Resource`], with retries, as
+    /// per [`AgentConfigs::http_retries`], and, if enabled, [`AgentConfigs::conditional_requests`]
+    /// and [`AgentConfigs::verify_table_variant`].
+    fn get_response_opt_page(&self, resource: &Resource, page: Option<Page>) -> Result<(Response, Freshness)> {
+        // A .season_range cannot be represented in a URL, it must be split into separate requests
+        if resource.season_range().is_some() {
+            return Err(Error::UnsupportedSeasonRange(resource.to_endpoint()));
+        }
+
+        let (response, freshness) = self.get_response_opt_page_unverified(resource, page)?;
+
+        if self.configs.verify_table_variant {
+            response.verify_matches(resource)?;
+        }
+
+        Ok((response, freshness))
+    }
+
+    /// Implementation of [`Agent::get_response_opt_page`], without [`Response::verify_matches`].
+    fn get_response_opt_page_unverified(
+        &self,
+        resource: &Resource,
+        page: Option<Page>,
+    ) -> Result<(Response, Freshness)> {
+        match &self.configs.cache {
+            CacheOption::None => self.get_response_opt_page_uncached(resource, page),
+            CacheOption::Directory(dir) => self.get_response_opt_page_cached(dir, resource, page),
+        }
+    }
+
+    /// Implementation of [`Agent::get_response_opt_page_unverified`] for
+    /// [`CacheOption::Directory`], serving responses from, and writing them to, the cache `dir`.
+    fn get_response_opt_page_cached(
+        &self,
+        dir: &Path,
+        resource: &Resource,
+        page: Option<Page>,
+    ) -> Result<(Response, Freshness)> {
+        let url = resource.to_url_with_base_and_opt_page(&self.configs.base_url, page);
+        let path = dir.join(cache_file_name(url.as_str()));
+
+        let is_fresh = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| {
+                !self
+                    .configs
+                    .cache_expiry
+                    .is_expired(resource, modified.elapsed().unwrap_or_default())
+            });
+
+        // A cache file that cannot be read or parsed, e.g. if corrupted, is treated as a cache miss
+        if is_fresh && let Some(response) = read_cache_file(&path) {
+            return Ok((response, Freshness::Cached));
+        }
+
+        let headers = self.request_headers();
+        let json_str = self.retry(|| {
+            self.configs
+                .backend
+                .get_json_page(&self.configs.base_url, resource, page, &headers)
+        })?;
+        let response = serde_json::from_str::<Response>(&json_str)?;
+
+        fs::create_dir_all(dir)?;
+        write_cache_file(&path, &json_str)?;
+
+        Ok((response, Freshness::Fresh))
+    }
+
+    /// Implementation of [`Agent::get_response_opt_page_unverified`] for [`CacheOption::None`],
+    /// with [`AgentConfigs::conditional_requests`], if enabled.
+    fn get_response_opt_page_uncached(&self, resource: &Resource, page: Option<Page>) -> Result<(Response, Freshness)> {
+        let headers = self.request_headers();
+
+        if !self.configs.conditional_requests {
+            return self
+                .retry(|| {
+                    self.configs
+                        .backend
+                        .get_response_page(&self.configs.base_url, resource, page, &headers)
+                })
+                .map(|response| (response, Freshness::Fresh));
+        }
+
+        let url = resource
+            .to_url_with_base_and_opt_page(&self.configs.base_url, page)
+            .to_string();
+
+        let validators = self
+            .lock_response_cache()
+            .get(&url)
+            .map(|(validators, _)| validators.clone());
+
+        match self.retry(|| {
+            self.configs.backend.get_response_page_conditional(
+                &self.configs.base_url,
+                resource,
+                page,
+                validators.as_ref(),
+                &headers,
+            )
+        })? {
+            ConditionalResponse::Modified(response, validators) => {
+                if !validators.is_empty() {
+                    let _unused = self
+                        .lock_response_cache()
+                        .insert(url, (validators, (*response).clone()));
+                }
+                Ok((*response, Freshness::Fresh))
+            }
+            ConditionalResponse::NotModified => self
+                .lock_response_cache()
+                .get(&url)
+                .map(|(_, response)| (response.clone(), Freshness::NotModified))
+                .ok_or_else(|| Error::UnexpectedData(format!("Received 304 Not Modified for uncached URL: {url}"))),
+        }
+    }
+
+    /// Returns the `(name, value)` pairs of the HTTP headers to send with every request, as per
+    /// [`AgentConfigs::user_agent`] and [`AgentConfigs::extra_headers`], in that order.
+    fn request_headers(&self) -> Vec<(&str, &str)> {
+        request_headers(self.configs.user_agent.as_ref(), &self.configs.extra_headers)
+    }
+
+    /// Calls `f` via [`get::retry_with_policy`], with the configured rate limiting and retry options.
+    fn retry<T>(&self, f: impl Fn() -> Result<T>) -> Result<T> {
+        get::retry_with_policy(
+            f,
             self.configs.rate_limiter.get(),
-            self.configs.http_retries,
+            self.configs.http_retries.as_ref(),
+            self.configs.retry_predicate.as_deref(),
         )
     }
 
+    /// Locks and returns the [`Response`] cache used for [`AgentConfigs::conditional_requests`].
+    fn lock_response_cache(&self) -> MutexGuard<'_, BTreeMap<String, (CacheValidators, Response)>> {
+        self.response_cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Performs GET requests to the jolpica-f1 API for all pages of the specified [`Resource`],
     /// starting from the `initial_page`, and returns a vector of [`Response`]s, one per page.
     ///
@@ -235,7 +694,9 @@ impl<'a> Agent<'a> {
     /// # Errors
     ///
     /// If `max_page_count` is specified, and the total number of pages would exceed it, then an
-    /// [`Error::ExceededMaxPageCount`] is returned and no requests beyond the first are made.
+    /// [`Error::ExceededMaxPageCount`] is returned and no requests beyond the first are made. If
+    /// the [`Resource`] has a [`Filters::season_range`], an [`Error::UnsupportedSeasonRange`] is
+    /// returned without making any requests.
     ///
     /// # Examples
     ///
@@ -269,14 +730,45 @@ impl<'a> Agent<'a> {
         initial_page: Option<Page>,
         max_page_count: Option<usize>,
     ) -> Result<Vec<Response>> {
-        get::get_response_multi_pages(
-            &self.configs.base_url,
-            resource,
-            initial_page,
-            max_page_count,
-            self.configs.rate_limiter.get(),
-            self.configs.http_retries,
-        )
+        get::get_multi_pages_with(initial_page, max_page_count, self.configs.multi_page_concurrency, |page| {
+            self.get_response_opt_page(resource, page).map(|(response, _)| response)
+        })
+    }
+
+    /// Performs GET requests to the jolpica-f1 API for all pages of the specified [`Resource`],
+    /// starting from an arbitrary `offset`, and returns a vector of [`Response`]s, one per page.
+    ///
+    /// This is equivalent to [`get_response_multi_pages`](Self::get_response_multi_pages) with an
+    /// `initial_page` of [`Page::with(limit, offset)`](Page::with) and no `max_page_count`. It
+    /// allows a long-running fetch to persist its progress, e.g. the [`Pagination::offset`] of the
+    /// last [`Response`] that was processed, and later resume from there instead of from the start.
+    ///
+    /// # Errors
+    ///
+    /// Any errors from the underlying requests, e.g. [`Error::Http`], are returned. If the
+    /// [`Resource`] has a [`Filters::season_range`], an [`Error::UnsupportedSeasonRange`] is
+    /// returned without making any requests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` exceeds the maximum limit in [`JOLPICA_API_PAGINATION`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::{Filters, Resource}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let responses = jolpica
+    ///     .get_response_from_offset(&Resource::SeasonList(Filters::none()), 50, 10)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(responses.first().unwrap().pagination.offset, 50);
+    /// assert!(responses.last().unwrap().pagination.is_last_page());
+    /// assert_eq!(responses.first().unwrap().table.as_seasons().unwrap()[0].season, 2000);
+    /// ```
+    pub fn get_response_from_offset(&self, resource: &Resource, offset: u32, limit: u32) -> Result<Vec<Response>> {
+        self.get_response_multi_pages(resource, Some(Page::with(limit, offset)), None)
     }
 
     /// Performs a GET request to the jolpica-f1 API for a specified [`Resource`] and returns a
@@ -291,6 +783,10 @@ impl<'a> Agent<'a> {
     /// [`Response`]s are then concatenated into a single [`Response`], via
     /// [`concat_response_multi_pages`].
     ///
+    /// If the [`Resource`] has a [`Filters::season_range`], a separate request, or multi-page
+    /// requests, as above, is made for each season in the range, and the resulting [`Response`]s
+    /// are merged into a single [`Response`], via [`concat_response_seasons`].
+    ///
     /// Aside from potentially concatenating multiple [`Response`]s, this method performs no
     /// additional processing; it returns the top-level [`Response`] type that is a direct
     /// representation of the full JSON response. It is expected that users will use one of the
@@ -328,6 +824,10 @@ impl<'a> Agent<'a> {
     /// assert_eq!(seasons[73].season, 2023);
     /// ```
     pub fn get_response(&self, resource: &Resource) -> Result<Response> {
+        if resource.season_range().is_some() {
+            return get_response_season_range(resource, |resource| self.get_response(resource));
+        }
+
         if self.configs.multi_page.is_enabled() {
             self.get_response_multi_pages(resource, Some(Page::with_max_limit()), self.configs.multi_page.into())
                 .and_then(|responses| concat_response_multi_pages(responses, PageVerify::ALL))
@@ -337,6 +837,145 @@ impl<'a> Agent<'a> {
         }
     }
 
+    /// Performs a GET request for a specified [`Resource`], like [`Agent::get_response`], but can be
+    /// cancelled mid-flight via the caller-supplied `cancel` flag.
+    ///
+    /// The `cancel` flag is checked before each page request, including the first, so setting it
+    /// from another thread, e.g. when a user navigates away from a UI view, aborts a long-running
+    /// multi-page fetch before its next page is requested. Any pages already retrieved are dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the `cancel` flag is set before all pages have been retrieved, then an
+    /// [`Error::Cancelled`] is returned. Otherwise, the same errors as [`Agent::get_response`] may
+    /// be returned, e.g. [`Error::MultiPage`] or [`Error::ExceededMaxPageCount`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::sync::atomic::AtomicBool;
+    /// # use f1_data::{error::Error, jolpica::{agent::Agent, resource::{Filters, Resource}}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let resource = Resource::SeasonList(Filters::none());
+    ///
+    /// let resp = jolpica
+    ///     .get_response_cancellable(&resource, &AtomicBool::new(false))
+    ///     .unwrap();
+    /// assert!(resp.table.as_seasons().unwrap().len() >= 74);
+    ///
+    /// assert!(matches!(
+    ///     jolpica.get_response_cancellable(&resource, &AtomicBool::new(true)),
+    ///     Err(Error::Cancelled)
+    /// ));
+    /// ```
+    pub fn get_response_cancellable(&self, resource: &Resource, cancel: &AtomicBool) -> Result<Response> {
+        if resource.season_range().is_some() {
+            return get_response_season_range(resource, |resource| self.get_response_cancellable(resource, cancel));
+        }
+
+        let get_page = |page| {
+            if cancel.load(Ordering::Relaxed) {
+                return Err(Error::Cancelled);
+            }
+            self.get_response_opt_page(resource, page).map(|(response, _)| response)
+        };
+
+        if self.configs.multi_page.is_enabled() {
+            get::get_multi_pages_with(
+                Some(Page::with_max_limit()),
+                self.configs.multi_page.into(),
+                self.configs.multi_page_concurrency,
+                get_page,
+            )
+            .and_then(|responses| concat_response_multi_pages(responses, PageVerify::ALL))
+        } else {
+            get_page(Some(Page::with_max_limit())).and_then(verify_is_single_page)
+        }
+    }
+
+    /// Performs GET requests for a batch of [`Resource`]s, as per [`Agent::get_response`], returning
+    /// the results in the same order as the `resources`.
+    ///
+    /// Duplicate [`Resource`]s in the batch are only requested once, and the result is copied to
+    /// each of the duplicate positions, which saves requests under the rate limiter. A [`Response`]
+    /// is cloned, and since [`Error`]s cannot be cloned, an [`Error`] is copied with the same
+    /// [`Error::kind`] and message, but without its original source.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::{Filters, Resource}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let seasons = Resource::SeasonList(Filters::none());
+    /// let drivers = Resource::DriverInfo(Filters::new().season(2023));
+    ///
+    /// // Only two requests are made, for the two unique resources
+    /// let results = jolpica.get_batch(&[seasons.clone(), drivers, seasons]);
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(results[0].as_ref().unwrap(), results[2].as_ref().unwrap());
+    /// ```
+    pub fn get_batch(&self, resources: &[Resource]) -> Vec<Result<Response>> {
+        let mut results: Vec<Result<Response>> = Vec::with_capacity(resources.len());
+
+        for (idx, resource) in resources.iter().enumerate() {
+            // Copy the result of the first occurrence of a duplicate instead of requesting it again
+            let result = resources[..idx]
+                .iter()
+                .position(|previous| previous == resource)
+                .map_or_else(
+                    || self.get_response(resource),
+                    |first_idx| match &results[first_idx] {
+                        Ok(response) => Ok(response.clone()),
+                        Err(err) => Err(err.duplicate()),
+                    },
+                );
+            results.push(result);
+        }
+
+        results
+    }
+
+    /// Estimates the number of pages, i.e. requests, that [`Agent::get_response`] would need to
+    /// fetch all of the results for the specified [`Resource`], with [`Page::with_max_limit`].
+    ///
+    /// This performs a single, cheap request for a page with a limit of `1`, and computes the page
+    /// count from its [`Pagination::total`]. It can be used, for example, to warn users before
+    /// starting a large multi-page fetch, which may take a long time under rate limiting. A
+    /// [`Resource`] with no results is estimated to take `1` page, as it still requires a request.
+    ///
+    /// # Errors
+    ///
+    /// Any errors from the underlying request, e.g. [`Error::Http`], are returned. If the
+    /// [`Resource`] has a [`Filters::season_range`], an [`Error::UnsupportedSeasonRange`] is
+    /// returned without making any requests.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::{LapTimeFilters, Resource}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let pages = jolpica
+    ///     .estimate_pages(&Resource::LapTimes(LapTimeFilters::new(2023, 4)))
+    ///     .unwrap();
+    /// assert!(pages > 1);
+    /// ```
+    pub fn estimate_pages(&self, resource: &Resource) -> Result<usize> {
+        let total = self.get_response_page(resource, Page::with_limit(1))?.pagination.total;
+        let pages = total.div_ceil(JOLPICA_API_PAGINATION.max_limit).max(1);
+
+        Ok(pages as usize)
+    }
+
+    /// Performs a GET request for `resource` via [`Agent::get_response`], and extracts the result
+    /// from the [`Response`] with `extract`, adding the endpoint of `resource` as context to any
+    /// [`Error::NotFound`] that is returned, e.g. `"/2023/4/laps: expected one element, found none"`.
+    fn get_and_extract<T>(&self, resource: &Resource, extract: impl FnOnce(Response) -> Result<T>) -> Result<T> {
+        extract(self.get_response(resource)?).map_err(|err| with_endpoint_context(resource, err))
+    }
+
     /// Performs a GET request to the jolpica-f1 API for the [`Resource`] associated with the
     /// [`TableInnerList`], with the argument [`Filters`], and returns the resulting inner list from
     /// [`Response::table`], from the variant associated with the [`TableInnerList`].
@@ -365,7 +1004,47 @@ impl<'a> Agent<'a> {
     /// assert_eq!(seasons[73].season, 2023);
     /// ```
     pub fn get_table_list<T: ToResource + TableInnerList>(&self, filters: Filters) -> Result<Vec<T>> {
-        self.get_response(&T::to_resource(filters))?.into_table_list::<T>()
+        self.get_and_extract(&T::to_resource(filters), Response::into_table_list::<T>)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for the [`Resource`] associated with the
+    /// [`TableInnerList`], with the argument [`Filters`], and returns the first element of the
+    /// resulting inner list from [`Response::table`], without checking whether there are others.
+    ///
+    /// This is useful when any matching element will do, unlike
+    /// [`get_table_list_single_element`](Self::get_table_list_single_element), which returns an
+    /// [`Error::TooMany`] if more than one element matches. Only a single element is requested,
+    /// i.e. with [`Page::with_limit`] of `1`, so this is cheap even if many elements match. The
+    /// first element is as ordered by the jolpica-f1 API, e.g. the earliest [`Season`].
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if no elements match the [`Filters`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{error::Error, jolpica::{agent::Agent, resource::Filters, response::Season}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let season = jolpica.get_first_element::<Season>(Filters::none()).unwrap();
+    /// assert_eq!(season.season, 1950);
+    ///
+    /// assert!(matches!(
+    ///     jolpica.get_first_element::<Season>(Filters::new().season(1940)),
+    ///     Err(Error::NotFound(_))
+    /// ));
+    /// ```
+    pub fn get_first_element<T: ToResource + TableInnerList>(&self, filters: Filters) -> Result<T> {
+        let resource = T::to_resource(filters);
+
+        self.get_response_page(&resource, Page::with_limit(1))?
+            .into_table_list::<T>()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Error::NotFound(format!("{}: expected at least one element, found none", resource.to_endpoint()))
+            })
     }
 
     /// Performs a GET request to the jolpica-f1 API for a single element of the [`Resource`]
@@ -399,11 +1078,10 @@ impl<'a> Agent<'a> {
     ///         .season,
     ///     1950
     /// );
-    /// assert!(matches!(jolpica.get_table_list_single_element::<Season>(1940), Err(Error::NotFound)));
+    /// assert!(matches!(jolpica.get_table_list_single_element::<Season>(1940), Err(Error::NotFound(_))));
     /// ```
     pub fn get_table_list_single_element<T: ToResource + IdFilter + TableInnerList>(&self, id: T::ID) -> Result<T> {
-        self.get_response(&T::to_resource(T::id_filter(id)))?
-            .into_single_table_list_element::<T>()
+        self.get_and_extract(&T::to_resource(T::id_filter(id)), Response::into_single_table_list_element::<T>)
     }
 
     /// Performs a GET request to the jolpica-f1 API for [`Resource::SeasonList`], with the argument
@@ -443,73 +1121,230 @@ impl<'a> Agent<'a> {
     /// # let jolpica = Agent::default();
     /// #
     /// assert_eq!(jolpica.get_season(1950).unwrap().season, 1950);
-    /// assert!(matches!(jolpica.get_season(1940), Err(Error::NotFound)));
+    /// assert!(matches!(jolpica.get_season(1940), Err(Error::NotFound(_))));
     /// ```
     pub fn get_season(&self, season: SeasonID) -> Result<Season> {
         self.get_table_list_single_element::<Season>(season)
     }
 
-    /// Performs a GET request to the jolpica-f1 API for [`Resource::DriverInfo`], with the argument
-    /// [`Filters`], and returns the resulting inner [`Driver`]s from [`Table`] in
-    /// [`Response::table`].
+    /// Performs a GET request to the jolpica-f1 API for a single [`Season`], identified by a
+    /// [`SeasonID`], via [`Agent::get_season`], returning `Ok(None)` if the season is not found.
     ///
     /// # Errors
     ///
-    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
-    /// `drivers` would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
-    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
-    /// the total number of pages would exceed it.
+    /// The same errors as [`Agent::get_season`] may be returned, except for [`Error::NotFound`].
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use f1_data::jolpica::{agent::Agent, resource::Filters};
+    /// # use f1_data::jolpica::agent::Agent;
     /// # let jolpica = Agent::default();
     /// #
-    /// let drivers = jolpica.get_drivers(Filters::new().season(2022)).unwrap();
-    /// assert!(!drivers.is_empty());
-    /// assert_eq!(
-    ///     drivers
-    ///         .iter()
-    ///         .find(|driver| driver.driver_id == "alonso".to_string())
-    ///         .unwrap()
-    ///         .given_name,
-    ///     "Fernando".to_string()
-    /// );
+    /// assert_eq!(jolpica.get_season_opt(1950).unwrap().unwrap().season, 1950);
+    /// assert!(jolpica.get_season_opt(1940).unwrap().is_none());
     /// ```
-    pub fn get_drivers(&self, filters: Filters) -> Result<Vec<Driver>> {
-        self.get_table_list::<Driver>(filters)
+    pub fn get_season_opt(&self, season: SeasonID) -> Result<Option<Season>> {
+        not_found_as_none(self.get_season(season))
     }
 
-    /// Performs a GET request to the jolpica-f1 API for a single [`Driver`], identified by a
-    /// [`DriverID`], from [`Resource::DriverInfo`].
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::SeasonList`], and returns the
+    /// [`SeasonID`] of the current season, i.e. the latest season supported by the API.
     ///
     /// # Errors
     ///
-    /// An [`Error::NotFound`] is returned if the driver is not found.
+    /// An [`Error::NotFound`] is returned if no seasons are found.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use f1_data::{error::Error, id::DriverID, jolpica::agent::Agent};
+    /// # use f1_data::jolpica::agent::Agent;
     /// # let jolpica = Agent::default();
     /// #
-    /// assert_eq!(
-    ///     jolpica
-    ///         .get_driver(DriverID::from("alonso"))
-    ///         .unwrap()
-    ///         .given_name,
-    ///     "Fernando".to_string()
-    /// );
-    /// assert!(matches!(jolpica.get_driver(DriverID::from("unknown")), Err(Error::NotFound)));
+    /// assert!(jolpica.get_current_season().unwrap() >= 2025);
     /// ```
-    pub fn get_driver(&self, driver_id: DriverID) -> Result<Driver> {
-        self.get_table_list_single_element::<Driver>(driver_id)
+    pub fn get_current_season(&self) -> Result<SeasonID> {
+        self.get_seasons(Filters::none())?
+            .iter()
+            .map(|season| season.season)
+            .max()
+            .ok_or_else(|| Error::NotFound(String::from("no seasons found")))
     }
 
-    /// Performs a GET request to the jolpica-f1 API for [`Resource::ConstructorInfo`], with the
-    /// argument [`Filters`], and returns the resulting [`Constructor`]s from [`Table`] in
-    /// [`Response::table`].
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::SeasonList`], and returns the
+    /// [`Season`]s between `start` and `end`, inclusive, sorted in ascending order.
+    ///
+    /// An empty list is returned, rather than an error, if the range is outside of the seasons
+    /// supported by the API, e.g. for seasons before `1950` or after the current season, or if
+    /// `start` is greater than `end`, in which case no request is made.
+    ///
+    /// # Errors
+    ///
+    /// Any errors from the underlying request, e.g. [`Error::Http`], are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let seasons = jolpica.get_seasons_between(2020, 2023).unwrap();
+    /// assert_eq!(seasons.iter().map(|s| s.season).collect::<Vec<_>>(), [2020, 2021, 2022, 2023]);
+    ///
+    /// assert!(jolpica.get_seasons_between(1900, 1949).unwrap().is_empty());
+    /// assert!(jolpica.get_seasons_between(2023, 2020).unwrap().is_empty());
+    /// ```
+    pub fn get_seasons_between(&self, start: SeasonID, end: SeasonID) -> Result<Vec<Season>> {
+        if start > end {
+            return Ok(Vec::new());
+        }
+
+        let mut seasons: Vec<_> = self
+            .get_seasons(Filters::none())?
+            .into_iter()
+            .filter(|season| (start..=end).contains(&season.season))
+            .collect();
+
+        seasons.sort_by_key(|season| season.season);
+        Ok(seasons)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::DriverInfo`], with the argument
+    /// [`Filters`], and returns the resulting inner [`Driver`]s from [`Table`] in
+    /// [`Response::table`].
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// `drivers` would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
+    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
+    /// the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::Filters};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let drivers = jolpica.get_drivers(Filters::new().season(2022)).unwrap();
+    /// assert!(!drivers.is_empty());
+    /// assert_eq!(
+    ///     drivers
+    ///         .iter()
+    ///         .find(|driver| driver.driver_id == "alonso".to_string())
+    ///         .unwrap()
+    ///         .given_name,
+    ///     "Fernando".to_string()
+    /// );
+    /// ```
+    pub fn get_drivers(&self, filters: Filters) -> Result<Vec<Driver>> {
+        self.get_table_list::<Driver>(filters)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for a single [`Driver`], identified by a
+    /// [`DriverID`], from [`Resource::DriverInfo`].
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the driver is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{error::Error, id::DriverID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// assert_eq!(
+    ///     jolpica
+    ///         .get_driver(DriverID::from("alonso"))
+    ///         .unwrap()
+    ///         .given_name,
+    ///     "Fernando".to_string()
+    /// );
+    /// assert!(matches!(jolpica.get_driver(DriverID::from("unknown")), Err(Error::NotFound(_))));
+    /// ```
+    pub fn get_driver(&self, driver_id: DriverID) -> Result<Driver> {
+        self.get_table_list_single_element::<Driver>(driver_id)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for a single [`Driver`], identified by a
+    /// [`DriverID`], via [`Agent::get_driver`], returning `Ok(None)` if the driver is not found.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Agent::get_driver`] may be returned, except for [`Error::NotFound`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let driver = jolpica.get_driver_opt(DriverID::from("alonso")).unwrap();
+    /// assert_eq!(driver.unwrap().given_name, "Fernando");
+    /// assert!(jolpica.get_driver_opt(DriverID::from("unknown")).unwrap().is_none());
+    /// ```
+    pub fn get_driver_opt(&self, driver_id: DriverID) -> Result<Option<Driver>> {
+        not_found_as_none(self.get_driver(driver_id))
+    }
+
+    /// Performs GET requests to the jolpica-f1 API for the [`Driver`]s of the current season, as
+    /// resolved by [`Agent::get_current_season`], i.e. the drivers that are currently racing.
+    ///
+    /// This is equivalent to [`get_drivers`](Self::get_drivers) with [`Filters::season`] set to the
+    /// current season, but without having to first request the current season separately.
+    ///
+    /// **Note:** The jolpica-f1 API lists the drivers of a season based on its results, so the list
+    /// may be empty, or incomplete, before the first race of a new season.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the current season cannot be resolved. Otherwise, the
+    /// same errors as [`Agent::get_drivers`] may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let drivers = jolpica.get_current_drivers().unwrap();
+    /// assert!(drivers.len() >= 20);
+    /// ```
+    pub fn get_current_drivers(&self) -> Result<Vec<Driver>> {
+        self.get_drivers(Filters::new().season(self.get_current_season()?))
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::DriverInfo`], with the argument
+    /// [`Filters`], and returns only the [`Driver::driver_id`]s of the resulting [`Driver`]s.
+    ///
+    /// The jolpica-f1 API does not support selecting a subset of fields, so the full [`Driver`]s
+    /// are still requested, but they are projected into their IDs, e.g. to cheaply build an index.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Agent::get_drivers`] may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::{agent::Agent, resource::Filters}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let driver_ids = jolpica.get_drivers_minimal(Filters::new().season(2023)).unwrap();
+    /// assert_eq!(driver_ids.len(), 22);
+    /// assert!(driver_ids.contains(&DriverID::from("leclerc")));
+    /// ```
+    pub fn get_drivers_minimal(&self, filters: Filters) -> Result<Vec<DriverID>> {
+        Ok(self
+            .get_drivers(filters)?
+            .into_iter()
+            .map(|driver| driver.driver_id)
+            .collect())
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::ConstructorInfo`], with the
+    /// argument [`Filters`], and returns the resulting [`Constructor`]s from [`Table`] in
+    /// [`Response::table`].
     ///
     /// # Errors
     ///
@@ -563,13 +1398,60 @@ impl<'a> Agent<'a> {
     /// );
     /// assert!(matches!(
     ///     jolpica.get_constructor(ConstructorID::from("unknown")),
-    ///     Err(Error::NotFound)
+    ///     Err(Error::NotFound(_))
     /// ));
     /// ```
     pub fn get_constructor(&self, constructor_id: ConstructorID) -> Result<Constructor> {
         self.get_table_list_single_element::<Constructor>(constructor_id)
     }
 
+    /// Performs a GET request to the jolpica-f1 API for a single [`Constructor`], identified by a
+    /// [`ConstructorID`], via [`Agent::get_constructor`], returning `Ok(None)` if the constructor
+    /// is not found.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Agent::get_constructor`] may be returned, except for
+    /// [`Error::NotFound`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::ConstructorID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let constructor = jolpica.get_constructor_opt(ConstructorID::from("ferrari")).unwrap();
+    /// assert_eq!(constructor.unwrap().name, "Ferrari");
+    /// assert!(jolpica.get_constructor_opt(ConstructorID::from("unknown")).unwrap().is_none());
+    /// ```
+    pub fn get_constructor_opt(&self, constructor_id: ConstructorID) -> Result<Option<Constructor>> {
+        not_found_as_none(self.get_constructor(constructor_id))
+    }
+
+    /// Performs GET requests to the jolpica-f1 API for the [`Constructor`]s of the current season,
+    /// as resolved by [`Agent::get_current_season`], i.e. the teams that are currently racing.
+    ///
+    /// This is equivalent to [`get_constructors`](Self::get_constructors) with [`Filters::season`]
+    /// set to the current season, see [`Agent::get_current_drivers`].
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the current season cannot be resolved. Otherwise, the
+    /// same errors as [`Agent::get_constructors`] may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let constructors = jolpica.get_current_constructors().unwrap();
+    /// assert!(constructors.len() >= 10);
+    /// ```
+    pub fn get_current_constructors(&self) -> Result<Vec<Constructor>> {
+        self.get_constructors(Filters::new().season(self.get_current_season()?))
+    }
+
     /// Performs a GET request to the jolpica-f1 API for [`Resource::CircuitInfo`], with the
     /// argument [`Filters`], and returns the resulting inner [`Circuit`]s from [`Table`] in
     /// [`Response::table`].
@@ -622,12 +1504,121 @@ impl<'a> Agent<'a> {
     ///         .circuit_name,
     ///     "Circuit de Spa-Francorchamps".to_string()
     /// );
-    /// assert!(matches!(jolpica.get_circuit(CircuitID::from("unknown")), Err(Error::NotFound)));
+    /// assert!(matches!(jolpica.get_circuit(CircuitID::from("unknown")), Err(Error::NotFound(_))));
     /// ```
     pub fn get_circuit(&self, circuit_id: CircuitID) -> Result<Circuit> {
         self.get_table_list_single_element::<Circuit>(circuit_id)
     }
 
+    /// Performs a GET request to the jolpica-f1 API for a single [`Circuit`], identified by a
+    /// [`CircuitID`], via [`Agent::get_circuit`], returning `Ok(None)` if the circuit is not found.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Agent::get_circuit`] may be returned, except for [`Error::NotFound`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::CircuitID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let circuit = jolpica.get_circuit_opt(CircuitID::from("spa")).unwrap();
+    /// assert_eq!(circuit.unwrap().circuit_name, "Circuit de Spa-Francorchamps");
+    /// assert!(jolpica.get_circuit_opt(CircuitID::from("unknown")).unwrap().is_none());
+    /// ```
+    pub fn get_circuit_opt(&self, circuit_id: CircuitID) -> Result<Option<Circuit>> {
+        not_found_as_none(self.get_circuit(circuit_id))
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for a single [`Circuit`], identified by a
+    /// [`CircuitID`], via [`Agent::get_circuit`], and returns it along with the number of races
+    /// that it has hosted, according to [`Resource::RaceSchedule`].
+    ///
+    /// The race count is read from [`Pagination::total`] of a [`Page::with_limit`] of `1` request,
+    /// so this is cheap regardless of how many races the circuit has hosted.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the circuit is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{jolpica::agent::Agent, error::Error, id::CircuitID};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let (circuit, race_count) = jolpica
+    ///     .get_circuit_with_race_count(CircuitID::from("spa"))
+    ///     .unwrap();
+    /// assert_eq!(circuit.circuit_name, "Circuit de Spa-Francorchamps");
+    /// assert!(race_count >= 56);
+    /// ```
+    pub fn get_circuit_with_race_count(&self, circuit_id: CircuitID) -> Result<(Circuit, u32)> {
+        let resource = Resource::RaceSchedule(Filters::new().circuit_id(circuit_id.clone()));
+        let circuit = self.get_circuit(circuit_id)?;
+
+        let race_count = self.get_response_page(&resource, Page::with_limit(1))?.pagination.total;
+        Ok((circuit, race_count))
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::CircuitInfo`], with the
+    /// argument [`Filters`], via [`Agent::get_circuits`], and returns the resulting [`Circuit`]s
+    /// deduplicated by [`Circuit::circuit_id`] and sorted by [`Circuit::circuit_name`].
+    ///
+    /// This is a convenience method to get a clean and stable list of circuits, e.g. for an index.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Agent::get_circuits`] may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::Filters};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let circuits = jolpica.get_unique_circuits(Filters::none()).unwrap();
+    /// assert!(circuits.is_sorted_by(|lhs, rhs| lhs.circuit_name <= rhs.circuit_name));
+    /// ```
+    pub fn get_unique_circuits(&self, filters: Filters) -> Result<Vec<Circuit>> {
+        let mut seen = HashSet::new();
+
+        let mut circuits: Vec<Circuit> = self
+            .get_circuits(filters)?
+            .into_iter()
+            .filter(|circuit| seen.insert(circuit.circuit_id.clone()))
+            .collect();
+
+        circuits.sort_by(|lhs, rhs| lhs.circuit_name.cmp(&rhs.circuit_name));
+        Ok(circuits)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::CircuitInfo`], filtered by
+    /// [`Filters::driver_id`], and returns every [`Circuit`] at which the driver has competed,
+    /// deduplicated and sorted as per [`Agent::get_unique_circuits`].
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the circuits would not fit in a [`Page::with_max_limit`], which is unlikely even for the
+    /// longest careers. If [`MultiPageOption::Enabled`] is configured with a `max_page_count`, then
+    /// an [`Error::ExceededMaxPageCount`] is returned if the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let circuits = jolpica.get_circuits_for_driver(DriverID::from("alonso")).unwrap();
+    /// assert!(circuits.iter().any(|circuit| circuit.circuit_id == "baku"));
+    /// assert!(circuits.iter().any(|circuit| circuit.circuit_id == "indianapolis"));
+    /// ```
+    pub fn get_circuits_for_driver(&self, driver_id: DriverID) -> Result<Vec<Circuit>> {
+        self.get_unique_circuits(Filters::new().driver_id(driver_id))
+    }
+
     /// Performs a GET request to the jolpica-f1 API for [`Resource::RaceSchedule`], with the
     /// [`Filters`], and returns a sequence of [`Race<Schedule>`]s processed from the inner
     /// [`Race`]s from [`Table`].
@@ -665,8 +1656,7 @@ impl<'a> Agent<'a> {
     /// assert_eq!(races[0].time.unwrap(), time!(15:00:00));
     /// ```
     pub fn get_race_schedules(&self, filters: Filters) -> Result<Vec<Race<Schedule>>> {
-        self.get_response(&Resource::RaceSchedule(filters))?
-            .into_race_schedules()
+        self.get_and_extract(&Resource::RaceSchedule(filters), Response::into_race_schedules)
     }
 
     /// Performs a GET request to the jolpica-f1 API for a single [`Race<Schedule>`] from
@@ -703,8 +1693,31 @@ impl<'a> Agent<'a> {
     /// );
     /// ```
     pub fn get_race_schedule(&self, race_id: RaceID) -> Result<Race<Schedule>> {
-        self.get_response(&Resource::RaceSchedule(Filters::new().season(race_id.season).round(race_id.round)))?
-            .into_race_schedule()
+        self.get_and_extract(
+            &Resource::RaceSchedule(Filters::new().season(race_id.season).round(race_id.round)),
+            Response::into_race_schedule,
+        )
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for the [`Race<Schedule>`] of a race weekend,
+    /// identified by a [`RaceID`], and returns `true` if it was a sprint weekend, as per
+    /// [`Schedule::has_sprint`].
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the race is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::RaceID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// assert!(jolpica.is_sprint_weekend(RaceID::from(2023, 4)).unwrap());
+    /// assert!(!jolpica.is_sprint_weekend(RaceID::from(2023, 5)).unwrap());
+    /// ```
+    pub fn is_sprint_weekend(&self, race_id: RaceID) -> Result<bool> {
+        self.get_race_schedule(race_id).map(|race| race.schedule().has_sprint())
     }
 
     /// Performs a GET request to the jolpica-f1 API for the [`Resource`] corresponding to the
@@ -770,8 +1783,7 @@ impl<'a> Agent<'a> {
     /// assert_eq!(race_points + sprint_points, 585.5);
     /// ```
     pub fn get_session_results<T: SessionResult>(&self, filters: Filters) -> Result<Vec<Race<Vec<T>>>> {
-        self.get_response(&T::to_resource(filters))?
-            .into_many_races_with_many_session_results::<T>()
+        self.get_and_extract(&T::to_resource(filters), Response::into_many_races_with_many_session_results::<T>)
     }
 
     /// Performs a GET request to the jolpica-f1 API for the [`Resource`] corresponding to the
@@ -820,8 +1832,7 @@ impl<'a> Agent<'a> {
     /// assert_eq!(race.race_results()[1].position, 2);
     /// ```
     pub fn get_session_results_for_event<T: SessionResult>(&self, filters: Filters) -> Result<Race<Vec<T>>> {
-        self.get_response(&T::to_resource(filters))?
-            .into_one_race_with_many_session_results::<T>()
+        self.get_and_extract(&T::to_resource(filters), Response::into_one_race_with_many_session_results::<T>)
     }
 
     /// Performs a GET request to the jolpica-f1 API for the [`Resource`] corresponding to the
@@ -880,8 +1891,7 @@ impl<'a> Agent<'a> {
     /// assert_eq!(seb_poles, 57);
     /// ```
     pub fn get_session_result_for_events<T: SessionResult>(&self, filters: Filters) -> Result<Vec<Race<T>>> {
-        self.get_response(&T::to_resource(filters))?
-            .into_many_races_with_one_session_result::<T>()
+        self.get_and_extract(&T::to_resource(filters), Response::into_many_races_with_one_session_result::<T>)
     }
 
     /// Performs a GET request to the jolpica-f1 API for the [`Resource`] corresponding to the
@@ -927,8 +1937,7 @@ impl<'a> Agent<'a> {
     /// assert_eq!(race.sprint_result().driver.family_name, "Verstappen");
     /// ```
     pub fn get_session_result<T: SessionResult>(&self, filters: Filters) -> Result<Race<T>> {
-        self.get_response(&T::to_resource(filters))?
-            .into_one_race_with_one_session_result::<T>()
+        self.get_and_extract(&T::to_resource(filters), Response::into_one_race_with_one_session_result::<T>)
     }
 
     /// Alias for [`get_session_results::<QualifyingResult>`][Self::get_session_results].
@@ -996,59 +2005,761 @@ impl<'a> Agent<'a> {
         self.get_session_result::<RaceResult>(filters)
     }
 
-    /// Performs a GET request to the jolpica-f1 API for [`Resource::FinishingStatus`], with the
-    /// argument [`Filters`], and return the resulting inner [`Status`]s from [`Table`] in
-    /// [`Response::table`].
+    /// Performs GET requests to the jolpica-f1 API for [`Resource::RaceResults`] of a range of
+    /// consecutive `rounds` in a `season`, returning the [`Race`]s in order of [`Race::round`].
+    ///
+    /// One request is made per round, via [`Agent::get_race_results_for_event`], since the results
+    /// of a single race fit in a single page, whereas those of a full season require several pages.
+    /// Rounds whose results are not found, e.g. future rounds, are skipped rather than an error.
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting.
     ///
     /// # Errors
     ///
-    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
-    /// `status` would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
-    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
-    /// the total number of pages would exceed it.
+    /// Any errors from the underlying requests, aside from [`Error::NotFound`], are returned.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use f1_data::jolpica::{agent::Agent, resource::Filters};
+    /// # use f1_data::jolpica::agent::Agent;
     /// # let jolpica = Agent::default();
     /// #
-    /// let statuses = jolpica.get_statuses(Filters::none()).unwrap();
-    /// assert!(!statuses.is_empty());
-    /// assert_eq!(
-    ///     statuses
-    ///         .iter()
-    ///         .find(|status| status.status_id == 1)
-    ///         .unwrap()
-    ///         .status,
-    ///     "Finished".to_string()
-    /// );
+    /// let races = jolpica.get_race_results_for_rounds(2024, 1..=5).unwrap();
+    /// assert_eq!(races.len(), 5);
+    /// assert_eq!(races[0].race_name, "Bahrain Grand Prix");
     /// ```
-    pub fn get_statuses(&self, filters: Filters) -> Result<Vec<Status>> {
-        self.get_response(&Resource::FinishingStatus(filters))?.into_statuses()
+    pub fn get_race_results_for_rounds(
+        &self,
+        season: SeasonID,
+        rounds: RangeInclusive<RoundID>,
+    ) -> Result<Vec<Race<Vec<RaceResult>>>> {
+        rounds
+            .filter_map(|round| {
+                not_found_as_none(self.get_race_results_for_event(Filters::new().season(season).round(round)))
+                    .transpose()
+            })
+            .collect()
     }
 
-    /// Performs a GET request to the jolpica-f1 API for [`Resource::LapTimes`] from a specified
-    /// [`RaceID`] and for a specified single [`DriverID`].
+    /// Performs GET requests to the jolpica-f1 API to assemble a [`Weekend`] for a specified
+    /// [`RaceID`], i.e. its [`Schedule`] and all of its available session results.
     ///
-    /// It returns a list of [`DriverLap`]s, which is a flattened combination of [`Lap`]s and
-    /// [`Timing`]s.
+    /// It requests [`Resource::RaceSchedule`], and then [`Resource::QualifyingResults`],
+    /// [`Resource::SprintResults`], and [`Resource::RaceResults`]. The sprint results are only
+    /// requested if [`Schedule::sprint`] indicates that there is a sprint session. Session results
+    /// that are not found, e.g. for sessions that have not taken place yet, are set to [`None`].
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting.
     ///
     /// # Errors
     ///
-    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
-    /// `lap_times` would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
-    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
-    /// the total number of pages would exceed it.
+    /// An [`Error::NotFound`] is returned if the race is not found. Any other errors from the
+    /// underlying requests, aside from [`Error::NotFound`] for missing session results, are also
+    /// returned.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use f1_data::id::{DriverID, RaceID};
-    /// # use f1_data::jolpica::{agent::Agent, time::duration_m_s_ms};
+    /// # use f1_data::jolpica::agent::Agent;
     /// # let jolpica = Agent::default();
     /// #
-    /// let laps = jolpica
+    /// let weekend = jolpica.get_weekend(RaceID::from(2023, 4)).unwrap();
+    /// assert_eq!(weekend.schedule.race_name, "Azerbaijan Grand Prix");
+    /// assert_eq!(weekend.qualifying_results.unwrap()[0].driver.driver_id, DriverID::from("leclerc"));
+    /// assert_eq!(weekend.sprint_results.unwrap()[0].driver.driver_id, DriverID::from("perez"));
+    /// assert_eq!(weekend.race_results.unwrap()[0].driver.driver_id, DriverID::from("perez"));
+    /// ```
+    pub fn get_weekend(&self, race_id: RaceID) -> Result<Weekend> {
+        let schedule = self.get_race_schedule(race_id)?;
+        let filters = || Filters::new().season(race_id.season).round(race_id.round);
+
+        let qualifying_results =
+            not_found_as_none(self.get_qualifying_results_for_event(filters()))?.map(Race::into_qualifying_results);
+
+        let sprint_results = if schedule.schedule().has_sprint() {
+            not_found_as_none(self.get_sprint_results_for_event(filters()))?.map(Race::into_sprint_results)
+        } else {
+            None
+        };
+
+        let race_results = not_found_as_none(self.get_race_results_for_event(filters()))?.map(Race::into_race_results);
+
+        Ok(Weekend {
+            schedule,
+            qualifying_results,
+            sprint_results,
+            race_results,
+        })
+    }
+
+    /// Performs GET requests to the jolpica-f1 API to assemble a [`WeekendSummary`] for a specified
+    /// [`RaceID`], i.e. every driver's qualifying, sprint, and race results joined into a single
+    /// row per driver.
+    ///
+    /// The same requests as [`get_weekend`](Self::get_weekend) are made, and the results are then
+    /// joined by [`DriverID`], as per [`WeekendSummary::from`]. Sessions that did not take place,
+    /// e.g. sprint sessions, or in which a driver did not take part, have their results set to
+    /// [`None`] in the driver's row.
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting.
+    ///
+    /// # Errors
+    ///
+    /// Any errors from [`get_weekend`](Self::get_weekend), e.g. an [`Error::NotFound`] if the race
+    /// is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::RaceID, jolpica::{agent::Agent, response::Position}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let summary = jolpica.get_weekend_summary(RaceID::from(2023, 4)).unwrap();
+    /// assert_eq!(summary.drivers.len(), 20);
+    ///
+    /// let perez = &summary.drivers[0];
+    /// assert_eq!(perez.driver.driver_id, "perez");
+    /// assert_eq!(perez.qualifying_position(), Some(3));
+    /// assert_eq!(perez.sprint_position(), Some(Position::Finished(1)));
+    /// assert_eq!(perez.grid(), Some(3));
+    /// assert_eq!(perez.race_position(), Some(Position::Finished(1)));
+    /// ```
+    pub fn get_weekend_summary(&self, race_id: RaceID) -> Result<WeekendSummary> {
+        self.get_weekend(race_id).map(WeekendSummary::from)
+    }
+
+    /// Performs GET requests to the jolpica-f1 API to assemble a [`DriverSeason`] for a specified
+    /// driver and season, i.e. the driver's qualifying and race results for every round.
+    ///
+    /// Only two requests are made, for [`Resource::QualifyingResults`] and [`Resource::RaceResults`]
+    /// filtered by [`Filters::season`] and [`Filters::driver_id`], and the results are then aligned
+    /// by round. A round in which the driver has only one of the results, e.g. qualifying results
+    /// before the 2003 season, has the other one set to [`None`].
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the driver has no results at all in the season. Any
+    /// other errors from the underlying requests are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let season = jolpica.get_driver_season("leclerc".into(), 2023).unwrap();
+    /// assert_eq!(season.rounds.len(), 22);
+    /// assert_eq!(season.rounds[3].race.race_name, "Azerbaijan Grand Prix");
+    /// assert_eq!(season.rounds[3].qualifying_result.as_ref().unwrap().position, 1);
+    /// assert_eq!(season.rounds[3].race_result.as_ref().unwrap().position, 3);
+    /// ```
+    pub fn get_driver_season(&self, driver_id: DriverID, season: SeasonID) -> Result<DriverSeason> {
+        let filters = Filters::new().season(season).driver_id(driver_id);
+
+        let qualifying_results =
+            not_found_as_none(self.get_qualifying_result_for_events(filters.clone()))?.unwrap_or_default();
+        let race_results = not_found_as_none(self.get_race_result_for_events(filters.clone()))?.unwrap_or_default();
+
+        let new_round = |race_info| DriverRound {
+            race: race_info,
+            qualifying_result: None,
+            race_result: None,
+        };
+
+        let mut rounds = BTreeMap::new();
+
+        for race in qualifying_results {
+            let round = rounds.entry(race.round).or_insert_with(|| new_round(race.to_info()));
+            round.qualifying_result = Some(race.payload);
+        }
+
+        for race in race_results {
+            let round = rounds.entry(race.round).or_insert_with(|| new_round(race.to_info()));
+            round.race_result = Some(race.payload);
+        }
+
+        if rounds.is_empty() {
+            let driver_id = filters.driver_id.unwrap_or_default();
+            return Err(Error::NotFound(format!("no results found for driver {driver_id} in season {season}")));
+        }
+
+        Ok(DriverSeason {
+            rounds: rounds.into_values().collect(),
+        })
+    }
+
+    /// Performs GET requests to the jolpica-f1 API for the first and last [`Race<Schedule>`]s in
+    /// which a specified driver took part, i.e. the span of their career, in that order.
+    ///
+    /// Only up to two requests for [`Resource::RaceSchedule`], filtered by [`Filters::driver_id`],
+    /// are made, each for a single race, at the first and last offsets of the results, so this is
+    /// cheap even for drivers with long careers. For a driver that took part in a single race, both
+    /// of the returned races are the same. For an active driver, the last race is the latest one
+    /// for which the jolpica-f1 API has results, so it will change as the season progresses.
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the driver has not taken part in any races. Any other
+    /// errors from the underlying requests are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let (first, last) = jolpica.get_driver_career_span("raikkonen".into()).unwrap();
+    /// assert_eq!((first.season, first.race_name.as_str()), (2001, "Australian Grand Prix"));
+    /// assert_eq!((last.season, last.race_name.as_str()), (2021, "Abu Dhabi Grand Prix"));
+    /// ```
+    pub fn get_driver_career_span(&self, driver_id: DriverID) -> Result<(Race<Schedule>, Race<Schedule>)> {
+        let resource = Resource::RaceSchedule(Filters::new().driver_id(driver_id));
+
+        let response = self.get_response_page(&resource, Page::with_limit(1))?;
+        let total = response.pagination.total;
+
+        if total == 0 {
+            return Err(Error::NotFound(format!("{}: expected at least one race, found none", resource.to_endpoint())));
+        }
+
+        let first = response.into_race_schedule()?;
+        let last = if total > 1 {
+            self.get_response_page(&resource, Page::with(1, total - 1))?
+                .into_race_schedule()?
+        } else {
+            first.clone()
+        };
+
+        Ok((first, last))
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for the race results of a race weekend,
+    /// specified by a [`RaceID`], and returns each [`RaceResult`] paired with its gap to the winner.
+    ///
+    /// The gap is [`RaceTime::delta`] of [`RaceResult::time`], i.e. zero for the winner. Drivers
+    /// that did not finish on the lead lap, e.g. lapped or retired drivers, have no
+    /// [`RaceResult::time`], so their gap is [`None`]. The results are in the order returned by the
+    /// jolpica-f1 API, i.e. by finishing position.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the race results are not found. Any other errors from
+    /// the underlying request are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::RaceID, jolpica::{agent::Agent, time::{Duration, duration_s_ms}}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let results = jolpica.get_race_results_with_gaps(RaceID::from(2023, 4)).unwrap();
+    /// assert_eq!(results[0].1, Some(Duration::ZERO));
+    /// assert_eq!(results[1].1, Some(duration_s_ms(2, 137)));
+    /// assert_eq!(results.last().unwrap().1, None);
+    /// ```
+    pub fn get_race_results_with_gaps(&self, race_id: RaceID) -> Result<Vec<(RaceResult, Option<Duration>)>> {
+        Ok(self
+            .get_race_results_for_event(Filters::new().season(race_id.season).round(race_id.round))?
+            .into_race_results()
+            .into_iter()
+            .map(|result| {
+                let gap = result.time.map(|time| *time.delta());
+                (result, gap)
+            })
+            .collect())
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for the [`RaceResult`] of the winner of a race,
+    /// specified by a [`RaceID`].
+    ///
+    /// This requests the race results filtered by [`Filters::finish_pos`] of `1`, via
+    /// [`Agent::get_race_results_for_event`]. Historically, a car could be shared by more than one
+    /// driver during a race, e.g. in the 1950s, in which case more than one result may have been
+    /// classified first. Rather than an [`Error::TooMany`], as from [`Agent::get_race_result`], the
+    /// first such result, in the order returned by the jolpica-f1 API, is returned.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the race does not exist, or has no results yet. Any
+    /// other errors from the underlying request are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::{DriverID, RaceID}, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let winner = jolpica.get_race_winner(RaceID::from(2008, 18)).unwrap();
+    /// assert_eq!(winner.driver.driver_id, DriverID::from("massa"));
+    /// ```
+    pub fn get_race_winner(&self, race_id: RaceID) -> Result<RaceResult> {
+        self.get_race_results_for_event(Filters::new().season(race_id.season).round(race_id.round).finish_pos(1))?
+            .into_race_results()
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Error::NotFound(format!("no winner found for season {}, round {}", race_id.season, race_id.round))
+            })
+    }
+
+    /// Performs GET requests to the jolpica-f1 API to get the total points scored by each driver in
+    /// a race weekend, specified by a [`RaceID`], i.e. the sum of race and sprint points.
+    ///
+    /// [`RaceResult::points`] does not include points scored in a sprint, which are instead in
+    /// [`SprintResult::points`]. This method requests [`Resource::RaceResults`] and, only if
+    /// [`Schedule::sprint`] from [`Resource::RaceSchedule`] indicates that there is a sprint
+    /// session, [`Resource::SprintResults`], summing the points of each driver.
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the race, or its race or sprint results, are not
+    /// found. Any other errors from the underlying requests are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::id::{DriverID, RaceID};
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let points = jolpica.get_round_points(RaceID::from(2023, 4)).unwrap();
+    /// assert_eq!(points[&DriverID::from("perez")], 33.0); // 8 in the sprint, 25 in the race
+    /// ```
+    pub fn get_round_points(&self, race_id: RaceID) -> Result<HashMap<DriverID, Points>> {
+        let schedule = self.get_race_schedule(race_id)?;
+        let filters = || Filters::new().season(race_id.season).round(race_id.round);
+
+        let mut points: HashMap<DriverID, Points> = HashMap::new();
+
+        for result in self.get_race_results_for_event(filters())?.into_race_results() {
+            *points.entry(result.driver.driver_id).or_default() += result.points;
+        }
+
+        if schedule.schedule().has_sprint() {
+            for result in self.get_sprint_results_for_event(filters())?.into_sprint_results() {
+                *points.entry(result.driver.driver_id).or_default() += result.points;
+            }
+        }
+
+        Ok(points)
+    }
+
+    /// Performs GET requests to the jolpica-f1 API to get the total points scored by each driver in
+    /// a season, i.e. the sum of the points from all of their race and sprint results.
+    ///
+    /// Points are attributed to each driver's [`DriverID`], regardless of the constructor they
+    /// scored them with, e.g. for drivers who switched teams mid-season. Fractional points, e.g.
+    /// the half points awarded at the 2021 Belgian Grand Prix, are summed as-is. This method
+    /// requests [`Resource::RaceResults`] and [`Resource::SprintResults`] for the season, which
+    /// span multiple pages, so [`MultiPageOption::Enabled`] should be configured.
+    ///
+    /// **Note:** These totals are computed client-side from the session results, so they may differ
+    /// from [`DriverStanding::points`] for seasons in which not all results counted towards the
+    /// championship, e.g. when only a driver's best results were counted.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if no race results are found for the season. If
+    /// [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the results would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
+    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
+    /// the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let points = jolpica.get_season_driver_points(2021).unwrap();
+    /// assert_eq!(points[&DriverID::from("max_verstappen")], 395.5);
+    /// assert_eq!(points[&DriverID::from("hamilton")], 387.5);
+    /// ```
+    pub fn get_season_driver_points(&self, season: SeasonID) -> Result<HashMap<DriverID, Points>> {
+        let race_results = self.get_race_results(Filters::new().season(season))?;
+
+        if race_results.is_empty() {
+            return Err(Error::NotFound(format!("no race results found for season {season}")));
+        }
+
+        let mut points: HashMap<DriverID, Points> = HashMap::new();
+
+        for result in race_results.into_iter().flat_map(Race::into_race_results) {
+            *points.entry(result.driver.driver_id).or_default() += result.points;
+        }
+
+        for result in self
+            .get_sprint_results(Filters::new().season(season))?
+            .into_iter()
+            .flat_map(Race::into_sprint_results)
+        {
+            *points.entry(result.driver.driver_id).or_default() += result.points;
+        }
+
+        Ok(points)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for all of a driver's [`RaceResult`]s, and
+    /// returns the car number they raced with in each season, derived from [`RaceResult::number`].
+    ///
+    /// Unlike [`Driver::permanent_number`], this captures the car numbers actually used over time,
+    /// e.g. prior to the introduction of permanent numbers in 2014, or when a driver raced with
+    /// the reigning champion's number `1`. Any [`RaceResult::NO_NUMBER`] entries are filtered out.
+    /// If a driver used multiple car numbers in a single season, the one from the last race is used.
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the driver's results would not fit in a [`Page::with_max_limit`]. If
+    /// [`MultiPageOption::Enabled`] is configured with a `max_page_count`, then an
+    /// [`Error::ExceededMaxPageCount`] is returned if the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let numbers = jolpica
+    ///     .get_driver_numbers_by_season(DriverID::from("max_verstappen"))
+    ///     .unwrap();
+    /// assert_eq!(numbers[&2021], 33);
+    /// assert_eq!(numbers[&2022], 1);
+    /// ```
+    pub fn get_driver_numbers_by_season(&self, driver_id: DriverID) -> Result<HashMap<SeasonID, u32>> {
+        Ok(self
+            .get_race_result_for_events(Filters::new().driver_id(driver_id))?
+            .into_iter()
+            .filter_map(|race| race.race_result().car_number().map(|number| (race.season, number)))
+            .collect())
+    }
+
+    /// Performs GET requests to the jolpica-f1 API for all [`RaceResult`]s of a constructor across
+    /// all of its name changes, i.e. for every [`ConstructorID`] in its lineage.
+    ///
+    /// The lineage is looked up via [`constructor_lineage`], e.g. `"alphatauri"` also includes the
+    /// results for `"minardi"`, `"toro_rosso"`, and `"rb"`. The results of each [`ConstructorID`] are
+    /// requested via [`Agent::get_race_results`], and merged into a single list sorted by season and
+    /// round. If the team raced under multiple [`ConstructorID`]s in the same race, their results are
+    /// merged into a single [`Race`], sorted by [`RaceResult::position`].
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting. See the note on
+    /// [`CONSTRUCTOR_LINEAGES`] regarding IDs that are shared with unrelated historical teams.
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// any constructor's results would not fit in a [`Page::with_max_limit`]. If
+    /// [`MultiPageOption::Enabled`] is configured with a `max_page_count`, then an
+    /// [`Error::ExceededMaxPageCount`] is returned if the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let races = jolpica
+    ///     .get_constructor_lineage_results("rb".into())
+    ///     .unwrap();
+    /// assert_eq!(races[0].season, 1985);
+    /// assert_eq!(races[0].race_results()[0].constructor.constructor_id, "minardi");
+    /// ```
+    pub fn get_constructor_lineage_results(&self, constructor_id: ConstructorID) -> Result<Vec<Race<Vec<RaceResult>>>> {
+        let mut races = Vec::new();
+
+        for constructor_id in constructor_lineage(constructor_id) {
+            races.extend(self.get_race_results(Filters::new().constructor_id(constructor_id))?);
+        }
+
+        races.sort_by_key(|race| (race.season, race.round));
+        races.dedup_by(|race, prev| {
+            let is_same_race = race.id() == prev.id();
+            if is_same_race {
+                prev.payload.append(&mut race.payload);
+                prev.payload.sort_by_key(|result| result.position);
+            }
+            is_same_race
+        });
+
+        Ok(races)
+    }
+
+    /// Returns an iterator that lazily performs GET requests to the jolpica-f1 API for all
+    /// [`RaceResult`]s, one season at a time, from the `start` season onwards, e.g. to
+    /// incrementally mirror the full history.
+    ///
+    /// On the first call to [`Iterator::next`], it requests the list of all seasons via
+    /// [`Agent::get_seasons`]. It then yields the [`RaceResult`]s of each season from `start`, in
+    /// order, as returned by [`Agent::get_race_results`] with a [`Filters::season`], which are only
+    /// requested when that season is reached. Unlike a single `get_race_results(Filters::none())`,
+    /// this bounds the memory use and number of requests made by any one call, and allows callers
+    /// to stop, e.g. on an error, and later resume by passing the next season as `start`. Seasons
+    /// before `start` are skipped without requesting their results, so resuming is cheap, and a
+    /// `start` of `1950`, or any earlier season, yields the full history.
+    ///
+    /// **Note:** Each of the requests is subject to the configured rate limiting, which paces the
+    /// iteration. Seasons with no results yet, e.g. the current season prior to its first race,
+    /// are yielded with an empty list.
+    ///
+    /// # Errors
+    ///
+    /// If the list of seasons cannot be requested, then the iterator yields only that error. Any
+    /// errors from requesting a season's results, e.g. [`Error::MultiPage`] if
+    /// [`MultiPageOption::Disabled`] is configured, are yielded in place of that season's results,
+    /// and iteration may continue with the following seasons.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// for season_results in jolpica.iter_all_race_results(1950).take(2) {
+    ///     let (season, races) = season_results.unwrap();
+    ///     assert!(season == 1950 || season == 1951);
+    ///     assert!(races.iter().all(|race| race.season == season));
+    /// }
+    ///
+    /// // Resume from 2000, without requesting the results of any earlier seasons
+    /// let (season, _) = jolpica.iter_all_race_results(2000).next().unwrap().unwrap();
+    /// assert_eq!(season, 2000);
+    /// ```
+    pub fn iter_all_race_results(
+        &self,
+        start: SeasonID,
+    ) -> impl Iterator<Item = Result<(SeasonID, Vec<Race<Vec<RaceResult>>>)>> {
+        std::iter::once_with(|| self.get_seasons(Filters::none()))
+            .flat_map(move |seasons| match seasons {
+                Ok(seasons) => seasons
+                    .into_iter()
+                    .map(|season| season.season)
+                    .filter(|season| *season >= start)
+                    .map(Ok)
+                    .collect(),
+                Err(error) => vec![Err(error)],
+            })
+            .map(|season| {
+                let season = season?;
+                self.get_race_results(Filters::new().season(season))
+                    .map(|races| (season, races))
+            })
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::FinishingStatus`], with the
+    /// argument [`Filters`], and return the resulting inner [`Status`]s from [`Table`] in
+    /// [`Response::table`].
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// `status` would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
+    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
+    /// the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::Filters};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let statuses = jolpica.get_statuses(Filters::none()).unwrap();
+    /// assert!(!statuses.is_empty());
+    /// assert_eq!(
+    ///     statuses
+    ///         .iter()
+    ///         .find(|status| status.status_id == 1)
+    ///         .unwrap()
+    ///         .status,
+    ///     "Finished".to_string()
+    /// );
+    /// ```
+    pub fn get_statuses(&self, filters: Filters) -> Result<Vec<Status>> {
+        self.get_and_extract(&Resource::FinishingStatus(filters), Response::into_statuses)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::FinishingStatus`], filtered by
+    /// [`Filters::driver_id`], and returns the resulting [`Status`]s, i.e. the finishing statuses
+    /// that a specified driver has had throughout their career.
+    ///
+    /// Each [`Status::count`] is the number of times that the driver has had that status, across
+    /// all seasons, rather than the number of occurrences for all drivers.
+    ///
+    /// # Errors
+    ///
+    /// Any errors from [`get_statuses`](Self::get_statuses). An empty list is returned, rather
+    /// than an error, if the driver is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let statuses = jolpica.get_statuses_for_driver("grosjean".into()).unwrap();
+    /// let accidents = statuses.iter().find(|status| status.status == "Accident").unwrap();
+    /// assert!(accidents.count > 0);
+    /// ```
+    pub fn get_statuses_for_driver(&self, driver_id: DriverID) -> Result<Vec<Status>> {
+        self.get_statuses(Filters::new().driver_id(driver_id))
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::DriverStandings`], with the
+    /// argument [`Filters`], and returns the resulting [`StandingsList`]s, with the inner
+    /// [`Standings::DriverStandings`] already extracted into a [`Vec<DriverStanding>`].
+    ///
+    /// There is one [`StandingsList`] per season matching the [`Filters`], e.g. a single one for
+    /// [`Filters::season`], or one per season that the driver took part in for
+    /// [`Filters::driver_id`].
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the standings would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`]
+    /// is configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned
+    /// if the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::{agent::Agent, resource::Filters}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let standings = jolpica
+    ///     .get_driver_standings(Filters::new().season(2021).round(22))
+    ///     .unwrap();
+    /// assert_eq!(standings.len(), 1);
+    ///
+    /// let leader = &standings[0].standings[0];
+    /// assert_eq!(leader.driver.driver_id, DriverID::from("max_verstappen"));
+    /// assert_eq!(leader.points, 395.5);
+    /// ```
+    pub fn get_driver_standings(&self, filters: Filters) -> Result<Vec<StandingsList<Vec<DriverStanding>>>> {
+        self.get_standings(&Resource::DriverStandings(filters), Standings::into_driver_standings)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::ConstructorStandings`], with the
+    /// argument [`Filters`], and returns the resulting [`StandingsList`]s, with the inner
+    /// [`Standings::ConstructorStandings`] already extracted into a [`Vec<ConstructorStanding>`].
+    ///
+    /// There is one [`StandingsList`] per season matching the [`Filters`], e.g. a single one for
+    /// [`Filters::season`], or one per season that the constructor took part in for
+    /// [`Filters::constructor_id`].
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the standings would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`]
+    /// is configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned
+    /// if the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::ConstructorID, jolpica::{agent::Agent, resource::Filters}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let standings = jolpica
+    ///     .get_constructor_standings(Filters::new().season(2021).round(22))
+    ///     .unwrap();
+    ///
+    /// let leader = &standings[0].standings[0];
+    /// assert_eq!(leader.constructor.constructor_id, ConstructorID::from("mercedes"));
+    /// assert_eq!(leader.points, 613.5);
+    /// ```
+    pub fn get_constructor_standings(&self, filters: Filters) -> Result<Vec<StandingsList<Vec<ConstructorStanding>>>> {
+        self.get_standings(&Resource::ConstructorStandings(filters), Standings::into_constructor_standings)
+    }
+
+    /// Performs GET requests to the jolpica-f1 API for [`Resource::ConstructorStandings`] after
+    /// each round of a given `season`, and returns the [`ConstructorStanding`]s of each round,
+    /// paired with its [`RoundID`] and ordered by round, e.g. to chart the championship progression.
+    ///
+    /// The latest round with standings is determined first, via [`Agent::get_constructor_standings`]
+    /// without [`Filters::round`], so only completed rounds are requested for a season in progress.
+    /// An empty list is returned if there are no standings for the season, e.g. if it has not yet
+    /// started, or for seasons before the constructors' championship was introduced in `1958`.
+    ///
+    /// **Note:** This performs one request per completed round, so it may be rate limited.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the standings for any of the rounds are not found, or
+    /// an [`Error::TooMany`] if more than one [`StandingsList`] is returned for any of them.
+    /// Otherwise, the same errors as [`Agent::get_constructor_standings`] may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::ConstructorID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let progression = jolpica.get_constructor_standings_progression(2022).unwrap();
+    /// assert_eq!(progression.len(), 22);
+    /// assert_eq!(progression[0].0, 1);
+    ///
+    /// let (_, final_standings) = progression.last().unwrap();
+    /// assert_eq!(final_standings[0].constructor.constructor_id, ConstructorID::from("red_bull"));
+    /// assert_eq!(final_standings[0].points, 759.0);
+    /// ```
+    pub fn get_constructor_standings_progression(
+        &self,
+        season: SeasonID,
+    ) -> Result<Vec<(RoundID, Vec<ConstructorStanding>)>> {
+        let Some(latest) = self.get_constructor_standings(Filters::new().season(season))?.pop() else {
+            return Ok(Vec::new());
+        };
+
+        let mut progression = (1..latest.round)
+            .map(|round| {
+                let standings = self.get_constructor_standings(Filters::new().season(season).round(round))?;
+                verify_has_one_element_and_extract(standings).map(|list| (round, list.standings))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        progression.push((latest.round, latest.standings));
+        Ok(progression)
+    }
+
+    /// Performs a GET request for the standings `resource` and extracts the [`Standings`] variant
+    /// of each resulting [`StandingsList`] with `extract`, returning [`Error::BadPayloadVariant`]
+    /// if any is not of the expected variant.
+    fn get_standings<T>(
+        &self,
+        resource: &Resource,
+        extract: fn(Standings) -> std::result::Result<T, Standings>,
+    ) -> Result<Vec<StandingsList<T>>> {
+        into_standings(self.get_and_extract(resource, Response::into_table_list::<StandingsList>)?, extract)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::LapTimes`] from a specified
+    /// [`RaceID`] and for a specified single [`DriverID`].
+    ///
+    /// It returns a list of [`DriverLap`]s, which is a flattened combination of [`Lap`]s and
+    /// [`Timing`]s.
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// `lap_times` would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
+    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
+    /// the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::id::{DriverID, RaceID};
+    /// # use f1_data::jolpica::{agent::Agent, time::duration_m_s_ms};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let laps = jolpica
     ///     .get_driver_laps(RaceID::from(2023, 4), &DriverID::from("leclerc"))
     ///     .unwrap();
     /// assert_eq!(laps.len(), 51);
@@ -1059,13 +2770,15 @@ impl<'a> Agent<'a> {
     /// assert_eq!(laps[2].position, 2)
     /// ```
     pub fn get_driver_laps(&self, race_id: RaceID, driver_id: &DriverID) -> Result<Vec<DriverLap>> {
-        self.get_response(&Resource::LapTimes(LapTimeFilters {
-            season: race_id.season,
-            round: race_id.round,
-            lap: None,
-            driver_id: Some(driver_id.clone()),
-        }))?
-        .into_driver_laps(driver_id)
+        self.get_and_extract(
+            &Resource::LapTimes(LapTimeFilters {
+                season: race_id.season,
+                round: race_id.round,
+                lap: None,
+                driver_id: Some(driver_id.clone()),
+            }),
+            |response| response.into_driver_laps(driver_id),
+        )
     }
 
     /// Performs a GET request to the jolpica-f1 API for [`Resource::LapTimes`] from a specified
@@ -1093,13 +2806,76 @@ impl<'a> Agent<'a> {
     /// assert_eq!(timings[0].time, duration_m_s_ms(1, 50, 109));
     /// ```
     pub fn get_lap_timings(&self, race_id: RaceID, lap: u32) -> Result<Vec<Timing>> {
-        self.get_response(&Resource::LapTimes(LapTimeFilters {
-            season: race_id.season,
-            round: race_id.round,
-            lap: Some(lap),
-            driver_id: None,
-        }))?
-        .into_lap_timings()
+        self.get_and_extract(
+            &Resource::LapTimes(LapTimeFilters {
+                season: race_id.season,
+                round: race_id.round,
+                lap: Some(lap),
+                driver_id: None,
+            }),
+            Response::into_lap_timings,
+        )
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::LapTimes`] from a specified
+    /// [`RaceID`] and for a specified single lap, returning the fastest [`Timing`] on that lap, as
+    /// per [`fastest_timing`].
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the race or lap does not exist, or if it has no
+    /// timings. Otherwise, the same errors as [`Agent::get_lap_timings`] may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::id::{DriverID, RaceID};
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let timing = jolpica.get_fastest_on_lap(RaceID::from(2023, 4), 1).unwrap();
+    /// assert_eq!(timing.driver_id, DriverID::from("leclerc"));
+    /// ```
+    pub fn get_fastest_on_lap(&self, race_id: RaceID, lap: u32) -> Result<Timing> {
+        fastest_timing(&self.get_lap_timings(race_id, lap)?)
+            .cloned()
+            .ok_or_else(|| {
+                Error::NotFound(format!(
+                    "no timings found for season {}, round {}, lap {lap}",
+                    race_id.season, race_id.round
+                ))
+            })
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::LapTimes`] from a specified
+    /// [`RaceID`], returning a list of all [`Lap`]s, each with [`Timing`]s for all drivers.
+    ///
+    /// **Note:** A full race's lap data can be very large, e.g. `~20` drivers for `~50-70` laps,
+    /// requiring many pages at [`Page::with_max_limit`] and thus many requests to the API.
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the laps would not fit in a [`Page::with_max_limit`]. If [`MultiPageOption::Enabled`] is
+    /// configured with a `max_page_count`, then an [`Error::ExceededMaxPageCount`] is returned if
+    /// the total number of pages would exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::id::{DriverID, RaceID};
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let laps = jolpica.get_laps(RaceID::from(2023, 4)).unwrap();
+    /// assert_eq!(laps.len(), 51);
+    /// assert_eq!(laps[0].timings[0].driver_id, DriverID::from("leclerc"));
+    /// ```
+    pub fn get_laps(&self, race_id: RaceID) -> Result<Vec<Lap>> {
+        self.get_and_extract(
+            &Resource::LapTimes(LapTimeFilters::new(race_id.season, race_id.round)),
+            Response::into_laps,
+        )
     }
 
     /// Performs a GET request to the jolpica-f1 API for [`Resource::PitStops`], with the passed
@@ -1140,10 +2916,339 @@ impl<'a> Agent<'a> {
     /// );
     /// ```
     pub fn get_pit_stops(&self, filters: PitStopFilters) -> Result<Vec<PitStop>> {
-        self.get_response(&Resource::PitStops(filters))?.into_pit_stops()
+        self.get_and_extract(&Resource::PitStops(filters), Response::into_pit_stops)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API for [`Resource::PitStops`] from a specified
+    /// [`RaceID`], returning the fastest [`PitStop`] of the race, as per [`fastest_pit_stop`].
+    ///
+    /// **Note:** [`PitStop::duration`] is the total time spent in the pit lane, including the
+    /// transit through it, and not the stationary time in the pit box.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::NotFound`] is returned if the race does not exist, or if it has no pit stops.
+    /// Otherwise, the same errors as [`Agent::get_pit_stops`] may be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::id::RaceID;
+    /// # use f1_data::jolpica::agent::Agent;
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let pit_stop = jolpica.get_fastest_pit_stop(RaceID::from(2023, 4)).unwrap();
+    /// assert_eq!(pit_stop.stop, 1);
+    /// ```
+    pub fn get_fastest_pit_stop(&self, race_id: RaceID) -> Result<PitStop> {
+        fastest_pit_stop(&self.get_pit_stops(PitStopFilters::new(race_id.season, race_id.round))?)
+            .cloned()
+            .ok_or_else(|| {
+                Error::NotFound(format!("no pit stops found for season {}, round {}", race_id.season, race_id.round))
+            })
+    }
+
+    /// Performs GET requests to the jolpica-f1 API to find all the races in which the specified
+    /// driver achieved a "grand slam", i.e. pole position, win, fastest lap, and led every lap.
+    ///
+    /// It first requests all of the driver's wins, and then, only for the wins where they also set
+    /// the fastest lap, i.e. [`FastestLap::rank`] of `Some(1)`, it requests their qualifying result
+    /// and, if they were on pole, the race's [`Lap`]s, to check via [`stats::is_grand_slam`]. Races
+    /// without qualifying or lap data, e.g. prior to 1996, are skipped.
+    ///
+    /// **Note:** This may make a large number of requests for drivers with many wins, and all of
+    /// them are subject to the configured rate limiting, so it may take a long time to complete.
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is likely to be
+    /// returned, since a race's lap data is unlikely to fit in a [`Page::with_max_limit`]. Any
+    /// other errors from the underlying requests, aside from [`Error::NotFound`] for missing
+    /// qualifying or lap data, are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::{DriverID, RaceID}, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let grand_slams = jolpica.get_grand_slams(DriverID::from("max_verstappen")).unwrap();
+    /// assert!(grand_slams.iter().any(|race| race.id() == RaceID::from(2021, 9)));
+    /// ```
+    pub fn get_grand_slams(&self, driver_id: DriverID) -> Result<Vec<Race<RaceResult>>> {
+        let driver_filters = Filters::new().driver_id(driver_id);
+        let wins = self.get_race_result_for_events(driver_filters.clone().finish_pos(1))?;
+
+        let mut grand_slams = Vec::new();
+
+        for win in wins {
+            // Avoid making further requests for races that cannot be grand slams
+            if win.race_result().fastest_lap.and_then(|fastest_lap| fastest_lap.rank) != Some(1) {
+                continue;
+            }
+
+            let filters = driver_filters.clone().season(win.season).round(win.round);
+
+            let qualifying = match self.get_qualifying_result(filters) {
+                Ok(qualifying) => qualifying.into_qualifying_result(),
+                Err(Error::NotFound(_)) => continue,
+                Err(err) => return Err(err),
+            };
+
+            if qualifying.position != 1 {
+                continue;
+            }
+
+            let lap_matrix = match self.get_laps(win.id()) {
+                Ok(laps) => LapMatrix::new(laps),
+                Err(Error::NotFound(_)) => continue,
+                Err(err) => return Err(err),
+            };
+
+            if stats::is_grand_slam(win.race_result(), &qualifying, &lap_matrix, &win.race_result().driver.driver_id) {
+                grand_slams.push(win);
+            }
+        }
+
+        Ok(grand_slams)
+    }
+
+    /// Performs GET requests to the jolpica-f1 API to get all the races in which the specified
+    /// driver finished on the podium, i.e. in positions `1` to `3`, in chronological order.
+    ///
+    /// Since [`Filters::finish_pos`] only filters by a single position, this method performs one
+    /// request per podium position, i.e. three requests, via [`Agent::get_race_result_for_events`],
+    /// and merges the results. This is cheaper than requesting all of the driver's race results
+    /// and filtering them, which could require many pages for drivers with long careers.
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the driver's results for any single podium position would not fit in a
+    /// [`Page::with_max_limit`], e.g. for drivers with more than `100` wins. Any other errors from
+    /// the underlying requests are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::{DriverID, RaceID}, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let podiums = jolpica.get_podiums(DriverID::from("leclerc")).unwrap();
+    /// assert!(podiums.iter().any(|race| race.id() == RaceID::from(2023, 4)));
+    /// assert!(podiums.iter().all(|race| race.race_result().position <= 3));
+    /// ```
+    pub fn get_podiums(&self, driver_id: DriverID) -> Result<Vec<Race<RaceResult>>> {
+        let filters = Filters::new().driver_id(driver_id);
+        let mut podiums = Vec::new();
+
+        for finish_pos in 1..=3 {
+            podiums.extend(self.get_race_result_for_events(filters.clone().finish_pos(finish_pos))?);
+        }
+
+        podiums.sort_by_key(|race| (race.season, race.round));
+        Ok(podiums)
+    }
+
+    /// Performs GET requests to the jolpica-f1 API for the qualifying results of two drivers, e.g.
+    /// teammates, in a given `season`, and returns their head-to-head record, i.e. the number of
+    /// rounds in which `driver_a` and `driver_b`, respectively, qualified ahead of the other.
+    ///
+    /// The results of each driver are requested via [`Agent::get_qualifying_result_for_events`],
+    /// and compared via [`stats::qualifying_head_to_head`]. Rounds in which either driver did not
+    /// take part, e.g. due to an injury, or did not set a lap time, don't count for either driver.
+    ///
+    /// # Errors
+    ///
+    /// Any errors from the underlying requests are returned, aside from [`Error::NotFound`] if
+    /// either driver has no qualifying results in the season, in which case `(0, 0)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let (leclerc, sainz) = jolpica
+    ///     .get_qualifying_head_to_head(2022, &DriverID::from("leclerc"), &DriverID::from("sainz"))
+    ///     .unwrap();
+    /// assert!(leclerc > sainz);
+    /// ```
+    pub fn get_qualifying_head_to_head(
+        &self,
+        season: SeasonID,
+        driver_a: &DriverID,
+        driver_b: &DriverID,
+    ) -> Result<(u32, u32)> {
+        let get_results = |driver_id: &DriverID| {
+            not_found_as_none(
+                self.get_qualifying_result_for_events(Filters::new().season(season).driver_id(driver_id.clone())),
+            )
+            .map(Option::unwrap_or_default)
+        };
+
+        Ok(stats::qualifying_head_to_head(&get_results(driver_a)?, &get_results(driver_b)?))
+    }
+
+    /// Performs a GET request to the jolpica-f1 API to get all of the specified driver's wins, and
+    /// returns the number of wins at each circuit, keyed by [`Circuit::circuit_id`].
+    ///
+    /// Only circuits at which the driver has won at least once are included, i.e. an empty map is
+    /// returned for a driver without any wins.
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured, then an [`Error::MultiPage`] is returned if
+    /// the driver's wins would not fit in a [`Page::with_max_limit`], e.g. for drivers with more
+    /// than `100` wins. Any other errors from the underlying request are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::{CircuitID, DriverID}, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let wins = jolpica.get_wins_by_circuit(DriverID::from("leclerc")).unwrap();
+    /// assert!(wins[&CircuitID::from("monza")] >= 2);
+    ///
+    /// let (_top_circuit, top_wins) = wins.iter().max_by_key(|(_, count)| **count).unwrap();
+    /// assert!(*top_wins >= wins[&CircuitID::from("monza")]);
+    /// ```
+    pub fn get_wins_by_circuit(&self, driver_id: DriverID) -> Result<HashMap<CircuitID, u32>> {
+        let mut wins: HashMap<CircuitID, u32> = HashMap::new();
+
+        for race in self.get_race_result_for_events(Filters::new().driver_id(driver_id).finish_pos(1))? {
+            *wins.entry(race.circuit.circuit_id).or_default() += 1;
+        }
+
+        Ok(wins)
+    }
+
+    /// Performs a GET request to the jolpica-f1 API to get the winning [`Driver`] of each round of
+    /// the specified season, in round order.
+    ///
+    /// This requests the race results filtered by [`Filters::finish_pos`] of `1`, i.e. usually only
+    /// one result per race, which is far cheaper than requesting all race results for the season.
+    /// Wins shared between drivers of the same car, e.g. in round 4 of 1951, result in more than
+    /// one winning result for a race, in which case the first one, as ordered by the jolpica-f1
+    /// API, is returned for that round. For a season in progress, only the rounds that have already
+    /// been held are returned, i.e. there may be fewer winners than scheduled rounds, and an empty
+    /// list for a season yet to start.
+    ///
+    /// # Errors
+    ///
+    /// Any errors from the underlying request, e.g. [`Error::Http`], are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let winners = jolpica.get_round_winners(2023).unwrap();
+    /// assert_eq!(winners.len(), 22);
+    /// assert_eq!(winners[3].0, 4);
+    /// assert_eq!(winners[3].1.driver_id, DriverID::from("perez"));
+    /// ```
+    pub fn get_round_winners(&self, season: SeasonID) -> Result<Vec<(RoundID, Driver)>> {
+        let mut winners: Vec<_> = self
+            .get_race_results(Filters::new().season(season).finish_pos(1))?
+            .into_iter()
+            .filter_map(|race| {
+                let round = race.round;
+                race.into_race_results()
+                    .into_iter()
+                    .next()
+                    .map(|result| (round, result.driver))
+            })
+            .collect();
+
+        winners.sort_by_key(|(round, _)| *round);
+        Ok(winners)
+    }
+}
+
+/// Returns the `(name, value)` pairs of the HTTP headers for an optional `user_agent` and the
+/// `extra_headers`, in that order, as per [`AgentConfigs::user_agent`] and
+/// [`AgentConfigs::extra_headers`].
+pub(crate) fn request_headers<'h>(
+    user_agent: Option<&'h String>,
+    extra_headers: &'h BTreeMap<String, String>,
+) -> Vec<(&'h str, &'h str)> {
+    user_agent
+        .map(|user_agent| ("User-Agent", user_agent.as_str()))
+        .into_iter()
+        .chain(
+            extra_headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        )
+        .collect()
+}
+
+/// Adds the endpoint of `resource` as context to an [`Error::NotFound`], passing through any other
+/// [`Error`], e.g. `"/2023/4/laps: expected one element, found none"`.
+pub(crate) fn with_endpoint_context(resource: &Resource, err: Error) -> Error {
+    match err {
+        Error::NotFound(context) => Error::NotFound(format!("{}: {context}", resource.to_endpoint())),
+        err => err,
+    }
+}
+
+/// Extracts the [`Standings`] variant of each [`StandingsList`] in `lists` via `extract`, returning
+/// an [`Error::BadPayloadVariant`] if any of them is of a different variant.
+pub(crate) fn into_standings<T>(
+    lists: Vec<StandingsList>,
+    extract: fn(Standings) -> std::result::Result<T, Standings>,
+) -> Result<Vec<StandingsList<T>>> {
+    lists
+        .into_iter()
+        .map(|list| list.try_map(|standings| extract(standings).map_err(|_| Error::BadPayloadVariant)))
+        .collect()
+}
+
+/// Converts an [`Error::NotFound`] into `Ok(None)`, passing through any other [`Result`] values.
+pub(crate) fn not_found_as_none<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::NotFound(_)) => Ok(None),
+        Err(err) => Err(err),
     }
 }
 
+/// Returns the name of the file in which a response for the given request `url` is cached, as per
+/// [`AgentConfigs::cache`], by percent-encoding the full URL, so that distinct URLs never collide.
+fn cache_file_name(url: &str) -> String {
+    url::form_urlencoded::byte_serialize(url.as_bytes()).collect::<String>() + ".json"
+}
+
+/// Reads and parses the cached [`Response`] at `path`, as per [`AgentConfigs::cache`], returning
+/// [`None`], after logging a warning, if the file cannot be read or parsed, e.g. if corrupted.
+fn read_cache_file(path: &Path) -> Option<Response> {
+    fs::read_to_string(path)
+        .map_err(Error::from)
+        .and_then(|json_str| Ok(serde_json::from_str::<Response>(&json_str)?))
+        .inspect_err(|err| log::warn!("Ignoring invalid cache file {}: {err}", path.display()))
+        .ok()
+}
+
+/// Writes `json_str` to the cache file at `path`, as per [`AgentConfigs::cache`], via a temporary
+/// file in the same directory that is then renamed into place, so that an interrupted write, or
+/// concurrent writes of the same file, never leave a truncated file at `path`.
+fn write_cache_file(path: &Path, json_str: &str) -> Result<()> {
+    static TMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let tmp_file_idx = TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let tmp_path = path.with_extension(format!("{}.{tmp_file_idx}.tmp", std::process::id()));
+
+    fs::write(&tmp_path, json_str)
+        .and_then(|()| fs::rename(&tmp_path, path))
+        .inspect_err(|_| {
+            let _unused = fs::remove_file(&tmp_path);
+        })
+        .map_err(Error::from)
+}
+
 /// This trait allows generically requesting [`Resource`]s based on the corresponding underlying
 /// inner types, e.g. [`Season`]s are requested via [`Resource::SeasonList`].
 pub trait ToResource
@@ -1267,7 +3372,7 @@ impl SessionResult for RaceResult {}
 
 /// Convert a [`Response`] to [`Result<Response>`], enforcing that [`Response`] is single-page, via
 /// [`Pagination::is_single_page`], and returning an [`Error::MultiPage`] if it's multi-page.
-fn verify_is_single_page(response: Response) -> Result<Response> {
+pub(crate) fn verify_is_single_page(response: Response) -> Result<Response> {
     if response.pagination.is_single_page() {
         Ok(response)
     } else {
@@ -1275,10 +3380,25 @@ fn verify_is_single_page(response: Response) -> Result<Response> {
     }
 }
 
+/// Splits a `resource` with a [`Filters::season_range`] into one [`Resource`] per season, via
+/// [`Resource::split_season_range`], requests each of them in order with `get_response`, and
+/// merges the resulting [`Response`]s via [`concat_response_seasons`].
+fn get_response_season_range(
+    resource: &Resource,
+    get_response: impl Fn(&Resource) -> Result<Response>,
+) -> Result<Response> {
+    resource
+        .split_season_range()
+        .iter()
+        .map(get_response)
+        .collect::<Result<Vec<_>>>()
+        .and_then(concat_response_seasons)
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage, coverage(off))]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::sync::LazyLock;
     use std::time::{Duration, Instant};
 
@@ -1286,6 +3406,7 @@ mod tests {
         id::{RoundID, SeasonID},
         jolpica::{
             api::JOLPICA_API_PAGINATION,
+            backend::MockBackend,
             resource::{Filters, LapTimeFilters, PitStopFilters, Resource},
             response::*,
         },
@@ -1293,7 +3414,10 @@ mod tests {
 
     use crate::jolpica::tests::{
         assets::*,
-        util::{JOLPICA_MP, JOLPICA_SP, get_jolpica_test_base_url, get_request_avg_duration_ms},
+        util::{
+            JOLPICA_MP, JOLPICA_SP, MOCK_ETAG, get_jolpica_test_base_url, get_request_avg_duration_ms, race_table_json,
+            spawn_conditional_mock_server, spawn_mock_server,
+        },
     };
     use crate::tests::asserts::*;
     use shadow_asserts::assert_eq;
@@ -1402,24 +3526,103 @@ mod tests {
         );
     }
 
-    /// Call a `get` function and assert that the returned [`Result<Vec<T>>`] is [`Ok`], and that
-    /// held sequence value is empty.
-    fn assert_is_empty<G: Fn() -> Result<Vec<T>>, T>(get: G) {
-        assert_true!(get().unwrap().is_empty());
+    /// Call a `get` function and assert that the returned [`Result<Vec<T>>`] is [`Ok`], and that
+    /// held sequence value is empty.
+    fn assert_is_empty<G: Fn() -> Result<Vec<T>>, T>(get: G) {
+        assert_true!(get().unwrap().is_empty());
+    }
+
+    /// Call a `get` function and assert that the returned [`Result`] is [`Err(Error::NotFound(_))`].
+    fn assert_not_found<G: Fn() -> Result<T>, T>(get: G) {
+        assert!(matches!(get(), Err(Error::NotFound(_))));
+    }
+
+    /// Call a `get` function and assert that the returned [`Result`] is [`Err(Error::TooMany)`].
+    fn assert_too_many<G: Fn() -> Result<T>, T>(get: G) {
+        assert!(matches!(get(), Err(Error::TooMany)));
+    }
+
+    // Resource::SeasonList
+    // --------------------
+
+    #[test]
+    #[ignore]
+    fn get_current_season() {
+        let current = JOLPICA_SP.get_current_season().unwrap();
+        assert_ge!(current, 2025);
+
+        let seasons = JOLPICA_SP.get_seasons(Filters::none()).unwrap();
+        assert_eq!(current, seasons.last().unwrap().season);
+    }
+
+    #[test]
+    #[ignore]
+    fn get_seasons_between() {
+        let seasons = JOLPICA_SP.get_seasons_between(1949, 1952).unwrap();
+        assert_eq!(seasons.iter().map(|s| s.season).collect::<Vec<_>>(), [1950, 1951, 1952]);
+
+        let seasons = JOLPICA_SP.get_seasons_between(2023, 2023).unwrap();
+        assert_eq!(seasons.len(), 1);
+        assert_eq!(seasons[0].season, 2023);
+
+        assert_true!(JOLPICA_SP.get_seasons_between(1900, 1949).unwrap().is_empty());
+        assert_true!(JOLPICA_SP.get_seasons_between(3000, 3001).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get_seasons_between_inverted_range() {
+        // No responses are mocked, so making any request would fail
+        let jolpica = Agent::new(AgentConfigs {
+            backend: Arc::new(MockBackend::new()),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        assert_true!(jolpica.get_seasons_between(2023, 2022).unwrap().is_empty());
     }
 
-    /// Call a `get` function and assert that the returned [`Result`] is [`Err(Error::NotFound)`].
-    fn assert_not_found<G: Fn() -> Result<T>, T>(get: G) {
-        assert!(matches!(get(), Err(Error::NotFound)));
+    #[test]
+    #[ignore]
+    fn get_current_drivers_and_constructors() {
+        let current = JOLPICA_SP.get_current_season().unwrap();
+
+        assert_eq!(
+            JOLPICA_SP.get_current_drivers().unwrap(),
+            JOLPICA_SP.get_drivers(Filters::new().season(current)).unwrap()
+        );
+        assert_eq!(
+            JOLPICA_SP.get_current_constructors().unwrap(),
+            JOLPICA_SP.get_constructors(Filters::new().season(current)).unwrap()
+        );
     }
 
-    /// Call a `get` function and assert that the returned [`Result`] is [`Err(Error::TooMany)`].
-    fn assert_too_many<G: Fn() -> Result<T>, T>(get: G) {
-        assert!(matches!(get(), Err(Error::TooMany)));
+    #[test]
+    #[ignore]
+    fn get_first_element() {
+        assert_eq!(JOLPICA_SP.get_first_element::<Season>(Filters::none()).unwrap(), *SEASON_1950);
+        assert_eq!(
+            JOLPICA_SP
+                .get_first_element::<Season>(Filters::new().season(2023))
+                .unwrap(),
+            *SEASON_2023
+        );
+
+        let driver = JOLPICA_SP
+            .get_first_element::<Driver>(Filters::new().season(2023))
+            .unwrap();
+        assert_true!(
+            JOLPICA_SP
+                .get_drivers(Filters::new().season(2023))
+                .unwrap()
+                .contains(&driver)
+        );
     }
 
-    // Resource::SeasonList
-    // --------------------
+    #[test]
+    #[ignore]
+    fn get_first_element_error_not_found() {
+        assert_not_found(|| JOLPICA_SP.get_first_element::<Season>(Filters::new().season(1949)));
+    }
 
     #[test]
     #[ignore]
@@ -1437,18 +3640,62 @@ mod tests {
         assert_each_get_eq_expected(|season| JOLPICA_SP.get_season(season.season), SEASON_TABLE.as_seasons().unwrap());
     }
 
+    #[test]
+    #[ignore]
+    fn get_season_opt() {
+        assert_eq!(JOLPICA_SP.get_season_opt(2023).unwrap().unwrap().season, 2023);
+        assert_true!(JOLPICA_SP.get_season_opt(1940).unwrap().is_none());
+    }
+
     #[test]
     #[ignore]
     fn get_seasons_empty() {
         assert_is_empty(|| JOLPICA_SP.get_seasons(Filters::new().season(1949)));
     }
 
+    #[test]
+    #[ignore]
+    fn get_seasons_by_constructor() {
+        let seasons = JOLPICA_SP
+            .get_seasons(Filters::new().constructor_id("red_bull".into()))
+            .unwrap();
+        assert_eq!(seasons.first().unwrap().season, 2005);
+        assert_true!(
+            seasons
+                .iter()
+                .zip(seasons.iter().skip(1))
+                .all(|(prev, next)| next.season == prev.season + 1)
+        );
+        assert_true!(seasons.iter().any(|season| season == &*SEASON_2023));
+
+        let seasons = JOLPICA_SP
+            .get_seasons(Filters::new().constructor_id("williams".into()))
+            .unwrap();
+        assert_true!(seasons.iter().any(|season| season.season == 1980));
+        assert_true!(seasons.iter().any(|season| season == &*SEASON_2023));
+        assert_false!(seasons.iter().any(|season| season == &*SEASON_1950));
+    }
+
     #[test]
     #[ignore]
     fn get_season_error_not_found() {
         assert_not_found(|| JOLPICA_SP.get_season(1949));
     }
 
+    #[test]
+    #[ignore]
+    fn get_season_error_not_found_context() {
+        let Err(Error::NotFound(context)) = JOLPICA_SP.get_season(1949) else {
+            panic!("Expected Error::NotFound");
+        };
+        assert_eq!(context, "/1949/seasons: expected one element, found none");
+
+        let Err(Error::NotFound(context)) = JOLPICA_SP.get_race_schedule(RaceID::from(1949, 1)) else {
+            panic!("Expected Error::NotFound");
+        };
+        assert!(context.starts_with("/1949/1/races"));
+    }
+
     // Resource::DriverInfo
     // --------------------
 
@@ -1480,6 +3727,32 @@ mod tests {
         );
     }
 
+    #[test]
+    #[ignore]
+    fn get_drivers_season_range() {
+        let drivers = JOLPICA_MP
+            .get_drivers(Filters::new().season_range(2010..=2015))
+            .unwrap();
+
+        let mut expected: Vec<Driver> = Vec::new();
+        for season in 2010..=2015 {
+            for driver in JOLPICA_SP.get_drivers(Filters::new().season(season)).unwrap() {
+                if !expected.contains(&driver) {
+                    expected.push(driver);
+                }
+            }
+        }
+
+        assert_eq!(drivers, expected);
+        assert_true!(drivers.iter().any(|driver| driver.driver_id == "schumacher"));
+        assert_true!(drivers.iter().any(|driver| driver.driver_id == "max_verstappen"));
+
+        let response = JOLPICA_MP
+            .get_response(&Resource::DriverInfo(Filters::new().season_range(2010..=2015)))
+            .unwrap();
+        assert_eq!(response.pagination.total as usize, expected.len());
+    }
+
     #[test]
     #[ignore]
     fn get_driver() {
@@ -1489,6 +3762,13 @@ mod tests {
         );
     }
 
+    #[test]
+    #[ignore]
+    fn get_driver_opt() {
+        assert_eq!(JOLPICA_SP.get_driver_opt(DRIVER_MAX.driver_id.clone()).unwrap(), Some(DRIVER_MAX.clone()));
+        assert_true!(JOLPICA_SP.get_driver_opt(DriverID::from("unknown")).unwrap().is_none());
+    }
+
     #[test]
     #[ignore]
     fn get_drivers_empty() {
@@ -1507,6 +3787,30 @@ mod tests {
         assert_not_found(|| JOLPICA_SP.get_driver(DriverID::from("unknown")));
     }
 
+    #[test]
+    #[ignore]
+    fn get_drivers_minimal() {
+        let filters = Filters::new().season(2023);
+
+        let driver_ids = JOLPICA_SP.get_drivers_minimal(filters.clone()).unwrap();
+        assert_eq!(driver_ids.len(), 22);
+        assert_true!(driver_ids.contains(&DRIVER_LECLERC.driver_id));
+
+        let drivers = JOLPICA_SP.get_drivers(filters).unwrap();
+        assert_eq!(driver_ids, drivers.into_iter().map(|driver| driver.driver_id).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[ignore]
+    fn get_drivers_minimal_empty() {
+        assert_true!(
+            JOLPICA_SP
+                .get_drivers_minimal(Filters::new().season(1949))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     // Resource::ConstructorInfo
     // -------------------------
 
@@ -1547,6 +3851,19 @@ mod tests {
         );
     }
 
+    #[test]
+    #[ignore]
+    fn get_constructor_opt() {
+        let constructor = JOLPICA_SP.get_constructor_opt(ConstructorID::from("ferrari")).unwrap();
+        assert_eq!(constructor.unwrap().name, "Ferrari");
+        assert_true!(
+            JOLPICA_SP
+                .get_constructor_opt(ConstructorID::from("unknown"))
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     #[ignore]
     fn get_constructors_empty() {
@@ -1587,6 +3904,61 @@ mod tests {
         );
     }
 
+    #[test]
+    #[ignore]
+    fn get_circuit_opt() {
+        assert_eq!(JOLPICA_SP.get_circuit_opt(CIRCUIT_SPA.circuit_id.clone()).unwrap(), Some(CIRCUIT_SPA.clone()));
+        assert_true!(
+            JOLPICA_SP
+                .get_circuit_opt(CircuitID::from("unknown"))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn get_circuit_with_race_count() {
+        let (circuit, race_count) = JOLPICA_SP
+            .get_circuit_with_race_count(CIRCUIT_SPA.circuit_id.clone())
+            .unwrap();
+        assert_eq!(circuit, *CIRCUIT_SPA);
+        assert_ge!(race_count, 56);
+
+        assert_not_found(|| JOLPICA_SP.get_circuit_with_race_count(CircuitID::from("unknown")));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_unique_circuits() {
+        let circuits = JOLPICA_SP.get_unique_circuits(Filters::none()).unwrap();
+        assert_ge!(circuits.len(), 77);
+        assert!(circuits.is_sorted_by(|lhs, rhs| lhs.circuit_name <= rhs.circuit_name));
+
+        let circuit_ids: HashSet<_> = circuits.iter().map(|circuit| &circuit.circuit_id).collect();
+        assert_eq!(circuit_ids.len(), circuits.len());
+
+        for circuit in CIRCUIT_TABLE.as_circuits().unwrap() {
+            assert_true!(circuits.contains(circuit));
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn get_circuits_for_driver() {
+        let circuits = JOLPICA_MP.get_circuits_for_driver(DriverID::from("alonso")).unwrap();
+        assert_ge!(circuits.len(), 40);
+        assert!(circuits.is_sorted_by(|lhs, rhs| lhs.circuit_name <= rhs.circuit_name));
+
+        let circuit_ids: HashSet<_> = circuits.iter().map(|circuit| &circuit.circuit_id).collect();
+        assert_eq!(circuit_ids.len(), circuits.len());
+
+        // Baku was first raced in 2016, and Indianapolis last hosted F1 in 2007
+        assert_true!(circuits.contains(&CIRCUIT_BAKU));
+        assert_true!(circuit_ids.contains(&CircuitID::from("indianapolis")));
+        assert_false!(circuit_ids.contains(&CircuitID::from("reims")));
+    }
+
     #[test]
     #[ignore]
     fn get_circuits_empty() {
@@ -1679,6 +4051,18 @@ mod tests {
         assert_not_found(|| JOLPICA_SP.get_race_schedule(RaceID::from(1949, 1)));
     }
 
+    #[test]
+    #[ignore]
+    fn is_sprint_weekend() {
+        assert_false!(JOLPICA_SP.is_sprint_weekend(RaceID::from(2015, 11)).unwrap());
+        assert_true!(JOLPICA_SP.is_sprint_weekend(RaceID::from(2022, 4)).unwrap());
+        assert_true!(JOLPICA_SP.is_sprint_weekend(RaceID::from(2023, 4)).unwrap());
+        assert_false!(JOLPICA_SP.is_sprint_weekend(RaceID::from(2023, 5)).unwrap());
+        assert_true!(JOLPICA_SP.is_sprint_weekend(RaceID::from(2024, 5)).unwrap());
+
+        assert_not_found(|| JOLPICA_SP.is_sprint_weekend(RaceID::from(1949, 1)));
+    }
+
     // Resource::QualifyingResults
     // ---------------------------
 
@@ -2057,108 +4441,416 @@ mod tests {
 
     #[test]
     #[ignore]
-    fn get_race_result() {
-        // @todo Cannot use all available race results because, counterintuitively, non-finishing
-        // race results cannot be filtered by .finish_pos, even though .position would be set.
-        // See [`Resource::RaceResults`], and try reaching out to jolpica-f1 maintainers about it.
-        let filter_out_non_finishing = |mut race: Race| {
-            race.payload
-                .as_race_results_mut()
-                .unwrap()
-                .retain(|result| matches!(result.position_text, Position::Finished(_)));
-            race
-        };
+    fn get_race_result() {
+        // @todo Cannot use all available race results because, counterintuitively, non-finishing
+        // race results cannot be filtered by .finish_pos, even though .position would be set.
+        // See [`Resource::RaceResults`], and try reaching out to jolpica-f1 maintainers about it.
+        let filter_out_non_finishing = |mut race: Race| {
+            race.payload
+                .as_race_results_mut()
+                .unwrap()
+                .retain(|result| matches!(result.position_text, Position::Finished(_)));
+            race
+        };
+
+        let mut races_race_results = RACES_RACE_RESULTS
+            .iter()
+            .map(|race| filter_out_non_finishing(race.clone()))
+            .collect::<Vec<_>>();
+
+        races_race_results.retain(|race| !race.payload.as_race_results().unwrap().is_empty());
+        assert_false!(races_race_results.is_empty());
+
+        for race in races_race_results {
+            assert_each_get_eq_expected_session_result(
+                |filters| JOLPICA_SP.get_race_result(filters),
+                |result, filters| filters.finish_pos(result.position),
+                &race,
+            );
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_results_single_page_error_multi_page() {
+        assert!(matches!(JOLPICA_SP.get_race_results(Filters::none()), Err(Error::MultiPage)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_results_empty() {
+        assert_is_empty(|| JOLPICA_SP.get_race_results(Filters::new().season(1949)));
+        assert_is_empty(|| JOLPICA_SP.get_race_results(Filters::new().season(2021).finish_pos(100)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_results_for_rounds() {
+        let races = JOLPICA_SP.get_race_results_for_rounds(2023, 3..=4).unwrap();
+        assert_eq!(races.len(), 2);
+        assert_eq!(races[0].id(), RaceID::from(2023, 3));
+        assert_eq!(races[1].id(), RaceID::from(2023, 4));
+        assert_eq!(races[1].race_name, RACE_2023_4.race_name);
+
+        // Rounds that don't exist are skipped
+        let races = JOLPICA_SP.get_race_results_for_rounds(2023, 22..=25).unwrap();
+        assert_eq!(races.len(), 1);
+        assert_eq!(races[0].id(), RaceID::from(2023, 22));
+
+        assert_true!(JOLPICA_SP.get_race_results_for_rounds(1949, 1..=2).unwrap().is_empty());
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_results_for_event_error_not_found() {
+        assert_not_found(|| JOLPICA_SP.get_race_results_for_event(Filters::new().season(1949).round(1)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_results_for_event_error_too_many() {
+        // Using [`Filters::constructor_id`] in addition to `season` to avoid getting `MultiPage`,
+        // with the new jolpica-f1 API lower limit, instead of the [`Error::TooMany`] being tested
+        assert_too_many(|| {
+            JOLPICA_SP.get_race_results_for_event(Filters::new().season(2021).constructor_id("ferrari".into()))
+        });
+
+        // With multi-page support enabled, we can use `season` to get [`Error::TooMany`]
+        assert_too_many(|| JOLPICA_MP.get_race_results_for_event(Filters::new().season(2021)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_result_for_events_single_page_error_multi_page() {
+        assert!(matches!(
+            JOLPICA_SP.get_race_result_for_events(Filters::none().driver_id("alonso".into())),
+            Err(Error::MultiPage)
+        ));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_result_for_events_empty() {
+        assert_is_empty(|| JOLPICA_SP.get_race_result_for_events(Filters::new().season(1949).finish_pos(1)));
+        assert_is_empty(|| JOLPICA_SP.get_race_result_for_events(Filters::new().season(2021).finish_pos(100)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_result_for_events_error_too_many() {
+        // Using [`Filters::constructor_id`] in addition to `season` to avoid getting `MultiPage`,
+        // with the new jolpica-f1 API lower limit, instead of the [`Error::TooMany`] being tested
+        assert_too_many(|| {
+            JOLPICA_SP.get_race_result_for_events(Filters::new().season(2021).constructor_id("ferrari".into()))
+        });
+
+        // With multi-page support enabled, we can use `season` to get [`Error::TooMany`]
+        assert_too_many(|| JOLPICA_MP.get_race_result_for_events(Filters::new().season(2021)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_result_error_not_found() {
+        assert_not_found(|| JOLPICA_SP.get_race_result(Filters::new().season(1949).round(1).finish_pos(1)));
+        assert_not_found(|| JOLPICA_SP.get_race_result(Filters::new().season(2021).round(10).finish_pos(100)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_result_error_too_many() {
+        assert_too_many(|| JOLPICA_SP.get_race_result(Filters::new().season(2021).finish_pos(1)));
+        assert_too_many(|| JOLPICA_SP.get_race_result(Filters::new().season(2021).round(10)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_weekend() {
+        let weekend = JOLPICA_SP.get_weekend(RaceID::from(2023, 4)).unwrap();
+        assert_eq!(weekend.schedule.race_name, RACE_2023_4.race_name);
+        assert_true!(weekend.schedule.schedule().sprint.is_some());
+
+        let qualifying_results = weekend.qualifying_results.unwrap();
+        let sprint_results = weekend.sprint_results.unwrap();
+        let race_results = weekend.race_results.unwrap();
+
+        for expected in RACE_2023_4_QUALIFYING_RESULTS.payload.as_qualifying_results().unwrap() {
+            assert_true!(qualifying_results.contains(expected));
+        }
+        for expected in RACE_2023_4_SPRINT_RESULTS.payload.as_sprint_results().unwrap() {
+            assert_true!(sprint_results.contains(expected));
+        }
+        for expected in RACE_2023_4_RACE_RESULTS.payload.as_race_results().unwrap() {
+            assert_true!(race_results.contains(expected));
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn get_weekend_no_sprint() {
+        let weekend = JOLPICA_SP.get_weekend(RaceID::from(2023, 3)).unwrap();
+        assert_true!(weekend.schedule.schedule().sprint.is_none());
+        assert_true!(weekend.sprint_results.is_none());
+        assert_false!(weekend.qualifying_results.unwrap().is_empty());
+        assert_false!(weekend.race_results.unwrap().is_empty());
+    }
+
+    #[test]
+    #[ignore]
+    fn get_driver_career_span() {
+        let (first, last) = JOLPICA_SP.get_driver_career_span("raikkonen".into()).unwrap();
+        assert_eq!((first.season, first.round), (2001, 1));
+        assert_eq!(first.race_name, "Australian Grand Prix");
+        assert_eq!((last.season, last.round), (2021, 22));
+        assert_eq!(last.race_name, "Abu Dhabi Grand Prix");
+
+        // Marco Apicella only took part in the 1993 Italian Grand Prix
+        let (first, last) = JOLPICA_SP.get_driver_career_span("apicella".into()).unwrap();
+        assert_eq!((first.season, first.round), (1993, 13));
+        assert_eq!(first, last);
+
+        let (first, last) = JOLPICA_SP.get_driver_career_span("leclerc".into()).unwrap();
+        assert_eq!((first.season, first.round), (2018, 1));
+        assert_ge!(last.season, 2024);
+
+        assert_not_found(|| JOLPICA_SP.get_driver_career_span("unknown".into()));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_weekend_summary() {
+        let summary = JOLPICA_SP.get_weekend_summary(RaceID::from(2023, 4)).unwrap();
+        assert_eq!(summary.race, RACE_2023_4.to_info());
+        assert_eq!(summary.drivers.len(), 20);
+
+        let row = |driver_id: &str| {
+            summary
+                .drivers
+                .iter()
+                .find(|row| row.driver.driver_id == driver_id)
+                .unwrap()
+        };
+
+        let perez = row("perez");
+        assert_eq!(perez.qualifying_result.as_ref(), Some(&*QUALIFYING_RESULT_2023_4_P3));
+        assert_eq!(perez.sprint_result.as_ref(), Some(&*SPRINT_RESULT_2023_4_P1));
+        assert_eq!(perez.race_result.as_ref(), Some(&*RACE_RESULT_2023_4_P1));
+        assert_eq!(row("leclerc").qualifying_result.as_ref(), Some(&*QUALIFYING_RESULT_2023_4_P1));
+
+        let summary = JOLPICA_SP.get_weekend_summary(RaceID::from(2023, 3)).unwrap();
+        assert_false!(summary.drivers.is_empty());
+        assert_true!(summary.drivers.iter().all(|row| row.sprint_result.is_none()));
+
+        assert_not_found(|| JOLPICA_SP.get_weekend_summary(RaceID::from(1949, 1)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_results_with_gaps() {
+        use crate::jolpica::time::duration_s_ms;
+
+        let results = JOLPICA_SP.get_race_results_with_gaps(RaceID::from(2023, 4)).unwrap();
+        assert_eq!(results.len(), 20);
+
+        assert_eq!(results[0], (RACE_RESULT_2023_4_P1.clone(), Some(super::Duration::ZERO)));
+        assert_eq!(results[1], (RACE_RESULT_2023_4_P2.clone(), Some(duration_s_ms(2, 137))));
+        assert_eq!(results[19], (RACE_RESULT_2023_4_P20.clone(), None));
+
+        assert_true!(
+            results
+                .iter()
+                .all(|(result, gap)| result.time.is_some() == gap.is_some())
+        );
+
+        assert_not_found(|| JOLPICA_SP.get_race_results_with_gaps(RaceID::from(1949, 1)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_round_points() {
+        let points = JOLPICA_SP.get_round_points(RaceID::from(2023, 4)).unwrap();
+        assert_eq!(points.len(), 20);
 
-        let mut races_race_results = RACES_RACE_RESULTS
-            .iter()
-            .map(|race| filter_out_non_finishing(race.clone()))
-            .collect::<Vec<_>>();
+        let race_results = RACE_2023_4_RACE_RESULTS.payload.as_race_results().unwrap();
+        let sprint_results = RACE_2023_4_SPRINT_RESULTS.payload.as_sprint_results().unwrap();
 
-        races_race_results.retain(|race| !race.payload.as_race_results().unwrap().is_empty());
-        assert_false!(races_race_results.is_empty());
+        for race_result in race_results {
+            let driver_id = &race_result.driver.driver_id;
 
-        for race in races_race_results {
-            assert_each_get_eq_expected_session_result(
-                |filters| JOLPICA_SP.get_race_result(filters),
-                |result, filters| filters.finish_pos(result.position),
-                &race,
-            );
+            if let Some(sprint_result) = sprint_results
+                .iter()
+                .find(|result| &result.driver.driver_id == driver_id)
+            {
+                assert_eq!(points[driver_id], race_result.points + sprint_result.points);
+            }
         }
+
+        assert_eq!(points[&DriverID::from("perez")], 33.0);
+        assert_eq!(points[&DriverID::from("leclerc")], 22.0);
     }
 
     #[test]
     #[ignore]
-    fn get_race_results_single_page_error_multi_page() {
-        assert!(matches!(JOLPICA_SP.get_race_results(Filters::none()), Err(Error::MultiPage)));
+    fn get_round_points_no_sprint() {
+        let points = JOLPICA_SP.get_round_points(RaceID::from(2023, 3)).unwrap();
+        assert_eq!(points[&DriverID::from("max_verstappen")], 25.0);
     }
 
     #[test]
     #[ignore]
-    fn get_race_results_empty() {
-        assert_is_empty(|| JOLPICA_SP.get_race_results(Filters::new().season(1949)));
-        assert_is_empty(|| JOLPICA_SP.get_race_results(Filters::new().season(2021).finish_pos(100)));
+    fn get_season_driver_points() {
+        let points = JOLPICA_MP.get_season_driver_points(2021).unwrap();
+        assert_eq!(points[&DriverID::from("max_verstappen")], 395.5);
+        assert_eq!(points[&DriverID::from("hamilton")], 387.5);
+
+        // There were no sprints in 2020
+        let points = JOLPICA_MP.get_season_driver_points(2020).unwrap();
+        assert_eq!(points[&DriverID::from("hamilton")], 347.0);
+        assert_eq!(points[&DriverID::from("sainz")], 105.0);
+
+        // Kvyat and Verstappen swapped teams mid-season in 2016
+        let points = JOLPICA_MP.get_season_driver_points(2016).unwrap();
+        assert_eq!(points[&DriverID::from("max_verstappen")], 204.0);
+        assert_eq!(points[&DriverID::from("kvyat")], 25.0);
+
+        assert_not_found(|| JOLPICA_MP.get_season_driver_points(1949));
     }
 
     #[test]
     #[ignore]
-    fn get_race_results_for_event_error_not_found() {
-        assert_not_found(|| JOLPICA_SP.get_race_results_for_event(Filters::new().season(1949).round(1)));
+    fn get_weekend_error_not_found() {
+        assert_not_found(|| JOLPICA_SP.get_weekend(RaceID::from(1949, 1)));
     }
 
     #[test]
     #[ignore]
-    fn get_race_results_for_event_error_too_many() {
-        // Using [`Filters::constructor_id`] in addition to `season` to avoid getting `MultiPage`,
-        // with the new jolpica-f1 API lower limit, instead of the [`Error::TooMany`] being tested
-        assert_too_many(|| {
-            JOLPICA_SP.get_race_results_for_event(Filters::new().season(2021).constructor_id("ferrari".into()))
-        });
+    fn get_driver_season() {
+        let season = JOLPICA_SP.get_driver_season(DriverID::from("perez"), 2023).unwrap();
+        assert_eq!(season.rounds.len(), 22);
 
-        // With multi-page support enabled, we can use `season` to get [`Error::TooMany`]
-        assert_too_many(|| JOLPICA_MP.get_race_results_for_event(Filters::new().season(2021)));
+        for (idx, round) in season.rounds.iter().enumerate() {
+            assert_eq!(round.race.season, 2023);
+            assert_eq!(round.race.round, idx as RoundID + 1);
+        }
+
+        let round = &season.rounds[3];
+        assert_eq!(round.race, RACE_2023_4.to_info());
+
+        let qualifying_results = RACE_2023_4_QUALIFYING_RESULTS.payload.as_qualifying_results().unwrap();
+        let race_results = RACE_2023_4_RACE_RESULTS.payload.as_race_results().unwrap();
+
+        assert_true!(qualifying_results.contains(round.qualifying_result.as_ref().unwrap()));
+        assert_true!(race_results.contains(round.race_result.as_ref().unwrap()));
+        assert_eq!(round.race_result.as_ref().unwrap().position, 1);
     }
 
     #[test]
     #[ignore]
-    fn get_race_result_for_events_single_page_error_multi_page() {
-        assert!(matches!(
-            JOLPICA_SP.get_race_result_for_events(Filters::none().driver_id("alonso".into())),
-            Err(Error::MultiPage)
-        ));
+    fn get_driver_season_error_not_found() {
+        assert_not_found(|| JOLPICA_SP.get_driver_season(DriverID::from("perez"), 1950));
     }
 
     #[test]
     #[ignore]
-    fn get_race_result_for_events_empty() {
-        assert_is_empty(|| JOLPICA_SP.get_race_result_for_events(Filters::new().season(1949).finish_pos(1)));
-        assert_is_empty(|| JOLPICA_SP.get_race_result_for_events(Filters::new().season(2021).finish_pos(100)));
+    fn get_driver_numbers_by_season() {
+        let numbers = JOLPICA_MP
+            .get_driver_numbers_by_season(DriverID::from("max_verstappen"))
+            .unwrap();
+        assert_eq!(numbers[&2015], 33);
+        assert_eq!(numbers[&2021], 33);
+        assert_eq!(numbers[&2022], 1);
+        assert_eq!(numbers[&2023], 1);
+        assert_false!(numbers.contains_key(&2014));
+
+        let numbers = JOLPICA_SP
+            .get_driver_numbers_by_season(DriverID::from("de_vries"))
+            .unwrap();
+        assert_eq!(numbers, HashMap::from([(2022, 45), (2023, 21)]));
     }
 
     #[test]
     #[ignore]
-    fn get_race_result_for_events_error_too_many() {
-        // Using [`Filters::constructor_id`] in addition to `season` to avoid getting `MultiPage`,
-        // with the new jolpica-f1 API lower limit, instead of the [`Error::TooMany`] being tested
-        assert_too_many(|| {
-            JOLPICA_SP.get_race_result_for_events(Filters::new().season(2021).constructor_id("ferrari".into()))
-        });
+    fn get_driver_numbers_by_season_empty() {
+        assert_true!(
+            JOLPICA_SP
+                .get_driver_numbers_by_season(DriverID::from("unknown"))
+                .unwrap()
+                .is_empty()
+        );
+    }
 
-        // With multi-page support enabled, we can use `season` to get [`Error::TooMany`]
-        assert_too_many(|| JOLPICA_MP.get_race_result_for_events(Filters::new().season(2021)));
+    #[test]
+    #[ignore]
+    fn get_constructor_lineage_results() {
+        let races = JOLPICA_MP
+            .get_constructor_lineage_results(ConstructorID::from("alphatauri"))
+            .unwrap();
+
+        assert_true!(
+            races
+                .windows(2)
+                .all(|pair| (pair[0].season, pair[0].round) < (pair[1].season, pair[1].round))
+        );
+
+        let constructor_ids: HashSet<_> = races
+            .iter()
+            .flat_map(|race| race.race_results())
+            .map(|result| result.constructor.constructor_id.as_str())
+            .collect();
+        assert_eq!(constructor_ids, HashSet::from(["minardi", "toro_rosso", "alphatauri", "rb"]));
+
+        assert_eq!(races[0].season, 1985);
+        assert_eq!(
+            races
+                .iter()
+                .find(|race| race.id() == RaceID::from(2023, 4))
+                .unwrap()
+                .payload
+                .len(),
+            2
+        );
     }
 
     #[test]
     #[ignore]
-    fn get_race_result_error_not_found() {
-        assert_not_found(|| JOLPICA_SP.get_race_result(Filters::new().season(1949).round(1).finish_pos(1)));
-        assert_not_found(|| JOLPICA_SP.get_race_result(Filters::new().season(2021).round(10).finish_pos(100)));
+    fn get_constructor_lineage_results_not_in_lineage() {
+        let races = JOLPICA_MP
+            .get_constructor_lineage_results(ConstructorID::from("ferrari"))
+            .unwrap();
+        assert_true!(
+            races
+                .iter()
+                .flat_map(|race| race.race_results())
+                .all(|result| result.constructor.constructor_id == "ferrari")
+        );
     }
 
     #[test]
     #[ignore]
-    fn get_race_result_error_too_many() {
-        assert_too_many(|| JOLPICA_SP.get_race_result(Filters::new().season(2021).finish_pos(1)));
-        assert_too_many(|| JOLPICA_SP.get_race_result(Filters::new().season(2021).round(10)));
+    fn iter_all_race_results() {
+        let seasons: Vec<_> = JOLPICA_MP
+            .iter_all_race_results(1950)
+            .take(2)
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(seasons.len(), 2);
+        assert_eq!(seasons[0].0, 1950);
+        assert_eq!(seasons[1].0, 1951);
+
+        for (season, races) in &seasons {
+            assert_false!(races.is_empty());
+            assert_true!(races.iter().all(|race| race.season == *season));
+        }
+
+        assert_eq!(seasons[0].1[0].race_name, "British Grand Prix");
+        assert_eq!(seasons[0].1[0].race_results()[0].driver.driver_id, DriverID::from("farina"));
+    }
+
+    #[test]
+    #[ignore]
+    fn iter_all_race_results_error_multi_page() {
+        let first = JOLPICA_SP.iter_all_race_results(1950).next().unwrap();
+        assert!(matches!(first, Err(Error::MultiPage)));
     }
 
     // Resource::FinishingStatus
@@ -2194,6 +4886,31 @@ mod tests {
         assert_is_empty(|| JOLPICA_SP.get_statuses(Filters::new().season(1949)));
     }
 
+    #[test]
+    #[ignore]
+    fn get_statuses_for_driver() {
+        // Romain Grosjean entered 181 Grands Prix, starting 179, between 2009 and 2020
+        let statuses = JOLPICA_SP.get_statuses_for_driver("grosjean".into()).unwrap();
+        assert_true!(statuses.iter().all(|status| status.count > 0));
+
+        let total = statuses.iter().map(|status| status.count).sum::<u32>();
+        assert_ge!(total, 179);
+        assert_le!(total, 181);
+
+        let count = |status: &str| statuses.iter().find(|s| s.status == status).map(|s| s.count);
+        assert_gt!(count("Accident").unwrap(), 0);
+        assert_gt!(count("Finished").unwrap(), count("Accident").unwrap());
+
+        // Counts are scoped to the driver, i.e. lower than the counts for all drivers
+        let all_statuses = JOLPICA_MP.get_statuses(Filters::none()).unwrap();
+        for status in &statuses {
+            let all = all_statuses.iter().find(|s| s.status_id == status.status_id).unwrap();
+            assert_lt!(status.count, all.count);
+        }
+
+        assert_is_empty(|| JOLPICA_SP.get_statuses_for_driver("unknown".into()));
+    }
+
     // Resource::LapTimes
     // ------------------
 
@@ -2201,6 +4918,7 @@ mod tests {
         assert_eq!(driver_lap.number, lap.number);
         assert_eq!(driver_lap.position, timing.position);
         assert_eq!(driver_lap.time, timing.time);
+        assert_eq!(driver_lap.sectors, timing.sectors);
     }
 
     #[test]
@@ -2306,19 +5024,378 @@ mod tests {
 
     #[test]
     #[ignore]
-    fn get_response_pit_stops_race_2023_4() {
-        let resp = JOLPICA_SP
-            .get_response(&Resource::PitStops(PitStopFilters::new(2023, 4)))
-            .unwrap();
-        let race = verify_has_one_race_and_extract(resp).unwrap();
+    fn get_fastest_pit_stop() {
+        let pit_stops = JOLPICA_SP.get_pit_stops(PitStopFilters::new(2023, 4)).unwrap();
+        let fastest = JOLPICA_SP.get_fastest_pit_stop(RaceID::from(2023, 4)).unwrap();
+
+        assert_true!(pit_stops.contains(&fastest));
+        pit_stops
+            .iter()
+            .for_each(|pit_stop| assert_le!(fastest.duration, pit_stop.duration));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_fastest_pit_stop_error_not_found() {
+        assert_not_found(|| JOLPICA_SP.get_fastest_pit_stop(RaceID::from(1949, 1)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_fastest_on_lap() {
+        let timings = JOLPICA_SP.get_lap_timings(RaceID::from(2023, 4), 1).unwrap();
+        let fastest = JOLPICA_SP.get_fastest_on_lap(RaceID::from(2023, 4), 1).unwrap();
+
+        assert_true!(timings.contains(&fastest));
+        timings.iter().for_each(|timing| assert_le!(fastest.time, timing.time));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_fastest_on_lap_error_not_found() {
+        assert_not_found(|| JOLPICA_SP.get_fastest_on_lap(RaceID::from(1949, 1), 1));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_laps() {
+        let laps = JOLPICA_MP.get_laps(RaceID::from(2023, 4)).unwrap();
+        assert_eq!(laps.len(), 51);
+        assert_eq!(laps[0].timings[..2], LAP_2023_4_L1.timings[..]);
+        assert_eq!(laps[1].timings[..2], LAP_2023_4_L2.timings[..]);
+    }
+
+    #[test]
+    #[ignore]
+    fn get_laps_error_not_found() {
+        assert_not_found(|| JOLPICA_MP.get_laps(RaceID::from(1949, 1)));
+    }
+
+    #[test]
+    #[ignore]
+    fn is_grand_slam_race_2021_9() {
+        let race_id = RaceID::from(2021, 9);
+        let max = DriverID::from("max_verstappen");
+        let filters = Filters::new().season(race_id.season).round(race_id.round);
+
+        let race_result = JOLPICA_SP
+            .get_race_result(filters.clone().driver_id(max.clone()))
+            .unwrap()
+            .into_race_result();
+        let qualifying = JOLPICA_SP
+            .get_qualifying_result(filters.driver_id(max.clone()))
+            .unwrap()
+            .into_qualifying_result();
+        let lap_matrix = LapMatrix::new(JOLPICA_MP.get_laps(race_id).unwrap());
+
+        assert_true!(stats::is_grand_slam(&race_result, &qualifying, &lap_matrix, &max));
+        assert_false!(stats::is_grand_slam(&race_result, &qualifying, &lap_matrix, &DriverID::from("hamilton")));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_grand_slams_none() {
+        assert_true!(
+            JOLPICA_MP
+                .get_grand_slams(DriverID::from("de_vries"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn get_race_winner() {
+        let winner = JOLPICA_SP.get_race_winner(RaceID::from(2008, 18)).unwrap();
+        assert_eq!(winner.driver.driver_id, DriverID::from("massa"));
+        assert_eq!(winner.position, 1);
+
+        assert_eq!(JOLPICA_SP.get_race_winner(RaceID::from(2021, 12)).unwrap(), *RACE_RESULT_2021_12_P1);
+        assert_eq!(JOLPICA_SP.get_race_winner(RaceID::from(2023, 4)).unwrap(), *RACE_RESULT_2023_4_P1);
+
+        // 1951 French Grand Prix, won by Juan Manuel Fangio in the car he took over from Luigi Fagioli
+        let winner = JOLPICA_SP.get_race_winner(RaceID::from(1951, 4)).unwrap();
+        assert_true!([DriverID::from("fangio"), DriverID::from("fagioli")].contains(&winner.driver.driver_id));
+
+        assert_not_found(|| JOLPICA_SP.get_race_winner(RaceID::from(1949, 1)));
+        assert_not_found(|| JOLPICA_SP.get_race_winner(RaceID::from(2021, 100)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_qualifying_head_to_head() {
+        let (leclerc, sainz) = JOLPICA_SP
+            .get_qualifying_head_to_head(2022, &DriverID::from("leclerc"), &DriverID::from("sainz"))
+            .unwrap();
+        assert_eq!(leclerc + sainz, 22);
+        assert_ge!(leclerc, 15);
+
+        let (sainz_again, leclerc_again) = JOLPICA_SP
+            .get_qualifying_head_to_head(2022, &DriverID::from("sainz"), &DriverID::from("leclerc"))
+            .unwrap();
+        assert_eq!((leclerc_again, sainz_again), (leclerc, sainz));
+
+        assert_eq!(
+            JOLPICA_SP
+                .get_qualifying_head_to_head(1949, &DriverID::from("leclerc"), &DriverID::from("sainz"))
+                .unwrap(),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn get_podiums() {
+        let podiums = JOLPICA_SP.get_podiums(DriverID::from("leclerc")).unwrap();
+        assert_ge!(podiums.len(), 40);
+
+        assert_true!(podiums.iter().all(|race| race.race_result().position <= 3));
+        assert_true!(podiums.is_sorted_by_key(|race| (race.season, race.round)));
+
+        let race = podiums.iter().find(|race| race.id() == RaceID::from(2023, 4)).unwrap();
+        assert_eq!(race.race_result().position, 3);
+    }
+
+    #[test]
+    #[ignore]
+    fn get_podiums_none() {
+        assert_true!(JOLPICA_SP.get_podiums(DriverID::from("de_vries")).unwrap().is_empty());
+    }
+
+    #[test]
+    #[ignore]
+    fn get_wins_by_circuit() {
+        let wins = JOLPICA_SP.get_wins_by_circuit(DriverID::from("leclerc")).unwrap();
+        assert_ge!(wins[&CircuitID::from("monaco")], 1);
+        assert_ge!(wins[&CircuitID::from("monza")], 2);
+        assert_ge!(wins.values().sum::<u32>(), 8);
+        assert_false!(wins.contains_key(&CircuitID::from("baku")));
+
+        assert_true!(
+            JOLPICA_SP
+                .get_wins_by_circuit(DriverID::from("de_vries"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn get_round_winners() {
+        let winners = JOLPICA_SP.get_round_winners(2023).unwrap();
+        assert_eq!(winners.len(), 22);
+        assert_true!(winners.is_sorted_by_key(|(round, _)| *round));
+
+        assert_eq!(winners[0].0, 1);
+        assert_eq!(winners[0].1, *DRIVER_MAX);
+        assert_eq!(winners[3].0, 4);
+        assert_eq!(winners[3].1, *DRIVER_PEREZ);
+
+        assert_eq!(JOLPICA_SP.get_round_winners(1950).unwrap().len(), 7);
+
+        // Round 4 of 1951 was a shared drive win, with two race results in P1
+        let winners = JOLPICA_SP.get_round_winners(1951).unwrap();
+        assert_eq!(winners.len(), 8);
+        assert_eq!(winners[3].0, 4);
+        assert_true!([DriverID::from("fagioli"), DriverID::from("fangio")].contains(&winners[3].1.driver_id));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_round_winners_none() {
+        assert_true!(JOLPICA_SP.get_round_winners(1949).unwrap().is_empty());
+    }
+
+    #[test]
+    #[ignore]
+    fn get_response_pit_stops_race_2023_4() {
+        let resp = JOLPICA_SP
+            .get_response(&Resource::PitStops(PitStopFilters::new(2023, 4)))
+            .unwrap();
+        let race = verify_has_one_race_and_extract(resp).unwrap();
+
+        assert_eq!(race.as_info(), RACE_2023_4_PIT_STOPS.as_info());
+        assert_eq!(race.payload.as_pit_stops().unwrap().len(), 23);
+    }
+
+    // Resource::DriverStandings
+    // --------------------------
+
+    #[test]
+    #[ignore]
+    fn get_driver_standings() {
+        let standings = JOLPICA_SP
+            .get_driver_standings(Filters::new().season(2021).round(22))
+            .unwrap();
+        assert_eq!(standings.len(), 1);
+        assert_eq!((standings[0].season, standings[0].round), (2021, 22));
+
+        let standings = &standings[0].standings;
+        assert_eq!(standings.len(), 21);
+        assert_eq!(standings[0], *DRIVER_STANDING_2021_22_P1);
+        assert_eq!(standings[1], *DRIVER_STANDING_2021_22_P2);
+    }
+
+    #[test]
+    #[ignore]
+    fn get_driver_standings_by_driver() {
+        let standings = JOLPICA_SP
+            .get_driver_standings(Filters::new().driver_id("max_verstappen".into()))
+            .unwrap();
+        assert_ge!(standings.len(), 10);
+        assert_true!(standings.iter().all(|list| list.standings.len() == 1));
+
+        let season_2021 = standings.iter().find(|list| list.season == 2021).unwrap();
+        assert_eq!(season_2021.standings[0], *DRIVER_STANDING_2021_22_P1);
+    }
+
+    #[test]
+    #[ignore]
+    fn get_driver_standings_empty() {
+        assert_is_empty(|| JOLPICA_SP.get_driver_standings(Filters::new().season(1949)));
+    }
+
+    // Resource::ConstructorStandings
+    // ------------------------------
+
+    #[test]
+    #[ignore]
+    fn get_constructor_standings() {
+        let standings = JOLPICA_SP
+            .get_constructor_standings(Filters::new().season(2021).round(22))
+            .unwrap();
+        assert_eq!(standings.len(), 1);
+        assert_eq!((standings[0].season, standings[0].round), (2021, 22));
+
+        let standings = &standings[0].standings;
+        assert_eq!(standings.len(), 10);
+        assert_eq!(standings[0], *CONSTRUCTOR_STANDING_2021_22_P1);
+        assert_eq!(standings[1], *CONSTRUCTOR_STANDING_2021_22_P2);
+    }
+
+    #[test]
+    #[ignore]
+    fn get_constructor_standings_empty() {
+        assert_is_empty(|| JOLPICA_SP.get_constructor_standings(Filters::new().season(1949)));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_constructor_standings_progression() {
+        let progression = JOLPICA_SP.get_constructor_standings_progression(2021).unwrap();
+        assert_eq!(progression.len(), 22);
+        assert_true!(progression.iter().map(|(round, _)| *round).eq(1..=22));
+
+        let (_, final_standings) = progression.last().unwrap();
+        assert_eq!(final_standings[0], *CONSTRUCTOR_STANDING_2021_22_P1);
+        assert_eq!(final_standings[1], *CONSTRUCTOR_STANDING_2021_22_P2);
+
+        let (_, first_standings) = &progression[0];
+        assert_eq!(first_standings[0].constructor.constructor_id, ConstructorID::from("mercedes"));
+        assert_true!(
+            progression
+                .windows(2)
+                .all(|pair| pair[0].1[0].points <= pair[1].1[0].points)
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn get_constructor_standings_progression_empty() {
+        assert_true!(
+            JOLPICA_SP
+                .get_constructor_standings_progression(1949)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    // Pagination, get_response_page, get_response, get_response_max_limit
+    // -------------------------------------------------------------------
+
+    #[test]
+    fn agent_is_send_and_sync() {
+        // Compile-time checks; these fail to build if any field loses thread-safety
+        const fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Agent<'static>>();
+        assert_send_sync::<AgentConfigs<'static>>();
+        assert_send_sync::<RateLimiterOption<'static>>();
+        assert_send_sync::<Arc<RetryPredicate>>();
+    }
+
+    #[test]
+    fn get_response_cancellable_cancelled_before_first_page() {
+        // No requests are made if the flag is already set, so this does not hit the network
+        let agent = Agent::new(AgentConfigs {
+            base_url: "http://localhost:0".into(),
+            ..AgentConfigs::default()
+        });
+
+        assert!(matches!(
+            agent.get_response_cancellable(&Resource::SeasonList(Filters::none()), &AtomicBool::new(true)),
+            Err(Error::Cancelled)
+        ));
+    }
+
+    #[test]
+    #[ignore]
+    fn get_response_cancellable() {
+        let resource = Resource::SeasonList(Filters::none());
+
+        assert_eq!(
+            JOLPICA_SP
+                .get_response_cancellable(&resource, &AtomicBool::new(false))
+                .unwrap(),
+            JOLPICA_SP.get_response(&resource).unwrap()
+        );
+
+        // 2023, round 4 had ~1000 timings, i.e. multiple pages; cancel after the first request
+        let cancel = AtomicBool::new(false);
+        let result = std::thread::scope(|scope| {
+            let _unused = scope.spawn(|| {
+                std::thread::sleep(Duration::from_millis(get_request_avg_duration_ms() / 2));
+                cancel.store(true, Ordering::Relaxed);
+            });
+
+            JOLPICA_MP.get_response_cancellable(&Resource::LapTimes(LapTimeFilters::new(2023, 4)), &cancel)
+        });
+
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+
+    #[test]
+    #[ignore]
+    fn estimate_pages() {
+        assert_eq!(
+            JOLPICA_SP
+                .estimate_pages(&Resource::SeasonList(Filters::none()))
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            JOLPICA_SP
+                .estimate_pages(&Resource::SeasonList(Filters::new().season(1949)))
+                .unwrap(),
+            1
+        );
 
-        assert_eq!(race.as_info(), RACE_2023_4_PIT_STOPS.as_info());
-        assert_eq!(race.payload.as_pit_stops().unwrap().len(), 23);
+        // 2023, round 4 had 51 laps with ~20 drivers each, i.e. ~1000 timings
+        let pages = JOLPICA_SP
+            .estimate_pages(&Resource::LapTimes(LapTimeFilters::new(2023, 4)))
+            .unwrap();
+        assert_ge!(pages, 10);
+
+        let responses = JOLPICA_MP
+            .get_response_multi_pages(
+                &Resource::LapTimes(LapTimeFilters::new(2023, 4)),
+                Some(Page::with_max_limit()),
+                None,
+            )
+            .unwrap();
+        assert_eq!(responses.len(), pages);
     }
 
-    // Pagination, get_response_page, get_response, get_response_max_limit
-    // -------------------------------------------------------------------
-
     #[test]
     #[ignore]
     fn get_response_single_element() {
@@ -2470,6 +5547,28 @@ mod tests {
         assert_eq!(seasons.last().unwrap().season, 1950 + current_offset + (seasons.len() as u32) - 1);
     }
 
+    #[test]
+    #[ignore]
+    fn get_response_from_offset() {
+        let resource = Resource::SeasonList(Filters::none());
+
+        let responses = JOLPICA_SP.get_response_from_offset(&resource, 50, 10).unwrap();
+        assert_ge!(responses.len(), 3); // (76 - 50) / 10
+
+        for (idx, resp) in responses.iter().enumerate() {
+            assert_eq!(resp.pagination.limit, 10);
+            assert_eq!(resp.pagination.offset, 50 + 10 * idx as u32);
+        }
+
+        assert_eq!(responses[0].table.as_seasons().unwrap()[0], *SEASON_2000);
+        assert_true!(responses.last().unwrap().pagination.is_last_page());
+
+        let from_start = JOLPICA_SP
+            .get_response_multi_pages(&resource, Some(Page::with_limit(10)), None)
+            .unwrap();
+        assert_eq!(responses, from_start[5..]);
+    }
+
     #[test]
     #[ignore]
     fn get_response_error_wrong_base_url() {
@@ -2492,7 +5591,7 @@ mod tests {
     fn get_response_error_http_retries() {
         let jolpica = Agent::new(AgentConfigs {
             base_url: "http://nonexistent.local".into(),
-            http_retries: Some(1),
+            http_retries: Some(RetryPolicy::immediate(1)),
             rate_limiter: RateLimiterOption::None,
             ..Default::default()
         });
@@ -2504,9 +5603,533 @@ mod tests {
         assert!(matches!(jolpica.get_response(&resource), Err(Error::HttpRetries((1, _)))));
     }
 
+    #[test]
+    #[ignore]
+    fn get_response_error_retry_predicate() {
+        let jolpica = Agent::new(AgentConfigs {
+            base_url: "http://nonexistent.local".into(),
+            http_retries: Some(RetryPolicy::immediate(1)),
+            retry_predicate: Some(Arc::new(|_| false)),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let resource = Resource::SeasonList(Filters::none());
+
+        assert!(matches!(jolpica.get_response_page(&resource, Page::default()), Err(Error::Http(_))));
+        assert!(matches!(jolpica.get_response_multi_pages(&resource, None, None), Err(Error::Http(_))));
+        assert!(matches!(jolpica.get_response(&resource), Err(Error::Http(_))));
+    }
+
+    #[test]
+    fn get_response_conditional_requests() {
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 4);
+        let resource = Resource::SeasonList(Filters::none());
+
+        let jolpica = Agent::new(AgentConfigs {
+            base_url,
+            multi_page: MultiPageOption::Enabled(None),
+            conditional_requests: true,
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let (resp, freshness) = jolpica
+            .get_response_page_with_freshness(&resource, Page::default())
+            .unwrap();
+        assert_eq!(freshness, Freshness::Fresh);
+        assert_eq!(resp.table, *SEASON_TABLE);
+
+        let (cached, freshness) = jolpica
+            .get_response_page_with_freshness(&resource, Page::default())
+            .unwrap();
+        assert_eq!(freshness, Freshness::NotModified);
+        assert_eq!(cached, resp);
+
+        // Uses a different URL, i.e. with a max page limit, so a fresh request is made and cached
+        assert_eq!(jolpica.get_response(&resource).unwrap().table, *SEASON_TABLE);
+
+        jolpica.clear_response_cache();
+        let (_, freshness) = jolpica
+            .get_response_page_with_freshness(&resource, Page::default())
+            .unwrap();
+        assert_eq!(freshness, Freshness::Fresh);
+
+        assert_eq!(server.join().unwrap(), 1);
+    }
+
+    #[test]
+    fn get_response_filesystem_cache() {
+        // The server only handles two requests, so any additional request would fail
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 2);
+        let resource = Resource::SeasonList(Filters::none());
+
+        let cache_dir = std::env::temp_dir().join(format!("f1_data_agent_cache_{}", std::process::id()));
+        let _unused = fs::remove_dir_all(&cache_dir);
+
+        let make_agent = |cache_expiry| {
+            Agent::new(AgentConfigs {
+                base_url: base_url.clone(),
+                http_retries: None,
+                cache: CacheOption::Directory(cache_dir.clone()),
+                cache_expiry,
+                rate_limiter: RateLimiterOption::None,
+                ..Default::default()
+            })
+        };
+
+        let jolpica = make_agent(CacheExpiry::Never);
+        assert_eq!(jolpica.get_seasons(Filters::none()).unwrap(), SEASON_TABLE.as_seasons().unwrap().clone());
+        assert_eq!(jolpica.get_seasons(Filters::none()).unwrap(), SEASON_TABLE.as_seasons().unwrap().clone());
+
+        // Uses a different URL, i.e. without a max page limit, so a fresh request is made and cached
+        let (resp, freshness) = jolpica
+            .get_response_page_with_freshness(&resource, Page::default())
+            .unwrap();
+        assert_eq!(freshness, Freshness::Fresh);
+        assert_eq!(resp.table, *SEASON_TABLE);
+
+        // The cache is shared across agents, as long as entries have not expired
+        let (cached, freshness) = make_agent(CacheExpiry::Never)
+            .get_response_page_with_freshness(&resource, Page::default())
+            .unwrap();
+        assert_eq!(freshness, Freshness::Cached);
+        assert_eq!(cached, resp);
+
+        assert_eq!(server.join().unwrap(), 0);
+
+        // Expired entries are requested again, which fails since the server is no longer running
+        assert!(matches!(
+            make_agent(CacheExpiry::After(Duration::ZERO)).get_response(&resource),
+            Err(Error::Http(_))
+        ));
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn get_response_filesystem_cache_invalid_file() {
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 1);
+        let resource = Resource::SeasonList(Filters::none());
+
+        let cache_dir = std::env::temp_dir().join(format!("f1_data_agent_cache_invalid_{}", std::process::id()));
+        let _unused = fs::remove_dir_all(&cache_dir);
+        fs::create_dir_all(&cache_dir).unwrap();
+
+        let url = resource.to_url_with_base_and_opt_page(&base_url, Some(Page::default()));
+        let path = cache_dir.join(cache_file_name(url.as_str()));
+
+        // A truncated file, e.g. from an interrupted write, is treated as a cache miss and replaced
+        fs::write(&path, &RESPONSE_SEASON_TABLE_STR[..RESPONSE_SEASON_TABLE_STR.len() / 2]).unwrap();
+
+        let jolpica = Agent::new(AgentConfigs {
+            base_url,
+            http_retries: None,
+            cache: CacheOption::Directory(cache_dir.clone()),
+            cache_expiry: CacheExpiry::Never,
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let (resp, freshness) = jolpica
+            .get_response_page_with_freshness(&resource, Page::default())
+            .unwrap();
+        assert_eq!(freshness, Freshness::Fresh);
+        assert_eq!(resp.table, *SEASON_TABLE);
+
+        let (cached, freshness) = jolpica
+            .get_response_page_with_freshness(&resource, Page::default())
+            .unwrap();
+        assert_eq!(freshness, Freshness::Cached);
+        assert_eq!(cached, resp);
+
+        // No temporary files are left behind
+        assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 1);
+        assert_eq!(fs::read_to_string(&path).unwrap(), RESPONSE_SEASON_TABLE_STR);
+
+        assert_eq!(server.join().unwrap(), 0);
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn cache_expiry_is_expired() {
+        let (hour, minute) = (Duration::from_hours(1), Duration::from_mins(1));
+
+        let past = Resource::RaceResults(Filters::new().season(1950));
+        let current = Resource::RaceResults(Filters::new().season(9999));
+        let unfiltered = Resource::SeasonList(Filters::none());
+
+        for resource in [&past, &current, &unfiltered] {
+            assert_false!(CacheExpiry::Never.is_expired(resource, hour));
+            assert_true!(CacheExpiry::After(minute).is_expired(resource, hour));
+            assert_false!(CacheExpiry::After(hour).is_expired(resource, minute));
+        }
+
+        let expiry = CacheExpiry::ImmutablePastSeasons(minute);
+        assert_false!(expiry.is_expired(&past, hour));
+        assert_true!(expiry.is_expired(&current, hour));
+        assert_true!(expiry.is_expired(&unfiltered, hour));
+        assert_false!(expiry.is_expired(&unfiltered, Duration::ZERO));
+    }
+
+    #[test]
+    fn cache_file_name_is_unique() {
+        assert_eq!(
+            cache_file_name("https://api.jolpi.ca/ergast/f1/seasons.json?limit=100&offset=0"),
+            "https%3A%2F%2Fapi.jolpi.ca%2Fergast%2Ff1%2Fseasons.json%3Flimit%3D100%26offset%3D0.json"
+        );
+        assert_true!(
+            cache_file_name("https://api.jolpi.ca/ergast/f1/2023/drivers.json")
+                != cache_file_name("https://api.jolpi.ca/ergast/f1/2023_drivers.json")
+        );
+    }
+
+    #[test]
+    fn get_response_verify_table_variant() {
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 3);
+        let resource = Resource::DriverInfo(Filters::none());
+
+        let make_agent = |verify_table_variant| {
+            Agent::new(AgentConfigs {
+                base_url: base_url.clone(),
+                verify_table_variant,
+                rate_limiter: RateLimiterOption::None,
+                ..Default::default()
+            })
+        };
+
+        assert!(matches!(
+            make_agent(true).get_response(&resource),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Drivers,
+                actual: TableKind::Seasons
+            })
+        ));
+        assert_true!(
+            make_agent(true)
+                .get_response(&Resource::SeasonList(Filters::none()))
+                .is_ok()
+        );
+
+        // Without verification, the mismatch is only caught at extraction time
+        let response = make_agent(false).get_response(&resource).unwrap();
+        assert!(matches!(response.into_drivers(), Err(Error::BadTableVariant { .. })));
+
+        assert_eq!(server.join().unwrap(), 0);
+    }
+
+    #[test]
+    fn get_response_season_range() {
+        // One request is made per season, each returning the same seasons, which are deduplicated
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 3);
+
+        let jolpica = Agent::new(AgentConfigs {
+            base_url,
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let response = jolpica
+            .get_response(&Resource::SeasonList(Filters::new().season_range(2021..=2023)))
+            .unwrap();
+        assert_eq!(response.table, *SEASON_TABLE);
+        assert_eq!(response.pagination.total as usize, SEASON_TABLE.as_seasons().unwrap().len());
+
+        assert_eq!(server.join().unwrap(), 0);
+    }
+
+    #[test]
+    fn get_season_range_with_mock_backend() {
+        let empty = race_table_json("https://api.jolpi.ca/ergast/f1/results.json", 100, 0, 0, "");
+        let resource = |season| Resource::RaceResults(Filters::new().season(season).round(99));
+
+        let jolpica = Agent::new(AgentConfigs {
+            backend: Arc::new(
+                MockBackend::new()
+                    .with_response(resource(2021), empty.clone())
+                    .with_response(resource(2022), empty),
+            ),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let filters = Filters::new().season_range(2021..=2022).round(99);
+        assert!(matches!(
+            jolpica.get_race_results_for_event(filters.clone()),
+            Err(Error::NotFound(endpoint)) if endpoint.starts_with("/2021..=2022/99/results")
+        ));
+
+        // Single-request methods cannot split the range, so they error without making any requests
+        let resource = Resource::RaceResults(filters);
+        let is_unsupported =
+            |error: &Error| matches!(error, Error::UnsupportedSeasonRange(e) if e == "/2021..=2022/99/results");
+
+        assert_true!(is_unsupported(&jolpica.get_response_page(&resource, Page::with_limit(1)).unwrap_err()));
+        assert_true!(is_unsupported(&jolpica.estimate_pages(&resource).unwrap_err()));
+        assert_true!(is_unsupported(&jolpica.get_response_from_offset(&resource, 0, 10).unwrap_err()));
+    }
+
+    #[test]
+    fn get_round_winners_with_mock_backend() {
+        // A shared drive, i.e. two results with a finishing position of 1 in the same race, as for
+        // round 4 of 1951; the first result, as ordered by the jolpica-f1 API, is the winner
+        let shared_drive_p1 = RACE_RESULT_2023_4_P2_STR
+            .replace(r#""position": "2""#, r#""position": "1""#)
+            .replace(r#""positionText": "2""#, r#""positionText": "1""#);
+
+        let response = race_table_json(
+            "https://api.jolpi.ca/ergast/f1/2023/results/1.json",
+            100,
+            0,
+            2,
+            &format!(r#"{{ {RACE_2023_4_STR}, "Results": [{RACE_RESULT_2023_4_P1_STR}, {shared_drive_p1}] }}"#),
+        );
+
+        let jolpica = Agent::new(AgentConfigs {
+            backend: Arc::new(
+                MockBackend::new()
+                    .with_response(Resource::RaceResults(Filters::new().season(2023).finish_pos(1)), response),
+            ),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let winners = jolpica.get_round_winners(2023).unwrap();
+        assert_eq!(winners.len(), 1);
+        assert_eq!(winners[0].0, 4);
+        assert_eq!(winners[0].1, *DRIVER_PEREZ);
+    }
+
+    #[test]
+    fn iter_all_race_results_with_mock_backend() {
+        let race_results = race_table_json(
+            "https://api.jolpi.ca/ergast/f1/2023/results.json",
+            100,
+            0,
+            4,
+            &format!("{RACE_2023_3_RACE_RESULTS_STR}, {RACE_2023_4_RACE_RESULTS_STR}"),
+        );
+
+        // Only the seasons list and 2023 are mocked, so requesting any other season would fail
+        let jolpica = Agent::new(AgentConfigs {
+            backend: Arc::new(
+                MockBackend::new()
+                    .with_response(Resource::SeasonList(Filters::none()), RESPONSE_SEASON_TABLE_STR)
+                    .with_response(Resource::RaceResults(Filters::new().season(2023)), race_results),
+            ),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let seasons: Vec<_> = jolpica.iter_all_race_results(2001).map(Result::unwrap).collect();
+        assert_eq!(seasons.len(), 1);
+        assert_eq!(seasons[0].0, 2023);
+        assert_eq!(seasons[0].1.len(), 2);
+
+        assert!(matches!(jolpica.iter_all_race_results(2000).next(), Some(Err(Error::UnexpectedData(_)))));
+        assert_true!(jolpica.iter_all_race_results(2024).next().is_none());
+    }
+
+    #[test]
+    fn get_with_mock_backend() {
+        let backend =
+            MockBackend::new().with_response(Resource::SeasonList(Filters::none()), RESPONSE_SEASON_TABLE_STR);
+
+        let jolpica = Agent::new(AgentConfigs {
+            backend: Arc::new(backend),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        assert_eq!(jolpica.get_seasons(Filters::none()).unwrap(), SEASON_TABLE.clone().into_seasons().unwrap());
+        assert!(matches!(jolpica.get_seasons(Filters::new().season(2023)), Err(Error::UnexpectedData(_))));
+    }
+
+    #[test]
+    fn get_driver_career_span_with_mock_backend() {
+        let response = |offset: u32, total: u32, races: &str| {
+            race_table_json("https://api.jolpi.ca/ergast/f1/races.json", 1, offset, total, races)
+        };
+        let resource = |driver_id: &str| Resource::RaceSchedule(Filters::new().driver_id(driver_id.into()));
+
+        let backend = MockBackend::new()
+            .with_page_response(resource("alonso"), Page::with_limit(1), response(0, 3, RACE_2003_4_SCHEDULE_STR))
+            .with_page_response(resource("alonso"), Page::with(1, 2), response(2, 3, RACE_2023_4_SCHEDULE_STR))
+            .with_response(resource("single"), response(0, 1, RACE_2023_4_SCHEDULE_STR))
+            .with_response(resource("none"), response(0, 0, ""));
+
+        let jolpica = Agent::new(AgentConfigs {
+            backend: Arc::new(backend),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let schedule = |race: &Race<Payload>| race.clone().map(|payload| payload.into_schedule().unwrap());
+
+        let (first, last) = jolpica.get_driver_career_span("alonso".into()).unwrap();
+        assert_eq!(first, schedule(&RACE_2003_4_SCHEDULE));
+        assert_eq!(last, schedule(&RACE_2023_4_SCHEDULE));
+
+        let (first, last) = jolpica.get_driver_career_span("single".into()).unwrap();
+        assert_eq!(first, schedule(&RACE_2023_4_SCHEDULE));
+        assert_eq!(first, last);
+
+        assert_not_found(|| jolpica.get_driver_career_span("none".into()));
+    }
+
+    #[test]
+    fn get_season_driver_points_with_mock_backend() {
+        let url = "https://api.jolpi.ca/ergast/f1/2023/results.json";
+
+        // Multiple results per race, across multiple races, for both race and sprint results
+        let backend = MockBackend::new()
+            .with_response(
+                Resource::RaceResults(Filters::new().season(2023)),
+                race_table_json(
+                    url,
+                    100,
+                    0,
+                    4,
+                    &format!("{RACE_2023_3_RACE_RESULTS_STR}, {RACE_2023_4_RACE_RESULTS_STR}"),
+                ),
+            )
+            .with_response(
+                Resource::SprintResults(Filters::new().season(2023)),
+                race_table_json(url, 100, 0, 2, RACE_2023_4_SPRINT_RESULTS_STR),
+            )
+            .with_response(Resource::RaceResults(Filters::new().season(2024)), race_table_json(url, 100, 0, 0, ""));
+
+        let jolpica = Agent::new(AgentConfigs {
+            backend: Arc::new(backend),
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let points = jolpica.get_season_driver_points(2023).unwrap();
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[&DriverID::from("perez")], 25.0 + 8.0);
+        assert_eq!(points[&DriverID::from("max_verstappen")], 18.0 + 6.0);
+        assert_eq!(points[&DriverID::from("de_vries")], 0.0);
+
+        assert!(matches!(jolpica.get_season_driver_points(2024), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn get_with_user_agent_and_extra_headers() {
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 1);
+
+        let jolpica = Agent::new(AgentConfigs {
+            base_url,
+            user_agent: Some("my-f1-app/1.0".into()),
+            extra_headers: BTreeMap::from([("If-None-Match".into(), MOCK_ETAG.into())]),
+            conditional_requests: true,
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        // The mock server responds with `304 Not Modified` to the extra `If-None-Match` header, but
+        // there's no cached response for it, since the agent did not set that header itself
+        assert!(matches!(
+            jolpica.get_response_page(&Resource::SeasonList(Filters::none()), Page::default()),
+            Err(Error::UnexpectedData(_))
+        ));
+        assert_eq!(server.join().unwrap(), 1);
+
+        assert_eq!(jolpica.request_headers(), [("User-Agent", "my-f1-app/1.0"), ("If-None-Match", MOCK_ETAG)]);
+    }
+
+    #[test]
+    fn get_season_opt_error_too_many() {
+        // The mocked response contains multiple seasons, so only NotFound is converted into None
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 1);
+
+        let jolpica = Agent::new(AgentConfigs {
+            base_url,
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        assert!(matches!(jolpica.get_season_opt(2023), Err(Error::TooMany)));
+        assert_eq!(server.join().unwrap(), 0);
+    }
+
+    #[test]
+    fn get_batch_deduplicates_requests() {
+        // The server only handles two requests, so any additional request would fail
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 2);
+
+        let jolpica = Agent::new(AgentConfigs {
+            base_url,
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+
+        let seasons = Resource::SeasonList(Filters::none());
+        let seasons_2023 = Resource::SeasonList(Filters::new().season(2023));
+
+        let results = jolpica.get_batch(&[seasons.clone(), seasons_2023, seasons]);
+        assert_eq!(results.len(), 3);
+
+        let responses: Vec<_> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(responses[0], responses[2]);
+        for response in &responses {
+            assert_eq!(response.table, *SEASON_TABLE);
+        }
+
+        assert_eq!(server.join().unwrap(), 0);
+
+        // A failing duplicate is also requested only once, as the server handles a single request
+        let (base_url, server) = spawn_mock_server(vec![String::from(
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        )]);
+
+        let jolpica = Agent::new(AgentConfigs {
+            base_url,
+            rate_limiter: RateLimiterOption::None,
+            http_retries: None,
+            ..Default::default()
+        });
+
+        let drivers = Resource::DriverInfo(Filters::new().season(2023));
+
+        let results = jolpica.get_batch(&[drivers.clone(), drivers]);
+        assert_eq!(results.len(), 2);
+
+        for result in results {
+            assert!(matches!(result, Err(Error::Http(ureq::Error::StatusCode(404)))));
+        }
+
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn get_batch_empty() {
+        assert_true!(Agent::default().get_batch(&[]).is_empty());
+    }
+
     // Rate limiting
     // -------------
 
+    #[test]
+    fn agent_rate_limiter() {
+        let default = Agent::default();
+        assert_eq!(default.rate_limiter().unwrap().quota(), JOLPICA_API_RATE_LIMIT_QUOTA);
+        assert_eq!(default.rate_limiter().unwrap().available(), JOLPICA_API_RATE_LIMIT_QUOTA.burst_size().get());
+
+        let external = RateLimiter::new(JOLPICA_API_RATE_LIMIT_QUOTA);
+        let agent = Agent::new(AgentConfigs {
+            rate_limiter: RateLimiterOption::External(&external),
+            ..Default::default()
+        });
+        assert_true!(std::ptr::eq(agent.rate_limiter().unwrap(), &external));
+
+        let agent = Agent::new(AgentConfigs {
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        });
+        assert_true!(agent.rate_limiter().is_none());
+    }
+
     #[test]
     #[ignore]
     fn rate_limiting() {
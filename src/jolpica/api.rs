@@ -3,13 +3,13 @@
 
 use nonzero_ext::nonzero;
 
-use crate::rate_limiter::Quota;
+use crate::{id::ConstructorID, rate_limiter::Quota};
 
 #[cfg(doc)]
 use crate::{
     jolpica::{
         resource::{Filters, Page, Resource},
-        response::{RaceResult, Response, SprintResult},
+        response::{Constructor, Driver, Location, RaceResult, Response, SprintResult},
     },
     rate_limiter::RateLimiter,
 };
@@ -69,3 +69,297 @@ pub const JOLPICA_API_PAGINATION: Pagination = Pagination {
 /// It can be set in the [`Filters::grid_pos`] field, and may be returned in [`SprintResult::grid`]
 /// and [`RaceResult::grid`]. See [`Resource::RaceResults`] for more information.
 pub const GRID_PIT_LANE: u32 = 0;
+
+/// Lineages of constructors that have changed identity over the years, i.e. the same team racing
+/// under different [`ConstructorID`]s in the jolpica-f1 API, each in chronological order.
+///
+/// For example, the team now known as Racing Bulls raced as `"minardi"`, `"toro_rosso"`,
+/// `"alphatauri"`, and `"rb"`. See [`constructor_lineage`] to look up the lineage of a constructor.
+///
+/// **Note:** Some [`ConstructorID`]s are shared with unrelated historical teams, e.g. `"renault"`
+/// was also used by the Renault works team from 1977 to 1985, and `"mercedes"` by the Mercedes
+/// works team in 1954 and 1955, so results for a lineage may include those of such teams.
+pub const CONSTRUCTOR_LINEAGES: &[&[&str]] = &[
+    &["minardi", "toro_rosso", "alphatauri", "rb"],
+    &[
+        "jordan",
+        "mf1",
+        "spyker_mf1",
+        "spyker",
+        "force_india",
+        "racing_point",
+        "aston_martin",
+    ],
+    &["toleman", "benetton", "renault", "lotus_f1", "alpine"],
+    &["tyrrell", "bar", "honda", "brawn", "mercedes"],
+    &["stewart", "jaguar", "red_bull"],
+    &["sauber", "bmw_sauber", "alfa"],
+    &["ligier", "prost"],
+    &["virgin", "marussia", "manor"],
+];
+
+/// Returns the lineage of the constructor with `constructor_id`, i.e. all of the [`ConstructorID`]s
+/// that the same team has raced under, in chronological order, as listed in [`CONSTRUCTOR_LINEAGES`].
+///
+/// If the constructor is not part of any lineage, a list with only `constructor_id` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use f1_data::jolpica::api::constructor_lineage;
+///
+/// assert_eq!(constructor_lineage("alphatauri".into()), ["minardi", "toro_rosso", "alphatauri", "rb"]);
+/// assert_eq!(constructor_lineage("ferrari".into()), ["ferrari"]);
+/// ```
+pub fn constructor_lineage(constructor_id: ConstructorID) -> Vec<ConstructorID> {
+    CONSTRUCTOR_LINEAGES
+        .iter()
+        .find(|lineage| lineage.contains(&constructor_id.as_str()))
+        .map_or_else(|| vec![constructor_id], |lineage| lineage.iter().map(ToString::to_string).collect())
+}
+
+/// Maps the nationalities used by the jolpica-f1 API to ISO 3166-1 alpha-2 country codes.
+///
+/// The nationalities are those in e.g. [`Driver::nationality`] and [`Constructor::nationality`],
+/// and the codes are [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2), e.g.
+/// `"Dutch"` maps to `"NL"`. See [`nationality_country_code`] to look up a code.
+///
+/// Some nationalities have multiple spellings, e.g. `"Argentine"` and `"Argentinian"`, and some
+/// drivers have dual nationalities, e.g. `"American-Italian"`, which map to the first nationality.
+///
+/// **Note:** Nationalities of historical states map to the codes that were assigned to them at the
+/// time, which are no longer part of ISO 3166-1, i.e. `"East German"` to `"DD"` and `"Rhodesian"`
+/// to `"RH"`, so that they can be told apart from their present-day successors.
+pub const NATIONALITY_COUNTRY_CODES: &[(&str, &str)] = &[
+    ("American", "US"),
+    ("American-Italian", "US"),
+    ("Argentine", "AR"),
+    ("Argentine-Italian", "AR"),
+    ("Argentinian", "AR"),
+    ("Australian", "AU"),
+    ("Austrian", "AT"),
+    ("Belgian", "BE"),
+    ("Brazilian", "BR"),
+    ("British", "GB"),
+    ("Canadian", "CA"),
+    ("Chilean", "CL"),
+    ("Chinese", "CN"),
+    ("Colombian", "CO"),
+    ("Czech", "CZ"),
+    ("Danish", "DK"),
+    ("Dutch", "NL"),
+    ("East German", "DD"),
+    ("English", "GB"),
+    ("Finnish", "FI"),
+    ("French", "FR"),
+    ("German", "DE"),
+    ("Hong Kong", "HK"),
+    ("Hungarian", "HU"),
+    ("Indian", "IN"),
+    ("Indonesian", "ID"),
+    ("Irish", "IE"),
+    ("Israeli", "IL"),
+    ("Italian", "IT"),
+    ("Japanese", "JP"),
+    ("Liechtensteiner", "LI"),
+    ("Malaysian", "MY"),
+    ("Mexican", "MX"),
+    ("Monacan", "MC"),
+    ("Monegasque", "MC"),
+    ("New Zealander", "NZ"),
+    ("Polish", "PL"),
+    ("Portuguese", "PT"),
+    ("Rhodesian", "RH"),
+    ("Russian", "RU"),
+    ("Scottish", "GB"),
+    ("South African", "ZA"),
+    ("Spanish", "ES"),
+    ("Swedish", "SE"),
+    ("Swiss", "CH"),
+    ("Thai", "TH"),
+    ("Uruguayan", "UY"),
+    ("Venezuelan", "VE"),
+    ("Welsh", "GB"),
+    ("West German", "DE"),
+];
+
+/// Maps the country names used by the jolpica-f1 API to ISO 3166-1 alpha-2 country codes.
+///
+/// The country names are those in e.g. [`Location::country`], and the codes are
+/// [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2), e.g. `"Netherlands"`
+/// maps to `"NL"`. See [`country_code`] to look up a code.
+///
+/// Some countries are abbreviated by the jolpica-f1 API, e.g. `"UK"`, `"USA"`, and `"UAE"`, while
+/// others use their full names, so both forms are included, e.g. `"UK"` and `"United Kingdom"`.
+pub const COUNTRY_CODES: &[(&str, &str)] = &[
+    ("Argentina", "AR"),
+    ("Australia", "AU"),
+    ("Austria", "AT"),
+    ("Azerbaijan", "AZ"),
+    ("Bahrain", "BH"),
+    ("Belgium", "BE"),
+    ("Brazil", "BR"),
+    ("Canada", "CA"),
+    ("China", "CN"),
+    ("France", "FR"),
+    ("Germany", "DE"),
+    ("Great Britain", "GB"),
+    ("Hungary", "HU"),
+    ("India", "IN"),
+    ("Italy", "IT"),
+    ("Japan", "JP"),
+    ("Korea", "KR"),
+    ("Malaysia", "MY"),
+    ("Mexico", "MX"),
+    ("Monaco", "MC"),
+    ("Morocco", "MA"),
+    ("Netherlands", "NL"),
+    ("Portugal", "PT"),
+    ("Qatar", "QA"),
+    ("Russia", "RU"),
+    ("Saudi Arabia", "SA"),
+    ("Singapore", "SG"),
+    ("South Africa", "ZA"),
+    ("South Korea", "KR"),
+    ("Spain", "ES"),
+    ("Sweden", "SE"),
+    ("Switzerland", "CH"),
+    ("Turkey", "TR"),
+    ("UAE", "AE"),
+    ("UK", "GB"),
+    ("USA", "US"),
+    ("United Arab Emirates", "AE"),
+    ("United Kingdom", "GB"),
+    ("United States", "US"),
+    ("Vietnam", "VN"),
+];
+
+/// Returns the ISO 3166-1 alpha-2 country code for a `nationality`, or [`None`] if not known.
+///
+/// The `nationality` is as used by the jolpica-f1 API, e.g. in [`Driver::nationality`], and is
+/// looked up in [`NATIONALITY_COUNTRY_CODES`]. The lookup ignores ASCII case and surrounding
+/// whitespace. Unknown dual nationalities, i.e. of the form `"First-Second"`, fall back to the
+/// code of the first nationality.
+///
+/// # Examples
+///
+/// ```
+/// use f1_data::jolpica::api::nationality_country_code;
+///
+/// assert_eq!(nationality_country_code("Dutch"), Some("NL"));
+/// assert_eq!(nationality_country_code("Monegasque"), Some("MC"));
+/// assert_eq!(nationality_country_code("American-Italian"), Some("US"));
+/// assert_eq!(nationality_country_code("Martian"), None);
+/// ```
+pub fn nationality_country_code(nationality: &str) -> Option<&'static str> {
+    let nationality = nationality.trim();
+
+    lookup_country_code(NATIONALITY_COUNTRY_CODES, nationality).or_else(|| {
+        nationality
+            .split_once('-')
+            .and_then(|(first, _)| lookup_country_code(NATIONALITY_COUNTRY_CODES, first.trim()))
+    })
+}
+
+/// Returns the ISO 3166-1 alpha-2 country code for a `country` name, or [`None`] if not known.
+///
+/// The `country` name is as used by the jolpica-f1 API, e.g. in [`Location::country`], and is
+/// looked up in [`COUNTRY_CODES`]. The lookup ignores ASCII case and surrounding whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use f1_data::jolpica::api::{country_code, nationality_country_code};
+///
+/// assert_eq!(country_code("UK"), Some("GB"));
+/// assert_eq!(country_code("UK"), nationality_country_code("British"));
+/// assert_eq!(country_code("Atlantis"), None);
+/// ```
+pub fn country_code(country: &str) -> Option<&'static str> {
+    lookup_country_code(COUNTRY_CODES, country.trim())
+}
+
+/// Returns the country code for `name` in a table of `(name, code)` pairs, ignoring ASCII case.
+fn lookup_country_code(table: &[(&str, &'static str)], name: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(entry, _)| entry.eq_ignore_ascii_case(name))
+        .map(|(_, code)| *code)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn constructor_lineages_are_unique() {
+        let ids: Vec<_> = CONSTRUCTOR_LINEAGES.iter().flat_map(|lineage| lineage.iter()).collect();
+        assert_eq!(ids.len(), ids.iter().collect::<HashSet<_>>().len());
+        assert_true!(CONSTRUCTOR_LINEAGES.iter().all(|lineage| lineage.len() > 1));
+    }
+
+    #[test]
+    fn constructor_lineage() {
+        let racing_bulls = ["minardi", "toro_rosso", "alphatauri", "rb"];
+
+        for id in racing_bulls {
+            assert_eq!(super::constructor_lineage(id.into()), racing_bulls);
+        }
+
+        assert_eq!(super::constructor_lineage("red_bull".into()), ["stewart", "jaguar", "red_bull"]);
+        assert_eq!(super::constructor_lineage("ferrari".into()), ["ferrari"]);
+        assert_eq!(super::constructor_lineage("unknown".into()), ["unknown"]);
+    }
+
+    #[test]
+    fn country_code_tables_are_valid() {
+        for table in [NATIONALITY_COUNTRY_CODES, COUNTRY_CODES] {
+            let names: Vec<_> = table.iter().map(|(name, _)| name.to_ascii_lowercase()).collect();
+            assert_eq!(names.len(), names.iter().collect::<HashSet<_>>().len());
+
+            for (name, code) in table {
+                assert_eq!(name.trim(), *name);
+                assert_eq!(code.len(), 2);
+                assert_true!(code.chars().all(|c| c.is_ascii_uppercase()));
+            }
+        }
+    }
+
+    #[test]
+    fn nationality_country_code() {
+        assert_eq!(super::nationality_country_code("Dutch"), Some("NL"));
+        assert_eq!(super::nationality_country_code("British"), Some("GB"));
+        assert_eq!(super::nationality_country_code("Monegasque"), Some("MC"));
+        assert_eq!(super::nationality_country_code("East German"), Some("DD"));
+
+        // Alternative spellings, dual nationalities, case, and whitespace
+        assert_eq!(super::nationality_country_code("Argentinian"), Some("AR"));
+        assert_eq!(super::nationality_country_code("Argentine-Italian"), Some("AR"));
+        assert_eq!(super::nationality_country_code("Finnish-Swedish"), Some("FI"));
+        assert_eq!(super::nationality_country_code("dutch"), Some("NL"));
+        assert_eq!(super::nationality_country_code(" New Zealander "), Some("NZ"));
+
+        assert_eq!(super::nationality_country_code(""), None);
+        assert_eq!(super::nationality_country_code("Martian"), None);
+        assert_eq!(super::nationality_country_code("Martian-Dutch"), None);
+    }
+
+    #[test]
+    fn country_code() {
+        assert_eq!(super::country_code("Netherlands"), Some("NL"));
+        assert_eq!(super::country_code("UK"), Some("GB"));
+        assert_eq!(super::country_code("United Kingdom"), Some("GB"));
+        assert_eq!(super::country_code("USA"), Some("US"));
+        assert_eq!(super::country_code("uae"), Some("AE"));
+        assert_eq!(super::country_code(" Monaco "), Some("MC"));
+
+        assert_eq!(super::country_code(""), None);
+        assert_eq!(super::country_code("Atlantis"), None);
+    }
+}
@@ -0,0 +1,831 @@
+//! An [`AsyncAgent`], and associated configuration options, for asynchronously accessing the
+//! [jolpica-f1](https://github.com/jolpica/jolpica-f1) API, available with the `async` feature.
+//!
+//! [`AsyncAgent`] is built on [`reqwest`], and so it must be used from within a
+//! [Tokio](https://tokio.rs) runtime. It reuses the same [`Resource`], [`Filters`], [`Response`],
+//! and multi-page concatenation logic as the synchronous [`Agent`], so results are identical.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use futures_util::{StreamExt, TryStreamExt, stream};
+
+use crate::{
+    error::{Error, Result},
+    id::{CircuitID, ConstructorID, DriverID, RaceID, SeasonID},
+    jolpica::{
+        agent::{
+            IdFilter, MultiPageOption, RateLimiterOption, SessionResult, ToResource, into_standings, not_found_as_none,
+            request_headers, verify_is_single_page, with_endpoint_context,
+        },
+        api::{JOLPICA_API_BASE_URL, JOLPICA_API_PAGINATION, JOLPICA_API_RATE_LIMIT_QUOTA},
+        concat::{PageVerify, concat_response_multi_pages, concat_response_seasons},
+        get::{self, RetryPolicy, RetryPredicate},
+        resource::{Filters, LapTimeFilters, Page, PitStopFilters, Resource},
+        response::{
+            Circuit, Constructor, ConstructorStanding, Driver, DriverLap, DriverStanding, Lap, PitStop,
+            QualifyingResult, Race, RaceResult, Response, Schedule, Season, SprintResult, Standings, StandingsList,
+            Status, TableInnerList, Timing,
+        },
+    },
+    rate_limiter::RateLimiter,
+};
+
+#[cfg(doc)]
+use crate::jolpica::{agent::Agent, agent::AgentConfigs, response::Table};
+
+/// Options to configure the behavior of an [`AsyncAgent`], e.g. rate limiting, multi-page handling.
+///
+/// These are the subset of [`AgentConfigs`] that apply to asynchronous requests, and each one
+/// behaves as documented for the field of the same name in [`AgentConfigs`]. The synchronous
+/// [`AgentConfigs::backend`], [`AgentConfigs::conditional_requests`], and [`AgentConfigs::cache`]
+/// options are not supported; every request is made to the jolpica-f1 API at
+/// [`base_url`](Self::base_url).
+pub struct AsyncAgentConfigs<'a> {
+    /// See [`AgentConfigs::base_url`].
+    pub base_url: String,
+
+    /// See [`AgentConfigs::user_agent`].
+    pub user_agent: Option<String>,
+
+    /// See [`AgentConfigs::extra_headers`].
+    pub extra_headers: BTreeMap<String, String>,
+
+    /// See [`AgentConfigs::multi_page`].
+    pub multi_page: MultiPageOption,
+
+    /// Configuration for the maximum number of pages of a multi-page response to request
+    /// concurrently, if [`multi_page`](Self::multi_page) is [`MultiPageOption::Enabled`].
+    ///
+    /// This behaves as [`AgentConfigs::multi_page_concurrency`], except that the remaining pages
+    /// are requested as concurrent futures on the calling task, rather than from separate threads.
+    pub multi_page_concurrency: usize,
+
+    /// See [`AgentConfigs::http_retries`]. Delays before retries do not block the calling thread.
+    pub http_retries: Option<RetryPolicy>,
+
+    /// See [`AgentConfigs::retry_predicate`]. If [`None`], [`Error::AsyncHttp`] errors are also
+    /// retried, in addition to [`Error::Http`] and [`Error::RetryAfter`].
+    pub retry_predicate: Option<Arc<RetryPredicate>>,
+
+    /// See [`AgentConfigs::verify_table_variant`].
+    pub verify_table_variant: bool,
+
+    /// See [`AgentConfigs::rate_limiter`]. A [`RateLimiterOption::External`] [`RateLimiter`] can be
+    /// shared with synchronous [`Agent`]s, to enforce one quota across both.
+    pub rate_limiter: RateLimiterOption<'a>,
+}
+
+impl std::fmt::Debug for AsyncAgentConfigs<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncAgentConfigs")
+            .field("base_url", &self.base_url)
+            .field("user_agent", &self.user_agent)
+            .field("extra_headers", &self.extra_headers)
+            .field("multi_page", &self.multi_page)
+            .field("multi_page_concurrency", &self.multi_page_concurrency)
+            .field("http_retries", &self.http_retries)
+            .field("retry_predicate", &self.retry_predicate.as_ref().map(|_| "Fn(&Error) -> bool"))
+            .field("verify_table_variant", &self.verify_table_variant)
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
+}
+
+impl Default for AsyncAgentConfigs<'_> {
+    /// Creates a new [`AsyncAgentConfigs`] with the same default settings as
+    /// [`AgentConfigs::default`], for the options that apply to an [`AsyncAgent`].
+    fn default() -> Self {
+        Self {
+            base_url: JOLPICA_API_BASE_URL.to_string(),
+            user_agent: None,
+            extra_headers: BTreeMap::new(),
+            multi_page: MultiPageOption::Enabled(None),
+            multi_page_concurrency: 1,
+            http_retries: Some(RetryPolicy::exponential(2, std::time::Duration::from_millis(500))),
+            retry_predicate: None,
+            verify_table_variant: false,
+            rate_limiter: RateLimiterOption::Internal(RateLimiter::new(JOLPICA_API_RATE_LIMIT_QUOTA)),
+        }
+    }
+}
+
+/// An asynchronous agent for accessing the [jolpica-f1](https://github.com/jolpica/jolpica-f1) API
+/// for querying Formula 1 data, available with the `async` feature.
+///
+/// This mirrors the methods of [`Agent`] that each request a single [`Resource`], e.g.
+/// [`get_race_results`](Self::get_race_results), as `async fn`s, which do not block the calling
+/// thread while waiting for responses, rate limiting, or retries. This allows many requests to be
+/// awaited concurrently, e.g. via [`futures::future::join_all`], without spawning blocking
+/// threads. Helpers of [`Agent`] that combine several requests, e.g. [`Agent::get_weekend`], or
+/// that compute statistics, e.g. [`Agent::get_season_driver_points`], are not mirrored.
+///
+/// [`AsyncAgent`] is [`Send`] and [`Sync`], and the futures returned by its methods are [`Send`],
+/// so it can be shared between tasks, e.g. via an [`Arc<AsyncAgent>`](Arc).
+///
+/// [`futures::future::join_all`]: https://docs.rs/futures/latest/futures/future/fn.join_all.html
+///
+/// # Examples
+///
+/// ```no_run
+/// # use f1_data::jolpica::{async_agent::AsyncAgent, resource::Filters};
+/// # async fn example() {
+/// let jolpica = AsyncAgent::default();
+///
+/// let races = futures_util::future::join_all(
+///     (1..=3).map(|round| jolpica.get_race_results_for_event(Filters::new().season(2023).round(round))),
+/// )
+/// .await;
+///
+/// assert_eq!(races.len(), 3);
+/// assert_eq!(races[0].as_ref().unwrap().race_name, "Bahrain Grand Prix");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncAgent<'a> {
+    configs: AsyncAgentConfigs<'a>,
+    client: reqwest::Client,
+}
+
+impl Default for AsyncAgent<'_> {
+    /// Creates a new [`AsyncAgent`] with default settings via [`AsyncAgentConfigs::default`].
+    fn default() -> Self {
+        Self::new(AsyncAgentConfigs::default())
+    }
+}
+
+impl<'a> AsyncAgent<'a> {
+    /// Creates a new [`AsyncAgent`] with the given [`AsyncAgentConfigs`].
+    pub fn new(configs: AsyncAgentConfigs<'a>) -> Self {
+        Self {
+            configs,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Returns the [`RateLimiter`] configured via [`AsyncAgentConfigs::rate_limiter`], if any.
+    pub const fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.configs.rate_limiter.get()
+    }
+
+    /// Asynchronous variant of [`Agent::get_response_page`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`Resource`] has a [`Filters::season_range`], which cannot be requested via a single
+    /// page, an [`Error::UnsupportedSeasonRange`] is returned without making any requests.
+    pub async fn get_response_page(&self, resource: &Resource, page: Page) -> Result<Response> {
+        self.get_response_opt_page(resource, Some(page)).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_response_multi_pages`].
+    ///
+    /// Up to [`AsyncAgentConfigs::multi_page_concurrency`] of the pages after the first are
+    /// requested concurrently, and the [`Response`]s are returned in page order.
+    ///
+    /// # Errors
+    ///
+    /// If `max_page_count` is specified, and the total number of pages would exceed it, then an
+    /// [`Error::ExceededMaxPageCount`] is returned and no requests beyond the first are made. If
+    /// the [`Resource`] has a [`Filters::season_range`], an [`Error::UnsupportedSeasonRange`] is
+    /// returned without making any requests.
+    pub async fn get_response_multi_pages(
+        &self,
+        resource: &Resource,
+        initial_page: Option<Page>,
+        max_page_count: Option<usize>,
+    ) -> Result<Vec<Response>> {
+        let first_response = self.get_response_opt_page(resource, initial_page).await?;
+        let pages = get::all_pages(first_response.pagination, max_page_count)?;
+
+        if pages.len() > 1 {
+            log::debug!(
+                "Multi-page response with {} total elements, requesting {} more pages (max concurrency: {})",
+                first_response.pagination.total,
+                pages.len() - 1,
+                self.configs.multi_page_concurrency.max(1)
+            );
+        }
+
+        // Owned pages are iterated, as borrowed ones would make the returned future not `Send`
+        let remaining_responses: Vec<_> = stream::iter(pages.into_iter().skip(1))
+            .map(|page| {
+                log::trace!("Requesting page at offset {} with limit {}", page.offset, page.limit);
+                self.get_response_opt_page(resource, Some(page.into()))
+            })
+            .buffered(self.configs.multi_page_concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        Ok(std::iter::once(first_response).chain(remaining_responses).collect())
+    }
+
+    /// Asynchronous variant of [`Agent::get_response`].
+    ///
+    /// If the [`Resource`] has a [`Filters::season_range`], the seasons in the range are requested
+    /// one after the other, and the resulting [`Response`]s are merged via
+    /// [`concat_response_seasons`].
+    ///
+    /// # Errors
+    ///
+    /// If [`MultiPageOption::Disabled`] is configured and a requested [`Resource`] results in a
+    /// multi-pager response, then an [`Error::MultiPage`] is returned. If
+    /// [`MultiPageOption::Enabled`] is configured with a `max_page_count`, then an
+    /// [`Error::ExceededMaxPageCount`] is returned if the total number of pages would exceed it.
+    pub async fn get_response(&self, resource: &Resource) -> Result<Response> {
+        if resource.season_range().is_none() {
+            return self.get_response_single_season(resource).await;
+        }
+
+        let mut responses = Vec::new();
+        for resource in resource.split_season_range() {
+            responses.push(self.get_response_single_season(&resource).await?);
+        }
+
+        concat_response_seasons(responses)
+    }
+
+    /// Asynchronous variant of [`Agent::estimate_pages`].
+    ///
+    /// # Errors
+    ///
+    /// Any errors from the underlying request, e.g. [`Error::Http`], are returned. If the
+    /// [`Resource`] has a [`Filters::season_range`], an [`Error::UnsupportedSeasonRange`] is
+    /// returned without making any requests.
+    pub async fn estimate_pages(&self, resource: &Resource) -> Result<usize> {
+        let total = self
+            .get_response_page(resource, Page::with_limit(1))
+            .await?
+            .pagination
+            .total;
+
+        Ok(total.div_ceil(JOLPICA_API_PAGINATION.max_limit).max(1) as usize)
+    }
+
+    /// Asynchronous variant of [`Agent::get_table_list`].
+    pub async fn get_table_list<T: ToResource + TableInnerList>(&self, filters: Filters) -> Result<Vec<T>> {
+        self.get_and_extract(&T::to_resource(filters), Response::into_table_list::<T>)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_first_element`].
+    pub async fn get_first_element<T: ToResource + TableInnerList>(&self, filters: Filters) -> Result<T> {
+        let resource = T::to_resource(filters);
+
+        self.get_response_page(&resource, Page::with_limit(1))
+            .await?
+            .into_table_list::<T>()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Error::NotFound(format!("{}: expected at least one element, found none", resource.to_endpoint()))
+            })
+    }
+
+    /// Asynchronous variant of [`Agent::get_table_list_single_element`].
+    pub async fn get_table_list_single_element<T: ToResource + IdFilter + TableInnerList>(
+        &self,
+        id: T::ID,
+    ) -> Result<T> {
+        self.get_and_extract(&T::to_resource(T::id_filter(id)), Response::into_single_table_list_element::<T>)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_seasons`].
+    pub async fn get_seasons(&self, filters: Filters) -> Result<Vec<Season>> {
+        self.get_table_list::<Season>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_season`].
+    pub async fn get_season(&self, season: SeasonID) -> Result<Season> {
+        self.get_table_list_single_element::<Season>(season).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_season_opt`].
+    pub async fn get_season_opt(&self, season: SeasonID) -> Result<Option<Season>> {
+        not_found_as_none(self.get_season(season).await)
+    }
+
+    /// Asynchronous variant of [`Agent::get_current_season`].
+    pub async fn get_current_season(&self) -> Result<SeasonID> {
+        self.get_seasons(Filters::none())
+            .await?
+            .iter()
+            .map(|season| season.season)
+            .max()
+            .ok_or_else(|| Error::NotFound(String::from("no seasons found")))
+    }
+
+    /// Asynchronous variant of [`Agent::get_drivers`].
+    pub async fn get_drivers(&self, filters: Filters) -> Result<Vec<Driver>> {
+        self.get_table_list::<Driver>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_driver`].
+    pub async fn get_driver(&self, driver_id: DriverID) -> Result<Driver> {
+        self.get_table_list_single_element::<Driver>(driver_id).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_driver_opt`].
+    pub async fn get_driver_opt(&self, driver_id: DriverID) -> Result<Option<Driver>> {
+        not_found_as_none(self.get_driver(driver_id).await)
+    }
+
+    /// Asynchronous variant of [`Agent::get_constructors`].
+    pub async fn get_constructors(&self, filters: Filters) -> Result<Vec<Constructor>> {
+        self.get_table_list::<Constructor>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_constructor`].
+    pub async fn get_constructor(&self, constructor_id: ConstructorID) -> Result<Constructor> {
+        self.get_table_list_single_element::<Constructor>(constructor_id).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_constructor_opt`].
+    pub async fn get_constructor_opt(&self, constructor_id: ConstructorID) -> Result<Option<Constructor>> {
+        not_found_as_none(self.get_constructor(constructor_id).await)
+    }
+
+    /// Asynchronous variant of [`Agent::get_circuits`].
+    pub async fn get_circuits(&self, filters: Filters) -> Result<Vec<Circuit>> {
+        self.get_table_list::<Circuit>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_circuit`].
+    pub async fn get_circuit(&self, circuit_id: CircuitID) -> Result<Circuit> {
+        self.get_table_list_single_element::<Circuit>(circuit_id).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_circuit_opt`].
+    pub async fn get_circuit_opt(&self, circuit_id: CircuitID) -> Result<Option<Circuit>> {
+        not_found_as_none(self.get_circuit(circuit_id).await)
+    }
+
+    /// Asynchronous variant of [`Agent::get_race_schedules`].
+    pub async fn get_race_schedules(&self, filters: Filters) -> Result<Vec<Race<Schedule>>> {
+        self.get_and_extract(&Resource::RaceSchedule(filters), Response::into_race_schedules)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_race_schedule`].
+    pub async fn get_race_schedule(&self, race_id: RaceID) -> Result<Race<Schedule>> {
+        self.get_and_extract(
+            &Resource::RaceSchedule(Filters::new().season(race_id.season).round(race_id.round)),
+            Response::into_race_schedule,
+        )
+        .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_session_results`].
+    pub async fn get_session_results<T: SessionResult>(&self, filters: Filters) -> Result<Vec<Race<Vec<T>>>> {
+        self.get_and_extract(&T::to_resource(filters), Response::into_many_races_with_many_session_results::<T>)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_session_results_for_event`].
+    pub async fn get_session_results_for_event<T: SessionResult>(&self, filters: Filters) -> Result<Race<Vec<T>>> {
+        self.get_and_extract(&T::to_resource(filters), Response::into_one_race_with_many_session_results::<T>)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_session_result_for_events`].
+    pub async fn get_session_result_for_events<T: SessionResult>(&self, filters: Filters) -> Result<Vec<Race<T>>> {
+        self.get_and_extract(&T::to_resource(filters), Response::into_many_races_with_one_session_result::<T>)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_session_result`].
+    pub async fn get_session_result<T: SessionResult>(&self, filters: Filters) -> Result<Race<T>> {
+        self.get_and_extract(&T::to_resource(filters), Response::into_one_race_with_one_session_result::<T>)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_qualifying_results`].
+    pub async fn get_qualifying_results(&self, filters: Filters) -> Result<Vec<Race<Vec<QualifyingResult>>>> {
+        self.get_session_results::<QualifyingResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_qualifying_results_for_event`].
+    pub async fn get_qualifying_results_for_event(&self, filters: Filters) -> Result<Race<Vec<QualifyingResult>>> {
+        self.get_session_results_for_event::<QualifyingResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_qualifying_result_for_events`].
+    pub async fn get_qualifying_result_for_events(&self, filters: Filters) -> Result<Vec<Race<QualifyingResult>>> {
+        self.get_session_result_for_events::<QualifyingResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_qualifying_result`].
+    pub async fn get_qualifying_result(&self, filters: Filters) -> Result<Race<QualifyingResult>> {
+        self.get_session_result::<QualifyingResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_sprint_results`].
+    pub async fn get_sprint_results(&self, filters: Filters) -> Result<Vec<Race<Vec<SprintResult>>>> {
+        self.get_session_results::<SprintResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_sprint_results_for_event`].
+    pub async fn get_sprint_results_for_event(&self, filters: Filters) -> Result<Race<Vec<SprintResult>>> {
+        self.get_session_results_for_event::<SprintResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_sprint_result_for_events`].
+    pub async fn get_sprint_result_for_events(&self, filters: Filters) -> Result<Vec<Race<SprintResult>>> {
+        self.get_session_result_for_events::<SprintResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_sprint_result`].
+    pub async fn get_sprint_result(&self, filters: Filters) -> Result<Race<SprintResult>> {
+        self.get_session_result::<SprintResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_race_results`].
+    pub async fn get_race_results(&self, filters: Filters) -> Result<Vec<Race<Vec<RaceResult>>>> {
+        self.get_session_results::<RaceResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_race_results_for_event`].
+    pub async fn get_race_results_for_event(&self, filters: Filters) -> Result<Race<Vec<RaceResult>>> {
+        self.get_session_results_for_event::<RaceResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_race_result_for_events`].
+    pub async fn get_race_result_for_events(&self, filters: Filters) -> Result<Vec<Race<RaceResult>>> {
+        self.get_session_result_for_events::<RaceResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_race_result`].
+    pub async fn get_race_result(&self, filters: Filters) -> Result<Race<RaceResult>> {
+        self.get_session_result::<RaceResult>(filters).await
+    }
+
+    /// Asynchronous variant of [`Agent::get_statuses`].
+    pub async fn get_statuses(&self, filters: Filters) -> Result<Vec<Status>> {
+        self.get_and_extract(&Resource::FinishingStatus(filters), Response::into_statuses)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_driver_standings`].
+    pub async fn get_driver_standings(&self, filters: Filters) -> Result<Vec<StandingsList<Vec<DriverStanding>>>> {
+        self.get_standings(&Resource::DriverStandings(filters), Standings::into_driver_standings)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_constructor_standings`].
+    pub async fn get_constructor_standings(
+        &self,
+        filters: Filters,
+    ) -> Result<Vec<StandingsList<Vec<ConstructorStanding>>>> {
+        self.get_standings(&Resource::ConstructorStandings(filters), Standings::into_constructor_standings)
+            .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_driver_laps`].
+    pub async fn get_driver_laps(&self, race_id: RaceID, driver_id: &DriverID) -> Result<Vec<DriverLap>> {
+        self.get_and_extract(
+            &Resource::LapTimes(LapTimeFilters {
+                season: race_id.season,
+                round: race_id.round,
+                lap: None,
+                driver_id: Some(driver_id.clone()),
+            }),
+            |response| response.into_driver_laps(driver_id),
+        )
+        .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_lap_timings`].
+    pub async fn get_lap_timings(&self, race_id: RaceID, lap: u32) -> Result<Vec<Timing>> {
+        self.get_and_extract(
+            &Resource::LapTimes(LapTimeFilters {
+                season: race_id.season,
+                round: race_id.round,
+                lap: Some(lap),
+                driver_id: None,
+            }),
+            Response::into_lap_timings,
+        )
+        .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_laps`].
+    pub async fn get_laps(&self, race_id: RaceID) -> Result<Vec<Lap>> {
+        self.get_and_extract(
+            &Resource::LapTimes(LapTimeFilters::new(race_id.season, race_id.round)),
+            Response::into_laps,
+        )
+        .await
+    }
+
+    /// Asynchronous variant of [`Agent::get_pit_stops`].
+    pub async fn get_pit_stops(&self, filters: PitStopFilters) -> Result<Vec<PitStop>> {
+        self.get_and_extract(&Resource::PitStops(filters), Response::into_pit_stops)
+            .await
+    }
+
+    /// Implementation of [`AsyncAgent::get_response`] for a [`Resource`] without a
+    /// [`Filters::season_range`], as per [`AsyncAgentConfigs::multi_page`].
+    async fn get_response_single_season(&self, resource: &Resource) -> Result<Response> {
+        if self.configs.multi_page.is_enabled() {
+            self.get_response_multi_pages(resource, Some(Page::with_max_limit()), self.configs.multi_page.into())
+                .await
+                .and_then(|responses| concat_response_multi_pages(responses, PageVerify::ALL))
+        } else {
+            self.get_response_page(resource, Page::with_max_limit())
+                .await
+                .and_then(verify_is_single_page)
+        }
+    }
+
+    /// Performs a GET request for an optional page of the specified [`Resource`], with retries, as
+    /// per [`AsyncAgentConfigs::http_retries`], and, if enabled,
+    /// [`AsyncAgentConfigs::verify_table_variant`].
+    async fn get_response_opt_page(&self, resource: &Resource, page: Option<Page>) -> Result<Response> {
+        // A .season_range cannot be represented in a URL, it must be split into separate requests
+        if resource.season_range().is_some() {
+            return Err(Error::UnsupportedSeasonRange(resource.to_endpoint()));
+        }
+
+        let json_str = self.retry(|| self.get_json_page(resource, page)).await?;
+        let response = serde_json::from_str::<Response>(&json_str)?;
+
+        if self.configs.verify_table_variant {
+            response.verify_matches(resource)?;
+        }
+
+        Ok(response)
+    }
+
+    /// Performs a single GET request for an optional page of the specified [`Resource`], and
+    /// returns the raw JSON response, as per [`get::get_json_page_with_headers`].
+    async fn get_json_page(&self, resource: &Resource, page: Option<Page>) -> Result<String> {
+        let url = resource.to_url_with_base_and_opt_page(&self.configs.base_url, page);
+        log::debug!("GET {url}");
+
+        let request = request_headers(self.configs.user_agent.as_ref(), &self.configs.extra_headers)
+            .into_iter()
+            .fold(self.client.get(url.as_str()), |request, (name, value)| request.header(name, value));
+
+        let http_response = request.send().await?;
+        let status = http_response.status();
+
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            && let Some(delay) = get::retry_after(http_response.headers())
+        {
+            return Err(Error::RetryAfter(delay));
+        }
+
+        if status.is_client_error() || status.is_server_error() {
+            return Err(Error::Http(ureq::Error::StatusCode(status.as_u16())));
+        }
+
+        http_response.text().await.map_err(Into::into)
+    }
+
+    /// Asynchronous equivalent of [`get::retry_with_policy`], with the configured rate limiting and
+    /// retry options, which waits for the rate limiter and between retries without blocking.
+    async fn retry<T: Send, F: Future<Output = Result<T>> + Send>(&self, f: impl Fn() -> F + Sync) -> Result<T> {
+        let retry_policy = self.configs.http_retries.as_ref();
+        let retry_predicate = self.configs.retry_predicate.as_deref();
+        let max_retries = retry_policy.map_or(0, |policy| policy.max_retries);
+
+        let rate_limited_call = async || {
+            if let Some(limiter) = self.rate_limiter() {
+                limiter.wait_until_ready_async().await;
+            }
+            f().await
+        };
+
+        let mut result = rate_limited_call().await;
+
+        if max_retries == 0 || !get::should_retry(&result, retry_predicate) {
+            return result;
+        }
+
+        for retry in 0..max_retries {
+            let delay = get::retry_delay(&result, retry_policy, retry);
+
+            if !delay.is_zero() {
+                futures_timer::Delay::new(delay).await;
+            }
+
+            result = rate_limited_call().await;
+
+            if !get::should_retry(&result, retry_predicate) {
+                return result;
+            }
+        }
+
+        get::into_exhausted_retries(result, max_retries)
+    }
+
+    /// Asynchronous equivalent of [`Agent::get_and_extract`].
+    async fn get_and_extract<T>(
+        &self,
+        resource: &Resource,
+        extract: impl FnOnce(Response) -> Result<T> + Send,
+    ) -> Result<T> {
+        extract(self.get_response(resource).await?).map_err(|err| with_endpoint_context(resource, err))
+    }
+
+    /// Asynchronous equivalent of [`Agent::get_standings`].
+    async fn get_standings<T>(
+        &self,
+        resource: &Resource,
+        extract: fn(Standings) -> std::result::Result<T, Standings>,
+    ) -> Result<Vec<StandingsList<T>>> {
+        into_standings(
+            self.get_and_extract(resource, Response::into_table_list::<StandingsList>)
+                .await?,
+            extract,
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use crate::jolpica::tests::{
+        assets::*,
+        util::{get_jolpica_test_base_url, get_jolpica_test_rate_limiter_option, race_table_json, spawn_mock_server},
+    };
+    use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
+
+    use super::*;
+
+    /// Formats a raw `200 OK` HTTP response for [`spawn_mock_server`], with a race results `body`.
+    fn race_results_response(offset: u32, limit: u32, total: u32, races: &str) -> String {
+        let body = race_table_json("https://api.jolpi.ca/ergast/f1/2023/4/results.json", limit, offset, total, races);
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn mock_agent(base_url: String) -> AsyncAgent<'static> {
+        AsyncAgent::new(AsyncAgentConfigs {
+            base_url,
+            http_retries: None,
+            rate_limiter: RateLimiterOption::None,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn async_agent_is_send_and_sync() {
+        // Compile-time check; this fails to build if the agent, or its futures, are not thread-safe
+        const fn assert_send_sync<T: Send + Sync>() {}
+        fn assert_send<T: Send>(_: &T) {}
+
+        assert_send_sync::<AsyncAgent<'_>>();
+
+        let jolpica = AsyncAgent::default();
+        assert_send(&jolpica.get_race_results(Filters::none()));
+        assert_send(&jolpica.get_driver_standings(Filters::none()));
+    }
+
+    #[tokio::test]
+    async fn get_race_results_for_event_with_mock_server() {
+        let (base_url, server) =
+            spawn_mock_server(vec![race_results_response(0, 100, 3, RACE_2023_4_RACE_RESULTS_STR)]);
+
+        let jolpica = AsyncAgent::new(AsyncAgentConfigs {
+            user_agent: Some("my-f1-app/1.0".into()),
+            ..mock_agent(base_url).configs
+        });
+
+        let race = jolpica
+            .get_race_results_for_event(Filters::new().season(2023).round(4))
+            .await
+            .unwrap();
+        assert_eq!(race.race_name, RACE_2023_4_RACE_RESULTS.race_name);
+        assert_eq!(race.race_results(), RACE_2023_4_RACE_RESULTS.payload.as_race_results().unwrap());
+
+        let requests = server.join().unwrap();
+        assert_true!(requests[0][0].starts_with("GET /2023/4/results.json?limit=100&offset=0"));
+        assert_true!(
+            requests[0]
+                .iter()
+                .any(|line| line.eq_ignore_ascii_case("user-agent: my-f1-app/1.0"))
+        );
+    }
+
+    #[tokio::test]
+    async fn get_response_multi_pages_with_mock_server() {
+        let (p1, p2, p20) = (RACE_RESULT_2023_4_P1_STR, RACE_RESULT_2023_4_P2_STR, RACE_RESULT_2023_4_P20_STR);
+        let race = |result: &str| format!(r#"{{ {RACE_2023_4_STR}, "Results": [{result}] }}"#);
+
+        let (base_url, server) = spawn_mock_server(vec![
+            race_results_response(0, 1, 3, &race(p1)),
+            race_results_response(1, 1, 3, &race(p2)),
+            race_results_response(2, 1, 3, &race(p20)),
+        ]);
+
+        let jolpica = mock_agent(base_url);
+        let resource = Resource::RaceResults(Filters::new().season(2023).round(4));
+
+        let responses = jolpica
+            .get_response_multi_pages(&resource, Some(Page::with_limit(1)), None)
+            .await
+            .unwrap();
+        assert_eq!(responses.len(), 3);
+        assert_true!(responses.last().unwrap().pagination.is_last_page());
+
+        let race = concat_response_multi_pages(responses, PageVerify::ALL)
+            .unwrap()
+            .into_one_race_with_many_session_results::<RaceResult>()
+            .unwrap();
+        assert_eq!(race.race_results(), RACE_2023_4_RACE_RESULTS.payload.as_race_results().unwrap());
+
+        let requests = server.join().unwrap();
+        assert_true!(requests[1][0].contains("limit=1&offset=1"));
+        assert_true!(requests[2][0].contains("limit=1&offset=2"));
+    }
+
+    #[tokio::test]
+    async fn get_concurrently_with_mock_server() {
+        let response = race_results_response(0, 100, 3, RACE_2023_4_RACE_RESULTS_STR);
+        let (base_url, server) = spawn_mock_server(vec![response; 3]);
+
+        let jolpica = mock_agent(base_url);
+
+        let races = futures_util::future::try_join_all(
+            (0..3).map(|_| jolpica.get_race_results_for_event(Filters::new().season(2023).round(4))),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(races.len(), 3);
+        assert_true!(
+            races
+                .iter()
+                .all(|race| race.race_name == RACE_2023_4_RACE_RESULTS.race_name)
+        );
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_with_mock_server() {
+        let error = |status: &str, headers: &str| {
+            format!("HTTP/1.1 {status}\r\n{headers}Content-Length: 0\r\nConnection: close\r\n\r\n")
+        };
+
+        let (base_url, server) = spawn_mock_server(vec![
+            error("503 Service Unavailable", ""),
+            error("429 Too Many Requests", "Retry-After: 0\r\n"),
+            race_results_response(0, 100, 3, RACE_2023_4_RACE_RESULTS_STR),
+            error("503 Service Unavailable", ""),
+            error("503 Service Unavailable", ""),
+            error("503 Service Unavailable", ""),
+        ]);
+
+        let jolpica = AsyncAgent::new(AsyncAgentConfigs {
+            http_retries: Some(RetryPolicy::immediate(2)),
+            ..mock_agent(base_url).configs
+        });
+        let filters = Filters::new().season(2023).round(4);
+
+        let race = jolpica.get_race_results_for_event(filters.clone()).await.unwrap();
+        assert_eq!(race.race_name, RACE_2023_4_RACE_RESULTS.race_name);
+
+        assert!(matches!(
+            jolpica.get_race_results_for_event(filters).await,
+            Err(Error::HttpRetries((2, ureq::Error::StatusCode(503))))
+        ));
+
+        assert_eq!(server.join().unwrap().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn get_with_season_range() {
+        let jolpica = mock_agent(String::from("http://127.0.0.1:0"));
+        let resource = Resource::RaceResults(Filters::new().season_range(2021..=2022).round(99));
+
+        // Single-request methods cannot split the range, so they error without making any requests
+        assert!(matches!(
+            jolpica.get_response_page(&resource, Page::with_limit(1)).await,
+            Err(Error::UnsupportedSeasonRange(endpoint)) if endpoint == "/2021..=2022/99/results"
+        ));
+        assert!(matches!(jolpica.estimate_pages(&resource).await, Err(Error::UnsupportedSeasonRange(_))));
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn get_race_results_for_event() {
+        let jolpica = AsyncAgent::new(AsyncAgentConfigs {
+            base_url: get_jolpica_test_base_url(),
+            rate_limiter: get_jolpica_test_rate_limiter_option(),
+            ..Default::default()
+        });
+
+        let race = jolpica
+            .get_race_results_for_event(Filters::new().season(2023).round(4))
+            .await
+            .unwrap();
+
+        assert_eq!(race.race_name, RACE_2023_4_RACE_RESULTS.race_name);
+        assert_eq!(race.race_results().len(), 20);
+        assert_eq!(&race.race_results()[0], &RACE_2023_4_RACE_RESULTS.payload.as_race_results().unwrap()[0]);
+    }
+}
@@ -0,0 +1,284 @@
+//! The [`ResponseSource`] abstraction over where an [`Agent`] gets its [`Response`]s from, e.g. the
+//! jolpica-f1 API via [`HttpBackend`], or canned responses via [`MockBackend`].
+//!
+//! An [`Agent`] requests every page of every [`Resource`] through its configured
+//! [`AgentConfigs::backend`], and then applies the same processing to the result, regardless of
+//! where it came from. This allows code that consumes an [`Agent`] to be tested deterministically
+//! and without network access, by serving recorded or hand-written JSON responses.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use f1_data::jolpica::{
+//! #     agent::{Agent, AgentConfigs, RateLimiterOption},
+//! #     backend::MockBackend,
+//! #     resource::{Filters, Resource},
+//! # };
+//! #
+//! let backend = MockBackend::new().with_response(
+//!     Resource::SeasonList(Filters::new().season(2023)),
+//!     r#"{"MRData": {
+//!         "xmlns": "", "series": "f1", "url": "https://api.jolpi.ca/ergast/f1/2023/seasons.json",
+//!         "limit": "100", "offset": "0", "total": "1",
+//!         "SeasonTable": {
+//!             "Seasons": [{
+//!                 "season": "2023",
+//!                 "url": "https://en.wikipedia.org/wiki/2023_Formula_One_World_Championship"
+//!             }]
+//!         }
+//!     }}"#,
+//! );
+//!
+//! let jolpica = Agent::new(AgentConfigs {
+//!     backend: Arc::new(backend),
+//!     rate_limiter: RateLimiterOption::None,
+//!     ..AgentConfigs::default()
+//! });
+//!
+//! assert_eq!(jolpica.get_season(2023).unwrap().season, 2023);
+//! assert!(jolpica.get_season(2022).is_err());
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    jolpica::{
+        get::{self, CacheValidators, ConditionalResponse},
+        resource::{Page, Resource},
+        response::Response,
+    },
+};
+
+#[cfg(doc)]
+use crate::jolpica::{
+    agent::{Agent, AgentConfigs},
+    resource::Filters,
+};
+
+/// A source of JSON responses for the pages of a [`Resource`], as requested by an [`Agent`] via
+/// [`AgentConfigs::backend`].
+///
+/// Only [`get_json_page`](Self::get_json_page) must be implemented; the other methods have default
+/// implementations in terms of it. Rate limiting and retries, as per [`AgentConfigs`], are applied
+/// by the [`Agent`] around each call, so implementations should not perform them.
+///
+/// Each method is also given the `(name, value)` pairs of HTTP `headers` to send with the request,
+/// as per [`AgentConfigs::user_agent`] and [`AgentConfigs::extra_headers`], which implementations
+/// that don't make HTTP requests, e.g. [`MockBackend`], may ignore.
+pub trait ResponseSource: Send + Sync + std::fmt::Debug {
+    /// Returns the raw JSON response for an optional page of the specified [`Resource`], as would
+    /// be returned by the jolpica-f1 API at `base_url`, e.g. via [`get::get_json_page`].
+    ///
+    /// # Errors
+    ///
+    /// Any error that prevents the response from being returned, e.g. [`Error::Http`].
+    fn get_json_page(
+        &self,
+        base_url: &str,
+        resource: &Resource,
+        page: Option<Page>,
+        headers: &[(&str, &str)],
+    ) -> Result<String>;
+
+    /// Returns the [`Response`] for an optional page of the specified [`Resource`], as would be
+    /// returned by the jolpica-f1 API at `base_url`, e.g. via [`get::get_response_page`].
+    ///
+    /// The default implementation parses the JSON from [`get_json_page`](Self::get_json_page).
+    ///
+    /// # Errors
+    ///
+    /// Any error from [`get_json_page`](Self::get_json_page), or an [`Error::Parse`] if the JSON
+    /// cannot be parsed into a [`Response`].
+    fn get_response_page(
+        &self,
+        base_url: &str,
+        resource: &Resource,
+        page: Option<Page>,
+        headers: &[(&str, &str)],
+    ) -> Result<Response> {
+        serde_json::from_str::<Response>(&self.get_json_page(base_url, resource, page, headers)?).map_err(Into::into)
+    }
+
+    /// Returns the [`ConditionalResponse`] for an optional page of the specified [`Resource`], as
+    /// would be returned by the jolpica-f1 API at `base_url`, e.g. via
+    /// [`get::get_response_page_conditional`], if [`AgentConfigs::conditional_requests`] is enabled.
+    ///
+    /// The default implementation ignores the `validators` and always returns a
+    /// [`ConditionalResponse::Modified`] from [`get_response_page`](Self::get_response_page), with
+    /// empty [`CacheValidators`], i.e. it does not support conditional requests.
+    ///
+    /// # Errors
+    ///
+    /// Any error from [`get_response_page`](Self::get_response_page).
+    fn get_response_page_conditional(
+        &self,
+        base_url: &str,
+        resource: &Resource,
+        page: Option<Page>,
+        _validators: Option<&CacheValidators>,
+        headers: &[(&str, &str)],
+    ) -> Result<ConditionalResponse> {
+        self.get_response_page(base_url, resource, page, headers)
+            .map(|response| ConditionalResponse::Modified(Box::new(response), CacheValidators::default()))
+    }
+}
+
+/// The default [`ResponseSource`], which performs GET requests to the jolpica-f1 API at the given
+/// `base_url`, via the functions in the [`get`] module, e.g. [`get::get_json_page_with_headers`].
+#[derive(Clone, Copy, Default, Debug)]
+pub struct HttpBackend;
+
+impl ResponseSource for HttpBackend {
+    fn get_json_page(
+        &self,
+        base_url: &str,
+        resource: &Resource,
+        page: Option<Page>,
+        headers: &[(&str, &str)],
+    ) -> Result<String> {
+        get::get_json_page_with_headers(base_url, resource, page, headers)
+    }
+
+    fn get_response_page_conditional(
+        &self,
+        base_url: &str,
+        resource: &Resource,
+        page: Option<Page>,
+        validators: Option<&CacheValidators>,
+        headers: &[(&str, &str)],
+    ) -> Result<ConditionalResponse> {
+        get::get_response_page_conditional_with_headers(base_url, resource, page, validators, headers)
+    }
+}
+
+/// A [`ResponseSource`] that serves canned JSON responses, keyed by [`Resource`] and, optionally,
+/// by [`Page`], without making any network requests, e.g. for deterministic offline tests.
+///
+/// A response registered via [`with_page_response`](Self::with_page_response) is only served for
+/// that exact page, and takes precedence over one registered via
+/// [`with_response`](Self::with_response), which is served for any page. If there are multiple
+/// responses for the same key, the most recently registered one is served. Requests for which no
+/// response was registered fail with an [`Error::UnexpectedData`] naming the requested URL.
+///
+/// **Note:** [`Resource`]s are matched exactly, including all of their [`Filters`], so responses
+/// must be registered for the same [`Resource`] that the [`Agent`] requests, e.g.
+/// [`Agent::get_driver`] requests a [`Resource::DriverInfo`] with only [`Filters::driver_id`]
+/// set. A [`Filters::season_range`] is split per season by the [`Agent`] before any requests are
+/// made, so responses must be registered for each season instead.
+#[derive(Clone, Default, Debug)]
+pub struct MockBackend {
+    responses: Vec<(Resource, Option<Page>, String)>,
+}
+
+impl MockBackend {
+    /// Creates a new [`MockBackend`] without any responses.
+    pub const fn new() -> Self {
+        Self { responses: Vec::new() }
+    }
+
+    /// Registers the JSON response to be served for any page of the specified [`Resource`].
+    #[must_use]
+    pub fn with_response(mut self, resource: Resource, json: impl Into<String>) -> Self {
+        self.responses.push((resource, None, json.into()));
+        self
+    }
+
+    /// Registers the JSON response to be served for a specific [`Page`] of the specified
+    /// [`Resource`], e.g. to serve the pages of a multi-page response.
+    #[must_use]
+    pub fn with_page_response(mut self, resource: Resource, page: Page, json: impl Into<String>) -> Self {
+        self.responses.push((resource, Some(page), json.into()));
+        self
+    }
+
+    /// Returns the JSON response registered for the specified [`Resource`] and optional [`Page`].
+    fn find(&self, resource: &Resource, page: Option<Page>) -> Option<&str> {
+        let find = |page: Option<Page>| {
+            self.responses
+                .iter()
+                .rev()
+                .find(|(res, p, _)| res == resource && *p == page)
+                .map(|(_, _, json)| json.as_str())
+        };
+
+        page.and_then(|page| find(Some(page))).or_else(|| find(None))
+    }
+}
+
+impl ResponseSource for MockBackend {
+    fn get_json_page(
+        &self,
+        base_url: &str,
+        resource: &Resource,
+        page: Option<Page>,
+        _headers: &[(&str, &str)],
+    ) -> Result<String> {
+        self.find(resource, page).map(String::from).ok_or_else(|| {
+            Error::UnexpectedData(format!(
+                "No mock response for: {}",
+                resource.to_url_with_base_and_opt_page(base_url, page)
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use crate::jolpica::{api::JOLPICA_API_BASE_URL, resource::Filters, tests::assets::*};
+    use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn mock_backend_find() {
+        let seasons = Resource::SeasonList(Filters::none());
+        let drivers = Resource::DriverInfo(Filters::new().season(2023));
+
+        let backend = MockBackend::new()
+            .with_response(seasons.clone(), "any")
+            .with_page_response(seasons.clone(), Page::with_limit(1), "first")
+            .with_response(drivers.clone(), "old")
+            .with_response(drivers.clone(), "new");
+
+        assert_eq!(backend.find(&seasons, None), Some("any"));
+        assert_eq!(backend.find(&seasons, Some(Page::with_limit(1))), Some("first"));
+        assert_eq!(backend.find(&seasons, Some(Page::with_limit(2))), Some("any"));
+        assert_eq!(backend.find(&drivers, Some(Page::with_limit(1))), Some("new"));
+
+        assert_true!(
+            backend
+                .find(&Resource::SeasonList(Filters::new().season(2023)), None)
+                .is_none()
+        );
+        assert_true!(MockBackend::new().find(&seasons, None).is_none());
+    }
+
+    #[test]
+    fn mock_backend_get_response_page() {
+        let seasons = Resource::SeasonList(Filters::none());
+        let backend = MockBackend::new().with_response(seasons.clone(), RESPONSE_SEASON_TABLE_STR);
+
+        let response = backend
+            .get_response_page(JOLPICA_API_BASE_URL, &seasons, Some(Page::with_max_limit()), &[])
+            .unwrap();
+        assert_eq!(response.table, *SEASON_TABLE);
+
+        let ConditionalResponse::Modified(response, validators) = backend
+            .get_response_page_conditional(JOLPICA_API_BASE_URL, &seasons, None, Some(&CacheValidators::default()), &[])
+            .unwrap()
+        else {
+            panic!("expected a modified response");
+        };
+        assert_eq!(response.table, *SEASON_TABLE);
+        assert_true!(validators.is_empty());
+
+        let drivers = Resource::DriverInfo(Filters::none());
+        assert!(matches!(
+            backend.get_json_page(JOLPICA_API_BASE_URL, &drivers, None, &[("User-Agent", "test")]),
+            Err(Error::UnexpectedData(msg)) if msg.contains("/drivers")
+        ));
+
+        let backend = MockBackend::new().with_response(seasons.clone(), "{");
+        assert!(matches!(backend.get_response_page(JOLPICA_API_BASE_URL, &seasons, None, &[]), Err(Error::Parse(_))));
+    }
+}
@@ -2,19 +2,18 @@
 //! underlying [`Table`]s, [`Payload`]s, [`Race`]s, etc., and performing optional verifications.
 
 use bitflags::bitflags;
-use url::Url;
 
 use crate::{
     error::{Error, Result},
-    id::{RoundID, SeasonID},
-    jolpica::{
-        response::{Circuit, Pagination, Payload, Race, Response, Table},
-        time::{Date, Time},
-    },
+    jolpica::response::{Lap, Pagination, Payload, Race, RaceInfo, Response, Standings, StandingsList, Status, Table},
 };
 
 #[cfg(doc)]
-use crate::jolpica::{get::get_response_multi_pages, resource::Page};
+use crate::jolpica::{
+    get::get_response_multi_pages,
+    resource::{Filters, Page, Resource},
+    response::{Circuit, Constructor, Driver, Season, Timing},
+};
 
 bitflags! {
 /// Bitflags to control verification of response pages when concatenating multi-page responses.
@@ -23,13 +22,25 @@ pub struct PageVerify: u32 {
     /// Do not perform any verification of the response pages.
     const NONE = 0b0000;
     /// Verify that the response pages are contiguous, i.e. that there are no gaps or overlaps.
+    ///
+    /// Each page's `offset + limit` must equal the next page's `offset`, and all pages must report
+    /// the same `total`. Page `limit`s may differ between pages.
     const CONTIGUOUS = 0b0001;
     /// Verify that the first response page starts at offset 0.
     const START_AT_FIRST_PAGE = 0b0010;
     /// Verify that the last response page is the last page, i.e. `offset + limit >= total`.
     const FINISH_AT_LAST_PAGE = 0b0100;
+    /// Verify only that the response pages' offsets are contiguous, i.e. that each page's
+    /// `offset + limit` equals the next page's `offset`, allowing differing `limit`s and `total`s.
+    ///
+    /// This is a relaxed version of [`PageVerify::CONTIGUOUS`], useful for stitching together pages
+    /// fetched at different times or with different page sizes, e.g. when resuming a fetch.
+    const CONTIGUOUS_OFFSETS = 0b1000;
     /// Perform all verifications above.
-    const ALL = Self::CONTIGUOUS.bits() | Self::START_AT_FIRST_PAGE.bits() | Self::FINISH_AT_LAST_PAGE.bits();
+    const ALL = Self::CONTIGUOUS.bits()
+        | Self::START_AT_FIRST_PAGE.bits()
+        | Self::FINISH_AT_LAST_PAGE.bits()
+        | Self::CONTIGUOUS_OFFSETS.bits();
 }
 }
 
@@ -37,23 +48,28 @@ pub struct PageVerify: u32 {
 /// single [`Response`], concatenating underlying [`Table`]s, [`Payload`]s, [`Race`]s, etc.
 ///
 /// `page_verify` controls the verification of the pagination sequence, see [`PageVerify`] for
-/// details. If [`PageVerify::CONTIGUOUS`] is set, the [`Response::pagination`] field will be
-/// updated to reflect the concatenated [`Pagination::limit`], otherwise it will be left as-is from
-/// the first [`Response`] in the sequence. A sequence returned by [`get_response_multi_pages`]
-/// should satisfy [`PageVerify::ALL`] if requested with a [`Page`] starting at offset 0.
+/// details. The [`Response::pagination`] field will be updated to reflect the concatenated
+/// [`Pagination::limit`], which is only meaningful if [`PageVerify::CONTIGUOUS`] or
+/// [`PageVerify::CONTIGUOUS_OFFSETS`] is set; its other fields are left as-is from the first
+/// [`Response`] in the sequence. A sequence returned by [`get_response_multi_pages`] should satisfy
+/// [`PageVerify::ALL`] if requested with a [`Page`] starting at offset 0. The [`Table`] variants of
+/// all [`Response`]s must always match, regardless of `page_verify`.
 ///
-/// All [`Table`] variants, except [`Table::Races`], are concatenated by simply concatenating the
-/// underlying [`Vec<T>`]s, e.g. [`Vec<Season>`]s for [`Table::Seasons`]. For [`Table::Races`],
-/// the underlying [`Race`]s are first grouped by their [`Race::as_info`], and then their underlying
-/// [`Payload`]s are concatenated, e.g. [`Vec<RaceResult>`]s for [`Payload::RaceResults`], etc.
+/// All [`Table`] variants, except [`Table::Races`] and [`Table::StandingsLists`], are concatenated
+/// by simply concatenating the underlying [`Vec<T>`]s, e.g. [`Vec<Season>`]s for
+/// [`Table::Seasons`]. For [`Table::Races`], the underlying [`Race`]s are first grouped by their
+/// [`Race::as_info`], and then their underlying [`Payload`]s are concatenated, e.g.
+/// [`Vec<RaceResult>`]s for [`Payload::RaceResults`], etc. Similarly, [`StandingsList`]s are
+/// grouped by season and round, and their underlying [`Standings`] are concatenated.
 ///
 /// # Errors
 ///
 /// If `responses` is empty, an [`Error::EmptyResponseList`] is returned. If all
 /// [`Response::as_info`] do not match, an [`Error::BadResponseInfo`] is returned. If the [`Table`]
 /// variants do not match, an [`Error::BadTableVariant`] is returned. If the [`Payload`] variants of
-/// all [`Race`]s with the same [`Race::as_info`] do not match, an [`Error::BadPayloadVariant`] is
-/// returned. If any of the verification specified by `page_verify` fail, an
+/// all [`Race`]s with the same [`Race::as_info`], or the [`Standings`] variants of all
+/// [`StandingsList`]s with the same season and round, do not match, an [`Error::BadPayloadVariant`]
+/// is returned. If any of the verification specified by `page_verify` fail, an
 /// [`Error::BadPagination`] is returned.
 ///
 /// # Examples
@@ -73,6 +89,7 @@ pub struct PageVerify: u32 {
 ///     None,
 ///     None,
 ///     None,
+///     None,
 /// )
 /// .unwrap();
 ///
@@ -128,29 +145,158 @@ pub fn concat_response_multi_pages(mut responses: Vec<Response>, page_verify: Pa
         lhs_resp.table = concat_tables(lhs_resp.table, rhs_response.table)?;
     }
 
-    if lhs_resp.table.is_races() {
-        lhs_resp.table = Table::Races {
-            races: concat_races(lhs_resp.table.into_races()?)?,
+    match lhs_resp.table {
+        Table::Races { races } => {
+            lhs_resp.table = Table::Races {
+                races: concat_races(races)?,
+            }
         }
+        Table::StandingsLists { standings_lists } => {
+            lhs_resp.table = Table::StandingsLists {
+                standings_lists: concat_standings_lists(standings_lists)?,
+            }
+        }
+        _ => {}
     }
 
     Ok(lhs_resp)
 }
 
+/// Concatenate a sequence of [`Response`]s for the same [`Resource`] filtered by different seasons
+/// into a single [`Response`], concatenating the underlying [`Table`]s and removing duplicates.
+///
+/// This is used to merge the [`Response`]s for a [`Filters::season_range`], which is split into
+/// one [`Resource`] per season via [`Resource::split_season_range`].
+///
+/// Each of the `responses` should be a full, i.e. already concatenated, multi-page [`Response`].
+/// Unlike [`concat_response_multi_pages`], their [`Response::url`]s are expected to differ, so only
+/// [`Response::xmlns`] and [`Response::series`] must match; the other fields of [`Response`] are
+/// left as-is from the first [`Response`] in the sequence, except for [`Pagination::limit`] and
+/// [`Pagination::total`], which are summed.
+///
+/// [`Table`]s are concatenated as in [`concat_response_multi_pages`], except that duplicate
+/// [`Season`]s, [`Driver`]s, [`Constructor`]s and [`Circuit`]s, e.g. a driver who competed in
+/// several of the seasons, are removed, keeping the first occurrence, and the [`Status::count`]s of
+/// [`Status`]es with the same [`Status::status_id`] are summed. For these [`Table`]s, the
+/// [`Pagination::limit`] and [`Pagination::total`] are set to the number of unique elements.
+///
+/// # Errors
+///
+/// If `responses` is empty, an [`Error::EmptyResponseList`] is returned. If all
+/// [`Response::xmlns`] and [`Response::series`] do not match, an [`Error::BadResponseInfo`] is
+/// returned. If the [`Table`] variants do not match, an [`Error::BadTableVariant`] is returned.
+pub fn concat_response_seasons(mut responses: Vec<Response>) -> Result<Response> {
+    if responses.is_empty() {
+        return Err(Error::EmptyResponseList);
+    }
+
+    let mut lhs_resp = responses.remove(0);
+
+    for rhs_response in responses {
+        if (&lhs_resp.xmlns, &lhs_resp.series) != (&rhs_response.xmlns, &rhs_response.series) {
+            return Err(Error::BadResponseInfo(format!(
+                "Inconsistent response info: {:?} != {:?}",
+                lhs_resp.as_info(),
+                rhs_response.as_info()
+            )));
+        }
+
+        lhs_resp.pagination.limit += rhs_response.pagination.limit;
+        lhs_resp.pagination.total += rhs_response.pagination.total;
+        lhs_resp.table = concat_tables(lhs_resp.table, rhs_response.table)?;
+    }
+
+    lhs_resp.table = match lhs_resp.table {
+        Table::Seasons { seasons } => Table::Seasons {
+            seasons: dedup_unsorted(seasons),
+        },
+        Table::Drivers { drivers } => Table::Drivers {
+            drivers: dedup_unsorted(drivers),
+        },
+        Table::Constructors { constructors } => Table::Constructors {
+            constructors: dedup_unsorted(constructors),
+        },
+        Table::Circuits { circuits } => Table::Circuits {
+            circuits: dedup_unsorted(circuits),
+        },
+        Table::Races { races } => Table::Races {
+            races: concat_races(races)?,
+        },
+        Table::Status { status } => Table::Status {
+            status: concat_statuses(status),
+        },
+        Table::StandingsLists { standings_lists } => Table::StandingsLists {
+            standings_lists: concat_standings_lists(standings_lists)?,
+        },
+    };
+
+    // The summed pagination would count duplicates that have been removed
+    let unique_len = match &lhs_resp.table {
+        Table::Seasons { seasons } => Some(seasons.len()),
+        Table::Drivers { drivers } => Some(drivers.len()),
+        Table::Constructors { constructors } => Some(constructors.len()),
+        Table::Circuits { circuits } => Some(circuits.len()),
+        Table::Status { status } => Some(status.len()),
+        Table::Races { .. } | Table::StandingsLists { .. } => None,
+    };
+
+    if let Some(unique_len) = unique_len {
+        let unique_len = u32::try_from(unique_len).map_err(|err| Error::UnexpectedData(err.to_string()))?;
+        lhs_resp.pagination.limit = unique_len;
+        lhs_resp.pagination.total = unique_len;
+    }
+
+    Ok(lhs_resp)
+}
+
+/// Remove duplicate elements from `list`, keeping the first occurrence and preserving the order.
+fn dedup_unsorted<T: PartialEq>(list: Vec<T>) -> Vec<T> {
+    let mut unique = Vec::with_capacity(list.len());
+
+    for element in list {
+        if !unique.contains(&element) {
+            unique.push(element);
+        }
+    }
+
+    unique
+}
+
+/// Concatenate a sequence of [`Status`]es by grouping them by their [`Status::status_id`] and
+/// summing their [`Status::count`]s, e.g. for the same status across multiple seasons.
+fn concat_statuses(statuses: Vec<Status>) -> Vec<Status> {
+    let mut indexed_statuses: indexmap::IndexMap<_, Status> = indexmap::IndexMap::new();
+
+    for status in statuses {
+        if let Some(existing) = indexed_statuses.get_mut(&status.status_id) {
+            existing.count += status.count;
+        } else {
+            let _unused = indexed_statuses.insert(status.status_id, status);
+        }
+    }
+
+    indexed_statuses.into_values().collect()
+}
+
 /// Concatenate two [`Pagination`]s, updating the `limit` field to reflect the total number of items
-/// in the concatenated pages if `page_verify` contains [`PageVerify::CONTIGUOUS`].
+/// in the concatenated pages.
 ///
 /// # Errors
 ///
-/// If `page_verify` contains [`PageVerify::CONTIGUOUS`] and the two pages are not contiguous,
-/// an [`Error::BadPagination`] is returned.
+/// If `page_verify` contains [`PageVerify::CONTIGUOUS`] and the two pages are not contiguous or
+/// have different `total`s, or if it contains [`PageVerify::CONTIGUOUS_OFFSETS`] and the two pages'
+/// offsets are not contiguous, an [`Error::BadPagination`] is returned.
 fn concat_pagination(mut lhs: Pagination, rhs: Pagination, page_verify: PageVerify) -> Result<Pagination> {
-    if page_verify.contains(PageVerify::CONTIGUOUS)
-        && !((lhs.total == rhs.total) && (lhs.offset + lhs.limit == rhs.offset))
-    {
+    let offsets_contiguous = lhs.offset + lhs.limit == rhs.offset;
+
+    if page_verify.contains(PageVerify::CONTIGUOUS) && !((lhs.total == rhs.total) && offsets_contiguous) {
         return Err(Error::BadPagination(format!("Response pages are not contiguous: {lhs:?} and {rhs:?}")));
     }
 
+    if page_verify.contains(PageVerify::CONTIGUOUS_OFFSETS) && !offsets_contiguous {
+        return Err(Error::BadPagination(format!("Response page offsets are not contiguous: {lhs:?} and {rhs:?}")));
+    }
+
     lhs.limit += rhs.limit;
 
     Ok(lhs)
@@ -176,12 +322,14 @@ fn concat_tables(lhs_table: Table, rhs_table: Table) -> Result<Table> {
         (Circuits { circuits: lhs }, Circuits { circuits: rhs }) => Ok(Circuits { circuits: [lhs, rhs].concat() }),
         (Races { races: lhs }, Races { races: rhs }) => Ok(Races { races: [lhs, rhs].concat() }),
         (Status { status: lhs }, Status { status: rhs }) => Ok(Status { status: [lhs, rhs].concat() }),
-        _ => Err(Error::BadTableVariant),
+        (StandingsLists { standings_lists: lhs }, StandingsLists { standings_lists: rhs }) => Ok(StandingsLists { standings_lists: [lhs, rhs].concat() }),
+        (lhs, rhs) => Err(Error::BadTableVariant { expected: lhs.kind(), actual: rhs.kind() }),
     }
 }
 
 /// Concatenate a sequence of [`Race`]s by grouping them by their [`Race::as_info`] and
-/// concatenating their underlying [`Payload`]s.
+/// concatenating their underlying [`Payload`]s. [`Payload::Laps`] are concatenated via
+/// [`concat_laps`], merging any [`Lap`] whose [`Lap::timings`] are split across pages.
 ///
 /// # Errors
 ///
@@ -191,7 +339,6 @@ fn concat_races(races: Vec<Race>) -> Result<Vec<Race>> {
     #[allow(clippy::enum_glob_use)]
     use Payload::*;
 
-    type RaceInfo = (SeasonID, RoundID, Url, String, Circuit, Date, Option<Time>);
     let mut indexed_races: indexmap::IndexMap<RaceInfo, Race> = indexmap::IndexMap::new();
 
     for race in races {
@@ -203,7 +350,7 @@ fn concat_races(races: Vec<Race>) -> Result<Vec<Race>> {
                 (QualifyingResults(lhs), QualifyingResults(rhs)) => lhs.extend(rhs),
                 (SprintResults(lhs), SprintResults(rhs)) => lhs.extend(rhs),
                 (RaceResults(lhs), RaceResults(rhs)) => lhs.extend(rhs),
-                (Laps(lhs), Laps(rhs)) => lhs.extend(rhs),
+                (Laps(lhs), Laps(rhs)) => concat_laps(lhs, rhs),
                 (PitStops(lhs), PitStops(rhs)) => lhs.extend(rhs),
                 _ => return Err(Error::BadPayloadVariant),
             }
@@ -215,15 +362,61 @@ fn concat_races(races: Vec<Race>) -> Result<Vec<Race>> {
     Ok(indexed_races.into_values().collect())
 }
 
+/// Concatenate a sequence of [`StandingsList`]s by grouping them by their
+/// [`StandingsList::season`] and [`StandingsList::round`], and concatenating their underlying
+/// [`Standings`], e.g. when a single season's standings are split across pages.
+///
+/// # Errors
+///
+/// If the [`Standings`] variants of all [`StandingsList`]s with the same season and round do not
+/// match, an [`Error::BadPayloadVariant`] is returned.
+fn concat_standings_lists(standings_lists: Vec<StandingsList>) -> Result<Vec<StandingsList>> {
+    let mut indexed_lists: indexmap::IndexMap<_, StandingsList> = indexmap::IndexMap::new();
+
+    for list in standings_lists {
+        let key = (list.season, list.round);
+
+        if let Some(existing) = indexed_lists.get_mut(&key) {
+            match (&mut existing.standings, list.standings) {
+                (Standings::DriverStandings(lhs), Standings::DriverStandings(rhs)) => lhs.extend(rhs),
+                (Standings::ConstructorStandings(lhs), Standings::ConstructorStandings(rhs)) => lhs.extend(rhs),
+                _ => return Err(Error::BadPayloadVariant),
+            }
+        } else {
+            let _unused = indexed_lists.insert(key, list);
+        }
+    }
+
+    Ok(indexed_lists.into_values().collect())
+}
+
+/// Concatenate the [`Lap`]s in `rhs` onto `lhs`, merging the [`Lap::timings`] of [`Lap`]s with the
+/// same [`Lap::number`], rather than producing two [`Lap`]s with the same number.
+///
+/// Multi-page responses for [`Payload::Laps`] are paginated by [`Timing`]s, so a page boundary may
+/// split a single [`Lap`]'s [`Lap::timings`] across two pages, e.g. with `20` drivers and a page
+/// limit of `30`, lap `2` is split between the first and second pages.
+fn concat_laps(lhs: &mut Vec<Lap>, rhs: Vec<Lap>) {
+    for lap in rhs {
+        if let Some(existing) = lhs.iter_mut().find(|existing| existing.number == lap.number) {
+            existing.timings.extend(lap.timings);
+        } else {
+            lhs.push(lap);
+        }
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage, coverage(off))]
 mod tests {
     use std::sync::LazyLock;
 
+    use url::Url;
+
     use crate::jolpica::{
-        get::{get_response_multi_pages, get_response_page},
-        resource::{Filters, Page, Resource},
-        response::Pagination,
+        get::{RetryPolicy, get_response_multi_pages, get_response_page},
+        resource::{Filters, LapTimeFilters, Page, Resource},
+        response::{Pagination, TableKind},
         tests::util::{TESTS_DEFAULT_HTTP_RETRIES, get_jolpica_test_base_url, get_jolpica_test_rate_limiter},
     };
 
@@ -448,6 +641,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn concat_responses_races_laps_split_across_pages() {
+        let make_laps_response = |pagination, laps| Response {
+            pagination,
+            table: Table::Races {
+                races: vec![Race {
+                    payload: Payload::Laps(laps),
+                    ..RACE_2023_4.clone()
+                }],
+            },
+            ..RESPONSE_NONE.clone()
+        };
+
+        // Lap 1's timings straddle the page break
+        let responses = vec![
+            make_laps_response(
+                make_pagination(1, 0, 4),
+                vec![Lap {
+                    number: 1,
+                    timings: vec![TIMING_2023_4_L1_P1.clone()],
+                }],
+            ),
+            make_laps_response(
+                make_pagination(3, 1, 4),
+                vec![
+                    Lap {
+                        number: 1,
+                        timings: vec![TIMING_2023_4_L1_P2.clone()],
+                    },
+                    LAP_2023_4_L2.clone(),
+                ],
+            ),
+        ];
+
+        let response = concat_response_multi_pages(responses, PageVerify::ALL).unwrap();
+        let races = response.as_races().unwrap();
+        assert_eq!(races.len(), 1);
+        assert_eq!(races[0], *RACE_2023_4_LAPS);
+    }
+
+    #[test]
+    fn concat_responses_standings_split_across_pages() {
+        let make_standings_response = |pagination, standings| Response {
+            pagination,
+            table: Table::StandingsLists {
+                standings_lists: vec![StandingsList {
+                    standings,
+                    ..DRIVER_STANDINGS_2021_22.clone()
+                }],
+            },
+            ..RESPONSE_NONE.clone()
+        };
+
+        let responses = vec![
+            make_standings_response(
+                make_pagination(1, 0, 2),
+                Standings::DriverStandings(vec![DRIVER_STANDING_2021_22_P1.clone()]),
+            ),
+            make_standings_response(
+                make_pagination(1, 1, 2),
+                Standings::DriverStandings(vec![DRIVER_STANDING_2021_22_P2.clone()]),
+            ),
+        ];
+
+        let response = concat_response_multi_pages(responses.clone(), PageVerify::ALL).unwrap();
+        assert_eq!(response.table, *DRIVER_STANDINGS_TABLE_2021_22);
+
+        let mut bad_responses = responses;
+        bad_responses[1] = make_standings_response(
+            make_pagination(1, 1, 2),
+            Standings::ConstructorStandings(vec![CONSTRUCTOR_STANDING_2021_22_P1.clone()]),
+        );
+        assert!(matches!(concat_response_multi_pages(bad_responses, PageVerify::ALL), Err(Error::BadPayloadVariant)));
+    }
+
     #[test]
     fn concat_responses_error_empty_list() {
         assert!(matches!(concat_response_multi_pages(vec![], PageVerify::NONE), Err(Error::EmptyResponseList)));
@@ -460,6 +728,95 @@ mod tests {
         assert!(matches!(concat_response_multi_pages(responses, PageVerify::NONE), Err(Error::BadResponseInfo(_))));
     }
 
+    #[test]
+    fn concat_responses_error_different_table_variants() {
+        let responses = vec![RESPONSES_SEASONS[0].clone(), RESPONSES_DRIVERS[1].clone()];
+        assert!(matches!(
+            concat_response_multi_pages(responses, PageVerify::NONE),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Seasons,
+                actual: TableKind::Drivers
+            })
+        ));
+    }
+
+    #[test]
+    fn concat_response_seasons_drivers() {
+        let with_url = |response: &Response, url: &str| Response {
+            url: Url::parse(url).unwrap(),
+            ..response.clone()
+        };
+
+        let response = concat_response_seasons(vec![
+            with_url(&RESPONSES_DRIVERS[0], "https://api.jolpi.ca/ergast/f1/2022/drivers/"),
+            with_url(&RESPONSES_DRIVERS[1], "https://api.jolpi.ca/ergast/f1/2023/drivers/"),
+            with_url(&RESPONSES_DRIVERS[0], "https://api.jolpi.ca/ergast/f1/2024/drivers/"),
+        ])
+        .unwrap();
+
+        assert_eq!(response.url.as_str(), "https://api.jolpi.ca/ergast/f1/2022/drivers/");
+        assert_eq!(response.as_drivers().unwrap(), &DRIVER_TABLE.as_drivers().unwrap()[..8]);
+        assert_eq!(response.pagination, make_pagination(8, 0, 8));
+
+        // A single full response, i.e. with all of its pages, keeps its table and total
+        let full_response = concat_response_multi_pages(RESPONSES_DRIVERS.clone(), PageVerify::ALL).unwrap();
+        let response = concat_response_seasons(vec![full_response.clone()]).unwrap();
+        assert_eq!(response.table, full_response.table);
+        assert_eq!(response.pagination, make_pagination(16, 0, 16));
+        assert_eq!(response.pagination.total, full_response.pagination.total);
+    }
+
+    #[test]
+    fn concat_response_seasons_races() {
+        let response = concat_response_seasons(RESPONSE_RACES_RACE_RESULTS.clone()).unwrap();
+        assert_eq!(response.table, RESPONSE_RACES_CONCATENATED.table);
+    }
+
+    #[test]
+    fn concat_response_seasons_statuses() {
+        let make_status_response = |status: Vec<Status>| Response {
+            table: Table::Status { status },
+            ..RESPONSE_NONE.clone()
+        };
+
+        let response = concat_response_seasons(vec![
+            make_status_response(vec![STATUS_2022_FINISHED.clone(), STATUS_2022_ACCIDENT.clone()]),
+            make_status_response(vec![STATUS_2022_ENGINE.clone(), STATUS_2022_FINISHED.clone()]),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            response.table,
+            Table::Status {
+                status: vec![
+                    Status {
+                        count: STATUS_2022_FINISHED.count * 2,
+                        ..STATUS_2022_FINISHED.clone()
+                    },
+                    STATUS_2022_ACCIDENT.clone(),
+                    STATUS_2022_ENGINE.clone(),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn concat_response_seasons_errors() {
+        assert!(matches!(concat_response_seasons(vec![]), Err(Error::EmptyResponseList)));
+
+        let mut responses = RESPONSES_SEASONS.clone();
+        responses[1].series = "f2".into();
+        assert!(matches!(concat_response_seasons(responses), Err(Error::BadResponseInfo(_))));
+
+        assert!(matches!(
+            concat_response_seasons(vec![RESPONSES_SEASONS[0].clone(), RESPONSES_DRIVERS[1].clone()]),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Seasons,
+                actual: TableKind::Drivers
+            })
+        ));
+    }
+
     #[test]
     fn concat_responses_error_page_verify_contiguous() {
         let responses = |page_verify| {
@@ -479,6 +836,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn concat_responses_page_verify_contiguous_offsets() {
+        // Pages fetched with different limits, and with `total` changing between fetches
+        let mut responses = RESPONSES_DRIVERS.clone();
+        responses[2].pagination.total = 20;
+
+        assert!(matches!(
+            concat_response_multi_pages(responses.clone(), PageVerify::CONTIGUOUS),
+            Err(Error::BadPagination(_))
+        ));
+
+        let response = concat_response_multi_pages(responses, PageVerify::CONTIGUOUS_OFFSETS).unwrap();
+        assert_eq!(response.as_drivers().unwrap(), &DRIVER_TABLE.as_drivers().unwrap()[..]);
+        assert_eq!(response.pagination, make_pagination(38, 0, 16));
+    }
+
+    #[test]
+    fn concat_responses_error_page_verify_contiguous_offsets() {
+        let responses = |page_verify| {
+            concat_response_multi_pages(vec![RESPONSES_SEASONS[0].clone(), RESPONSES_SEASONS[2].clone()], page_verify)
+        };
+
+        let enforce_expect_err = vec![
+            (PageVerify::NONE, false),
+            (PageVerify::CONTIGUOUS_OFFSETS, true),
+            (PageVerify::START_AT_FIRST_PAGE, false),
+            (PageVerify::FINISH_AT_LAST_PAGE, false),
+            (PageVerify::ALL, true),
+        ];
+
+        for (page_verify, expect_err) in enforce_expect_err {
+            assert!(expect_err == matches!(responses(page_verify), Err(Error::BadPagination(_))));
+        }
+    }
+
     #[test]
     fn concat_responses_error_page_verify_start_at_first_page() {
         let responses = |page_verify| concat_response_multi_pages(RESPONSES_SEASONS[1..].to_vec(), page_verify);
@@ -522,7 +914,8 @@ mod tests {
             None,
             None,
             get_jolpica_test_rate_limiter(),
-            Some(TESTS_DEFAULT_HTTP_RETRIES),
+            Some(&RetryPolicy::immediate(TESTS_DEFAULT_HTTP_RETRIES)),
+            None,
         )
         .unwrap();
 
@@ -542,6 +935,35 @@ mod tests {
         assert_eq!(response.as_seasons().unwrap()[75].season, 2025);
     }
 
+    #[test]
+    #[ignore]
+    fn concat_responses_races_laps_get_response_multi_pages() {
+        // With 20 drivers per lap, a page limit of 30 splits every other lap across pages
+        let responses: Vec<_> = (0..4)
+            .map(|idx| {
+                if let Some(rate_limiter) = get_jolpica_test_rate_limiter() {
+                    rate_limiter.wait_until_ready();
+                }
+                get_response_page(
+                    &get_jolpica_test_base_url(),
+                    &Resource::LapTimes(LapTimeFilters::new(2023, 4)),
+                    Some(Page::with(30, idx * 30)),
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let response =
+            concat_response_multi_pages(responses, PageVerify::CONTIGUOUS | PageVerify::START_AT_FIRST_PAGE).unwrap();
+        let laps = response.into_laps().unwrap();
+
+        assert_eq!(laps.len(), 6);
+        for (idx, lap) in laps.iter().enumerate() {
+            assert_eq!(lap.number, idx as u32 + 1);
+            assert_eq!(lap.timings.len(), 20);
+        }
+    }
+
     #[test]
     #[ignore]
     fn concat_responses_races_race_results_get_response_multi_pages() {
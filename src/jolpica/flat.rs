@@ -0,0 +1,1319 @@
+//! Flattened, table-like representations of the data in a [`Response`], e.g. for CSV export.
+//!
+//! A [`Response`] can be turned into a list of [`FlatRow`]s via [`Response::into_flat_rows`],
+//! regardless of the [`Resource`] that was requested. Each row is a flat struct holding only scalar
+//! fields, i.e. nested objects such as [`Driver`], [`Constructor`], and [`Location`] are inlined.
+//! Values from the [`time`](crate::jolpica::time) module, e.g. [`DateTime`] and [`RaceTime`], as
+//! well as [`Position`] and [`QualifyingTime`], are considered scalar values.
+//!
+//! Rows for a [`Race`]'s session results, laps, and pit stops include its `season`, `round`,
+//! `race_name`, `circuit_id`, and `date` to identify the race, while the remaining [`Race`] and
+//! [`Circuit`] fields are available via [`FlatRow::Race`] rows of a [`Resource::RaceSchedule`].
+//!
+//! With the `csv` feature enabled, a [`Race`]'s session results can be written as CSV, with one
+//! row per flattened result, via `to_csv`, e.g. [`Race::<Vec<RaceResult>>::to_csv`](Race::to_csv).
+//!
+//! ```
+//! # use f1_data::jolpica::{flat::FlatRow, response::Response};
+//! # let response: Response = serde_json::from_str(r#"{"MRData": {
+//! #   "xmlns": "", "series": "f1", "url": "https://api.jolpi.ca/ergast/f1/seasons/",
+//! #   "limit": "30", "offset": "0", "total": "1",
+//! #   "SeasonTable": {"Seasons": [{"season": "1950", "url": "https://en.wikipedia.org/wiki/1950"}]}
+//! # }}"#).unwrap();
+//! let rows = response.into_flat_rows().unwrap();
+//! assert!(matches!(&rows[0], FlatRow::Season(season) if season.season == 1950));
+//! ```
+
+use ordered_float::OrderedFloat;
+use url::Url;
+
+use crate::{
+    error::{Error, Result},
+    id::{CircuitID, ConstructorID, DriverID, RoundID, SeasonID},
+    jolpica::{
+        response::{
+            Circuit, Constructor, Driver, FastestLap, Payload, PitStop, Points, Position, QualifyingResult, Race,
+            RaceResult, Season, SpeedUnits, SprintResult, Status, Table, Timing,
+        },
+        time::{Date, DateTime, Duration, QualifyingTime, RaceTime, Time},
+    },
+};
+
+#[cfg(feature = "csv")]
+use crate::jolpica::time::duration_to_string;
+
+#[cfg(doc)]
+use crate::jolpica::{
+    resource::Resource,
+    response::{Location, Response, Schedule},
+};
+
+/// A single flattened row of a [`Response`], with one variant per kind of entity.
+///
+/// Returned by [`Response::into_flat_rows`]. All rows from a single [`Response`] hold the same
+/// variant, which corresponds to the requested [`Resource`].
+#[derive(PartialEq, Clone, Debug)]
+pub enum FlatRow {
+    /// A [`Season`], which is already flat, from [`Table::Seasons`].
+    Season(Season),
+    /// A [`Driver`], which is already flat, from [`Table::Drivers`].
+    Driver(Driver),
+    /// A [`Constructor`], which is already flat, from [`Table::Constructors`].
+    Constructor(Constructor),
+    /// A [`FlatCircuit`], from [`Table::Circuits`].
+    Circuit(FlatCircuit),
+    /// A [`Status`], which is already flat, from [`Table::Status`].
+    Status(Status),
+    /// A [`FlatRace`], from a [`Race`] with a [`Payload::Schedule`].
+    Race(FlatRace),
+    /// A [`FlatQualifyingResult`], from a [`Race`] with a [`Payload::QualifyingResults`].
+    QualifyingResult(FlatQualifyingResult),
+    /// A [`FlatSprintResult`], from a [`Race`] with a [`Payload::SprintResults`].
+    SprintResult(FlatSprintResult),
+    /// A [`FlatRaceResult`], from a [`Race`] with a [`Payload::RaceResults`].
+    RaceResult(FlatRaceResult),
+    /// A [`FlatTiming`], from a [`Race`] with a [`Payload::Laps`].
+    Timing(FlatTiming),
+    /// A [`FlatPitStop`], from a [`Race`] with a [`Payload::PitStops`].
+    PitStop(FlatPitStop),
+}
+
+/// A flattened [`Circuit`], with its [`Location`] fields inlined.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct FlatCircuit {
+    /// See [`Circuit::circuit_id`].
+    pub circuit_id: CircuitID,
+    /// See [`Circuit::url`].
+    pub url: Url,
+    /// See [`Circuit::circuit_name`].
+    pub circuit_name: String,
+    /// See [`Location::lat`].
+    pub lat: OrderedFloat<f64>,
+    /// See [`Location::long`].
+    pub long: OrderedFloat<f64>,
+    /// See [`Location::locality`].
+    pub locality: String,
+    /// See [`Location::country`].
+    pub country: String,
+    /// See [`Location::alt`].
+    pub alt: Option<OrderedFloat<f64>>,
+}
+
+/// A flattened [`Race`] with a [`Schedule`], with its [`Circuit`] and [`Schedule`] fields inlined.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FlatRace {
+    /// See [`Race::season`].
+    pub season: SeasonID,
+    /// See [`Race::round`].
+    pub round: RoundID,
+    /// See [`Race::url`].
+    pub url: Url,
+    /// See [`Race::race_name`].
+    pub race_name: String,
+    /// See [`Circuit::circuit_id`].
+    pub circuit_id: CircuitID,
+    /// See [`Circuit::url`].
+    pub circuit_url: Url,
+    /// See [`Circuit::circuit_name`].
+    pub circuit_name: String,
+    /// See [`Location::lat`].
+    pub lat: OrderedFloat<f64>,
+    /// See [`Location::long`].
+    pub long: OrderedFloat<f64>,
+    /// See [`Location::locality`].
+    pub locality: String,
+    /// See [`Location::country`].
+    pub country: String,
+    /// See [`Location::alt`].
+    pub alt: Option<OrderedFloat<f64>>,
+    /// See [`Race::date`].
+    pub date: Date,
+    /// See [`Race::time`].
+    pub time: Option<Time>,
+    /// See [`Schedule::first_practice`].
+    pub first_practice: Option<DateTime>,
+    /// See [`Schedule::second_practice`].
+    pub second_practice: Option<DateTime>,
+    /// See [`Schedule::third_practice`].
+    pub third_practice: Option<DateTime>,
+    /// See [`Schedule::qualifying`].
+    pub qualifying: Option<DateTime>,
+    /// See [`Schedule::sprint`].
+    pub sprint: Option<DateTime>,
+    /// See [`Schedule::sprint_shootout`].
+    pub sprint_shootout: Option<DateTime>,
+    /// See [`Schedule::sprint_qualifying`].
+    pub sprint_qualifying: Option<DateTime>,
+}
+
+/// A flattened [`QualifyingResult`], with its [`Driver`] and [`Constructor`] fields inlined.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FlatQualifyingResult {
+    /// See [`Race::season`].
+    pub season: SeasonID,
+    /// See [`Race::round`].
+    pub round: RoundID,
+    /// See [`Race::race_name`].
+    pub race_name: String,
+    /// See [`Circuit::circuit_id`].
+    pub circuit_id: CircuitID,
+    /// See [`Race::date`].
+    pub date: Date,
+    /// See [`QualifyingResult::number`].
+    pub number: u32,
+    /// See [`QualifyingResult::position`].
+    pub position: u32,
+    /// See [`Driver::driver_id`].
+    pub driver_id: DriverID,
+    /// See [`Driver::permanent_number`].
+    pub permanent_number: Option<u32>,
+    /// See [`Driver::code`].
+    pub code: Option<String>,
+    /// See [`Driver::url`].
+    pub driver_url: Option<Url>,
+    /// See [`Driver::given_name`].
+    pub given_name: String,
+    /// See [`Driver::family_name`].
+    pub family_name: String,
+    /// See [`Driver::date_of_birth`].
+    pub date_of_birth: Option<Date>,
+    /// See [`Driver::nationality`].
+    pub driver_nationality: Option<String>,
+    /// See [`Constructor::constructor_id`].
+    pub constructor_id: ConstructorID,
+    /// See [`Constructor::url`].
+    pub constructor_url: Url,
+    /// See [`Constructor::name`].
+    pub constructor_name: String,
+    /// See [`Constructor::nationality`].
+    pub constructor_nationality: String,
+    /// See [`QualifyingResult::q1`].
+    pub q1: Option<QualifyingTime>,
+    /// See [`QualifyingResult::q2`].
+    pub q2: Option<QualifyingTime>,
+    /// See [`QualifyingResult::q3`].
+    pub q3: Option<QualifyingTime>,
+}
+
+/// A flattened [`SprintResult`], with its [`Driver`], [`Constructor`], and [`FastestLap`] fields
+/// inlined.
+#[derive(PartialEq, Clone, Debug)]
+pub struct FlatSprintResult {
+    /// See [`Race::season`].
+    pub season: SeasonID,
+    /// See [`Race::round`].
+    pub round: RoundID,
+    /// See [`Race::race_name`].
+    pub race_name: String,
+    /// See [`Circuit::circuit_id`].
+    pub circuit_id: CircuitID,
+    /// See [`Race::date`].
+    pub date: Date,
+    /// See [`SprintResult::number`].
+    pub number: u32,
+    /// See [`SprintResult::position`].
+    pub position: u32,
+    /// See [`SprintResult::position_text`].
+    pub position_text: Position,
+    /// See [`SprintResult::points`].
+    pub points: Points,
+    /// See [`Driver::driver_id`].
+    pub driver_id: DriverID,
+    /// See [`Driver::permanent_number`].
+    pub permanent_number: Option<u32>,
+    /// See [`Driver::code`].
+    pub code: Option<String>,
+    /// See [`Driver::url`].
+    pub driver_url: Option<Url>,
+    /// See [`Driver::given_name`].
+    pub given_name: String,
+    /// See [`Driver::family_name`].
+    pub family_name: String,
+    /// See [`Driver::date_of_birth`].
+    pub date_of_birth: Option<Date>,
+    /// See [`Driver::nationality`].
+    pub driver_nationality: Option<String>,
+    /// See [`Constructor::constructor_id`].
+    pub constructor_id: ConstructorID,
+    /// See [`Constructor::url`].
+    pub constructor_url: Url,
+    /// See [`Constructor::name`].
+    pub constructor_name: String,
+    /// See [`Constructor::nationality`].
+    pub constructor_nationality: String,
+    /// See [`SprintResult::grid`].
+    pub grid: u32,
+    /// See [`SprintResult::laps`].
+    pub laps: u32,
+    /// See [`SprintResult::status`].
+    pub status: String,
+    /// See [`SprintResult::time`].
+    pub time: Option<RaceTime>,
+    /// See [`FastestLap::rank`].
+    pub fastest_lap_rank: Option<u32>,
+    /// See [`FastestLap::lap`].
+    pub fastest_lap_lap: Option<u32>,
+    /// See [`FastestLap::time`].
+    pub fastest_lap_time: Option<Duration>,
+    /// See [`AverageSpeed::speed`](crate::jolpica::response::AverageSpeed::speed).
+    pub fastest_lap_average_speed: Option<f32>,
+    /// See [`AverageSpeed::units`](crate::jolpica::response::AverageSpeed::units).
+    pub fastest_lap_average_speed_units: Option<SpeedUnits>,
+}
+
+/// A flattened [`RaceResult`], with its [`Driver`], [`Constructor`], and [`FastestLap`] fields
+/// inlined.
+#[derive(PartialEq, Clone, Debug)]
+pub struct FlatRaceResult {
+    /// See [`Race::season`].
+    pub season: SeasonID,
+    /// See [`Race::round`].
+    pub round: RoundID,
+    /// See [`Race::race_name`].
+    pub race_name: String,
+    /// See [`Circuit::circuit_id`].
+    pub circuit_id: CircuitID,
+    /// See [`Race::date`].
+    pub date: Date,
+    /// See [`RaceResult::number`].
+    pub number: u32,
+    /// See [`RaceResult::position`].
+    pub position: u32,
+    /// See [`RaceResult::position_text`].
+    pub position_text: Position,
+    /// See [`RaceResult::points`].
+    pub points: Points,
+    /// See [`Driver::driver_id`].
+    pub driver_id: DriverID,
+    /// See [`Driver::permanent_number`].
+    pub permanent_number: Option<u32>,
+    /// See [`Driver::code`].
+    pub code: Option<String>,
+    /// See [`Driver::url`].
+    pub driver_url: Option<Url>,
+    /// See [`Driver::given_name`].
+    pub given_name: String,
+    /// See [`Driver::family_name`].
+    pub family_name: String,
+    /// See [`Driver::date_of_birth`].
+    pub date_of_birth: Option<Date>,
+    /// See [`Driver::nationality`].
+    pub driver_nationality: Option<String>,
+    /// See [`Constructor::constructor_id`].
+    pub constructor_id: ConstructorID,
+    /// See [`Constructor::url`].
+    pub constructor_url: Url,
+    /// See [`Constructor::name`].
+    pub constructor_name: String,
+    /// See [`Constructor::nationality`].
+    pub constructor_nationality: String,
+    /// See [`RaceResult::grid`].
+    pub grid: u32,
+    /// See [`RaceResult::laps`].
+    pub laps: u32,
+    /// See [`RaceResult::status`].
+    pub status: String,
+    /// See [`RaceResult::time`].
+    pub time: Option<RaceTime>,
+    /// See [`FastestLap::rank`].
+    pub fastest_lap_rank: Option<u32>,
+    /// See [`FastestLap::lap`].
+    pub fastest_lap_lap: Option<u32>,
+    /// See [`FastestLap::time`].
+    pub fastest_lap_time: Option<Duration>,
+    /// See [`AverageSpeed::speed`](crate::jolpica::response::AverageSpeed::speed).
+    pub fastest_lap_average_speed: Option<f32>,
+    /// See [`AverageSpeed::units`](crate::jolpica::response::AverageSpeed::units).
+    pub fastest_lap_average_speed_units: Option<SpeedUnits>,
+}
+
+/// A flattened [`Timing`], with the number of the [`Lap`](crate::jolpica::response::Lap) that it
+/// belongs to, and its sectors, inlined.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FlatTiming {
+    /// See [`Race::season`].
+    pub season: SeasonID,
+    /// See [`Race::round`].
+    pub round: RoundID,
+    /// See [`Race::race_name`].
+    pub race_name: String,
+    /// See [`Circuit::circuit_id`].
+    pub circuit_id: CircuitID,
+    /// See [`Race::date`].
+    pub date: Date,
+    /// See [`Lap::number`](crate::jolpica::response::Lap::number).
+    pub lap: u32,
+    /// See [`Timing::driver_id`].
+    pub driver_id: DriverID,
+    /// See [`Timing::position`].
+    pub position: u32,
+    /// See [`Timing::time`].
+    pub time: Duration,
+    /// First sector of [`Timing::sectors`].
+    pub sector_1: Option<Duration>,
+    /// Second sector of [`Timing::sectors`].
+    pub sector_2: Option<Duration>,
+    /// Third sector of [`Timing::sectors`].
+    pub sector_3: Option<Duration>,
+}
+
+/// A flattened [`PitStop`], with the identifying fields of its [`Race`] inlined.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FlatPitStop {
+    /// See [`Race::season`].
+    pub season: SeasonID,
+    /// See [`Race::round`].
+    pub round: RoundID,
+    /// See [`Race::race_name`].
+    pub race_name: String,
+    /// See [`Circuit::circuit_id`].
+    pub circuit_id: CircuitID,
+    /// See [`Race::date`].
+    pub date: Date,
+    /// See [`PitStop::driver_id`].
+    pub driver_id: DriverID,
+    /// See [`PitStop::lap`].
+    pub lap: u32,
+    /// See [`PitStop::stop`].
+    pub stop: u32,
+    /// See [`PitStop::time`].
+    pub time: Time,
+    /// See [`PitStop::duration`].
+    pub duration: Duration,
+}
+
+/// Flattens all of the elements of a [`Table`] into [`FlatRow`]s, see [`Response::into_flat_rows`].
+pub(crate) fn flatten_table(table: Table) -> Result<Vec<FlatRow>> {
+    match table {
+        Table::Seasons { seasons } => Ok(seasons.into_iter().map(FlatRow::Season).collect()),
+        Table::Drivers { drivers } => Ok(drivers.into_iter().map(FlatRow::Driver).collect()),
+        Table::Constructors { constructors } => Ok(constructors.into_iter().map(FlatRow::Constructor).collect()),
+        Table::Circuits { circuits } => Ok(circuits
+            .into_iter()
+            .map(|circuit| FlatRow::Circuit(flatten_circuit(circuit)))
+            .collect()),
+        Table::Status { status } => Ok(status.into_iter().map(FlatRow::Status).collect()),
+        Table::Races { races } => flatten_races(races),
+        Table::StandingsLists { .. } => {
+            Err(Error::UnexpectedData(String::from("flattening standings lists is not supported")))
+        }
+    }
+}
+
+fn flatten_circuit(circuit: Circuit) -> FlatCircuit {
+    let Circuit {
+        circuit_id,
+        url,
+        circuit_name,
+        location,
+    } = circuit;
+
+    FlatCircuit {
+        circuit_id,
+        url,
+        circuit_name,
+        lat: location.lat,
+        long: location.long,
+        locality: location.locality,
+        country: location.country,
+        alt: location.alt,
+    }
+}
+
+fn flatten_races(races: Vec<Race>) -> Result<Vec<FlatRow>> {
+    if let Some(first) = races.first()
+        && let Some(other) = races.iter().find(|race| race.payload_kind() != first.payload_kind())
+    {
+        return Err(Error::UnexpectedData(format!(
+            "expected races with a single payload kind, found {:?} and {:?}",
+            first.payload_kind(),
+            other.payload_kind()
+        )));
+    }
+
+    Ok(races.into_iter().flat_map(flatten_race).collect())
+}
+
+/// Identifying fields of a [`Race`], included in every row flattened from its [`Race::payload`].
+#[derive(Clone)]
+struct RaceKey {
+    season: SeasonID,
+    round: RoundID,
+    race_name: String,
+    circuit_id: CircuitID,
+    date: Date,
+}
+
+fn flatten_race(race: Race) -> Vec<FlatRow> {
+    let Race {
+        season,
+        round,
+        url,
+        race_name,
+        circuit,
+        date,
+        time,
+        payload,
+    } = race;
+
+    let Payload::Schedule(schedule) = payload else {
+        let key = RaceKey {
+            season,
+            round,
+            race_name,
+            circuit_id: circuit.circuit_id,
+            date,
+        };
+        return flatten_payload(&key, payload);
+    };
+
+    let circuit = flatten_circuit(circuit);
+
+    vec![FlatRow::Race(FlatRace {
+        season,
+        round,
+        url,
+        race_name,
+        circuit_id: circuit.circuit_id,
+        circuit_url: circuit.url,
+        circuit_name: circuit.circuit_name,
+        lat: circuit.lat,
+        long: circuit.long,
+        locality: circuit.locality,
+        country: circuit.country,
+        alt: circuit.alt,
+        date,
+        time,
+        first_practice: schedule.first_practice,
+        second_practice: schedule.second_practice,
+        third_practice: schedule.third_practice,
+        qualifying: schedule.qualifying,
+        sprint: schedule.sprint,
+        sprint_shootout: schedule.sprint_shootout,
+        sprint_qualifying: schedule.sprint_qualifying,
+    })]
+}
+
+fn flatten_payload(key: &RaceKey, payload: Payload) -> Vec<FlatRow> {
+    match payload {
+        Payload::QualifyingResults(results) => results
+            .into_iter()
+            .map(|result| FlatRow::QualifyingResult(flatten_qualifying_result(key.clone(), result)))
+            .collect(),
+        Payload::SprintResults(results) => results
+            .into_iter()
+            .map(|result| FlatRow::SprintResult(flatten_sprint_result(key.clone(), result)))
+            .collect(),
+        Payload::RaceResults(results) => results
+            .into_iter()
+            .map(|result| FlatRow::RaceResult(flatten_race_result(key.clone(), result)))
+            .collect(),
+        Payload::Laps(laps) => laps
+            .into_iter()
+            .flat_map(|lap| {
+                lap.timings
+                    .into_iter()
+                    .map(move |timing| FlatRow::Timing(flatten_timing(key.clone(), lap.number, timing)))
+            })
+            .collect(),
+        Payload::PitStops(pit_stops) => pit_stops
+            .into_iter()
+            .map(|pit_stop| FlatRow::PitStop(flatten_pit_stop(key.clone(), pit_stop)))
+            .collect(),
+        Payload::Schedule(_) => unreachable!("schedules are flattened into FlatRow::Race"),
+    }
+}
+
+fn flatten_qualifying_result(key: RaceKey, result: QualifyingResult) -> FlatQualifyingResult {
+    let RaceKey {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+    } = key;
+
+    let QualifyingResult {
+        number,
+        position,
+        driver,
+        constructor,
+        q1,
+        q2,
+        q3,
+    } = result;
+
+    FlatQualifyingResult {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+        number,
+        position,
+        driver_id: driver.driver_id,
+        permanent_number: driver.permanent_number,
+        code: driver.code,
+        driver_url: driver.url,
+        given_name: driver.given_name,
+        family_name: driver.family_name,
+        date_of_birth: driver.date_of_birth,
+        driver_nationality: driver.nationality,
+        constructor_id: constructor.constructor_id,
+        constructor_url: constructor.url,
+        constructor_name: constructor.name,
+        constructor_nationality: constructor.nationality,
+        q1,
+        q2,
+        q3,
+    }
+}
+
+fn flatten_sprint_result(key: RaceKey, result: SprintResult) -> FlatSprintResult {
+    let RaceKey {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+    } = key;
+
+    let SprintResult {
+        number,
+        position,
+        position_text,
+        points,
+        driver,
+        constructor,
+        grid,
+        laps,
+        status,
+        time,
+        fastest_lap,
+    } = result;
+
+    FlatSprintResult {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+        number,
+        position,
+        position_text,
+        points,
+        driver_id: driver.driver_id,
+        permanent_number: driver.permanent_number,
+        code: driver.code,
+        driver_url: driver.url,
+        given_name: driver.given_name,
+        family_name: driver.family_name,
+        date_of_birth: driver.date_of_birth,
+        driver_nationality: driver.nationality,
+        constructor_id: constructor.constructor_id,
+        constructor_url: constructor.url,
+        constructor_name: constructor.name,
+        constructor_nationality: constructor.nationality,
+        grid,
+        laps,
+        status,
+        time,
+        fastest_lap_rank: fastest_lap.and_then(|fastest_lap| fastest_lap.rank),
+        fastest_lap_lap: fastest_lap.map(|fastest_lap| fastest_lap.lap),
+        fastest_lap_time: fastest_lap.map(|fastest_lap| fastest_lap.time),
+        fastest_lap_average_speed: average_speed(fastest_lap).map(|(speed, _)| speed),
+        fastest_lap_average_speed_units: average_speed(fastest_lap).map(|(_, units)| units),
+    }
+}
+
+fn flatten_race_result(key: RaceKey, result: RaceResult) -> FlatRaceResult {
+    let RaceKey {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+    } = key;
+
+    let RaceResult {
+        number,
+        position,
+        position_text,
+        points,
+        driver,
+        constructor,
+        grid,
+        laps,
+        status,
+        time,
+        fastest_lap,
+    } = result;
+
+    FlatRaceResult {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+        number,
+        position,
+        position_text,
+        points,
+        driver_id: driver.driver_id,
+        permanent_number: driver.permanent_number,
+        code: driver.code,
+        driver_url: driver.url,
+        given_name: driver.given_name,
+        family_name: driver.family_name,
+        date_of_birth: driver.date_of_birth,
+        driver_nationality: driver.nationality,
+        constructor_id: constructor.constructor_id,
+        constructor_url: constructor.url,
+        constructor_name: constructor.name,
+        constructor_nationality: constructor.nationality,
+        grid,
+        laps,
+        status,
+        time,
+        fastest_lap_rank: fastest_lap.and_then(|fastest_lap| fastest_lap.rank),
+        fastest_lap_lap: fastest_lap.map(|fastest_lap| fastest_lap.lap),
+        fastest_lap_time: fastest_lap.map(|fastest_lap| fastest_lap.time),
+        fastest_lap_average_speed: average_speed(fastest_lap).map(|(speed, _)| speed),
+        fastest_lap_average_speed_units: average_speed(fastest_lap).map(|(_, units)| units),
+    }
+}
+
+fn flatten_timing(key: RaceKey, lap: u32, timing: Timing) -> FlatTiming {
+    let RaceKey {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+    } = key;
+
+    let Timing {
+        driver_id,
+        position,
+        time,
+        sectors,
+    } = timing;
+
+    FlatTiming {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+        lap,
+        driver_id,
+        position,
+        time,
+        sector_1: sectors.map(|sectors| sectors[0]),
+        sector_2: sectors.map(|sectors| sectors[1]),
+        sector_3: sectors.map(|sectors| sectors[2]),
+    }
+}
+
+fn flatten_pit_stop(key: RaceKey, pit_stop: PitStop) -> FlatPitStop {
+    let RaceKey {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+    } = key;
+
+    let PitStop {
+        driver_id,
+        lap,
+        stop,
+        time,
+        duration,
+    } = pit_stop;
+
+    FlatPitStop {
+        season,
+        round,
+        race_name,
+        circuit_id,
+        date,
+        driver_id,
+        lap,
+        stop,
+        time,
+        duration,
+    }
+}
+
+fn average_speed(fastest_lap: Option<FastestLap>) -> Option<(f32, SpeedUnits)> {
+    fastest_lap
+        .and_then(|fastest_lap| fastest_lap.average_speed)
+        .map(|average_speed| (average_speed.speed, average_speed.units))
+}
+
+#[cfg(feature = "csv")]
+impl Race<Vec<QualifyingResult>> {
+    /// Writes the [`QualifyingResult`]s as CSV to `writer`, with a header row, followed by one row
+    /// per result, in order. The columns are the fields of [`FlatQualifyingResult`], i.e. with
+    /// [`Driver`] and [`Constructor`] fields inlined, and the [`Race`] identified by its `season`,
+    /// `round`, `race_name`, `circuit_id`, and `date`.
+    ///
+    /// Qualifying times are written in the jolpica-f1 API format, e.g. `"1:40.203"`, see
+    /// [`duration_to_string`], with an empty string for [`QualifyingTime::NoTimeSet`] or `None`.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::Csv`] is returned if writing to `writer` fails.
+    pub fn to_csv<W: std::io::Write>(&self, writer: W) -> Result<()> {
+        let key = race_key(self);
+        write_csv(
+            writer,
+            self.payload
+                .iter()
+                .map(|result| flatten_qualifying_result(key.clone(), result.clone())),
+        )
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Race<Vec<SprintResult>> {
+    /// Writes the [`SprintResult`]s as CSV to `writer`, with a header row, followed by one row per
+    /// result, in order. The columns are the fields of [`FlatSprintResult`], i.e. with [`Driver`],
+    /// [`Constructor`], and [`FastestLap`] fields inlined.
+    ///
+    /// [`Position`]s are written via their [`Display`](std::fmt::Display), e.g. `"R"`, and times in
+    /// the jolpica-f1 API format, see [`duration_to_string`]. A [`RaceTime`] is written as two
+    /// columns, `time` for [`RaceTime::total`] and `time_delta` for [`RaceTime::delta`], e.g.
+    /// `"+2.137"`. Missing values are written as empty strings.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::Csv`] is returned if writing to `writer` fails.
+    pub fn to_csv<W: std::io::Write>(&self, writer: W) -> Result<()> {
+        let key = race_key(self);
+        write_csv(
+            writer,
+            self.payload
+                .iter()
+                .map(|result| flatten_sprint_result(key.clone(), result.clone())),
+        )
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Race<Vec<RaceResult>> {
+    /// Writes the [`RaceResult`]s as CSV to `writer`, with a header row, followed by one row per
+    /// result, in order. The columns are the fields of [`FlatRaceResult`], written as described in
+    /// [`Race::<Vec<SprintResult>>::to_csv`](Race::to_csv).
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::Csv`] is returned if writing to `writer` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::Filters};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let race = jolpica
+    ///     .get_race_results_for_event(Filters::new().season(2023).round(4))
+    ///     .unwrap();
+    ///
+    /// let mut csv = Vec::new();
+    /// race.to_csv(&mut csv).unwrap();
+    ///
+    /// let csv = String::from_utf8(csv).unwrap();
+    /// assert!(csv.starts_with("season,round,race_name,circuit_id,date,number,position,"));
+    /// ```
+    pub fn to_csv<W: std::io::Write>(&self, writer: W) -> Result<()> {
+        let key = race_key(self);
+        write_csv(
+            writer,
+            self.payload
+                .iter()
+                .map(|result| flatten_race_result(key.clone(), result.clone())),
+        )
+    }
+}
+
+/// Returns the identifying fields of a [`Race`], included in every row flattened from it.
+#[cfg(feature = "csv")]
+fn race_key<T>(race: &Race<T>) -> RaceKey {
+    RaceKey {
+        season: race.season,
+        round: race.round,
+        race_name: race.race_name.clone(),
+        circuit_id: race.circuit.circuit_id.clone(),
+        date: race.date,
+    }
+}
+
+/// A flat row that can be written as a CSV record, with a fixed set of columns.
+#[cfg(feature = "csv")]
+trait CsvRecord {
+    /// Names of the columns, written as the header row.
+    const HEADERS: &'static [&'static str];
+
+    /// Textual values of the columns, in the same order as [`CsvRecord::HEADERS`].
+    fn to_record(&self) -> Vec<String>;
+}
+
+/// Writes a header row from [`CsvRecord::HEADERS`], followed by one row per element of `rows`.
+#[cfg(feature = "csv")]
+fn write_csv<W: std::io::Write, R: CsvRecord>(writer: W, rows: impl Iterator<Item = R>) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+
+    writer.write_record(R::HEADERS)?;
+    for row in rows {
+        writer.write_record(row.to_record())?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Formats an optional value via its [`Display`](std::fmt::Display), or as an empty string.
+#[cfg(feature = "csv")]
+#[allow(clippy::ref_option)] // Fix would be very verbose for little gain
+fn csv_opt<T: std::fmt::Display>(value: &Option<T>) -> String {
+    value.as_ref().map_or_else(String::new, ToString::to_string)
+}
+
+#[cfg(feature = "csv")]
+fn csv_qualifying_time(time: Option<QualifyingTime>) -> String {
+    time.and_then(|time| time.as_duration())
+        .map_or_else(String::new, duration_to_string)
+}
+
+#[cfg(feature = "csv")]
+fn csv_race_time(time: Option<RaceTime>) -> (String, String) {
+    time.map_or_else(Default::default, |time| {
+        (duration_to_string(*time.total()), format!("+{}", duration_to_string(*time.delta())))
+    })
+}
+
+#[cfg(feature = "csv")]
+fn csv_speed_units(units: Option<SpeedUnits>) -> String {
+    units.map_or_else(String::new, |units| match units {
+        SpeedUnits::Kph => String::from("kph"),
+    })
+}
+
+#[cfg(feature = "csv")]
+impl CsvRecord for FlatQualifyingResult {
+    const HEADERS: &'static [&'static str] = &[
+        "season",
+        "round",
+        "race_name",
+        "circuit_id",
+        "date",
+        "number",
+        "position",
+        "driver_id",
+        "permanent_number",
+        "code",
+        "driver_url",
+        "given_name",
+        "family_name",
+        "date_of_birth",
+        "driver_nationality",
+        "constructor_id",
+        "constructor_url",
+        "constructor_name",
+        "constructor_nationality",
+        "q1",
+        "q2",
+        "q3",
+    ];
+
+    fn to_record(&self) -> Vec<String> {
+        vec![
+            self.season.to_string(),
+            self.round.to_string(),
+            self.race_name.clone(),
+            self.circuit_id.clone(),
+            self.date.to_string(),
+            self.number.to_string(),
+            self.position.to_string(),
+            self.driver_id.clone(),
+            csv_opt(&self.permanent_number),
+            csv_opt(&self.code),
+            csv_opt(&self.driver_url),
+            self.given_name.clone(),
+            self.family_name.clone(),
+            csv_opt(&self.date_of_birth),
+            csv_opt(&self.driver_nationality),
+            self.constructor_id.clone(),
+            self.constructor_url.to_string(),
+            self.constructor_name.clone(),
+            self.constructor_nationality.clone(),
+            csv_qualifying_time(self.q1),
+            csv_qualifying_time(self.q2),
+            csv_qualifying_time(self.q3),
+        ]
+    }
+}
+
+/// Column names shared by [`FlatSprintResult`] and [`FlatRaceResult`], which have the same fields.
+#[cfg(feature = "csv")]
+const SESSION_RESULT_HEADERS: &[&str] = &[
+    "season",
+    "round",
+    "race_name",
+    "circuit_id",
+    "date",
+    "number",
+    "position",
+    "position_text",
+    "points",
+    "driver_id",
+    "permanent_number",
+    "code",
+    "driver_url",
+    "given_name",
+    "family_name",
+    "date_of_birth",
+    "driver_nationality",
+    "constructor_id",
+    "constructor_url",
+    "constructor_name",
+    "constructor_nationality",
+    "grid",
+    "laps",
+    "status",
+    "time",
+    "time_delta",
+    "fastest_lap_rank",
+    "fastest_lap_lap",
+    "fastest_lap_time",
+    "fastest_lap_average_speed",
+    "fastest_lap_average_speed_units",
+];
+
+#[cfg(feature = "csv")]
+impl CsvRecord for FlatSprintResult {
+    const HEADERS: &'static [&'static str] = SESSION_RESULT_HEADERS;
+
+    fn to_record(&self) -> Vec<String> {
+        let (time, time_delta) = csv_race_time(self.time);
+
+        vec![
+            self.season.to_string(),
+            self.round.to_string(),
+            self.race_name.clone(),
+            self.circuit_id.clone(),
+            self.date.to_string(),
+            self.number.to_string(),
+            self.position.to_string(),
+            self.position_text.to_string(),
+            self.points.to_string(),
+            self.driver_id.clone(),
+            csv_opt(&self.permanent_number),
+            csv_opt(&self.code),
+            csv_opt(&self.driver_url),
+            self.given_name.clone(),
+            self.family_name.clone(),
+            csv_opt(&self.date_of_birth),
+            csv_opt(&self.driver_nationality),
+            self.constructor_id.clone(),
+            self.constructor_url.to_string(),
+            self.constructor_name.clone(),
+            self.constructor_nationality.clone(),
+            self.grid.to_string(),
+            self.laps.to_string(),
+            self.status.clone(),
+            time,
+            time_delta,
+            csv_opt(&self.fastest_lap_rank),
+            csv_opt(&self.fastest_lap_lap),
+            self.fastest_lap_time.map_or_else(String::new, duration_to_string),
+            csv_opt(&self.fastest_lap_average_speed),
+            csv_speed_units(self.fastest_lap_average_speed_units),
+        ]
+    }
+}
+
+#[cfg(feature = "csv")]
+impl CsvRecord for FlatRaceResult {
+    const HEADERS: &'static [&'static str] = SESSION_RESULT_HEADERS;
+
+    fn to_record(&self) -> Vec<String> {
+        let (time, time_delta) = csv_race_time(self.time);
+
+        vec![
+            self.season.to_string(),
+            self.round.to_string(),
+            self.race_name.clone(),
+            self.circuit_id.clone(),
+            self.date.to_string(),
+            self.number.to_string(),
+            self.position.to_string(),
+            self.position_text.to_string(),
+            self.points.to_string(),
+            self.driver_id.clone(),
+            csv_opt(&self.permanent_number),
+            csv_opt(&self.code),
+            csv_opt(&self.driver_url),
+            self.given_name.clone(),
+            self.family_name.clone(),
+            csv_opt(&self.date_of_birth),
+            csv_opt(&self.driver_nationality),
+            self.constructor_id.clone(),
+            self.constructor_url.to_string(),
+            self.constructor_name.clone(),
+            self.constructor_nationality.clone(),
+            self.grid.to_string(),
+            self.laps.to_string(),
+            self.status.clone(),
+            time,
+            time_delta,
+            csv_opt(&self.fastest_lap_rank),
+            csv_opt(&self.fastest_lap_lap),
+            self.fastest_lap_time.map_or_else(String::new, duration_to_string),
+            csv_opt(&self.fastest_lap_average_speed),
+            csv_speed_units(self.fastest_lap_average_speed_units),
+        ]
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use crate::jolpica::response::Response;
+    use crate::jolpica::tests::assets::*;
+    use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
+
+    use super::*;
+
+    fn make_response(table: Table) -> Response {
+        Response {
+            xmlns: String::new(),
+            series: "f1".into(),
+            url: Url::parse("https://api.jolpi.ca/ergast/f1/").unwrap(),
+            pagination: crate::jolpica::response::Pagination {
+                limit: 30,
+                offset: 0,
+                total: 0,
+            },
+            table,
+        }
+    }
+
+    #[test]
+    fn into_flat_rows_table_lists() {
+        let rows = make_response(SEASON_TABLE.clone()).into_flat_rows().unwrap();
+        assert_eq!(rows.len(), SEASON_TABLE.as_seasons().unwrap().len());
+        assert_eq!(rows[0], FlatRow::Season(SEASON_1950.clone()));
+
+        let rows = make_response(DRIVER_TABLE.clone()).into_flat_rows().unwrap();
+        assert_eq!(rows[0], FlatRow::Driver(DRIVER_TABLE.as_drivers().unwrap()[0].clone()));
+
+        let rows = make_response(CIRCUIT_TABLE.clone()).into_flat_rows().unwrap();
+        let circuit = &CIRCUIT_TABLE.as_circuits().unwrap()[0];
+        let FlatRow::Circuit(flat) = &rows[0] else {
+            panic!("Expected FlatRow::Circuit");
+        };
+        assert_eq!(flat.circuit_id, circuit.circuit_id);
+        assert_eq!(flat.lat, circuit.location.lat);
+        assert_eq!(flat.country, circuit.location.country);
+    }
+
+    #[test]
+    fn into_flat_rows_schedule() {
+        let rows = make_response(Table::Races {
+            races: vec![RACE_2023_4_SCHEDULE.clone()],
+        })
+        .into_flat_rows()
+        .unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let FlatRow::Race(flat) = &rows[0] else {
+            panic!("Expected FlatRow::Race");
+        };
+        assert_eq!(flat.season, 2023);
+        assert_eq!(flat.round, 4);
+        assert_eq!(flat.circuit_id, RACE_2023_4_SCHEDULE.circuit.circuit_id);
+        assert_eq!(flat.locality, RACE_2023_4_SCHEDULE.circuit.location.locality);
+
+        let race = RACE_2023_4_SCHEDULE.clone();
+        let schedule = race.payload.as_schedule().unwrap();
+        assert_eq!(flat.first_practice, schedule.first_practice);
+        assert_eq!(flat.sprint, schedule.sprint);
+    }
+
+    #[test]
+    fn into_flat_rows_race_results() {
+        let rows = make_response(Table::Races {
+            races: vec![RACE_2023_4_RACE_RESULTS.clone()],
+        })
+        .into_flat_rows()
+        .unwrap();
+        assert_eq!(rows.len(), 3);
+
+        let FlatRow::RaceResult(flat) = &rows[0] else {
+            panic!("Expected FlatRow::RaceResult");
+        };
+        let result = &*RACE_RESULT_2023_4_P1;
+
+        assert_eq!(flat.season, 2023);
+        assert_eq!(flat.round, 4);
+        assert_eq!(flat.race_name, RACE_2023_4.race_name);
+        assert_eq!(flat.position, result.position);
+        assert_eq!(flat.points, result.points);
+        assert_eq!(flat.driver_id, result.driver.driver_id);
+        assert_eq!(flat.family_name, result.driver.family_name);
+        assert_eq!(flat.constructor_id, result.constructor.constructor_id);
+        assert_eq!(flat.constructor_name, result.constructor.name);
+        assert_eq!(flat.time, result.time);
+        assert_eq!(flat.fastest_lap_lap, result.fastest_lap.map(|fastest_lap| fastest_lap.lap));
+    }
+
+    #[test]
+    fn into_flat_rows_laps_and_pit_stops() {
+        let rows = make_response(Table::Races {
+            races: vec![RACE_2023_4_LAPS.clone()],
+        })
+        .into_flat_rows()
+        .unwrap();
+        assert_eq!(rows.len(), LAP_2023_4_L1.timings.len() + LAP_2023_4_L2.timings.len());
+
+        let FlatRow::Timing(flat) = &rows[0] else {
+            panic!("Expected FlatRow::Timing");
+        };
+        let timing = &LAP_2023_4_L1.timings[0];
+        assert_eq!(flat.lap, 1);
+        assert_eq!(flat.driver_id, timing.driver_id);
+        assert_eq!(flat.time, timing.time);
+        assert_eq!(flat.sector_1, timing.sectors.map(|sectors| sectors[0]));
+
+        let FlatRow::Timing(flat) = rows.last().unwrap() else {
+            panic!("Expected FlatRow::Timing");
+        };
+        assert_eq!(flat.lap, 2);
+
+        let rows = make_response(Table::Races {
+            races: vec![RACE_2023_4_PIT_STOPS.clone()],
+        })
+        .into_flat_rows()
+        .unwrap();
+        assert_eq!(rows.len(), 2);
+
+        let FlatRow::PitStop(flat) = &rows[0] else {
+            panic!("Expected FlatRow::PitStop");
+        };
+        assert_eq!(flat.driver_id, PIT_STOP_2023_4_L10_MAX.driver_id);
+        assert_eq!(flat.duration, PIT_STOP_2023_4_L10_MAX.duration);
+    }
+
+    #[cfg(feature = "csv")]
+    fn csv_records(csv: &[u8]) -> Vec<Vec<String>> {
+        csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(csv)
+            .records()
+            .map(|record| record.unwrap().iter().map(String::from).collect())
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn race_results_to_csv() {
+        let race = RACE_2023_4_RACE_RESULTS
+            .clone()
+            .map(|payload| payload.into_race_results().unwrap());
+
+        let mut csv = Vec::new();
+        race.to_csv(&mut csv).unwrap();
+
+        let records = csv_records(&csv);
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0], SESSION_RESULT_HEADERS);
+        assert_eq!(
+            records[1].join(","),
+            "2023,4,Azerbaijan Grand Prix,baku,2023-04-30,11,1,1,25,perez,11,PER,\
+             http://en.wikipedia.org/wiki/Sergio_P%C3%A9rez,Sergio,Pérez,1990-01-26,Mexican,red_bull,\
+             https://en.wikipedia.org/wiki/Red_Bull_Racing,Red Bull,Austrian,3,51,Finished,1:32:42.436,+0.000,\
+             5,50,1:44.589,206.625,kph"
+        );
+
+        let column = |name| {
+            SESSION_RESULT_HEADERS
+                .iter()
+                .position(|header| *header == name)
+                .unwrap()
+        };
+        assert_eq!(records[2][column("time_delta")], "+2.137");
+        assert_eq!(records[3][column("position_text")], "R");
+        assert_eq!(records[3][column("time")], "");
+        assert_eq!(records[3][column("time_delta")], "");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn sprint_results_to_csv() {
+        let race = RACE_2023_4_SPRINT_RESULTS
+            .clone()
+            .map(|payload| payload.into_sprint_results().unwrap());
+
+        let mut csv = Vec::new();
+        race.to_csv(&mut csv).unwrap();
+
+        let records = csv_records(&csv);
+        assert_eq!(records.len(), race.payload.len() + 1);
+        assert_eq!(records[0], SESSION_RESULT_HEADERS);
+
+        for (record, result) in records[1..].iter().zip(&race.payload) {
+            assert_eq!(record.len(), SESSION_RESULT_HEADERS.len());
+            assert_eq!(record[7], result.position_text.to_string());
+            assert_eq!(record[9], result.driver.driver_id);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn qualifying_results_to_csv() {
+        let race = RACE_2023_4_QUALIFYING_RESULTS
+            .clone()
+            .map(|payload| payload.into_qualifying_results().unwrap());
+
+        let mut csv = Vec::new();
+        race.to_csv(&mut csv).unwrap();
+
+        let records = csv_records(&csv);
+        assert_eq!(records.len(), race.payload.len() + 1);
+        assert_eq!(records[0], FlatQualifyingResult::HEADERS);
+        assert_eq!(
+            records[1][..8],
+            [
+                "2023",
+                "4",
+                "Azerbaijan Grand Prix",
+                "baku",
+                "2023-04-30",
+                "16",
+                "1",
+                "leclerc"
+            ]
+        );
+        assert_eq!(records[1][19..], ["1:41.269", "1:41.037", "1:40.203"]);
+
+        let mut no_time_set = race.clone();
+        no_time_set.payload[0].q2 = Some(QualifyingTime::NoTimeSet);
+        no_time_set.payload[0].q3 = None;
+
+        let mut csv = Vec::new();
+        no_time_set.to_csv(&mut csv).unwrap();
+        assert_eq!(csv_records(&csv)[1][19..], ["1:41.269", "", ""]);
+    }
+
+    #[test]
+    fn into_flat_rows_error_mixed_payload_kinds() {
+        let response = make_response(Table::Races {
+            races: vec![RACE_2023_4_RACE_RESULTS.clone(), RACE_2023_4_PIT_STOPS.clone()],
+        });
+        assert!(matches!(response.into_flat_rows(), Err(Error::UnexpectedData(_))));
+    }
+}
@@ -1,17 +1,139 @@
 //! Functions for performing GET requests to the [jolpica-f1](https://github.com/jolpica/jolpica-f1)
 //! API, including multi-page requests, returning the JSON response(s) parsed into [`Response`]s.
+//!
+//! Each request, retry, and page of a multi-page request is logged at the `debug` or `trace`
+//! level via the [`log`](https://docs.rs/log) crate, e.g. visible with `RUST_LOG=f1_data=debug` if
+//! [`env_logger`](https://docs.rs/env_logger) is used, to help diagnose rate limiting stalls, etc.
+
+use std::{
+    hash::{BuildHasher, RandomState},
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    thread,
+    time::Duration,
+};
 
 use crate::{
     error::{Error, Result},
     jolpica::{
         resource::{Page, Resource},
-        response::Response,
+        response::{Pagination, Response},
     },
     rate_limiter::RateLimiter,
 };
 
 #[cfg(doc)]
-use crate::jolpica::{agent::Agent, response::Pagination};
+use crate::jolpica::agent::{Agent, AgentConfigs};
+
+/// A predicate that decides whether a failed GET request should be retried, based on the [`Error`].
+///
+/// See [`retry_on_error`] for how it is used, and [`AgentConfigs::retry_predicate`] for configuring
+/// it in an [`Agent`]. For example, it can be used to retry on server errors, i.e. HTTP `5xx`, but
+/// not on client errors, i.e. HTTP `4xx`, which are unlikely to succeed on a retry.
+pub type RetryPredicate = dyn Fn(&Error) -> bool + Send + Sync;
+
+/// A policy for retrying failed GET requests, with exponential backoff and optional jitter.
+///
+/// Up to [`max_retries`](Self::max_retries) retries are made, and before each one, the calling
+/// thread sleeps for a [`delay`](Self::delay) that doubles with every retry, starting from
+/// [`base_delay`](Self::base_delay), and capped at [`max_delay`](Self::max_delay). If
+/// [`jitter`](Self::jitter) is enabled, the delay is randomized, to avoid many clients retrying in
+/// lockstep. If a request fails with [`Error::RetryAfter`], i.e. the jolpica-f1 API responded with
+/// `429 Too Many Requests` and a `Retry-After` header, the requested delay is used instead.
+///
+/// See [`retry_with_policy`] for how it is used, and [`AgentConfigs::http_retries`] for
+/// configuring it in an [`Agent`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use f1_data::jolpica::get::RetryPolicy;
+/// #
+/// let policy = RetryPolicy {
+///     jitter: false,
+///     ..RetryPolicy::exponential(5, Duration::from_millis(500))
+/// };
+///
+/// assert_eq!(policy.delay(0), Duration::from_millis(500));
+/// assert_eq!(policy.delay(1), Duration::from_secs(1));
+/// assert_eq!(policy.delay(2), Duration::from_secs(2));
+///
+/// assert_eq!(RetryPolicy::immediate(2).delay(1), Duration::ZERO);
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt, where `0` means no retries.
+    pub max_retries: usize,
+    /// Delay before the first retry, which doubles with every subsequent retry.
+    pub base_delay: Duration,
+    /// Upper bound on the delay before any retry, not counting a requested `Retry-After`.
+    pub max_delay: Duration,
+    /// If `true`, each delay is randomized to be between half of and the full computed delay.
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Default upper bound on the delay before any retry, used by [`RetryPolicy::exponential`].
+    pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+    /// Creates a [`RetryPolicy`] with up to `max_retries` retries, made immediately, without delay.
+    pub const fn immediate(max_retries: usize) -> Self {
+        Self {
+            max_retries,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            jitter: false,
+        }
+    }
+
+    /// Creates a [`RetryPolicy`] with up to `max_retries` retries, with exponential backoff starting
+    /// from `base_delay`, capped at [`RetryPolicy::DEFAULT_MAX_DELAY`], and with jitter enabled.
+    pub const fn exponential(max_retries: usize, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+            jitter: true,
+        }
+    }
+
+    /// Returns the delay before the `retry`-th retry, `0`-based, i.e. the [`base_delay`]
+    /// multiplied by `2^retry`, capped at [`max_delay`], and randomized if [`jitter`] is enabled.
+    ///
+    /// [`base_delay`]: Self::base_delay
+    /// [`max_delay`]: Self::max_delay
+    /// [`jitter`]: Self::jitter
+    pub fn delay(&self, retry: usize) -> Duration {
+        let factor = u32::try_from(retry)
+            .ok()
+            .and_then(|retry| 2_u32.checked_pow(retry))
+            .unwrap_or(u32::MAX);
+
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay / 2 + (delay / 2).mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+}
+
+/// Returns a pseudo-random number in `[0, 1)`, for [`RetryPolicy::jitter`].
+///
+/// This relies on the random keys of [`RandomState`], which is sufficient for jitter, and avoids
+/// depending on a random number generator crate.
+#[allow(clippy::cast_precision_loss)]
+fn random_fraction() -> f64 {
+    (RandomState::new().hash_one(std::time::SystemTime::now()) >> 11) as f64 / (1_u64 << 53) as f64
+}
 
 /// Performs a GET request to the jolpica-f1 API for a specific page of the specified
 /// [`Resource`].
@@ -84,8 +206,7 @@ use crate::jolpica::{agent::Agent, response::Pagination};
 /// assert!(resp.pagination.is_last_page());
 /// ```
 pub fn get_response_page(base_url: &str, resource: &Resource, page: Option<Page>) -> Result<Response> {
-    let url = resource.to_url_with_base_and_opt_page(base_url, page);
-    let json_str = ureq::get(url.as_str()).call()?.into_body().read_to_string()?;
+    let json_str = get_json_page(base_url, resource, page)?;
 
     // Use `serde_json::from_str::<Resp..>(.read_to_string())` instead of `.read_json::<Response>()`
     // to get better error messages, e.g. to get an [`Error::Parse(serde_json::Error)`] instead of
@@ -95,6 +216,251 @@ pub fn get_response_page(base_url: &str, resource: &Resource, page: Option<Page>
     serde_json::from_str::<Response>(json_str.as_str()).map_err(Into::into)
 }
 
+/// Performs a GET request to the jolpica-f1 API for a specific page of the specified
+/// [`Resource`], and returns the raw JSON response as a [`String`], without parsing it.
+///
+/// This makes the same request as [`get_response_page`], and is mostly useful to persist responses
+/// as-is, e.g. in a filesystem cache, as per [`AgentConfigs::cache`]. The returned JSON can later
+/// be parsed into a [`Response`] via [`serde_json::from_str`].
+///
+/// <div class="warning">
+/// This method does not implement rate limiting or caching; users should be mindful to not violate
+/// the jolpica-f1 API's
+/// <a href="https://github.com/jolpica/jolpica-f1/blob/main/docs/rate_limits.md">rate limits</a> or
+/// any of its <a href="https://github.com/jolpica/jolpica-f1/blob/main/TERMS.md">terms of
+/// service</a>.
+/// </div>
+///
+/// # Examples
+///
+/// ```no_run
+/// # use f1_data::jolpica::{
+/// #    api::JOLPICA_API_BASE_URL,
+/// #    get::get_json_page,
+/// #    resource::{Filters, Resource},
+/// #    response::Response};
+/// #
+/// let json = get_json_page(JOLPICA_API_BASE_URL, &Resource::SeasonList(Filters::none()), None).unwrap();
+///
+/// let resp = serde_json::from_str::<Response>(&json).unwrap();
+/// assert_eq!(resp.table.as_seasons().unwrap()[0].season, 1950);
+/// ```
+pub fn get_json_page(base_url: &str, resource: &Resource, page: Option<Page>) -> Result<String> {
+    get_json_page_with_headers(base_url, resource, page, &[])
+}
+
+/// Performs a GET request to the jolpica-f1 API for a specific page of the specified
+/// [`Resource`], with additional HTTP `headers`, and returns the raw JSON response as a [`String`].
+///
+/// This behaves like [`get_json_page`], but each of the `(name, value)` pairs in `headers` is set
+/// on the request, e.g. a `User-Agent` to identify the client, as encouraged by the jolpica-f1
+/// API's [terms of service](https://github.com/jolpica/jolpica-f1/blob/main/TERMS.md). See
+/// [`AgentConfigs::user_agent`] and [`AgentConfigs::extra_headers`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use f1_data::jolpica::{
+/// #    api::JOLPICA_API_BASE_URL,
+/// #    get::get_json_page_with_headers,
+/// #    resource::{Filters, Resource}};
+/// #
+/// let json = get_json_page_with_headers(
+///     JOLPICA_API_BASE_URL,
+///     &Resource::SeasonList(Filters::none()),
+///     None,
+///     &[("User-Agent", "my-f1-app/1.0")],
+/// )
+/// .unwrap();
+/// assert!(json.contains("SeasonTable"));
+/// ```
+pub fn get_json_page_with_headers(
+    base_url: &str,
+    resource: &Resource,
+    page: Option<Page>,
+    headers: &[(&str, &str)],
+) -> Result<String> {
+    let url = resource.to_url_with_base_and_opt_page(base_url, page);
+    log::debug!("GET {url}");
+
+    call(get_request(url.as_str(), headers))?
+        .into_body()
+        .read_to_string()
+        .map_err(Into::into)
+}
+
+/// Creates a GET request for the given `url`, with each of the `(name, value)` pairs in `headers`.
+fn get_request(url: &str, headers: &[(&str, &str)]) -> ureq::RequestBuilder<ureq::typestate::WithoutBody> {
+    headers
+        .iter()
+        .fold(ureq::get(url), |request, (name, value)| request.header(*name, *value))
+}
+
+/// Performs a GET `request`, returning an [`Error::RetryAfter`] if the server responds with `429
+/// Too Many Requests` and a valid `Retry-After` header, or an [`Error::Http`] for any other HTTP
+/// `4xx` or `5xx` status, i.e. the same error that [`ureq::RequestBuilder::call`] would return.
+fn call(request: ureq::RequestBuilder<ureq::typestate::WithoutBody>) -> Result<ureq::http::Response<ureq::Body>> {
+    let http_response = request.config().http_status_as_error(false).build().call()?;
+    let status = http_response.status();
+
+    if status == ureq::http::StatusCode::TOO_MANY_REQUESTS
+        && let Some(delay) = retry_after(http_response.headers())
+    {
+        return Err(Error::RetryAfter(delay));
+    }
+
+    if status.is_client_error() || status.is_server_error() {
+        return Err(Error::Http(ureq::Error::StatusCode(status.as_u16())));
+    }
+
+    Ok(http_response)
+}
+
+/// Extracts the delay requested via a `Retry-After` header, if present and specified in seconds.
+pub(crate) fn retry_after(headers: &ureq::http::HeaderMap) -> Option<Duration> {
+    headers
+        .get(ureq::http::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Cache validators returned by the jolpica-f1 API, used to make conditional GET requests.
+///
+/// These hold the values of the `ETag` and `Last-Modified` headers of a response, if present, which
+/// can be sent back via the `If-None-Match` and `If-Modified-Since` headers of a subsequent request
+/// for the same URL, e.g. via [`get_response_page_conditional`].
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct CacheValidators {
+    /// The value of the `ETag` header, if present, sent back via the `If-None-Match` header.
+    pub etag: Option<String>,
+    /// The value of the `Last-Modified` header, if present, sent back via `If-Modified-Since`.
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    /// Extracts the [`CacheValidators`] from the headers of an HTTP response, if present.
+    pub fn from_headers(headers: &ureq::http::HeaderMap) -> Self {
+        let get = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+
+        Self {
+            etag: get(ureq::http::header::ETAG),
+            last_modified: get(ureq::http::header::LAST_MODIFIED),
+        }
+    }
+
+    /// Returns `true` if there are no validators, i.e. a conditional request cannot be made.
+    pub const fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// The outcome of a conditional GET request, e.g. via [`get_response_page_conditional`].
+#[derive(PartialEq, Clone, Debug)]
+pub enum ConditionalResponse {
+    /// The resource was modified, or no validators were sent, and a full [`Response`] was returned,
+    /// along with its [`CacheValidators`] to be used in subsequent conditional requests.
+    Modified(Box<Response>, CacheValidators),
+    /// The resource was not modified, i.e. `304 Not Modified`, and a previous response is current.
+    NotModified,
+}
+
+/// Performs a conditional GET request to the jolpica-f1 API for a specific page of the specified
+/// [`Resource`].
+///
+/// This behaves like [`get_response_page`], except that, if `validators` are provided, they are
+/// sent via the `If-None-Match` and `If-Modified-Since` headers. If the server responds with `304
+/// Not Modified`, then [`ConditionalResponse::NotModified`] is returned, and the caller is
+/// expected to reuse a previously cached [`Response`] for the same request. Otherwise, the
+/// [`Response`] is parsed and returned along with its [`CacheValidators`], if any.
+///
+/// <div class="warning">
+/// This method does not implement rate limiting or caching; users should be mindful to not violate
+/// the jolpica-f1 API's
+/// <a href="https://github.com/jolpica/jolpica-f1/blob/main/docs/rate_limits.md">rate limits</a> or
+/// any of its <a href="https://github.com/jolpica/jolpica-f1/blob/main/TERMS.md">terms of
+/// service</a>.
+/// </div>
+///
+/// # Examples
+///
+/// ```no_run
+/// # use f1_data::jolpica::{
+/// #    api::JOLPICA_API_BASE_URL,
+/// #    get::{ConditionalResponse, get_response_page_conditional},
+/// #    resource::{Filters, Resource}};
+/// #
+/// let resource = Resource::SeasonList(Filters::none());
+///
+/// let ConditionalResponse::Modified(resp, validators) =
+///     get_response_page_conditional(JOLPICA_API_BASE_URL, &resource, None, None).unwrap()
+/// else {
+///     unreachable!("Unconditional requests always return a full response");
+/// };
+/// assert_eq!(resp.table.as_seasons().unwrap()[0].season, 1950);
+///
+/// match get_response_page_conditional(JOLPICA_API_BASE_URL, &resource, None, Some(&validators)) {
+///     Ok(ConditionalResponse::NotModified) => println!("Seasons unchanged, reuse `resp`"),
+///     Ok(ConditionalResponse::Modified(resp, _)) => println!("Seasons changed: {resp:?}"),
+///     Err(err) => panic!("{err:?}"),
+/// }
+/// ```
+pub fn get_response_page_conditional(
+    base_url: &str,
+    resource: &Resource,
+    page: Option<Page>,
+    validators: Option<&CacheValidators>,
+) -> Result<ConditionalResponse> {
+    get_response_page_conditional_with_headers(base_url, resource, page, validators, &[])
+}
+
+/// Performs a conditional GET request to the jolpica-f1 API for a specific page of the specified
+/// [`Resource`], with additional HTTP `headers`.
+///
+/// This behaves like [`get_response_page_conditional`], but each of the `(name, value)` pairs in
+/// `headers` is set on the request, as per [`get_json_page_with_headers`].
+pub fn get_response_page_conditional_with_headers(
+    base_url: &str,
+    resource: &Resource,
+    page: Option<Page>,
+    validators: Option<&CacheValidators>,
+    headers: &[(&str, &str)],
+) -> Result<ConditionalResponse> {
+    let url = resource.to_url_with_base_and_opt_page(base_url, page);
+    log::debug!("GET {url} (conditional: {})", validators.is_some_and(|validators| !validators.is_empty()));
+
+    let mut request = get_request(url.as_str(), headers);
+
+    if let Some(validators) = validators {
+        if let Some(etag) = &validators.etag {
+            request = request.header(ureq::http::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &validators.last_modified {
+            request = request.header(ureq::http::header::IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let http_response = call(request)?;
+
+    if http_response.status() == ureq::http::StatusCode::NOT_MODIFIED {
+        log::trace!("GET {url} -> 304 Not Modified");
+        return Ok(ConditionalResponse::NotModified);
+    }
+
+    let validators = CacheValidators::from_headers(http_response.headers());
+    let json_str = http_response.into_body().read_to_string()?;
+
+    Ok(ConditionalResponse::Modified(
+        Box::new(serde_json::from_str::<Response>(json_str.as_str())?),
+        validators,
+    ))
+}
+
 /// Performs GET requests to the jolpica-f1 API for all pages of the specified [`Resource`].
 ///
 /// Performs GET requests to the jolpica-f1 API at `base_url` for all pages of the specified
@@ -105,7 +471,8 @@ pub fn get_response_page(base_url: &str, resource: &Resource, page: Option<Page>
 /// `initial_page`, or by specifying no page at all. The [`Response::pagination`] field of the first
 /// response is then used to determine the subsequent pages to request, if any, via
 /// [`Pagination::next_page`]. If a `rate_limiter` is provided, it is used to wait before each
-/// request, including the first.
+/// request, including the first. Each request is retried as per [`retry_with_policy`], with the
+/// provided `retry_policy` and `retry_predicate`.
 ///
 /// This method performs no additional processing; it returns the top-level [`Response`]s that
 /// are a direct representation of the full JSON responses. It is provided here to maximize
@@ -124,7 +491,7 @@ pub fn get_response_page(base_url: &str, resource: &Resource, page: Option<Page>
 /// # use f1_data::{
 /// #     jolpica::{
 /// #         api::{JOLPICA_API_BASE_URL, JOLPICA_API_RATE_LIMIT_QUOTA},
-/// #         get::get_response_multi_pages,
+/// #         get::{RetryPolicy, get_response_multi_pages},
 /// #         resource::{Filters, Page, Resource},
 /// #     },
 /// #     rate_limiter::{Quota, RateLimiter},
@@ -138,7 +505,8 @@ pub fn get_response_page(base_url: &str, resource: &Resource, page: Option<Page>
 ///     Some(Page::with_limit(50)),
 ///     Some(10),
 ///     Some(&rate_limiter),
-///     Some(2),
+///     Some(&RetryPolicy::immediate(2)),
+///     None,
 /// )
 /// .unwrap();
 ///
@@ -160,15 +528,62 @@ pub fn get_response_multi_pages(
     initial_page: Option<Page>,
     max_page_count: Option<usize>,
     rate_limiter: Option<&RateLimiter>,
-    http_retries: Option<usize>,
+    retry_policy: Option<&RetryPolicy>,
+    retry_predicate: Option<&RetryPredicate>,
 ) -> Result<Vec<Response>> {
-    let mut responses = vec![retry_on_http_error(
-        || get_response_page(base_url, resource, initial_page),
-        rate_limiter,
-        http_retries,
-    )?];
+    get_multi_pages_with(initial_page, max_page_count, 1, |page| {
+        retry_with_policy(|| get_response_page(base_url, resource, page), rate_limiter, retry_policy, retry_predicate)
+    })
+}
+
+/// Performs requests for all pages of a possibly multi-page response via the provided `get_page`.
+///
+/// This implements the pagination logic of [`get_response_multi_pages`], but delegates each page
+/// request to `get_page`, allowing callers to customize how each page is requested, e.g. with
+/// conditional requests, as done by [`Agent`].
+///
+/// Since all subsequent pages are known once the first response is received, up to
+/// `max_concurrency` of them are requested concurrently, via [`get_pages_concurrently`], if it is
+/// greater than `1`. Otherwise, they are requested sequentially. Either way, the [`Response`]s are
+/// returned in page order.
+pub(crate) fn get_multi_pages_with(
+    initial_page: Option<Page>,
+    max_page_count: Option<usize>,
+    max_concurrency: usize,
+    get_page: impl Fn(Option<Page>) -> Result<Response> + Sync,
+) -> Result<Vec<Response>> {
+    let first_response = get_page(initial_page)?;
+    let pages = all_pages(first_response.pagination, max_page_count)?;
+
+    if pages.len() > 1 {
+        log::debug!(
+            "Multi-page response with {} total elements, requesting {} more pages (max concurrency: {})",
+            first_response.pagination.total,
+            pages.len() - 1,
+            max_concurrency.max(1)
+        );
+    }
 
-    let mut pages = vec![responses.last().unwrap_or_else(|| unreachable!()).pagination];
+    let remaining_responses = if max_concurrency > 1 {
+        get_pages_concurrently(&pages[1..], max_concurrency, &get_page)?
+    } else {
+        pages[1..]
+            .iter()
+            .map(|page| {
+                log::trace!("Requesting page at offset {} with limit {}", page.offset, page.limit);
+                get_page(Some((*page).into()))
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    Ok(std::iter::once(first_response).chain(remaining_responses).collect())
+}
+
+/// Returns the [`Pagination`]s of all pages of a possibly multi-page response, starting with the
+/// `first` page, via [`Pagination::next_page`], returning an [`Error::ExceededMaxPageCount`] if
+/// there are more than `max_page_count` pages.
+pub(crate) fn all_pages(first: Pagination, max_page_count: Option<usize>) -> Result<Vec<Pagination>> {
+    let mut pages = vec![first];
 
     while let Some(next_page) = pages.last().unwrap_or_else(|| unreachable!()).next_page() {
         pages.push(next_page);
@@ -180,15 +595,49 @@ pub fn get_response_multi_pages(
         return Err(Error::ExceededMaxPageCount((pages.len(), max_page_count)));
     }
 
-    for page in &pages[1..] {
-        responses.push(retry_on_http_error(
-            || get_response_page(base_url, resource, Some((*page).into())),
-            rate_limiter,
-            http_retries,
-        )?);
-    }
+    Ok(pages)
+}
 
-    Ok(responses)
+/// Requests all of the given `pages` via `get_page`, from up to `max_concurrency` scoped threads,
+/// and returns the [`Response`]s in the same order as the `pages`.
+///
+/// Pages are handed out to the threads in order, so that if any request fails, no further pages are
+/// requested, and the [`Error`] for the earliest failed page is returned. Any rate limiting must be
+/// performed by `get_page`, e.g. via a shared [`RateLimiter`], which is thread-safe.
+fn get_pages_concurrently(
+    pages: &[Pagination],
+    max_concurrency: usize,
+    get_page: &(impl Fn(Option<Page>) -> Result<Response> + Sync),
+) -> Result<Vec<Response>> {
+    let next_index = AtomicUsize::new(0);
+    let has_failed = AtomicBool::new(false);
+    let results = Mutex::new(Vec::with_capacity(pages.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..max_concurrency.min(pages.len()) {
+            let _unused = scope.spawn(|| {
+                while !has_failed.load(Ordering::Relaxed) {
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+                    let Some(page) = pages.get(index) else { break };
+
+                    log::trace!("Requesting page at offset {} with limit {}", page.offset, page.limit);
+                    let result = get_page(Some((*page).into()));
+                    let _unused = has_failed.fetch_or(result.is_err(), Ordering::Relaxed);
+
+                    results
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .push((index, result));
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap_or_else(PoisonError::into_inner);
+    results.sort_by_key(|(index, _)| *index);
+
+    // Pages are handed out in order, so every page before the earliest failure has a result.
+    results.into_iter().map(|(_, result)| result).collect()
 }
 
 /// Call the provided function, retrying on HTTP errors, and forwarding anything else.
@@ -200,12 +649,75 @@ pub fn get_response_multi_pages(
 /// [`Error::Http`]. If all attempts result in [`Error::Http`], then an [`Error::HttpRetries`] is
 /// returned, holding the number of retries attempted and the last encountered [`ureq::Error`].
 /// If a `rate_limiter` is provided, it is used to wait before each attempt, including the first.
+///
+/// Retries are made immediately, except after an [`Error::RetryAfter`], which is otherwise treated
+/// as an [`Error::Http`], in which case the requested delay is honored. See [`retry_with_policy`]
+/// for retries with exponential backoff.
 pub fn retry_on_http_error<T>(
     f: impl Fn() -> Result<T>,
     rate_limiter: Option<&RateLimiter>,
     max_retries: Option<usize>,
 ) -> Result<T> {
-    let max_retries = max_retries.unwrap_or(0);
+    retry_on_error(f, rate_limiter, max_retries, None)
+}
+
+/// Call the provided function, retrying on errors accepted by the `retry_predicate`, and forwarding
+/// anything else.
+///
+/// This behaves like [`retry_on_http_error`], except that the optional `retry_predicate` is
+/// consulted before each retry to decide whether an [`Error`] should be retried. If it is [`None`],
+/// only [`Error::Http`] and [`Error::RetryAfter`] errors are retried, i.e. it is equivalent to
+/// [`retry_on_http_error`]. If all attempts result in errors accepted by the predicate, then an
+/// [`Error::HttpRetries`] is returned if the last error was an [`Error::Http`] or
+/// [`Error::RetryAfter`], otherwise the last error is returned as-is. This is equivalent to
+/// [`retry_with_policy`] with a [`RetryPolicy::immediate`].
+pub fn retry_on_error<T>(
+    f: impl Fn() -> Result<T>,
+    rate_limiter: Option<&RateLimiter>,
+    max_retries: Option<usize>,
+    retry_predicate: Option<&RetryPredicate>,
+) -> Result<T> {
+    retry_with_policy(f, rate_limiter, max_retries.map(RetryPolicy::immediate).as_ref(), retry_predicate)
+}
+
+/// Call the provided function, retrying on errors accepted by the `retry_predicate`, as per the
+/// `retry_policy`, and forwarding anything else.
+///
+/// This behaves like [`retry_on_error`], except that the maximum number of retries, and the delay
+/// before each retry, are configured via the optional [`RetryPolicy`]. If it is [`None`], no
+/// retries are performed. If the `retry_predicate` is [`None`], only [`Error::Http`] and
+/// [`Error::RetryAfter`] errors are retried, and for the latter, the requested delay is used
+/// instead of the [`RetryPolicy::delay`]. If all attempts result in errors accepted by the
+/// predicate, then an [`Error::HttpRetries`] is returned if the last error was an [`Error::Http`]
+/// or [`Error::RetryAfter`], otherwise the last error is returned as-is.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use f1_data::jolpica::{
+/// #    api::JOLPICA_API_BASE_URL,
+/// #    get::{RetryPolicy, get_response_page, retry_with_policy},
+/// #    resource::{Filters, Resource}};
+/// #
+/// // Retries after ~500ms, ~1s, and ~2s, or as requested by the server via `Retry-After`
+/// let policy = RetryPolicy::exponential(3, Duration::from_millis(500));
+///
+/// let resp = retry_with_policy(
+///     || get_response_page(JOLPICA_API_BASE_URL, &Resource::SeasonList(Filters::none()), None),
+///     None,
+///     Some(&policy),
+///     None,
+/// )
+/// .unwrap();
+/// ```
+pub fn retry_with_policy<T>(
+    f: impl Fn() -> Result<T>,
+    rate_limiter: Option<&RateLimiter>,
+    retry_policy: Option<&RetryPolicy>,
+    retry_predicate: Option<&RetryPredicate>,
+) -> Result<T> {
+    let max_retries = retry_policy.map_or(0, |policy| policy.max_retries);
 
     let rate_limited_call = || {
         if let Some(limiter) = rate_limiter {
@@ -216,22 +728,74 @@ pub fn retry_on_http_error<T>(
 
     let mut result = rate_limited_call();
 
-    if max_retries == 0 || !matches!(result, Err(Error::Http(_))) {
+    if max_retries == 0 || !should_retry(&result, retry_predicate) {
         return result;
     }
 
-    for _ in 0..max_retries {
+    for retry in 0..max_retries {
+        let delay = retry_delay(&result, retry_policy, retry);
+
+        if !delay.is_zero() {
+            thread::sleep(delay);
+        }
+
         result = rate_limited_call();
 
-        if !matches!(result, Err(Error::Http(_))) {
+        if !should_retry(&result, retry_predicate) {
             return result;
         }
     }
 
-    let Err(Error::Http(ureq_err)) = result else {
-        unreachable!()
+    into_exhausted_retries(result, max_retries)
+}
+
+/// Returns `true` if `result` is an [`Error`] that should be retried, as per the `retry_predicate`
+/// if [`Some`], or otherwise if it is an HTTP error, i.e. [`Error::Http`] or [`Error::RetryAfter`].
+pub(crate) fn should_retry<T>(result: &Result<T>, retry_predicate: Option<&RetryPredicate>) -> bool {
+    match result {
+        Ok(_) => false,
+        Err(err) => retry_predicate.map_or_else(|| is_http_error(err), |predicate| predicate(err)),
+    }
+}
+
+/// Returns `true` if `err` is an HTTP error, which are retried if no [`RetryPredicate`] is given.
+const fn is_http_error(err: &Error) -> bool {
+    match err {
+        Error::Http(_) | Error::RetryAfter(_) => true,
+        #[cfg(feature = "async")]
+        Error::AsyncHttp(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns the delay before the `retry`-th retry, `0`-based, after the failed `result`, i.e. the
+/// requested delay of an [`Error::RetryAfter`], or otherwise the [`RetryPolicy::delay`], if any.
+/// The upcoming retry, and the error that caused it, are logged at the `debug` level.
+pub(crate) fn retry_delay<T>(result: &Result<T>, retry_policy: Option<&RetryPolicy>, retry: usize) -> Duration {
+    let delay = match (result, retry_policy) {
+        (Err(Error::RetryAfter(delay)), _) => *delay,
+        (_, Some(policy)) => policy.delay(retry),
+        (_, None) => Duration::ZERO,
     };
-    Err(Error::HttpRetries((max_retries, ureq_err)))
+
+    if let (Err(err), Some(policy)) = (result, retry_policy) {
+        log::debug!("Retry {}/{} in {delay:?}, after error: {err}", retry + 1, policy.max_retries);
+    }
+
+    delay
+}
+
+/// Converts the `result` of the last attempt, after `max_retries` retries, into an
+/// [`Error::HttpRetries`] if it is an [`Error::Http`] or [`Error::RetryAfter`], or returns it as-is.
+pub(crate) fn into_exhausted_retries<T>(result: Result<T>, max_retries: usize) -> Result<T> {
+    match result {
+        Err(Error::Http(ureq_err)) => Err(Error::HttpRetries((max_retries, ureq_err))),
+        Err(Error::RetryAfter(_)) => Err(Error::HttpRetries((
+            max_retries,
+            ureq::Error::StatusCode(ureq::http::StatusCode::TOO_MANY_REQUESTS.as_u16()),
+        ))),
+        _ => result,
+    }
 }
 
 #[cfg(test)]
@@ -252,7 +816,7 @@ mod tests {
 
     use crate::jolpica::tests::{
         assets::*,
-        util::{TESTS_DEFAULT_HTTP_RETRIES, retry_http},
+        util::{MOCK_ETAG, TESTS_DEFAULT_HTTP_RETRIES, retry_http, spawn_conditional_mock_server, spawn_mock_server},
     };
     use crate::tests::asserts::*;
     use shadow_asserts::assert_eq;
@@ -430,7 +994,8 @@ mod tests {
             Some(page.clone()),
             None,
             get_jolpica_test_rate_limiter(),
-            Some(TESTS_DEFAULT_HTTP_RETRIES),
+            Some(&RetryPolicy::immediate(TESTS_DEFAULT_HTTP_RETRIES)),
+            None,
         )
         .unwrap();
 
@@ -492,6 +1057,7 @@ mod tests {
             None,
             Some(&rate_limiter),
             None,
+            None,
         );
         let elapsed = start.elapsed();
         assert_eq!(_responses.unwrap().len(), 4);
@@ -510,6 +1076,7 @@ mod tests {
             None,
             Some(&rate_limiter),
             None,
+            None,
         );
         let elapsed = start.elapsed();
         assert_eq!(_responses.unwrap().len(), 4);
@@ -534,6 +1101,7 @@ mod tests {
                 Some(Page::with_limit(5)),
                 Some(10),
                 Some(&rate_limiter),
+                None,
                 None
             ),
             // 76 / 5 -> 16 pages > 10 max
@@ -545,6 +1113,46 @@ mod tests {
         assert_lt!(elapsed, Duration::from_millis(get_request_avg_duration_ms() * (1 + 1)));
     }
 
+    #[test]
+    fn get_multi_pages_with_concurrency() {
+        let get_page = |page: Option<Page>| {
+            let page = page.unwrap();
+            if page.offset() == 50 || page.offset() == 70 {
+                return Err(Error::UnexpectedData(page.offset().to_string()));
+            }
+
+            let mut response = serde_json::from_str::<Response>(RESPONSE_SEASON_TABLE_STR).unwrap();
+            response.pagination = Pagination {
+                limit: page.limit(),
+                offset: page.offset(),
+                total: 45,
+            };
+            Ok(response)
+        };
+
+        for max_concurrency in [0, 1, 2, 4, 10] {
+            let responses = get_multi_pages_with(Some(Page::with_limit(5)), None, max_concurrency, get_page).unwrap();
+            let offsets: Vec<_> = responses.iter().map(|resp| resp.pagination.offset).collect();
+            assert_eq!(offsets, (0..9).map(|page| page * 5).collect::<Vec<_>>());
+
+            assert!(matches!(
+                get_multi_pages_with(Some(Page::with_limit(5)), Some(8), max_concurrency, get_page),
+                Err(Error::ExceededMaxPageCount((9, 8)))
+            ));
+
+            // The error for the earliest failed page is returned, regardless of completion order
+            assert!(matches!(
+                get_multi_pages_with(Some(Page::with_limit(10)), None, max_concurrency, |page| {
+                    get_page(page).map(|mut resp| {
+                        resp.pagination.total = 100;
+                        resp
+                    })
+                }),
+                Err(Error::UnexpectedData(offset)) if offset == "50"
+            ));
+        }
+    }
+
     // Helper function to create a closure that counts how many times it has been called.
     // The counter is reset to zero whenever this function is called to make a new closure.
     fn make_counter_f<T>(count: &RefCell<u32>, f: impl Fn() -> Result<T>) -> impl Fn() -> Result<T> {
@@ -579,7 +1187,7 @@ mod tests {
 
         let f_ok = || Ok(42);
         let f_err_http = || Err(Error::Http(ureq::Error::ConnectionFailed));
-        let f_err_non_http = || Err(Error::NotFound);
+        let f_err_non_http = || Err(Error::NotFound(String::new()));
 
         // let compiler deduce the type of the closures
         let _unused: Result<u32> = f_err_http();
@@ -595,7 +1203,7 @@ mod tests {
         assert_eq!(*count.borrow(), 1);
 
         let result = super::retry_on_http_error(make_counter_f(&count, f_err_non_http), None, Some(0));
-        assert!(matches!(result, Err(Error::NotFound)));
+        assert!(matches!(result, Err(Error::NotFound(_))));
         assert_eq!(*count.borrow(), 1);
 
         // Succeeds on first try
@@ -606,7 +1214,7 @@ mod tests {
 
         // Fails with non-HTTP error
         let result = super::retry_on_http_error(make_counter_f(&count, f_err_non_http), None, Some(3));
-        assert!(matches!(result, Err(Error::NotFound)));
+        assert!(matches!(result, Err(Error::NotFound(_))));
         assert_eq!(*count.borrow(), 1);
 
         // Fails twice with HTTP error, then succeeds
@@ -630,7 +1238,7 @@ mod tests {
             None,
             Some(3),
         );
-        assert!(matches!(result, Err(Error::NotFound)));
+        assert!(matches!(result, Err(Error::NotFound(_))));
         assert_eq!(*count.borrow(), 3);
 
         // Fails with HTTP error exceeding max retries
@@ -651,4 +1259,270 @@ mod tests {
         assert_ge!(elapsed, Duration::from_millis(100 * 4));
         assert_lt!(elapsed, Duration::from_millis(100 * (4 + 1))); // * +1 margin
     }
+
+    #[test]
+    fn retry_on_error() {
+        let count = RefCell::<u32>::new(0);
+
+        let f_err_status = |status| move || -> Result<u32> { Err(Error::Http(ureq::Error::StatusCode(status))) };
+        let f_err_non_http = || -> Result<u32> { Err(Error::NotFound(String::new())) };
+
+        let retry_server_errors: &RetryPredicate =
+            &|err| matches!(err, Error::Http(ureq::Error::StatusCode(status)) if *status >= 500);
+
+        // No predicate, behaves as retry_on_http_error
+        let result = super::retry_on_error(make_counter_f(&count, f_err_status(404)), None, Some(3), None);
+        assert!(matches!(result, Err(Error::HttpRetries((3, ureq::Error::StatusCode(404))))));
+        assert_eq!(*count.borrow(), 4);
+
+        // Predicate rejects client errors, no retries
+        let result =
+            super::retry_on_error(make_counter_f(&count, f_err_status(404)), None, Some(3), Some(retry_server_errors));
+        assert!(matches!(result, Err(Error::Http(ureq::Error::StatusCode(404)))));
+        assert_eq!(*count.borrow(), 1);
+
+        // Predicate accepts server errors, retries until exceeding max retries
+        let result =
+            super::retry_on_error(make_counter_f(&count, f_err_status(503)), None, Some(3), Some(retry_server_errors));
+        assert!(matches!(result, Err(Error::HttpRetries((3, ureq::Error::StatusCode(503))))));
+        assert_eq!(*count.borrow(), 4);
+
+        // Fails twice with server error, then succeeds
+        let result = super::retry_on_error(
+            make_counter_f(&count, || {
+                if *count.borrow() < 3 {
+                    f_err_status(500)()
+                } else {
+                    Ok(42)
+                }
+            }),
+            None,
+            Some(3),
+            Some(retry_server_errors),
+        );
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(*count.borrow(), 3);
+
+        // Predicate accepts non-HTTP errors, which are returned as-is after exceeding max retries
+        let result = super::retry_on_error(make_counter_f(&count, f_err_non_http), None, Some(2), Some(&|_| true));
+        assert!(matches!(result, Err(Error::NotFound(_))));
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn retry_policy_delay() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            jitter: false,
+        };
+
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(1), Duration::from_millis(200));
+        assert_eq!(policy.delay(2), Duration::from_millis(400));
+        assert_eq!(policy.delay(3), Duration::from_millis(800));
+        assert_eq!(policy.delay(4), Duration::from_secs(1));
+        assert_eq!(policy.delay(usize::MAX), Duration::from_secs(1));
+
+        assert_eq!(RetryPolicy::immediate(3).delay(2), Duration::ZERO);
+
+        let policy = RetryPolicy { jitter: true, ..policy };
+        for retry in 0..5 {
+            let delay = policy.delay(retry);
+            assert_ge!(delay, policy.max_delay.min(policy.base_delay * (1 << retry)) / 2);
+            assert_le!(delay, policy.max_delay.min(policy.base_delay * (1 << retry)));
+        }
+    }
+
+    #[test]
+    fn retry_with_policy_backoff() {
+        let count = RefCell::<u32>::new(0);
+
+        let f_err_http = || -> Result<u32> { Err(Error::Http(ureq::Error::ConnectionFailed)) };
+
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_millis(150),
+            jitter: false,
+        };
+
+        // Waits 50ms, 100ms, and 150ms (capped) before each retry
+        let start = std::time::Instant::now();
+        let result = retry_with_policy(make_counter_f(&count, f_err_http), None, Some(&policy), None);
+        let elapsed = start.elapsed();
+
+        assert!(matches!(result, Err(Error::HttpRetries((3, _)))));
+        assert_eq!(*count.borrow(), 4);
+        assert_ge!(elapsed, Duration::from_millis(50 + 100 + 150));
+        assert_lt!(elapsed, Duration::from_millis(50 + 100 + 150 + 100)); // + some margin
+
+        // No policy, no retries
+        let result = retry_with_policy(make_counter_f(&count, f_err_http), None, None, None);
+        assert!(matches!(result, Err(Error::Http(_))));
+        assert_eq!(*count.borrow(), 1);
+
+        // Requested `Retry-After` delays are used instead of the policy's delays
+        let result = retry_with_policy(
+            make_counter_f(&count, || -> Result<u32> { Err(Error::RetryAfter(Duration::ZERO)) }),
+            None,
+            Some(&RetryPolicy {
+                base_delay: Duration::from_secs(10),
+                ..policy
+            }),
+            None,
+        );
+        assert!(matches!(result, Err(Error::HttpRetries((3, ureq::Error::StatusCode(429))))));
+        assert_eq!(*count.borrow(), 4);
+    }
+
+    #[test]
+    fn get_response_page_retry_after() {
+        let too_many_requests = |retry_after: &str| {
+            format!("HTTP/1.1 429 Too Many Requests\r\n{retry_after}Content-Length: 0\r\nConnection: close\r\n\r\n")
+        };
+        let ok = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n\
+             {RESPONSE_SEASON_TABLE_STR}",
+            RESPONSE_SEASON_TABLE_STR.len()
+        );
+
+        let (base_url, server) = spawn_mock_server(vec![
+            too_many_requests("Retry-After: 1\r\n"),
+            ok,
+            too_many_requests(""),
+            too_many_requests("Retry-After: soon\r\n"),
+        ]);
+        let resource = Resource::SeasonList(Filters::none());
+
+        assert!(matches!(
+            super::get_response_page(&base_url, &resource, None),
+            Err(Error::RetryAfter(delay)) if delay == Duration::from_secs(1)
+        ));
+        assert_eq!(super::get_response_page(&base_url, &resource, None).unwrap().table, *SEASON_TABLE);
+
+        // Without a valid `Retry-After`, a plain HTTP error is returned
+        for _ in 0..2 {
+            assert!(matches!(
+                super::get_response_page(&base_url, &resource, None),
+                Err(Error::Http(ureq::Error::StatusCode(429)))
+            ));
+        }
+
+        assert_eq!(server.join().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn get_json_page_with_headers() {
+        let ok = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n\
+             {RESPONSE_SEASON_TABLE_STR}",
+            RESPONSE_SEASON_TABLE_STR.len()
+        );
+
+        let (base_url, server) = spawn_mock_server(vec![ok.clone(), ok]);
+        let resource = Resource::SeasonList(Filters::none());
+        let headers = [("User-Agent", "my-f1-app/1.0"), ("X-Client-Id", "f1-data-tests")];
+
+        let json = super::get_json_page_with_headers(&base_url, &resource, None, &headers).unwrap();
+        assert_eq!(json, RESPONSE_SEASON_TABLE_STR);
+
+        let ConditionalResponse::Modified(resp, _) =
+            get_response_page_conditional_with_headers(&base_url, &resource, None, None, &headers[1..]).unwrap()
+        else {
+            panic!("expected a modified response");
+        };
+        assert_eq!(resp.table, *SEASON_TABLE);
+
+        let requests = server.join().unwrap();
+        let has_header =
+            |request: &[String], header: &str| request.iter().any(|line| line.eq_ignore_ascii_case(header));
+
+        assert_true!(has_header(&requests[0], "user-agent: my-f1-app/1.0"));
+        assert_true!(has_header(&requests[0], "x-client-id: f1-data-tests"));
+        assert_false!(has_header(&requests[1], "user-agent: my-f1-app/1.0"));
+        assert_true!(has_header(&requests[1], "x-client-id: f1-data-tests"));
+    }
+
+    #[test]
+    fn retry_with_policy_honors_retry_after() {
+        let ok = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n\
+             {RESPONSE_SEASON_TABLE_STR}",
+            RESPONSE_SEASON_TABLE_STR.len()
+        );
+
+        let (base_url, server) = spawn_mock_server(vec![
+            String::from(
+                "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 1\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            ),
+            ok,
+        ]);
+        let resource = Resource::SeasonList(Filters::none());
+
+        let start = std::time::Instant::now();
+        let resp = retry_with_policy(
+            || super::get_response_page(&base_url, &resource, None),
+            None,
+            Some(&RetryPolicy::immediate(1)),
+            None,
+        )
+        .unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(resp.table, *SEASON_TABLE);
+        assert_ge!(elapsed, Duration::from_secs(1));
+
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn cache_validators_from_headers() {
+        let mut headers = ureq::http::HeaderMap::new();
+        assert_true!(CacheValidators::from_headers(&headers).is_empty());
+
+        let _unused = headers.insert(ureq::http::header::ETAG, MOCK_ETAG.parse().unwrap());
+        let validators = CacheValidators::from_headers(&headers);
+        assert_false!(validators.is_empty());
+        assert_eq!(validators.etag.as_deref(), Some(MOCK_ETAG));
+        assert_true!(validators.last_modified.is_none());
+
+        let _unused =
+            headers.insert(ureq::http::header::LAST_MODIFIED, "Sun, 01 Jan 2023 00:00:00 GMT".parse().unwrap());
+        assert_eq!(
+            CacheValidators::from_headers(&headers).last_modified.as_deref(),
+            Some("Sun, 01 Jan 2023 00:00:00 GMT")
+        );
+    }
+
+    #[test]
+    fn get_response_page_conditional() {
+        let (base_url, server) = spawn_conditional_mock_server(RESPONSE_SEASON_TABLE_STR, 3);
+        let resource = Resource::SeasonList(Filters::none());
+
+        let ConditionalResponse::Modified(resp, validators) =
+            super::get_response_page_conditional(&base_url, &resource, None, None).unwrap()
+        else {
+            panic!("Expected a full response for an unconditional request");
+        };
+        assert_eq!(resp.table, *SEASON_TABLE);
+        assert_eq!(validators.etag.as_deref(), Some(MOCK_ETAG));
+
+        assert_eq!(
+            super::get_response_page_conditional(&base_url, &resource, None, Some(&validators)).unwrap(),
+            ConditionalResponse::NotModified
+        );
+
+        let stale = CacheValidators {
+            etag: Some("\"stale\"".into()),
+            last_modified: None,
+        };
+        assert!(matches!(
+            super::get_response_page_conditional(&base_url, &resource, None, Some(&stale)).unwrap(),
+            ConditionalResponse::Modified(..)
+        ));
+
+        assert_eq!(server.join().unwrap(), 1);
+    }
 }
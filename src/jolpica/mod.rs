@@ -8,15 +8,24 @@
 
 pub mod agent;
 pub mod api;
+pub mod backend;
 pub mod concat;
+pub mod flat;
 pub mod get;
+pub mod points;
 pub mod resource;
 pub mod response;
+pub mod stats;
 pub mod time;
 
-#[cfg(test)]
+#[cfg(feature = "async")]
+pub mod async_agent;
+
+#[cfg(any(test, feature = "test-util"))]
 pub(crate) mod tests;
 
-pub use agent::{Agent, AgentConfigs, MultiPageOption, RateLimiterOption};
+pub use agent::{Agent, AgentConfigs, CacheExpiry, CacheOption, MultiPageOption, RateLimiterOption};
+#[cfg(feature = "async")]
+pub use async_agent::{AsyncAgent, AsyncAgentConfigs};
 pub use resource::{Filters, LapTimeFilters, PitStopFilters, Resource};
-pub use response::{Payload, Table};
+pub use response::{Payload, PayloadKind, Table, TableKind};
@@ -0,0 +1,269 @@
+//! Scoring systems used to award championship points for race results throughout F1 history.
+//!
+//! This allows computing the points that a [`RaceResult`] should be awarded as per the rules of
+//! its era, e.g. to validate the [`RaceResult::points`] reported by the jolpica-f1 API, via
+//! [`system_for_season`] and [`award_for_season`].
+//!
+//! **Note:** Only the base scoring rules are modelled. Exceptions such as shared drives in the
+//! 1950s, half points for shortened races, e.g. the 2021 Belgian Grand Prix, the double points of
+//! the 2014 season finale, dropped scores, etc., are not accounted for. Sprint points are also not
+//! included, as they are awarded for [`SprintResult`]s rather than [`RaceResult`]s.
+//!
+//! ```
+//! use f1_data::jolpica::points::{SYSTEM_2010_2018, system_for_season};
+//!
+//! assert_eq!(system_for_season(2014), SYSTEM_2010_2018);
+//! assert_eq!(system_for_season(2014).points_for_position(1), 25.0);
+//! assert_eq!(system_for_season(2002).points_for_position(7), 0.0);
+//! ```
+
+use crate::{
+    id::SeasonID,
+    jolpica::response::{Points, Position, RaceResult},
+};
+
+#[cfg(doc)]
+use crate::jolpica::response::{FastestLap, SprintResult};
+
+/// Describes how championship points are awarded for race results, as per the rules of an era.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ScoringSystem {
+    /// Points awarded for each finishing position, starting from first place. Positions beyond the
+    /// length of this list are not awarded any points.
+    pub positions: &'static [Points],
+    /// Points awarded for setting the fastest lap of the race, if any, in this scoring system.
+    pub fastest_lap: Option<FastestLapPoints>,
+}
+
+/// Describes the points awarded for setting the fastest lap of a race in a [`ScoringSystem`].
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct FastestLapPoints {
+    /// Points awarded to the driver that set the fastest lap of the race.
+    pub points: Points,
+    /// Lowest finishing position that is eligible for the fastest lap points, if restricted, e.g.
+    /// `Some(10)` for the 2019-2024 seasons. If [`None`], any driver is eligible.
+    pub max_position: Option<u32>,
+}
+
+/// Scoring system for the 1950-1959 seasons, `8-6-4-3-2` plus `1` point for the fastest lap.
+pub const SYSTEM_1950_1959: ScoringSystem = ScoringSystem {
+    positions: &[8.0, 6.0, 4.0, 3.0, 2.0],
+    fastest_lap: Some(FastestLapPoints {
+        points: 1.0,
+        max_position: None,
+    }),
+};
+
+/// Scoring system for the 1960 season, `8-6-4-3-2-1`, without points for the fastest lap.
+pub const SYSTEM_1960: ScoringSystem = ScoringSystem {
+    positions: &[8.0, 6.0, 4.0, 3.0, 2.0, 1.0],
+    fastest_lap: None,
+};
+
+/// Scoring system for the 1961-1990 seasons, `9-6-4-3-2-1`.
+pub const SYSTEM_1961_1990: ScoringSystem = ScoringSystem {
+    positions: &[9.0, 6.0, 4.0, 3.0, 2.0, 1.0],
+    fastest_lap: None,
+};
+
+/// Scoring system for the 1991-2002 seasons, `10-6-4-3-2-1`.
+pub const SYSTEM_1991_2002: ScoringSystem = ScoringSystem {
+    positions: &[10.0, 6.0, 4.0, 3.0, 2.0, 1.0],
+    fastest_lap: None,
+};
+
+/// Scoring system for the 2003-2009 seasons, `10-8-6-5-4-3-2-1`.
+pub const SYSTEM_2003_2009: ScoringSystem = ScoringSystem {
+    positions: &[10.0, 8.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0],
+    fastest_lap: None,
+};
+
+/// Scoring system for the 2010-2018 seasons, and from 2025 onwards, `25-18-15-12-10-8-6-4-2-1`.
+pub const SYSTEM_2010_2018: ScoringSystem = ScoringSystem {
+    positions: &[25.0, 18.0, 15.0, 12.0, 10.0, 8.0, 6.0, 4.0, 2.0, 1.0],
+    fastest_lap: None,
+};
+
+/// Scoring system for the 2019-2024 seasons, as [`SYSTEM_2010_2018`], plus `1` point for the
+/// fastest lap if the driver finished in the top `10`.
+pub const SYSTEM_2019_2024: ScoringSystem = ScoringSystem {
+    positions: SYSTEM_2010_2018.positions,
+    fastest_lap: Some(FastestLapPoints {
+        points: 1.0,
+        max_position: Some(10),
+    }),
+};
+
+impl ScoringSystem {
+    /// Returns the points awarded for finishing in the given `position`, starting from `1`, not
+    /// including any points for the fastest lap.
+    pub fn points_for_position(&self, position: u32) -> Points {
+        position
+            .checked_sub(1)
+            .and_then(|idx| self.positions.get(idx as usize))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the points that should be awarded for a [`RaceResult`] in this scoring system.
+    ///
+    /// Only results with a [`Position::Finished`] are awarded points for their position. Points
+    /// for the fastest lap are awarded if [`FastestLap::rank`] is `Some(1)`, and the result's
+    /// position is eligible as per [`FastestLapPoints::max_position`], i.e. if it is [`None`], to
+    /// any result, including non-finishing ones, e.g. retirements in the 1950s.
+    pub fn points_for(&self, result: &RaceResult) -> Points {
+        let position = match result.position_text {
+            Position::Finished(position) => Some(position),
+            _ => None,
+        };
+
+        let fastest_lap_points = self
+            .fastest_lap
+            .filter(|_| result.fastest_lap.as_ref().and_then(|fastest_lap| fastest_lap.rank) == Some(1))
+            .filter(|fastest_lap| {
+                fastest_lap
+                    .max_position
+                    .is_none_or(|max| position.is_some_and(|position| position <= max))
+            })
+            .map_or(0.0, |fastest_lap| fastest_lap.points);
+
+        position.map_or(0.0, |position| self.points_for_position(position)) + fastest_lap_points
+    }
+
+    /// Returns the points that should be awarded for each of the `results` in this scoring system,
+    /// as per [`ScoringSystem::points_for`], in the same order as the `results`.
+    pub fn award(&self, results: &[RaceResult]) -> Vec<Points> {
+        results.iter().map(|result| self.points_for(result)).collect()
+    }
+}
+
+/// Returns the historically correct [`ScoringSystem`] for the given `season`.
+///
+/// Seasons before `1950` are not part of the F1 World Championship, and are treated as `1950`.
+pub const fn system_for_season(season: SeasonID) -> ScoringSystem {
+    match season {
+        ..=1959 => SYSTEM_1950_1959,
+        1960 => SYSTEM_1960,
+        1961..=1990 => SYSTEM_1961_1990,
+        1991..=2002 => SYSTEM_1991_2002,
+        2003..=2009 => SYSTEM_2003_2009,
+        2010..=2018 | 2025.. => SYSTEM_2010_2018,
+        2019..=2024 => SYSTEM_2019_2024,
+    }
+}
+
+/// Returns the points that should be awarded for each of the `results` from a race in the given
+/// `season`, using the [`ScoringSystem`] from [`system_for_season`].
+///
+/// This can be used to validate the [`RaceResult::points`] reported by the jolpica-f1 API.
+pub fn award_for_season(season: SeasonID, results: &[RaceResult]) -> Vec<Points> {
+    system_for_season(season).award(results)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use crate::jolpica::tests::assets::*;
+    use crate::tests::asserts::*;
+    use shadow_asserts::{assert_eq, assert_ne};
+
+    use super::*;
+
+    #[test]
+    fn system_for_season_boundaries() {
+        assert_eq!(system_for_season(1950), SYSTEM_1950_1959);
+        assert_eq!(system_for_season(1959), SYSTEM_1950_1959);
+        assert_eq!(system_for_season(1960), SYSTEM_1960);
+        assert_eq!(system_for_season(1961), SYSTEM_1961_1990);
+        assert_eq!(system_for_season(1990), SYSTEM_1961_1990);
+        assert_eq!(system_for_season(1991), SYSTEM_1991_2002);
+        assert_eq!(system_for_season(2002), SYSTEM_1991_2002);
+        assert_eq!(system_for_season(2003), SYSTEM_2003_2009);
+        assert_eq!(system_for_season(2009), SYSTEM_2003_2009);
+        assert_eq!(system_for_season(2010), SYSTEM_2010_2018);
+        assert_eq!(system_for_season(2018), SYSTEM_2010_2018);
+        assert_eq!(system_for_season(2019), SYSTEM_2019_2024);
+        assert_eq!(system_for_season(2024), SYSTEM_2019_2024);
+        assert_eq!(system_for_season(2025), SYSTEM_2010_2018);
+    }
+
+    #[test]
+    fn scoring_system_points_for_position() {
+        assert_eq!(SYSTEM_2010_2018.points_for_position(0), 0.0);
+        assert_eq!(SYSTEM_2010_2018.points_for_position(1), 25.0);
+        assert_eq!(SYSTEM_2010_2018.points_for_position(10), 1.0);
+        assert_eq!(SYSTEM_2010_2018.points_for_position(11), 0.0);
+
+        assert_eq!(SYSTEM_1961_1990.points_for_position(1), 9.0);
+        assert_eq!(SYSTEM_1961_1990.points_for_position(7), 0.0);
+    }
+
+    #[test]
+    fn scoring_system_points_for() {
+        // 1st place and fastest lap, in and out of the fastest lap point era
+        assert_eq!(SYSTEM_2019_2024.points_for(&RACE_RESULT_2020_9_P1), 26.0);
+        assert_eq!(SYSTEM_2010_2018.points_for(&RACE_RESULT_2020_9_P1), 25.0);
+
+        // Fastest lap outside of the top 10 is not eligible for the fastest lap point
+        let mut result = RACE_RESULT_2020_9_P1.clone();
+        result.position_text = Position::Finished(11);
+        assert_eq!(SYSTEM_2019_2024.points_for(&result), 0.0);
+        assert_eq!(SYSTEM_1950_1959.points_for(&result), 1.0);
+
+        // Non-finishing results are not awarded any points
+        assert_eq!(SYSTEM_2003_2009.points_for(&RACE_RESULT_2003_4_P19), 0.0);
+        assert_eq!(SYSTEM_1961_1990.points_for(&RACE_RESULT_1963_10_P23), 0.0);
+    }
+
+    #[test]
+    fn scoring_system_points_for_retired_fastest_lap() {
+        // Any driver was eligible for the fastest lap point in the 1950s, including retirements
+        let mut result = RACE_RESULT_2020_9_P1.clone();
+        result.position_text = Position::Retired;
+        assert_eq!(result.fastest_lap.as_ref().unwrap().rank, Some(1));
+
+        assert_eq!(award_for_season(1954, &[result.clone()]), vec![1.0]);
+        assert_eq!(SYSTEM_2019_2024.points_for(&result), 0.0);
+
+        result.fastest_lap = None;
+        assert_eq!(award_for_season(1954, &[result]), vec![0.0]);
+    }
+
+    #[test]
+    fn award_for_season_matches_reported_points() {
+        let results = [
+            (1950, RACE_RESULT_1950_5_P1.clone()),
+            (1998, RACE_RESULT_1998_8_P1.clone()),
+            (2003, RACE_RESULT_2003_4_P1.clone()),
+            (2003, RACE_RESULT_2003_4_P2.clone()),
+            (2003, RACE_RESULT_2003_4_P19.clone()),
+            (2020, RACE_RESULT_2020_9_P1.clone()),
+            (2023, RACE_RESULT_2023_3_P15.clone()),
+            (2023, RACE_RESULT_2023_4_P1.clone()),
+            (2023, RACE_RESULT_2023_4_P2.clone()),
+            (2023, RACE_RESULT_2023_4_P20.clone()),
+        ];
+
+        for (season, result) in results {
+            assert_eq!(award_for_season(season, &[result.clone()]), vec![result.points]);
+        }
+    }
+
+    #[test]
+    fn award_for_season_surfaces_discrepancies() {
+        // Half points were awarded for the shortened 2021 Belgian Grand Prix
+        let results = [
+            RACE_RESULT_2021_12_P1.clone(),
+            RACE_RESULT_2021_12_P2.clone(),
+            RACE_RESULT_2021_12_P3.clone(),
+            RACE_RESULT_2021_12_P10.clone(),
+        ];
+
+        let awarded = award_for_season(2021, &results);
+        assert_eq!(awarded, vec![25.0, 18.0, 15.0, 1.0]);
+
+        for (points, result) in awarded.iter().zip(&results) {
+            assert_ne!(*points, result.points);
+        }
+    }
+}
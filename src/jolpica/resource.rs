@@ -6,11 +6,17 @@
 //! and route parameters, e.g. for
 //! [race results](https://github.com/jolpica/jolpica-f1/blob/main/docs/endpoints/results.md#route-parameters).
 
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
     id::{CircuitID, ConstructorID, DriverID, RoundID, SeasonID, StatusID},
-    jolpica::{api::JOLPICA_API_PAGINATION, response::Pagination},
+    jolpica::{
+        api::JOLPICA_API_PAGINATION,
+        response::{Pagination, TableKind},
+    },
 };
 
 #[cfg(doc)]
@@ -18,14 +24,21 @@ use crate::jolpica::{
     self,
     agent::Agent,
     api,
+    concat::concat_response_seasons,
     response::{
-        Circuit, Constructor, Driver, QualifyingResult, Race, RaceResult, Response, Season, SprintResult, Status,
+        Circuit, Constructor, ConstructorStanding, Driver, DriverStanding, QualifyingResult, Race, RaceResult,
+        Response, Season, SprintResult, StandingsList, Status,
     },
+    stats,
 };
 
 /// Each variant of the [`Resource`] enumeration represents a given resource that can be requested
 /// from the jolpica-f1 API, and it contains any options/filters that can be applied to the request.
-#[derive(Clone, Debug)]
+///
+/// [`Resource`] implements [`Serialize`] and [`Deserialize`], e.g. to persist saved queries, as an
+/// object with the variant name as the only key, e.g. `{"DriverInfo": {"season": 2023, ...}}`. The
+/// variant names, and the field names of the contained filters, are stable and part of the API.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub enum Resource {
     /// Get a list of seasons currently supported by the API. Each season listed in a response is
     /// uniquely identified by the year it took place in, returned in [`Season::season`], e.g.
@@ -186,11 +199,32 @@ pub enum Resource {
     /// Can be requested via methods like [`get_pit_stops`](Agent::get_pit_stops).
     PitStops(PitStopFilters),
 
-    // These resources are not yet supported.
-    #[doc(hidden)]
-    DriverStandings,
-    #[doc(hidden)]
-    ConstructorStandings,
+    /// Get the drivers' championship standings, after the latest or a given round of a season. Each
+    /// [`StandingsList`] in a response holds the [`DriverStanding`]s for a single season, and
+    /// a driver's championship position, returned in [`DriverStanding::position`], is determined
+    /// by the points scored across all the rounds up to and including [`StandingsList::round`].
+    ///
+    /// **Note:** If [`Filters::round`] is not set, the standings are after the latest round of the
+    /// season, i.e. the final standings for a completed season.
+    ///
+    /// Directly maps to <https://api.jolpi.ca/ergast/f1/driverstandings/>
+    ///
+    /// Can be requested via methods like [`get_driver_standings`](Agent::get_driver_standings).
+    DriverStandings(Filters),
+
+    /// Get the constructors' championship standings, after the latest or a given round of a
+    /// season. Each [`StandingsList`] in a response holds the [`ConstructorStanding`]s for a
+    /// single season, and a constructor's championship position, returned in
+    /// [`ConstructorStanding::position`], is determined by the points scored across all the rounds
+    /// up to and including [`StandingsList::round`].
+    ///
+    /// **Note:** The constructors' championship was first held in the 1958 season.
+    ///
+    /// Directly maps to <https://api.jolpi.ca/ergast/f1/constructorstandings/>
+    ///
+    /// Can be requested via methods like
+    /// [`get_constructor_standings`](Agent::get_constructor_standings).
+    ConstructorStandings(Filters),
 }
 
 impl Resource {
@@ -292,6 +326,10 @@ impl Resource {
     /// `to_url_*` methods, e.g. [`to_url`][Self::to_url] and [`to_url_with`][Self::to_url_with].
     /// It is provided here to cover any edge use cases.
     ///
+    /// A [`Filters::season_range`] is formatted as e.g. `/2021..=2022`, which is useful for error
+    /// messages, but is not a valid jolpica-f1 endpoint; such a [`Resource`] must first be split
+    /// into one request per season, e.g. via [`Resource::split_season_range`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -305,6 +343,9 @@ impl Resource {
     /// });
     ///
     /// assert_eq!(request.to_endpoint(), "/constructors/ferrari/drivers/leclerc");
+    ///
+    /// let request = Resource::RaceResults(Filters::new().season_range(2021..=2022).round(1));
+    /// assert_eq!(request.to_endpoint(), "/2021..=2022/1/results");
     /// ```
     pub fn to_endpoint(&self) -> String {
         type DynFF<'a> = &'a dyn FiltersFormatter;
@@ -324,9 +365,8 @@ impl Resource {
             Self::FinishingStatus(f) => ("/status", f as DynFF<'_>),
             Self::LapTimes(f) => ("/laps", f as DynFF<'_>),
             Self::PitStops(f) => ("/pitstops", f as DynFF<'_>),
-            // @todo Temporary catch-all until all variants are supported
-            #[allow(clippy::missing_panics_doc)]
-            _ => panic!("Unsupported resource: {self:?}"),
+            Self::DriverStandings(f) => ("/driverstandings", f as DynFF<'_>),
+            Self::ConstructorStandings(f) => ("/constructorstandings", f as DynFF<'_>),
         };
 
         let mut filters = filters.to_formatted_pairs();
@@ -352,6 +392,139 @@ impl Resource {
                 acc
             })
     }
+
+    /// Returns the [`TableKind`] that a [`Response`] for this [`Resource`] is expected to contain,
+    /// e.g. [`TableKind::Drivers`] for [`Resource::DriverInfo`], and [`TableKind::Races`] for all
+    /// session results, laps, and pit stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::{resource::{Filters, Resource}, response::TableKind};
+    /// #
+    /// assert_eq!(Resource::DriverInfo(Filters::none()).table_kind(), TableKind::Drivers);
+    /// assert_eq!(Resource::RaceResults(Filters::none()).table_kind(), TableKind::Races);
+    /// ```
+    pub const fn table_kind(&self) -> TableKind {
+        match self {
+            Self::SeasonList(_) => TableKind::Seasons,
+            Self::DriverInfo(_) => TableKind::Drivers,
+            Self::ConstructorInfo(_) => TableKind::Constructors,
+            Self::CircuitInfo(_) => TableKind::Circuits,
+            Self::RaceSchedule(_)
+            | Self::QualifyingResults(_)
+            | Self::SprintResults(_)
+            | Self::RaceResults(_)
+            | Self::LapTimes(_)
+            | Self::PitStops(_) => TableKind::Races,
+            Self::FinishingStatus(_) => TableKind::Status,
+            Self::DriverStandings(_) | Self::ConstructorStandings(_) => TableKind::StandingsLists,
+        }
+    }
+
+    /// Returns the season that this [`Resource`] is filtered by, if any, e.g. [`Filters::season`]
+    /// or [`LapTimeFilters::season`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::resource::{Filters, LapTimeFilters, Resource};
+    /// #
+    /// assert_eq!(Resource::SeasonList(Filters::none()).season(), None);
+    /// assert_eq!(Resource::RaceResults(Filters::new().season(2023)).season(), Some(2023));
+    /// assert_eq!(Resource::LapTimes(LapTimeFilters::new(2023, 4)).season(), Some(2023));
+    /// ```
+    pub const fn season(&self) -> Option<SeasonID> {
+        match self {
+            Self::SeasonList(filters)
+            | Self::DriverInfo(filters)
+            | Self::ConstructorInfo(filters)
+            | Self::CircuitInfo(filters)
+            | Self::RaceSchedule(filters)
+            | Self::QualifyingResults(filters)
+            | Self::SprintResults(filters)
+            | Self::RaceResults(filters)
+            | Self::FinishingStatus(filters)
+            | Self::DriverStandings(filters)
+            | Self::ConstructorStandings(filters) => filters.season,
+            Self::LapTimes(filters) => Some(filters.season),
+            Self::PitStops(filters) => Some(filters.season),
+        }
+    }
+
+    /// Returns the range of seasons that this [`Resource`] is filtered by, if any, i.e.
+    /// [`Filters::season_range`]. [`LapTimeFilters`] and [`PitStopFilters`] do not support ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::resource::{Filters, LapTimeFilters, Resource};
+    /// #
+    /// let resource = Resource::DriverInfo(Filters::new().season_range(2010..=2015));
+    /// assert_eq!(resource.season_range(), Some(&(2010..=2015)));
+    ///
+    /// assert_eq!(Resource::DriverInfo(Filters::new().season(2023)).season_range(), None);
+    /// assert_eq!(Resource::LapTimes(LapTimeFilters::new(2023, 4)).season_range(), None);
+    /// ```
+    pub const fn season_range(&self) -> Option<&RangeInclusive<SeasonID>> {
+        match self {
+            Self::SeasonList(filters)
+            | Self::DriverInfo(filters)
+            | Self::ConstructorInfo(filters)
+            | Self::CircuitInfo(filters)
+            | Self::RaceSchedule(filters)
+            | Self::QualifyingResults(filters)
+            | Self::SprintResults(filters)
+            | Self::RaceResults(filters)
+            | Self::FinishingStatus(filters)
+            | Self::DriverStandings(filters)
+            | Self::ConstructorStandings(filters) => filters.season_range.as_ref(),
+            Self::LapTimes(_) | Self::PitStops(_) => None,
+        }
+    }
+
+    /// Splits a [`Resource`] with a [`Filters::season_range`] into one [`Resource`] of the same
+    /// variant per season in the range, via [`Filters::split_season_range`]. If the [`Resource`]
+    /// has no [`Filters::season_range`], a clone of `self` is returned as the only element.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Filters::split_season_range`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::resource::{Filters, Resource};
+    /// #
+    /// let resources = Resource::DriverInfo(Filters::new().season_range(2022..=2023)).split_season_range();
+    /// assert_eq!(
+    ///     resources,
+    ///     [
+    ///         Resource::DriverInfo(Filters::new().season(2022)),
+    ///         Resource::DriverInfo(Filters::new().season(2023))
+    ///     ]
+    /// );
+    /// ```
+    pub fn split_season_range(&self) -> Vec<Self> {
+        let split = |filters: &Filters, variant: fn(Filters) -> Self| {
+            filters.clone().split_season_range().into_iter().map(variant).collect()
+        };
+
+        match self {
+            Self::SeasonList(filters) => split(filters, Self::SeasonList),
+            Self::DriverInfo(filters) => split(filters, Self::DriverInfo),
+            Self::ConstructorInfo(filters) => split(filters, Self::ConstructorInfo),
+            Self::CircuitInfo(filters) => split(filters, Self::CircuitInfo),
+            Self::RaceSchedule(filters) => split(filters, Self::RaceSchedule),
+            Self::QualifyingResults(filters) => split(filters, Self::QualifyingResults),
+            Self::SprintResults(filters) => split(filters, Self::SprintResults),
+            Self::RaceResults(filters) => split(filters, Self::RaceResults),
+            Self::FinishingStatus(filters) => split(filters, Self::FinishingStatus),
+            Self::DriverStandings(filters) => split(filters, Self::DriverStandings),
+            Self::ConstructorStandings(filters) => split(filters, Self::ConstructorStandings),
+            Self::LapTimes(_) | Self::PitStops(_) => vec![self.clone()],
+        }
+    }
 }
 
 /// Trait that all filter structs for [`Resource`]s must implement, used to format resource URLs
@@ -374,6 +547,11 @@ trait FiltersFormatter {
 ///    3. _Struct update syntax_, setting relevant fields and filling the rest with above methods
 ///    4. Field-update methods, which can be chained, and each of which overrides a single field
 ///
+/// [`Filters`] implements [`Serialize`] and [`Deserialize`], e.g. to persist saved queries, with
+/// the same field names as this struct, e.g. `{"season": 2023, "driver_id": "leclerc"}`. Unset
+/// fields are serialized as `null`, and missing fields are deserialized as `None`. The field names
+/// are stable and part of the API.
+///
 /// # Examples
 ///
 /// ```
@@ -382,6 +560,7 @@ trait FiltersFormatter {
 ///
 /// let filters = Filters {
 ///     season: Some(2023),
+///     season_range: None,
 ///     round: Some(1),
 ///     driver_id: Some(DriverID::from("alonso")),
 ///     constructor_id: Some(ConstructorID::from("aston_martin")),
@@ -415,13 +594,29 @@ trait FiltersFormatter {
 /// assert!(filters.driver_id.is_none() /* ... */);
 /// ```
 #[must_use]
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct Filters {
     /// Restrict responses to a given championship season, identified by the year it took place in,
     /// e.g. `2023` for the _2023 Formula One World Championship_. See [`Resource::SeasonList`] to
     /// get a list of seasons currently supported by the API.
     pub season: Option<SeasonID>,
 
+    /// Restrict responses to a contiguous, inclusive range of championship seasons, e.g.
+    /// `2010..=2015`. This is not supported by the jolpica-f1 API directly, so the [`Resource`] is
+    /// split into one request per season via [`Resource::split_season_range`], e.g. by
+    /// [`Agent::get_response`], and the [`Response`]s are merged via [`concat_response_seasons`].
+    ///
+    /// **Note:** If [`Filters::round`] is also set, it applies to each of the seasons in the range.
+    ///
+    /// # Panics
+    ///
+    /// [`Filters::season_range`] and [`Filters::season`] cannot both be set. A [`Resource`] with
+    /// this field set cannot be directly requested via a single URL, e.g. via [`Resource::to_url`];
+    /// it must first be split via [`Resource::split_season_range`]. [`Agent`] methods that issue a
+    /// single request, e.g. [`Agent::get_response_page`], return
+    /// [`Error::UnsupportedSeasonRange`](crate::error::Error::UnsupportedSeasonRange) instead.
+    pub season_range: Option<RangeInclusive<SeasonID>>,
+
     /// Restrict responses to a specific race, identified by the round index starting from `1`, in a
     /// specific season. See [`Resource::RaceSchedule`] to get a list of rounds for a given season.
     ///
@@ -470,6 +665,7 @@ pub struct Filters {
     /// Restrict responses to those in which a race result with a specific finishing position
     /// features, e.g. drivers or constructors that have won a race, etc. This is a numeric value,
     /// even if a driver did not finish a race. See [`Resource::RaceResults`] for more information.
+    /// To filter by a range of positions, e.g. podium finishes, see [`stats::finishes_in_range`].
     pub finish_pos: Option<u32>,
 
     /// Restrict responses to those in which a given fastest lap rank, of a driver's fastest lap
@@ -490,16 +686,19 @@ impl Filters {
     pub const GRID_PIT_LANE: u32 = crate::jolpica::api::GRID_PIT_LANE;
 
     /// Returns a [`Filters`] object with all fields set to `None`, i.e. requesting no filtering.
-    /// This method is identical to [`Filters::none`]; both are provided to maximize readability.
+    /// This method is identical to [`Filters::none`] and [`Filters::default`]; all are provided to
+    /// maximize readability, and [`Default`] to support struct-update syntax and generic code.
     pub const fn new() -> Self {
         Self::none()
     }
 
     /// Returns a [`Filters`] object with all fields set to `None`, i.e. requesting no filtering.
-    /// This method is identical to [`Filters::new`]; both are provided to maximize readability.
+    /// This method is identical to [`Filters::new`] and [`Filters::default`]; all are provided to
+    /// maximize readability, and [`Default`] to support struct-update syntax and generic code.
     pub const fn none() -> Self {
         Self {
             season: None,
+            season_range: None,
             round: None,
             driver_id: None,
             constructor_id: None,
@@ -521,6 +720,14 @@ impl Filters {
         }
     }
 
+    /// Field-update method for the [`season_range`][field@Filters::season_range] field.
+    pub fn season_range(self, seasons: RangeInclusive<SeasonID>) -> Self {
+        Self {
+            season_range: Some(seasons),
+            ..self
+        }
+    }
+
     /// Field-update method for the [`round`][field@Filters::round] field.
     pub fn round(self, round: RoundID) -> Self {
         Self {
@@ -600,6 +807,155 @@ impl Filters {
             ..self
         }
     }
+
+    /// Field-removal method for the [`season`][field@Filters::season] field, i.e. sets it to
+    /// `None`.
+    ///
+    /// **Note:** [`Filters::round`] requires [`Filters::season`] to be set, so it should usually be
+    /// removed as well, e.g. via [`without_round`](Self::without_round).
+    pub fn without_season(self) -> Self {
+        Self { season: None, ..self }
+    }
+
+    /// Field-removal method for the [`season_range`][field@Filters::season_range] field, i.e. sets
+    /// it to `None`.
+    pub fn without_season_range(self) -> Self {
+        Self {
+            season_range: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`round`][field@Filters::round] field, i.e. sets it to `None`.
+    pub fn without_round(self) -> Self {
+        Self { round: None, ..self }
+    }
+
+    /// Field-removal method for the [`driver_id`][field@Filters::driver_id] field, i.e. sets it to
+    /// `None`.
+    pub fn without_driver_id(self) -> Self {
+        Self {
+            driver_id: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`constructor_id`][field@Filters::constructor_id] field, i.e.
+    /// sets it to `None`.
+    pub fn without_constructor_id(self) -> Self {
+        Self {
+            constructor_id: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`circuit_id`][field@Filters::circuit_id] field, i.e. sets it
+    /// to `None`.
+    pub fn without_circuit_id(self) -> Self {
+        Self {
+            circuit_id: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`qualifying_pos`][field@Filters::qualifying_pos] field, i.e.
+    /// sets it to `None`.
+    pub fn without_qualifying_pos(self) -> Self {
+        Self {
+            qualifying_pos: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`grid_pos`][field@Filters::grid_pos] field, i.e. sets it to
+    /// `None`.
+    pub fn without_grid_pos(self) -> Self {
+        Self { grid_pos: None, ..self }
+    }
+
+    /// Field-removal method for the [`sprint_pos`][field@Filters::sprint_pos] field, i.e. sets it
+    /// to `None`.
+    pub fn without_sprint_pos(self) -> Self {
+        Self {
+            sprint_pos: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`finish_pos`][field@Filters::finish_pos] field, i.e. sets it
+    /// to `None`.
+    pub fn without_finish_pos(self) -> Self {
+        Self {
+            finish_pos: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`fastest_lap_rank`][field@Filters::fastest_lap_rank] field,
+    /// i.e. sets it to `None`.
+    pub fn without_fastest_lap_rank(self) -> Self {
+        Self {
+            fastest_lap_rank: None,
+            ..self
+        }
+    }
+
+    /// Field-removal method for the [`finishing_status`][field@Filters::finishing_status] field,
+    /// i.e. sets it to `None`.
+    pub fn without_finishing_status(self) -> Self {
+        Self {
+            finishing_status: None,
+            ..self
+        }
+    }
+
+    /// Splits [`Filters`] with a [`Filters::season_range`] into one [`Filters`] per season in the
+    /// range, in ascending order, each with [`Filters::season`] set instead, and all other fields
+    /// left as-is. If [`Filters::season_range`] is not set, `self` is returned as the only element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both [`Filters::season_range`] and [`Filters::season`] are set, or if the start of
+    /// the range is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::resource::Filters;
+    /// #
+    /// let filters = Filters::new().season_range(2021..=2023).round(1).split_season_range();
+    /// assert_eq!(
+    ///     filters,
+    ///     [
+    ///         Filters::new().season(2021).round(1),
+    ///         Filters::new().season(2022).round(1),
+    ///         Filters::new().season(2023).round(1)
+    ///     ]
+    /// );
+    ///
+    /// assert_eq!(Filters::new().season(2023).split_season_range(), [Filters::new().season(2023)]);
+    /// ```
+    pub fn split_season_range(self) -> Vec<Self> {
+        let Some(seasons) = self.season_range.clone() else {
+            return vec![self];
+        };
+
+        assert!(self.season.is_none(), "Filters::season and Filters::season_range cannot both be set");
+        assert!(
+            seasons.start() <= seasons.end(),
+            "Invalid season range, start: {} > end: {}",
+            seasons.start(),
+            seasons.end()
+        );
+
+        seasons
+            .map(|season| Self {
+                season: Some(season),
+                season_range: None,
+                ..self.clone()
+            })
+            .collect()
+    }
 }
 
 impl Default for Filters {
@@ -617,6 +973,9 @@ impl Default for Filters {
 /// signature of the [`new`](LapTimeFilters::new`) methods, which has parameters for the required
 /// fields in this type.
 ///
+/// This includes [`Serialize`] and [`Deserialize`], except that the required fields, e.g.
+/// `season` and `round`, must be present when deserializing.
+///
 /// # Examples
 ///
 /// ```
@@ -650,7 +1009,7 @@ impl Default for Filters {
 /// /* ... */
 /// ```
 #[must_use]
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct LapTimeFilters {
     /// Indicates a specific championship season, identified by the year it took place in.
     /// This is a required field, along with [`LapTimeFilters::round`], to uniquely identify a race.
@@ -703,6 +1062,9 @@ impl LapTimeFilters {
 /// signature of the [`new`](PitStopFilters::new`) method, which has parameters for the required
 /// fields in this type.
 ///
+/// This includes [`Serialize`] and [`Deserialize`], except that the required fields, e.g.
+/// `season` and `round`, must be present when deserializing.
+///
 /// # Examples
 ///
 /// ```
@@ -737,7 +1099,7 @@ impl LapTimeFilters {
 /// /* ... */
 /// ```
 #[must_use]
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct PitStopFilters {
     /// Indicates a specific championship season, identified by the year it took place in.
     /// This is a required field, along with [`PitStopFilters::round`], to uniquely identify a race.
@@ -803,11 +1165,18 @@ fn fmt_from_opt<T: std::fmt::Display>(field: &Option<T>) -> String {
 
 impl FiltersFormatter for Filters {
     fn to_formatted_pairs(&self) -> Vec<(&'static str, String)> {
-        // .round cannot be set without .season
-        assert!(!(self.round.is_some() && self.season.is_none()));
+        // .round cannot be set without .season, or .season_range
+        assert!(!(self.round.is_some() && self.season.is_none() && self.season_range.is_none()));
+
+        // .season_range cannot be represented in a URL, it must be split into separate requests,
+        // but it is still formatted, e.g. as "/2021..=2022", for use in error messages.
+        let season = self
+            .season_range
+            .as_ref()
+            .map_or_else(|| fmt_from_opt(&self.season), |range| format!("/{}..={}", range.start(), range.end()));
 
         Vec::from([
-            ("", fmt_from_opt(&self.season)),
+            ("", season),
             ("", fmt_from_opt(&self.round)),
             ("/drivers", fmt_from_opt(&self.driver_id)),
             ("/constructors", fmt_from_opt(&self.constructor_id)),
@@ -1001,6 +1370,11 @@ mod tests {
             url("/drivers/leclerc/seasons.json")
         );
 
+        assert_eq!(
+            Resource::SeasonList(Filters::new().constructor_id("williams".into())).to_url(),
+            url("/constructors/williams/seasons.json")
+        );
+
         assert_eq!(
             Resource::DriverInfo(Filters {
                 constructor_id: Some("ferrari".into()),
@@ -1028,6 +1402,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resource_to_url_composite_filters() {
+        assert_eq!(
+            Resource::RaceResults(Filters::new().season(2023).driver_id("leclerc".into()).grid_pos(1)).to_url(),
+            url("/2023/drivers/leclerc/grid/1/results.json")
+        );
+
+        assert_eq!(
+            Resource::RaceResults(Filters::new().season(2023).round(4).finish_pos(1).fastest_lap_rank(1)).to_url(),
+            url("/2023/4/fastest/1/results/1.json")
+        );
+
+        assert_eq!(
+            Resource::RaceResults(
+                Filters::new()
+                    .constructor_id("ferrari".into())
+                    .grid_pos(Filters::GRID_PIT_LANE)
+                    .finishing_status(1)
+            )
+            .to_url(),
+            url("/constructors/ferrari/grid/0/status/1/results.json")
+        );
+
+        assert_eq!(
+            Resource::DriverInfo(
+                Filters::new()
+                    .season(2021)
+                    .circuit_id("monaco".into())
+                    .grid_pos(1)
+                    .finish_pos(1)
+                    .fastest_lap_rank(1)
+                    .finishing_status(1)
+            )
+            .to_url(),
+            url("/2021/circuits/monaco/grid/1/results/1/fastest/1/status/1/drivers.json")
+        );
+
+        assert_eq!(
+            Resource::FinishingStatus(
+                Filters::new()
+                    .season(2022)
+                    .constructor_id("ferrari".into())
+                    .finishing_status(1)
+            )
+            .to_url(),
+            url("/2022/constructors/ferrari/status/1.json")
+        );
+
+        assert_eq!(
+            Resource::SprintResults(Filters::new().season(2023).driver_id("leclerc".into()).sprint_pos(1)).to_url(),
+            url("/2023/drivers/leclerc/sprint/1.json")
+        );
+
+        assert_eq!(
+            Resource::QualifyingResults(Filters::new().season(2023).grid_pos(1).qualifying_pos(2)).to_url(),
+            url("/2023/grid/1/qualifying/2.json")
+        );
+    }
+
     #[test]
     fn resource_to_url_season_round_filters() {
         assert_eq!(
@@ -1121,6 +1554,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resource_standings_to_url() {
+        assert_eq!(
+            Resource::DriverStandings(Filters::new().season(2021).round(22)).to_url(),
+            url("/2021/22/driverstandings.json")
+        );
+        assert_eq!(
+            Resource::DriverStandings(Filters::new().driver_id("hamilton".into())).to_url(),
+            url("/drivers/hamilton/driverstandings.json")
+        );
+        assert_eq!(
+            Resource::ConstructorStandings(Filters::new().season(2021)).to_url(),
+            url("/2021/constructorstandings.json")
+        );
+    }
+
     #[test]
     fn resource_to_url_with_base_and_opt_page() {
         assert_eq!(
@@ -1149,11 +1598,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resource_table_kind() {
+        assert_eq!(Resource::SeasonList(Filters::none()).table_kind(), TableKind::Seasons);
+        assert_eq!(Resource::DriverInfo(Filters::none()).table_kind(), TableKind::Drivers);
+        assert_eq!(Resource::ConstructorInfo(Filters::none()).table_kind(), TableKind::Constructors);
+        assert_eq!(Resource::CircuitInfo(Filters::none()).table_kind(), TableKind::Circuits);
+        assert_eq!(Resource::RaceSchedule(Filters::none()).table_kind(), TableKind::Races);
+        assert_eq!(Resource::QualifyingResults(Filters::none()).table_kind(), TableKind::Races);
+        assert_eq!(Resource::SprintResults(Filters::none()).table_kind(), TableKind::Races);
+        assert_eq!(Resource::RaceResults(Filters::none()).table_kind(), TableKind::Races);
+        assert_eq!(Resource::FinishingStatus(Filters::none()).table_kind(), TableKind::Status);
+        assert_eq!(Resource::LapTimes(LapTimeFilters::new(2023, 4)).table_kind(), TableKind::Races);
+        assert_eq!(Resource::PitStops(PitStopFilters::new(2023, 4)).table_kind(), TableKind::Races);
+        assert_eq!(Resource::DriverStandings(Filters::none()).table_kind(), TableKind::StandingsLists);
+        assert_eq!(Resource::ConstructorStandings(Filters::none()).table_kind(), TableKind::StandingsLists);
+    }
+
+    #[test]
+    fn resource_season() {
+        assert_eq!(Resource::SeasonList(Filters::none()).season(), None);
+        assert_eq!(Resource::DriverInfo(Filters::new().driver_id("leclerc".into())).season(), None);
+        assert_eq!(Resource::RaceResults(Filters::new().season(2023)).season(), Some(2023));
+        assert_eq!(Resource::DriverStandings(Filters::new().season(2021).round(22)).season(), Some(2021));
+        assert_eq!(Resource::LapTimes(LapTimeFilters::new(2023, 4)).season(), Some(2023));
+        assert_eq!(Resource::PitStops(PitStopFilters::new(2023, 4)).season(), Some(2023));
+    }
+
+    #[test]
+    fn resource_season_range() {
+        assert_eq!(Resource::SeasonList(Filters::none()).season_range(), None);
+        assert_eq!(Resource::RaceResults(Filters::new().season(2023)).season_range(), None);
+        assert_eq!(Resource::DriverInfo(Filters::new().season_range(2010..=2015)).season_range(), Some(&(2010..=2015)));
+        assert_eq!(Resource::LapTimes(LapTimeFilters::new(2023, 4)).season_range(), None);
+        assert_eq!(Resource::PitStops(PitStopFilters::new(2023, 4)).season_range(), None);
+    }
+
+    #[test]
+    fn resource_split_season_range() {
+        assert_eq!(
+            Resource::RaceResults(Filters::new().season_range(2021..=2023).finish_pos(1)).split_season_range(),
+            [
+                Resource::RaceResults(Filters::new().season(2021).finish_pos(1)),
+                Resource::RaceResults(Filters::new().season(2022).finish_pos(1)),
+                Resource::RaceResults(Filters::new().season(2023).finish_pos(1)),
+            ]
+        );
+
+        assert_eq!(
+            Resource::DriverStandings(Filters::new().season_range(2023..=2023)).split_season_range(),
+            [Resource::DriverStandings(Filters::new().season(2023))]
+        );
+
+        for resource in [
+            Resource::SeasonList(Filters::none()),
+            Resource::DriverInfo(Filters::new().season(2023)),
+            Resource::LapTimes(LapTimeFilters::new(2023, 4)),
+            Resource::PitStops(PitStopFilters::new(2023, 4)),
+        ] {
+            assert_eq!(resource.split_season_range(), [resource.clone()]);
+        }
+    }
+
+    #[test]
+    fn filters_split_season_range() {
+        assert_eq!(
+            Filters::new().season_range(2010..=2012).split_season_range(),
+            [
+                Filters::new().season(2010),
+                Filters::new().season(2011),
+                Filters::new().season(2012)
+            ]
+        );
+
+        assert_eq!(
+            Filters::new()
+                .season_range(2022..=2023)
+                .round(1)
+                .driver_id("alonso".into())
+                .split_season_range(),
+            [
+                Filters::new().season(2022).round(1).driver_id("alonso".into()),
+                Filters::new().season(2023).round(1).driver_id("alonso".into())
+            ]
+        );
+
+        assert_eq!(Filters::none().split_season_range(), [Filters::none()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn filters_split_season_range_with_season_panics() {
+        let _unused = Filters::new()
+            .season(2023)
+            .season_range(2010..=2015)
+            .split_season_range();
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn filters_split_season_range_invalid_range_panics() {
+        let _unused = Filters::new().season_range(2015..=2010).split_season_range();
+    }
+
+    #[test]
+    fn resource_to_endpoint_season_range() {
+        assert_eq!(
+            Resource::DriverInfo(Filters::new().season_range(2010..=2015)).to_endpoint(),
+            "/2010..=2015/drivers"
+        );
+        assert_eq!(
+            Resource::RaceResults(Filters::new().season_range(2021..=2022).round(99)).to_endpoint(),
+            "/2021..=2022/99/results"
+        );
+    }
+
     #[test]
     fn filters() {
         let filters = Filters::none();
         assert_true!(
             filters.season.is_none()
+                && filters.season_range.is_none()
                 && filters.round.is_none()
                 && filters.driver_id.is_none()
                 && filters.constructor_id.is_none()
@@ -1205,6 +1771,7 @@ mod tests {
         assert_eq!(
             Filters {
                 season: Some(2023),
+                season_range: None,
                 round: Some(1),
                 driver_id: Some("alonso".into()),
                 constructor_id: Some("aston_martin".into()),
@@ -1231,6 +1798,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filters_default() {
+        assert_eq!(Filters::default(), Filters::none());
+        assert_eq!(Filters::default(), Filters::new());
+
+        let filters = Filters {
+            season: Some(2023),
+            ..Default::default()
+        };
+        assert_eq!(filters, Filters::none().season(2023));
+    }
+
+    #[test]
+    fn filters_without() {
+        let filters = Filters::new()
+            .season(2023)
+            .round(1)
+            .driver_id("alonso".into())
+            .constructor_id("aston_martin".into())
+            .circuit_id("baku".into())
+            .qualifying_pos(6)
+            .grid_pos(6)
+            .sprint_pos(1)
+            .finish_pos(4)
+            .fastest_lap_rank(3)
+            .finishing_status(1);
+
+        assert_eq!(
+            filters
+                .clone()
+                .without_season()
+                .without_season_range()
+                .without_round()
+                .without_driver_id()
+                .without_constructor_id()
+                .without_circuit_id()
+                .without_qualifying_pos()
+                .without_grid_pos()
+                .without_sprint_pos()
+                .without_finish_pos()
+                .without_fastest_lap_rank()
+                .without_finishing_status(),
+            Filters::none()
+        );
+
+        assert_eq!(
+            filters.clone().without_driver_id(),
+            Filters {
+                driver_id: None,
+                ..filters.clone()
+            }
+        );
+
+        // Removing unset fields has no effect
+        assert_eq!(Filters::none().without_season().without_driver_id(), Filters::none());
+
+        let base = Filters::new().season(2023).round(1).driver_id("leclerc".into());
+
+        assert_eq!(Resource::RaceResults(base.clone()).to_url(), url("/2023/1/drivers/leclerc/results.json"));
+        assert_eq!(
+            Resource::RaceResults(base.clone().without_round()).to_url(),
+            url("/2023/drivers/leclerc/results.json")
+        );
+        assert_eq!(
+            Resource::RaceResults(base.without_season().without_round()).to_url(),
+            url("/drivers/leclerc/results.json")
+        );
+    }
+
     #[test]
     fn lap_time_filters() {
         let filters = LapTimeFilters::new(2023, 4);
@@ -1289,6 +1925,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filters_serde() {
+        let filters = Filters::new()
+            .season(2023)
+            .driver_id("leclerc".into())
+            .qualifying_pos(1);
+        let json = serde_json::to_string(&filters).unwrap();
+        assert_true!(json.contains(r#""season":2023"#) && json.contains(r#""driver_id":"leclerc""#));
+        assert_eq!(serde_json::from_str::<Filters>(&json).unwrap(), filters);
+
+        // Missing fields are deserialized as `None`
+        assert_eq!(
+            serde_json::from_str::<Filters>(r#"{"season": 2023, "driver_id": "leclerc", "qualifying_pos": 1}"#)
+                .unwrap(),
+            filters
+        );
+        assert_eq!(serde_json::from_str::<Filters>("{}").unwrap(), Filters::none());
+
+        let filters = Filters::new().season_range(2010..=2015);
+        assert_eq!(serde_json::from_str::<Filters>(&serde_json::to_string(&filters).unwrap()).unwrap(), filters);
+
+        let filters = LapTimeFilters::new(2023, 4).lap(1);
+        assert_eq!(serde_json::from_str::<LapTimeFilters>(&serde_json::to_string(&filters).unwrap()).unwrap(), filters);
+        assert_eq!(
+            serde_json::from_str::<LapTimeFilters>(r#"{"season": 2023, "round": 4, "lap": 1}"#).unwrap(),
+            filters
+        );
+        assert_true!(serde_json::from_str::<LapTimeFilters>(r#"{"season": 2023}"#).is_err());
+
+        let filters = PitStopFilters::new(2023, 4).pit_stop(1);
+        assert_eq!(serde_json::from_str::<PitStopFilters>(&serde_json::to_string(&filters).unwrap()).unwrap(), filters);
+        assert_true!(serde_json::from_str::<PitStopFilters>(r#"{"round": 4}"#).is_err());
+    }
+
+    #[test]
+    fn resource_serde() {
+        let resources = [
+            Resource::SeasonList(Filters::none()),
+            Resource::QualifyingResults(Filters::new().driver_id("leclerc".into()).qualifying_pos(1)),
+            Resource::LapTimes(LapTimeFilters::new(2023, 4).lap(1)),
+            Resource::PitStops(PitStopFilters::new(2023, 4).driver_id("alonso".into())),
+        ];
+
+        for resource in resources {
+            let json = serde_json::to_string(&resource).unwrap();
+            let deserialized = serde_json::from_str::<Resource>(&json).unwrap();
+            assert_eq!(deserialized.to_url(), resource.to_url());
+            assert_eq!(deserialized, resource);
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Resource>(r#"{"DriverInfo": {"season": 2023}}"#).unwrap(),
+            Resource::DriverInfo(Filters::new().season(2023))
+        );
+    }
+
     #[test]
     fn filters_to_formatted_pairs_lifetime() {
         let &mut formatted_pairs;
@@ -5,6 +5,37 @@
 //! endpoints and returned [structures from the API](https://api.jolpi.ca/ergast/). There are also
 //! a variety of convenience methods for extracting specific data and performing certain validation,
 //! as well as more ergonomic types that can be returned when requesting specific resources.
+//!
+//! # Lenient parsing
+//!
+//! Numeric values are represented as strings in the JSON responses, e.g. `"25"` for
+//! [`RaceResult::points`], and by default a value that fails to parse as a number, e.g. a blank
+//! `""`, fails the deserialization of the entire [`Response`]. For large multi-page requests, a
+//! single malformed record would cause all of the data to be discarded.
+//!
+//! If the `lenient-parsing` feature is enabled, numeric values that fail to parse are instead set to
+//! a default, i.e. `0` for required fields, e.g. [`RaceResult::grid`], or [`None`] for optional
+//! fields, e.g. [`Driver::permanent_number`], and a warning is logged via the [`log`] crate. The
+//! tradeoff is that malformed data is silently replaced by plausible-looking values, e.g. a grid
+//! position of `0`, which downstream processing cannot distinguish from real data. As such, this is
+//! recommended only when partial data is preferable to none, and the logged warnings are monitored.
+//!
+//! Fields that identify the data or are needed to process the response, i.e. [`Pagination`],
+//! [`Race::season`], [`Race::round`], [`Season::season`], [`Status::status_id`], and
+//! [`Lap::number`], are always parsed strictly, regardless of the feature.
+//!
+//! # Strict parsing
+//!
+//! By default, fields in the JSON responses that are not modeled by these types are ignored. If the
+//! `strict-parse` feature is enabled, they instead fail the deserialization, with an error naming
+//! the unknown field. This is intended as a development aid for keeping the types in sync with
+//! additions to the jolpica-f1 API, and is not recommended otherwise.
+//!
+//! Types with flattened fields, i.e. [`Response`] and the [`Race`] fields alongside a
+//! [`Payload`] other than [`Payload::Schedule`], cannot reject unknown fields this way, so are
+//! always parsed leniently with respect to unknown fields.
+//!
+//! [`log`]: https://docs.rs/log
 
 // @todo After Rust 1.92, there is an `unused_assignments` lint warning being triggered by the
 // inner fields of [`Table`] variants, e.g. [`Table::Seasons::seasons`]. I don't understand why
@@ -12,25 +43,31 @@
 // is not silencing the warning. For now, silencing it at the smallest scope that works.
 #![allow(unused_assignments)]
 
-use std::convert::Infallible;
+use std::{convert::Infallible, fmt::Display, str::FromStr};
 
 use enum_as_inner::EnumAsInner;
 use ordered_float::OrderedFloat;
 use serde::{Deserialize, Deserializer, de::DeserializeOwned};
-use serde_with::{DisplayFromStr, serde_as};
+use serde_with::{DeserializeAs, DisplayFromStr, serde_as};
 use url::Url;
 
 use crate::{
     error::{Error, Result},
     id::{CircuitID, ConstructorID, DriverID, RaceID, RoundID, SeasonID, StatusID},
+    jolpica::api::{country_code, nationality_country_code},
+    jolpica::flat::{FlatRow, flatten_table},
+    jolpica::resource::Resource,
     jolpica::time::{
         Date, DateTime, Duration, QualifyingTime, RaceTime, Time, deserialize_buggy_race_time, deserialize_duration,
-        deserialize_optional_time, deserialize_time,
+        deserialize_optional_sectors, deserialize_optional_time, deserialize_time, duration_from_secs_f64,
     },
 };
 
 #[cfg(doc)]
-use crate::jolpica::resource::{Filters, Resource};
+use crate::jolpica::{
+    agent::{Agent, AgentConfigs},
+    resource::Filters,
+};
 
 /// Represents a full JSON response from the jolpica-f1 API.
 ///
@@ -52,27 +89,92 @@ pub struct Response {
     pub table: Table,
 }
 
+/// Holds all the fields of a [`Response`] except for [`Response::pagination`] and
+/// [`Response::table`], i.e. the response's metadata, as returned by [`Response::to_info`].
+///
+/// This allows comparing [`Response`]s' metadata for equality while ignoring pagination and table
+/// data, e.g. to verify that multiple pages belong to the same response.
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub struct ResponseInfo {
+    /// See [`Response::xmlns`].
+    pub xmlns: String,
+    /// See [`Response::series`].
+    pub series: String,
+    /// See [`Response::url`].
+    pub url: Url,
+}
+
+impl From<&Response> for ResponseInfo {
+    fn from(response: &Response) -> Self {
+        let (xmlns, series, url) = response.as_info();
+
+        Self {
+            xmlns: xmlns.clone(),
+            series: series.clone(),
+            url: url.clone(),
+        }
+    }
+}
+
 impl Response {
     /// Returns a tuple with references to all the fields of this [`Response`] except for the
     /// [`pagination`](Self::pagination) and [`table`](Self::table) fields, to allow comparing the
     /// [`Response`]s' metadata for equality while ignoring pagination and table data.
-    //
-    // @todo If a new field is added to [`Response`], and this impl isn't updated accordingly, then
-    // comparisons will silently fail - unit tests won't catch it. I haven't figured out a way to
-    // solve this without adding generic parameters, inefficient cloning and discarding, etc.
     pub const fn as_info(&self) -> (&String, &String, &Url) {
-        (&self.xmlns, &self.series, &self.url)
+        // Exhaustive destructuring, so that adding a field to [`Response`] fails to compile until
+        // the new field is accounted for here, and thus in [`ResponseInfo`].
+        let Self {
+            xmlns,
+            series,
+            url,
+            pagination: _,
+            table: _,
+        } = self;
+
+        (xmlns, series, url)
     }
 
-    /// Returns a tuple with all the fields of this [`Response`] except for the
+    /// Returns a [`ResponseInfo`] with all the fields of this [`Response`] except for the
     /// [`pagination`](Self::pagination) and [`table`](Self::table) fields, to allow comparing the
     /// [`Response`]s' metadata for equality while ignoring pagination and table data. This method
     /// is more inefficient than [`as_info()`](Self::as_info) as it clones all of the fields.
-    /// It should only be used when `as_info()`would be too inconvenient due to lifetime, etc.
-    //
-    // @todo See the comment in [`as_info()`](Self::as_info).
-    pub fn to_info(&self) -> (String, String, Url) {
-        (self.xmlns.clone(), self.series.clone(), self.url.clone())
+    pub fn to_info(&self) -> ResponseInfo {
+        ResponseInfo::from(self)
+    }
+
+    /// Verifies that the [`Response::table`] variant corresponds to the requested `resource`, as
+    /// per [`Resource::table_kind`], e.g. that a [`Resource::DriverInfo`] yielded a
+    /// [`Table::Drivers`].
+    ///
+    /// This catches upstream inconsistencies early, rather than at extraction time, which may be
+    /// useful when pointing an [`Agent`] at alternate or experimental servers. It can be performed
+    /// automatically for every response via [`AgentConfigs::verify_table_variant`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::BadTableVariant`] with the expected and actual [`TableKind`]s if the
+    /// contained [`Table`] variant does not correspond to the `resource`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::{Filters, Resource}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let resource = Resource::DriverInfo(Filters::new().season(2023));
+    /// let resp = jolpica.get_response(&resource).unwrap();
+    ///
+    /// assert!(resp.verify_matches(&resource).is_ok());
+    /// assert!(resp.verify_matches(&Resource::SeasonList(Filters::none())).is_err());
+    /// ```
+    pub fn verify_matches(&self, resource: &Resource) -> Result<()> {
+        let (expected, actual) = (resource.table_kind(), self.table.kind());
+
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(Error::BadTableVariant { expected, actual })
+        }
     }
 
     // TableInnerLists
@@ -338,7 +440,9 @@ impl Response {
     /// Returns an [`Error::BadTableVariant`] if the contained [`Table`] variant is not
     /// [`Table::Races`], or an [`Error::BadPayloadVariant`] if the contained [`Payload`] variant is
     /// not the variant corresponding to the <code>T = [PayloadInnerList]</code>, e.g. if it's not
-    /// [`Payload::RaceResults`] when <code>T = [RaceResult]</code>.
+    /// [`Payload::RaceResults`] when <code>T = [RaceResult]</code>. If the [`Payload`] is instead
+    /// an empty schedule, as per [`Payload::is_empty_schedule`], an [`Error::UnexpectedData`] is
+    /// returned, explaining that the response likely contained an empty result set.
     ///
     /// # Examples
     ///
@@ -380,7 +484,7 @@ impl Response {
     pub fn into_many_races_with_many_session_results<T: PayloadInnerList>(self) -> Result<Vec<Race<Vec<T>>>> {
         self.into_races()?
             .into_iter()
-            .map(|race| race.try_map(|payload| T::try_into_inner_from(payload)))
+            .map(|race| race.try_map(try_into_session_results::<T>))
             .collect()
     }
 
@@ -596,6 +700,38 @@ impl Response {
             .collect()
     }
 
+    /// Extracts an inner list of [`Lap`]s, each with a list of [`Timing`]s for all drivers, from
+    /// the single expected [`Race`] from the [`Table::Races`] variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::BadTableVariant`] if the contained [`Table`] variant is not
+    /// [`Table::Races`], or an [`Error::BadPayloadVariant`] if the contained [`Payload`] variant is
+    /// not [`Payload::Laps`]. An [`Error::NotFound`] or [`Error::TooMany`] if there isn't exactly
+    /// one [`Race`] in the response.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::{Resource, LapTimeFilters}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let resp = jolpica.get_response(&Resource::LapTimes(LapTimeFilters::new(2023, 4))).unwrap();
+    ///
+    /// let laps = resp.into_laps().unwrap();
+    ///
+    /// assert_eq!(laps.len(), 51);
+    /// assert_eq!(laps[0].number, 1);
+    /// assert_eq!(laps[0].timings.len(), 20);
+    /// ```
+    pub fn into_laps(self) -> Result<Vec<Lap>> {
+        Ok(self)
+            .and_then(verify_has_one_race_and_extract)?
+            .payload
+            .into_laps()
+            .map_err(into)
+    }
+
     /// Extracts an expected single [`Lap`], from an expected single [`Race`] from the
     /// [`Table::Races`] variant, and extracts the [`Lap`]'s inner list of [`Timing`]s.
     ///
@@ -684,6 +820,42 @@ impl Response {
             .map_err(into)
     }
 
+    /// Extracts all of the elements of the [`Response::table`] as a list of flattened [`FlatRow`]s,
+    /// e.g. for exporting to a table or CSV, regardless of the [`Resource`] that was requested.
+    ///
+    /// Each [`FlatRow`] is a flat struct holding only scalar fields, e.g. a [`RaceResult`] is
+    /// flattened into a [`FlatRow::RaceResult`] with its [`Driver`] and [`Constructor`] fields
+    /// inlined, along with the identifying fields of its [`Race`]. Each [`Race`] with a
+    /// [`Payload::Schedule`] is flattened into a single [`FlatRow::Race`], and each [`Lap`] into
+    /// one [`FlatRow::Timing`] per [`Timing`]. See the [`flat`](crate::jolpica::flat) module.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::UnexpectedData`] is returned if the [`Table::Races`] contains [`Race`]s with
+    /// different [`PayloadKind`]s, i.e. if the resulting rows would not be of a single kind, or if
+    /// the [`Table`] is a [`Table::StandingsLists`], which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::id::{ConstructorID, DriverID};
+    /// # use f1_data::jolpica::{agent::Agent, flat::FlatRow, resource::{Filters, Resource}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let rows = jolpica
+    ///     .get_response(&Resource::RaceResults(Filters::new().season(2023).round(4)))
+    ///     .unwrap()
+    ///     .into_flat_rows()
+    ///     .unwrap();
+    ///
+    /// let FlatRow::RaceResult(row) = &rows[0] else { panic!("Expected race result") };
+    /// assert_eq!(row.driver_id, DriverID::from("perez"));
+    /// assert_eq!(row.constructor_id, ConstructorID::from("red_bull"));
+    /// ```
+    pub fn into_flat_rows(self) -> Result<Vec<FlatRow>> {
+        flatten_table(self.table)
+    }
+
     // Convenience aliases for into/as_table_list(s)::<T> and into/as_single_table_list_element::<T>
     // Aliases for TableInnerList's: Season, Driver, Constructor, Circuit, Status, Race<Payload>
     // ---------------------------------------------------------------------------------------------
@@ -971,6 +1143,121 @@ pub enum Table {
         #[serde(rename = "Status")]
         status: Vec<Status>,
     },
+    /// Contains a list of [`StandingsList`]s, and corresponds to the `"StandingsTable"` property
+    /// key in the JSON response from the jolpica-f1 API.
+    #[serde(rename = "StandingsTable")]
+    StandingsLists {
+        /// List of [`StandingsList`]s, corresponding to the `"StandingsLists"` property key in the
+        /// JSON response.
+        #[serde(rename = "StandingsLists")]
+        standings_lists: Vec<StandingsList>,
+    },
+}
+
+impl Table {
+    /// Returns the [`TableKind`] of this [`Table`], i.e. which variant it is, without having to
+    /// match on, or extract, its inner value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::response::{Table, TableKind};
+    /// #
+    /// assert_eq!(Table::Seasons { seasons: vec![] }.kind(), TableKind::Seasons);
+    /// assert_eq!(Table::Races { races: vec![] }.kind(), TableKind::Races);
+    /// ```
+    pub const fn kind(&self) -> TableKind {
+        match self {
+            Self::Seasons { .. } => TableKind::Seasons,
+            Self::Drivers { .. } => TableKind::Drivers,
+            Self::Constructors { .. } => TableKind::Constructors,
+            Self::Circuits { .. } => TableKind::Circuits,
+            Self::Races { .. } => TableKind::Races,
+            Self::Status { .. } => TableKind::Status,
+            Self::StandingsLists { .. } => TableKind::StandingsLists,
+        }
+    }
+
+    /// Creates a [`Table::Seasons`] from any iterator of [`Season`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::response::{Table, TableKind};
+    /// #
+    /// let table = Table::from_seasons([]);
+    /// assert_eq!(table.kind(), TableKind::Seasons);
+    /// assert!(table.as_seasons().unwrap().is_empty());
+    /// ```
+    pub fn from_seasons(seasons: impl IntoIterator<Item = Season>) -> Self {
+        Self::Seasons {
+            seasons: seasons.into_iter().collect(),
+        }
+    }
+
+    /// Creates a [`Table::Drivers`] from any iterator of [`Driver`]s.
+    pub fn from_drivers(drivers: impl IntoIterator<Item = Driver>) -> Self {
+        Self::Drivers {
+            drivers: drivers.into_iter().collect(),
+        }
+    }
+
+    /// Creates a [`Table::Constructors`] from any iterator of [`Constructor`]s.
+    pub fn from_constructors(constructors: impl IntoIterator<Item = Constructor>) -> Self {
+        Self::Constructors {
+            constructors: constructors.into_iter().collect(),
+        }
+    }
+
+    /// Creates a [`Table::Circuits`] from any iterator of [`Circuit`]s.
+    pub fn from_circuits(circuits: impl IntoIterator<Item = Circuit>) -> Self {
+        Self::Circuits {
+            circuits: circuits.into_iter().collect(),
+        }
+    }
+
+    /// Creates a [`Table::Races`] from any iterator of [`Race`]s.
+    pub fn from_races(races: impl IntoIterator<Item = Race>) -> Self {
+        Self::Races {
+            races: races.into_iter().collect(),
+        }
+    }
+
+    /// Creates a [`Table::Status`] from any iterator of [`Status`]es.
+    pub fn from_status(status: impl IntoIterator<Item = Status>) -> Self {
+        Self::Status {
+            status: status.into_iter().collect(),
+        }
+    }
+
+    /// Creates a [`Table::StandingsLists`] from any iterator of [`StandingsList`]s.
+    pub fn from_standings_lists(standings_lists: impl IntoIterator<Item = StandingsList>) -> Self {
+        Self::StandingsLists {
+            standings_lists: standings_lists.into_iter().collect(),
+        }
+    }
+}
+
+/// Represents the kind of a [`Table`], i.e. a tag for each of its variants, without their data.
+///
+/// This is returned by [`Table::kind`] and [`Resource::table_kind`], and is used to report the
+/// expected and actual variants in an [`Error::BadTableVariant`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum TableKind {
+    /// Corresponds to [`Table::Seasons`].
+    Seasons,
+    /// Corresponds to [`Table::Drivers`].
+    Drivers,
+    /// Corresponds to [`Table::Constructors`].
+    Constructors,
+    /// Corresponds to [`Table::Circuits`].
+    Circuits,
+    /// Corresponds to [`Table::Races`].
+    Races,
+    /// Corresponds to [`Table::Status`].
+    Status,
+    /// Corresponds to [`Table::StandingsLists`].
+    StandingsLists,
 }
 
 /// Inner list type of a [`Table`] variant for a [`TableInnerList`] type, and of a [`Payload`]
@@ -986,7 +1273,7 @@ type InnerList<T> = Vec<T>;
 /// [`Table::Seasons`] variant, via  [`T::try_into_inner_from()`](Self::try_into_inner_from).
 ///
 /// The trait is implemented for [`Season`], [`Driver`], [`Constructor`], [`Circuit`], [`Status`],
-/// and [`Race<Payload>`].
+/// [`Race<Payload>`], and [`StandingsList<Standings>`].
 pub trait TableInnerList
 where
     Self: Sized,
@@ -1007,6 +1294,7 @@ where
 /// Requested via [`Resource::SeasonList`] and returned in [`Table::Seasons`].
 #[serde_as]
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Season {
     /// Unique identifier for the season, i.e. the year in which it took place, e.g. `2024` for the
     /// _2024 Formula One World Championship_.
@@ -1019,11 +1307,14 @@ pub struct Season {
 
 impl TableInnerList for Season {
     fn try_into_inner_from(table: Table) -> Result<InnerList<Self>> {
-        table.into_seasons().map_err(into)
+        table.into_seasons().map_err(bad_table_variant(TableKind::Seasons))
     }
 
     fn try_as_inner_from(table: &Table) -> Result<&InnerList<Self>> {
-        table.as_seasons().ok_or(Error::BadTableVariant)
+        table.as_seasons().ok_or_else(|| Error::BadTableVariant {
+            expected: TableKind::Seasons,
+            actual: table.kind(),
+        })
     }
 }
 
@@ -1033,6 +1324,7 @@ impl TableInnerList for Season {
 #[serde_as]
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Driver {
     /// Unique identifier for the driver, e.g. `"max_verstappen"` for _Max Verstappen_.
     pub driver_id: DriverID,
@@ -1046,7 +1338,8 @@ pub struct Driver {
     /// the driver to run the number. Most notably, Max Verstappen has used the number `1` since
     /// 2022, following his titles in 2021, 2022, 2023, and 2024. For more information, see the
     /// [List of Formula One driver numbers](https://en.wikipedia.org/wiki/List_of_Formula_One_driver_numbers)
-    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde_as(as = "OptionNumberFromStr")]
+    #[serde(default)]
     pub permanent_number: Option<u32>,
     /// Three-letter code associated with the driver, if any, e.g. `"VER"` for _Max Verstappen_.
     // @todo Add more information about three-letter codes; there are exceptions and special cases.
@@ -1076,15 +1369,140 @@ impl Driver {
     pub fn full_name(&self) -> String {
         format!("{} {}", self.given_name, self.family_name)
     }
+
+    /// Returns the age of this [`Driver`] on a given `date`, in whole years, e.g. on a race's
+    /// [`Race::date`]. Returns `None` if [`Driver::date_of_birth`] is missing, or if `date` is
+    /// before it.
+    ///
+    /// For drivers born on February 29th, the birthday in non-leap years is taken to be March 1st,
+    /// i.e. they are a year older from March 1st, not February 28th.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::{DriverID, RaceID}, jolpica::agent::Agent};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let driver = jolpica.get_driver(DriverID::from("max_verstappen")).unwrap();
+    /// let race = jolpica.get_race_schedule(RaceID::from(2016, 5)).unwrap(); // His first win
+    /// assert_eq!(driver.age_on(race.date), Some(18));
+    /// ```
+    pub fn age_on(&self, date: Date) -> Option<u32> {
+        let date_of_birth = self.date_of_birth?;
+
+        if date < date_of_birth {
+            return None;
+        }
+
+        let had_birthday = (date.month(), date.day()) >= (date_of_birth.month(), date_of_birth.day());
+        let years = date.year() - date_of_birth.year() - i32::from(!had_birthday);
+
+        u32::try_from(years).ok()
+    }
+
+    /// Returns the age of this [`Driver`] on a given `date`, in whole days, e.g. on a race's
+    /// [`Race::date`]. Returns `None` if [`Driver::date_of_birth`] is missing, or if `date` is
+    /// before it.
+    pub fn age_on_days(&self, date: Date) -> Option<u32> {
+        let date_of_birth = self.date_of_birth?;
+
+        u32::try_from((date - date_of_birth).whole_days()).ok()
+    }
+
+    /// Returns this [`Driver`]'s [`permanent_number`](Self::permanent_number) as a [`CarNumber`],
+    /// distinguishing the reigning champion's number `1` from other permanent numbers, or `None`
+    /// if the driver doesn't have a permanent number.
+    pub fn car_number(&self) -> Option<CarNumber> {
+        self.permanent_number.map(CarNumber::from)
+    }
+
+    /// Returns `true` if this [`Driver`]'s [`permanent_number`](Self::permanent_number) is the
+    /// number `1`, reserved for the previous season's World Drivers' Champion, i.e.
+    /// [`CarNumber::Champion`].
+    pub const fn is_reigning_champion_number(&self) -> bool {
+        matches!(self.permanent_number, Some(CarNumber::CHAMPION_NUMBER))
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 country code for this [`Driver`]'s
+    /// [`nationality`](Self::nationality), e.g. `"NL"` for `"Dutch"`, as per
+    /// [`nationality_country_code`], or `None` if it is missing or not known.
+    pub fn country_code(&self) -> Option<&'static str> {
+        self.nationality.as_deref().and_then(nationality_country_code)
+    }
+}
+
+/// Represents a car number in Formula 1, distinguishing the number `1`, which is reserved for the
+/// previous season's World Drivers' Champion, from any other, i.e. permanent, number.
+///
+/// A [`CarNumber`] can be created from any `u32` via [`From`], e.g. from a
+/// [`Driver::permanent_number`] or a [`RaceResult::car_number`], the latter of which can be used to
+/// tell when a driver ran the number `1` instead of their permanent number, e.g. _Max Verstappen_
+/// running `1` instead of `33`.
+///
+/// # Examples
+///
+/// ```
+/// # use f1_data::jolpica::response::CarNumber;
+/// #
+/// assert_eq!(CarNumber::from(1), CarNumber::Champion);
+/// assert_eq!(CarNumber::from(33), CarNumber::Permanent(33));
+/// assert_eq!(CarNumber::Champion.number(), 1);
+/// assert_eq!(CarNumber::Permanent(33).to_string(), "33");
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum CarNumber {
+    /// The number `1`, reserved for the previous season's World Drivers' Champion.
+    Champion,
+    /// Any number other than `1`, e.g. a driver's permanent number.
+    Permanent(u32),
+}
+
+impl CarNumber {
+    /// The number reserved for the previous season's World Drivers' Champion, i.e. `1`.
+    pub const CHAMPION_NUMBER: u32 = 1;
+
+    /// Returns the underlying number of this [`CarNumber`], i.e. [`CarNumber::CHAMPION_NUMBER`] for
+    /// [`CarNumber::Champion`], or the contained number for [`CarNumber::Permanent`].
+    pub const fn number(self) -> u32 {
+        match self {
+            Self::Champion => Self::CHAMPION_NUMBER,
+            Self::Permanent(number) => number,
+        }
+    }
+
+    /// Returns `true` if this is [`CarNumber::Champion`].
+    pub const fn is_champion(self) -> bool {
+        matches!(self, Self::Champion)
+    }
+}
+
+impl From<u32> for CarNumber {
+    fn from(number: u32) -> Self {
+        if number == Self::CHAMPION_NUMBER {
+            Self::Champion
+        } else {
+            Self::Permanent(number)
+        }
+    }
+}
+
+impl Display for CarNumber {
+    /// Formats the [`CarNumber`] as its underlying number, e.g. `"1"` for [`CarNumber::Champion`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
 }
 
 impl TableInnerList for Driver {
     fn try_into_inner_from(table: Table) -> Result<InnerList<Self>> {
-        table.into_drivers().map_err(into)
+        table.into_drivers().map_err(bad_table_variant(TableKind::Drivers))
     }
 
     fn try_as_inner_from(table: &Table) -> Result<&InnerList<Self>> {
-        table.as_drivers().ok_or(Error::BadTableVariant)
+        table.as_drivers().ok_or_else(|| Error::BadTableVariant {
+            expected: TableKind::Drivers,
+            actual: table.kind(),
+        })
     }
 }
 
@@ -1093,6 +1511,7 @@ impl TableInnerList for Driver {
 /// Requested via [`Resource::ConstructorInfo`] and returned in [`Table::Constructors`].
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Constructor {
     /// Unique identifier for the constructor, e.g. `"red_bull"` for _Red Bull Racing_.
     pub constructor_id: ConstructorID,
@@ -1105,13 +1524,27 @@ pub struct Constructor {
     pub nationality: String,
 }
 
+impl Constructor {
+    /// Returns the ISO 3166-1 alpha-2 country code for this [`Constructor`]'s
+    /// [`nationality`](Self::nationality), e.g. `"AT"` for `"Austrian"`, as per
+    /// [`nationality_country_code`], or `None` if it is not known.
+    pub fn country_code(&self) -> Option<&'static str> {
+        nationality_country_code(&self.nationality)
+    }
+}
+
 impl TableInnerList for Constructor {
     fn try_into_inner_from(table: Table) -> Result<InnerList<Self>> {
-        table.into_constructors().map_err(into)
+        table
+            .into_constructors()
+            .map_err(bad_table_variant(TableKind::Constructors))
     }
 
     fn try_as_inner_from(table: &Table) -> Result<&InnerList<Self>> {
-        table.as_constructors().ok_or(Error::BadTableVariant)
+        table.as_constructors().ok_or_else(|| Error::BadTableVariant {
+            expected: TableKind::Constructors,
+            actual: table.kind(),
+        })
     }
 }
 
@@ -1120,6 +1553,7 @@ impl TableInnerList for Constructor {
 /// Requested via [`Resource::CircuitInfo`] and returned in [`Table::Circuits`].
 #[derive(Deserialize, Hash, Eq, PartialEq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Circuit {
     /// Unique identifier for the circuit, e.g. `"spa"` for the _Circuit de Spa-Francorchamps_.
     pub circuit_id: CircuitID,
@@ -1136,11 +1570,14 @@ pub struct Circuit {
 
 impl TableInnerList for Circuit {
     fn try_into_inner_from(table: Table) -> Result<InnerList<Self>> {
-        table.into_circuits().map_err(into)
+        table.into_circuits().map_err(bad_table_variant(TableKind::Circuits))
     }
 
     fn try_as_inner_from(table: &Table) -> Result<&InnerList<Self>> {
-        table.as_circuits().ok_or(Error::BadTableVariant)
+        table.as_circuits().ok_or_else(|| Error::BadTableVariant {
+            expected: TableKind::Circuits,
+            actual: table.kind(),
+        })
     }
 }
 
@@ -1150,12 +1587,16 @@ impl TableInnerList for Circuit {
 #[serde_as]
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Status {
     /// Unique numerical identifier for the status, e.g. `1` for "Finished".
     #[serde_as(as = "DisplayFromStr")]
     pub status_id: StatusID,
-    /// Number of occurrences of this status in a given time period, e.g. during a season.
-    #[serde_as(as = "DisplayFromStr")]
+    /// Number of occurrences of this status in the scope of the request's [`Filters`], e.g. during
+    /// a season if filtered by [`Filters::season`], or during a driver's career if filtered by
+    /// [`Filters::driver_id`]. As such, it varies between requests, and is not a fixed property of
+    /// the status.
+    #[serde_as(as = "NumberFromStr")]
     pub count: u32,
     /// Description of the status, e.g. `"Finished"`.
     pub status: String,
@@ -1163,11 +1604,14 @@ pub struct Status {
 
 impl TableInnerList for Status {
     fn try_into_inner_from(table: Table) -> Result<InnerList<Self>> {
-        table.into_status().map_err(into)
+        table.into_status().map_err(bad_table_variant(TableKind::Status))
     }
 
     fn try_as_inner_from(table: &Table) -> Result<&InnerList<Self>> {
-        table.as_status().ok_or(Error::BadTableVariant)
+        table.as_status().ok_or_else(|| Error::BadTableVariant {
+            expected: TableKind::Status,
+            actual: table.kind(),
+        })
     }
 }
 
@@ -1220,6 +1664,56 @@ pub struct Race<T = Payload> {
     pub payload: T,
 }
 
+/// Holds all the fields of a [`Race`] except for [`Race::payload`], i.e. the information about a
+/// race weekend event, as returned by [`Race::to_info`].
+///
+/// This allows comparing [`Race`]s for equality while ignoring their payloads, or using the event
+/// information as a key in a map, e.g. to group [`Race`]s with different payloads.
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub struct RaceInfo {
+    /// See [`Race::season`].
+    pub season: SeasonID,
+    /// See [`Race::round`].
+    pub round: RoundID,
+    /// See [`Race::url`].
+    pub url: Url,
+    /// See [`Race::race_name`].
+    pub race_name: String,
+    /// See [`Race::circuit`].
+    pub circuit: Circuit,
+    /// See [`Race::date`].
+    pub date: Date,
+    /// See [`Race::time`].
+    pub time: Option<Time>,
+}
+
+impl<T> From<&Race<T>> for RaceInfo {
+    fn from(race: &Race<T>) -> Self {
+        // Exhaustive destructuring, so that adding a field to [`Race`] fails to compile until the
+        // new field is accounted for here, and thus in comparisons via [`Race::to_info`].
+        let Race {
+            season,
+            round,
+            url,
+            race_name,
+            circuit,
+            date,
+            time,
+            payload: _,
+        } = race;
+
+        Self {
+            season: *season,
+            round: *round,
+            url: url.clone(),
+            race_name: race_name.clone(),
+            circuit: circuit.clone(),
+            date: *date,
+            time: *time,
+        }
+    }
+}
+
 impl<T> Race<T> {
     /// Returns the [`RaceID`] for this [`Race`], composed of its [`season`](Self::season) and
     /// [`round`](Self::round).
@@ -1232,30 +1726,29 @@ impl<T> Race<T> {
 
     /// Returns a tuple with references to all the fields of this [`Race`] except for the `payload`
     /// field, to allow comparing [`Race`]s for equality while ignoring [`payload`](Self::payload).
-    //
-    // @todo If a new field is added to [`Race`], and this impl isn't updated accordingly, then
-    // comparisons will silently fail - unit tests won't catch it. I haven't figured out a way to
-    // solve this without a lot of inefficient cloning to discard payload and compare [`Race<Void>`]
     pub const fn as_info(&self) -> (&SeasonID, &RoundID, &Url, &String, &Circuit, &Date, &Option<Time>) {
-        (&self.season, &self.round, &self.url, &self.race_name, &self.circuit, &self.date, &self.time)
-    }
-
-    /// Returns a tuple with all the fields of this [`Race`] except for the `payload` field, to
-    /// allow comparing [`Race`]s for equality while ignoring [`payload`](Self::payload). This
-    /// method is more inefficient than [`as_info()`](Self::as_info) as it clones all of the fields.
-    /// It should only be used when `as_info()`would be too inconvenient due to lifetime, etc.
-    //
-    // @todo See the comment in [`as_info()`](Self::as_info).
-    pub fn to_info(&self) -> (SeasonID, RoundID, Url, String, Circuit, Date, Option<Time>) {
-        (
-            self.season,
-            self.round,
-            self.url.clone(),
-            self.race_name.clone(),
-            self.circuit.clone(),
-            self.date,
-            self.time,
-        )
+        // Exhaustive destructuring, so that adding a field to [`Race`] fails to compile until the
+        // new field is accounted for here; see [`RaceInfo::from`].
+        let Self {
+            season,
+            round,
+            url,
+            race_name,
+            circuit,
+            date,
+            time,
+            payload: _,
+        } = self;
+
+        (season, round, url, race_name, circuit, date, time)
+    }
+
+    /// Returns a [`RaceInfo`] with all the fields of this [`Race`] except for the `payload` field,
+    /// to allow comparing [`Race`]s for equality while ignoring [`payload`](Self::payload), or to
+    /// use as a map key. This method is more inefficient than [`as_info()`](Self::as_info) as it
+    /// clones all of the fields. It should be used when `as_info()` would be too inconvenient.
+    pub fn to_info(&self) -> RaceInfo {
+        RaceInfo::from(self)
     }
 
     /// Maps a [`Race<T>`] to a [`Result<Race<U>, E>`] by applying a type `T` -> `U` conversion
@@ -1295,13 +1788,67 @@ impl<T> Race<T> {
     }
 }
 
+/// Collapses [`Race`]s that share the same [`Race::id`], i.e. `(season, round)`, merging their
+/// payload lists, e.g. when combining the results of multiple overlapping filtered requests.
+///
+/// The first occurrence of each race is kept, in its original position, and the payload elements
+/// of any later duplicates are appended to it, skipping elements that are already present. This
+/// prevents the same result from being double-counted, e.g. when race results for a driver are
+/// requested both by [`Filters::driver_id`] and by [`Filters::constructor_id`].
+///
+/// # Examples
+///
+/// ```
+/// # use f1_data::jolpica::response::{Race, dedupe_races_by_id};
+/// # let race: Race<Vec<u32>> = serde_json::from_str(r#"{
+/// #   "season": "2023", "round": "4", "url": "https://en.wikipedia.org/wiki/2023",
+/// #   "raceName": "Azerbaijan Grand Prix", "date": "2023-04-30",
+/// #   "Circuit": {"circuitId": "baku", "url": "https://en.wikipedia.org/wiki/Baku",
+/// #     "circuitName": "Baku City Circuit", "Location": {
+/// #       "lat": "40.3725", "long": "49.8533", "locality": "Baku", "country": "Azerbaijan"}}
+/// # }"#).map(|race: Race<()>| Race::from(race, vec![])).unwrap();
+/// let races = vec![
+///     Race::from(race.clone(), vec![1, 2]),
+///     Race::from(race.clone(), vec![2, 3]),
+/// ];
+///
+/// let races = dedupe_races_by_id(races);
+/// assert_eq!(races.len(), 1);
+/// assert_eq!(races[0].payload, vec![1, 2, 3]);
+/// ```
+pub fn dedupe_races_by_id<T: PartialEq>(races: Vec<Race<Vec<T>>>) -> Vec<Race<Vec<T>>> {
+    let mut indexed_races: indexmap::IndexMap<RaceID, Race<Vec<T>>> = indexmap::IndexMap::new();
+
+    for race in races {
+        match indexed_races.entry(race.id()) {
+            indexmap::map::Entry::Occupied(mut entry) => {
+                let payload = &mut entry.get_mut().payload;
+
+                for element in race.payload {
+                    if !payload.contains(&element) {
+                        payload.push(element);
+                    }
+                }
+            }
+            indexmap::map::Entry::Vacant(entry) => {
+                let _unused = entry.insert(race);
+            }
+        }
+    }
+
+    indexed_races.into_values().collect()
+}
+
 impl TableInnerList for Race<Payload> {
     fn try_into_inner_from(table: Table) -> Result<InnerList<Self>> {
-        table.into_races().map_err(into)
+        table.into_races().map_err(bad_table_variant(TableKind::Races))
     }
 
     fn try_as_inner_from(table: &Table) -> Result<&InnerList<Self>> {
-        table.as_races().ok_or(Error::BadTableVariant)
+        table.as_races().ok_or_else(|| Error::BadTableVariant {
+            expected: TableKind::Races,
+            actual: table.kind(),
+        })
     }
 }
 
@@ -1309,6 +1856,7 @@ impl TableInnerList for Race<Payload> {
 ///
 /// Requested via [`Resource::RaceSchedule`] and returned in [`Payload::Schedule`].
 #[derive(Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Schedule {
     /// Date and time of the first free-practice session, if any.
     #[serde(rename = "FirstPractice")]
@@ -1339,6 +1887,97 @@ pub struct Schedule {
     pub sprint_qualifying: Option<DateTime>,
 }
 
+impl Schedule {
+    /// Returns `true` if this is a sprint weekend, i.e. if [`Schedule::sprint`], or either of
+    /// [`Schedule::sprint_shootout`] or [`Schedule::sprint_qualifying`], as the sprint's dedicated
+    /// qualifying session was named in 2023 and 2024 onwards, respectively, is present.
+    pub const fn has_sprint(&self) -> bool {
+        self.sprint.is_some() || self.sprint_shootout.is_some() || self.sprint_qualifying.is_some()
+    }
+
+    /// Returns an iterator over the scheduled sessions, i.e. those whose [`DateTime`] is present,
+    /// paired with their [`SessionKind`] and in chronological order, e.g. to render a timetable.
+    ///
+    /// Both [`Schedule::sprint_shootout`] and [`Schedule::sprint_qualifying`] are yielded as
+    /// [`SessionKind::SprintQualifying`], with the latter taking precedence if both are present.
+    /// Sessions without a time are ordered before those with one on the same date, and otherwise
+    /// keep the order of the fields. Note that the race itself is not part of the [`Schedule`], but
+    /// is instead given by [`Race::date`] and [`Race::time`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::response::{Schedule, SessionKind};
+    /// #
+    /// let schedule: Schedule = serde_json::from_str(
+    ///     r#"{
+    ///     "FirstPractice": {"date": "2023-04-28", "time": "09:30:00Z"},
+    ///     "Qualifying": {"date": "2023-04-28", "time": "13:00:00Z"},
+    ///     "Sprint": {"date": "2023-04-29", "time": "13:30:00Z"},
+    ///     "SprintShootout": {"date": "2023-04-29", "time": "09:30:00Z"}
+    /// }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let kinds: Vec<_> = schedule.sessions().map(|(kind, _)| kind).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     vec![
+    ///         SessionKind::FirstPractice,
+    ///         SessionKind::Qualifying,
+    ///         SessionKind::SprintQualifying,
+    ///         SessionKind::Sprint
+    ///     ]
+    /// );
+    /// ```
+    pub fn sessions(&self) -> impl Iterator<Item = (SessionKind, DateTime)> {
+        // Exhaustive destructuring, so that adding a field to [`Schedule`] fails to compile until
+        // the new field is accounted for here.
+        let Self {
+            first_practice,
+            second_practice,
+            third_practice,
+            qualifying,
+            sprint,
+            sprint_shootout,
+            sprint_qualifying,
+        } = *self;
+
+        let mut sessions: Vec<_> = [
+            (SessionKind::FirstPractice, first_practice),
+            (SessionKind::SecondPractice, second_practice),
+            (SessionKind::ThirdPractice, third_practice),
+            (SessionKind::Qualifying, qualifying),
+            (SessionKind::SprintQualifying, sprint_qualifying.or(sprint_shootout)),
+            (SessionKind::Sprint, sprint),
+        ]
+        .into_iter()
+        .filter_map(|(kind, date_time)| date_time.map(|date_time| (kind, date_time)))
+        .collect();
+
+        sessions.sort_by_key(|(_, date_time)| (date_time.date, date_time.time));
+        sessions.into_iter()
+    }
+}
+
+/// Represents the kind of a session in a [`Schedule`], as yielded by [`Schedule::sessions`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum SessionKind {
+    /// Corresponds to [`Schedule::first_practice`].
+    FirstPractice,
+    /// Corresponds to [`Schedule::second_practice`].
+    SecondPractice,
+    /// Corresponds to [`Schedule::third_practice`].
+    ThirdPractice,
+    /// Corresponds to [`Schedule::qualifying`].
+    Qualifying,
+    /// Corresponds to [`Schedule::sprint_qualifying`], or [`Schedule::sprint_shootout`], which
+    /// is what the sprint qualifying session was called when it was introduced in 2023.
+    SprintQualifying,
+    /// Corresponds to [`Schedule::sprint`].
+    Sprint,
+}
+
 impl Race<Schedule> {
     /// Returns a reference to the field [`Race::payload`], a [`Schedule`].
     pub const fn schedule(&self) -> &Schedule {
@@ -1452,44 +2091,167 @@ impl<'de> Deserialize<'de> for Payload {
     }
 }
 
-/// This trait allows the generic extraction of the inner list types of all [`Payload`] variants.
-///
-/// For example, [`RaceResult`]s can be extracted from a [`Race`]'s [`Race::payload`], from the
-/// [`Payload::RaceResults`] variant, via  [`T::try_into_inner_from()`](Self::try_into_inner_from).
-///
-/// The trait is implemented for [`QualifyingResult`], [`SprintResult`], and [`RaceResult`].
-pub trait PayloadInnerList
-where
-    Self: Sized,
-{
-    /// Extract the inner value from the corresponding [`Payload`] variant for this
-    /// [`PayloadInnerList`], e.g. a [`Vec<RaceResult>`] from the [`Payload::RaceResults`] variant.
-    fn try_into_inner_from(payload: Payload) -> Result<InnerList<Self>>;
-}
+impl Payload {
+    /// Returns the [`PayloadKind`] of this [`Payload`], i.e. which variant it is, without having
+    /// to match on, or extract, its inner value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::response::{Payload, PayloadKind};
+    /// #
+    /// assert_eq!(Payload::Laps(vec![]).kind(), PayloadKind::Laps);
+    /// assert_eq!(Payload::RaceResults(vec![]).kind(), PayloadKind::RaceResults);
+    /// ```
+    pub const fn kind(&self) -> PayloadKind {
+        match self {
+            Self::QualifyingResults(_) => PayloadKind::QualifyingResults,
+            Self::SprintResults(_) => PayloadKind::SprintResults,
+            Self::RaceResults(_) => PayloadKind::RaceResults,
+            Self::Laps(_) => PayloadKind::Laps,
+            Self::PitStops(_) => PayloadKind::PitStops,
+            Self::Schedule(_) => PayloadKind::Schedule,
+        }
+    }
 
-/// Holds information about a driver's qualifying result in a Formula 1 qualifying session.
-///
-/// Requested via [`Resource::QualifyingResults`] and returned in [`Payload::QualifyingResults`].
-///
-/// Qualifying session formats have changed over the years, and have not always included the
-/// three-stage knockout format (Q1, Q2, Q3) that has been in use since 2006. As such, the `q1-3`
-/// fields are populated in that order based on the number of stages that were held in the session.
-/// For example, in 2000 there was only a single qualifying session, so only `q1` is populated. In
-/// 2005 there were two sessions, so `q1` and `q2` are populated. From 2006 onwards all three
-/// sessions are held, so all three fields are populated. If a driver was eliminated in an
-/// earlier session, the later session fields are not populated, represented by `None`. Qualifying
-/// results are not available prior to 1994, so all three stage fields are `None` for those years.
+    /// Creates a [`Payload::QualifyingResults`] from any iterator of [`QualifyingResult`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::response::{Payload, PayloadKind};
+    /// #
+    /// let payload = Payload::from_qualifying_results([]);
+    /// assert_eq!(payload.kind(), PayloadKind::QualifyingResults);
+    /// assert!(payload.as_qualifying_results().unwrap().is_empty());
+    /// ```
+    pub fn from_qualifying_results(qualifying_results: impl IntoIterator<Item = QualifyingResult>) -> Self {
+        Self::QualifyingResults(qualifying_results.into_iter().collect())
+    }
+
+    /// Creates a [`Payload::SprintResults`] from any iterator of [`SprintResult`]s.
+    pub fn from_sprint_results(sprint_results: impl IntoIterator<Item = SprintResult>) -> Self {
+        Self::SprintResults(sprint_results.into_iter().collect())
+    }
+
+    /// Creates a [`Payload::RaceResults`] from any iterator of [`RaceResult`]s.
+    pub fn from_race_results(race_results: impl IntoIterator<Item = RaceResult>) -> Self {
+        Self::RaceResults(race_results.into_iter().collect())
+    }
+
+    /// Creates a [`Payload::Laps`] from any iterator of [`Lap`]s.
+    pub fn from_laps(laps: impl IntoIterator<Item = Lap>) -> Self {
+        Self::Laps(laps.into_iter().collect())
+    }
+
+    /// Creates a [`Payload::PitStops`] from any iterator of [`PitStop`]s.
+    pub fn from_pit_stops(pit_stops: impl IntoIterator<Item = PitStop>) -> Self {
+        Self::PitStops(pit_stops.into_iter().collect())
+    }
+
+    /// Returns `true` if this is a [`Payload::Schedule`] with all of its fields set to [`None`].
+    ///
+    /// Because [`Payload::Schedule`] is untagged, this is what a [`Race`] with no payload, or with
+    /// an unrecognized one, is deserialized into. As such, it usually indicates an empty result
+    /// set where session results were expected, rather than an actual schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::response::Payload;
+    /// #
+    /// let payload: Payload = serde_json::from_str("{}").unwrap();
+    /// assert!(payload.is_empty_schedule());
+    /// assert!(!Payload::RaceResults(vec![]).is_empty_schedule());
+    /// ```
+    pub fn is_empty_schedule(&self) -> bool {
+        // Exhaustive struct expression, so that adding a field to [`Schedule`] fails to compile
+        // until the new field is accounted for here.
+        const EMPTY: Schedule = Schedule {
+            first_practice: None,
+            second_practice: None,
+            third_practice: None,
+            qualifying: None,
+            sprint: None,
+            sprint_shootout: None,
+            sprint_qualifying: None,
+        };
+
+        matches!(self, Self::Schedule(schedule) if *schedule == EMPTY)
+    }
+}
+
+impl Race<Payload> {
+    /// Returns the [`PayloadKind`] of the field [`Race::payload`], see [`Payload::kind`].
+    pub const fn payload_kind(&self) -> PayloadKind {
+        self.payload.kind()
+    }
+}
+
+/// Represents the kind of a [`Payload`], i.e. a tag for each of its variants, without their data.
+///
+/// This is returned by [`Payload::kind`] and [`Race::payload_kind`], and can be used to branch on
+/// the content of a [`Race`] before extracting its payload, e.g. when the requested [`Resource`] is
+/// not known at compile time.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum PayloadKind {
+    /// Corresponds to [`Payload::QualifyingResults`].
+    QualifyingResults,
+    /// Corresponds to [`Payload::SprintResults`].
+    SprintResults,
+    /// Corresponds to [`Payload::RaceResults`].
+    RaceResults,
+    /// Corresponds to [`Payload::Laps`].
+    Laps,
+    /// Corresponds to [`Payload::PitStops`].
+    PitStops,
+    /// Corresponds to [`Payload::Schedule`].
+    Schedule,
+}
+
+/// This trait allows the generic extraction of the inner list types of all [`Payload`] variants.
+///
+/// For example, [`RaceResult`]s can be extracted from a [`Race`]'s [`Race::payload`], from the
+/// [`Payload::RaceResults`] variant, via  [`T::try_into_inner_from()`](Self::try_into_inner_from).
+///
+/// The trait is implemented for [`QualifyingResult`], [`SprintResult`], and [`RaceResult`].
+pub trait PayloadInnerList
+where
+    Self: Sized,
+{
+    /// The [`PayloadKind`] of the [`Payload`] variant holding this [`PayloadInnerList`], e.g.
+    /// [`PayloadKind::RaceResults`] for [`RaceResult`].
+    const KIND: PayloadKind;
+
+    /// Extract the inner value from the corresponding [`Payload`] variant for this
+    /// [`PayloadInnerList`], e.g. a [`Vec<RaceResult>`] from the [`Payload::RaceResults`] variant.
+    fn try_into_inner_from(payload: Payload) -> Result<InnerList<Self>>;
+}
+
+/// Holds information about a driver's qualifying result in a Formula 1 qualifying session.
+///
+/// Requested via [`Resource::QualifyingResults`] and returned in [`Payload::QualifyingResults`].
+///
+/// Qualifying session formats have changed over the years, and have not always included the
+/// three-stage knockout format (Q1, Q2, Q3) that has been in use since 2006. As such, the `q1-3`
+/// fields are populated in that order based on the number of stages that were held in the session.
+/// For example, in 2000 there was only a single qualifying session, so only `q1` is populated. In
+/// 2005 there were two sessions, so `q1` and `q2` are populated. From 2006 onwards all three
+/// sessions are held, so all three fields are populated. If a driver was eliminated in an
+/// earlier session, the later session fields are not populated, represented by `None`. Qualifying
+/// results are not available prior to 1994, so all three stage fields are `None` for those years.
 ///
 /// See [Formula One qualifying](https://en.wikipedia.org/wiki/Formula_One_race_weekend#Qualifying)
 /// for more details about the different qualifying formats, including sprint qualifying sessions.
 #[serde_as]
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct QualifyingResult {
     /// Driver's car number during the qualifying session.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub number: u32,
     /// Driver's qualifying position after the session, `1` being pole position.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub position: u32,
     /// The driver that this qualifying result corresponds to.
     #[serde(rename = "Driver")]
@@ -1516,6 +2278,78 @@ pub struct QualifyingResult {
     pub q3: Option<QualifyingTime>,
 }
 
+impl QualifyingResult {
+    /// Returns the number of stages that were held in a qualifying session, inferred from its list
+    /// of [`QualifyingResult`]s, i.e. the last stage, `1`, `2`, or `3`, in which any driver took part.
+    ///
+    /// Returns `0` if no driver has any qualifying times, e.g. for sessions prior to 1994.
+    pub fn stage_count(results: &[Self]) -> u8 {
+        results.iter().map(Self::last_stage).max().unwrap_or(0)
+    }
+
+    /// Returns the stage, `1`, `2`, or `3`, in which the driver was eliminated from a qualifying
+    /// session with `stage_count` stages, i.e. the last stage in which they took part.
+    ///
+    /// Returns [`None`] if the driver took part in the final stage, or if they have no qualifying
+    /// times at all. The `stage_count` accounts for the historical single- and two-stage formats,
+    /// in which a driver with only a [`QualifyingResult::q1`] was not eliminated early, and is
+    /// typically computed via [`QualifyingResult::stage_count`] over the session's results.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::Filters, response::QualifyingResult};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let race = jolpica
+    ///     .get_qualifying_results_for_event(Filters::new().season(2023).round(4))
+    ///     .unwrap();
+    /// let stage_count = QualifyingResult::stage_count(race.qualifying_results());
+    /// assert_eq!(stage_count, 3);
+    ///
+    /// let results = race.qualifying_results();
+    /// assert_eq!(results[0].eliminated_in(stage_count), None);
+    /// assert_eq!(results[10].eliminated_in(stage_count), Some(2));
+    /// assert_eq!(results[19].eliminated_in(stage_count), Some(1));
+    /// ```
+    pub fn eliminated_in(&self, stage_count: u8) -> Option<u8> {
+        let last_stage = self.last_stage();
+        (last_stage != 0 && last_stage < stage_count).then_some(last_stage)
+    }
+
+    /// Returns the driver's best, i.e. fastest, lap time across all of the stages of a qualifying
+    /// session, [`QualifyingResult::q1`], [`QualifyingResult::q2`], and [`QualifyingResult::q3`].
+    ///
+    /// Returns [`None`] if the driver did not set a lap time in any stage, or if there are no
+    /// qualifying times at all, e.g. for sessions prior to 1994.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::Filters, time::duration_m_s_ms};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let race = jolpica
+    ///     .get_qualifying_results_for_event(Filters::new().season(2023).round(4))
+    ///     .unwrap();
+    /// assert_eq!(race.qualifying_results()[0].best_time(), Some(duration_m_s_ms(1, 40, 203)));
+    /// ```
+    pub fn best_time(&self) -> Option<Duration> {
+        [&self.q1, &self.q2, &self.q3]
+            .into_iter()
+            .filter_map(|time| time.as_ref().and_then(QualifyingTime::as_duration))
+            .min()
+    }
+
+    /// Returns the last stage, `1`, `2`, or `3`, in which the driver took part, or `0` if none.
+    fn last_stage(&self) -> u8 {
+        [(3, &self.q3), (2, &self.q2), (1, &self.q1)]
+            .into_iter()
+            .find(|(_, time)| time.is_some())
+            .map_or(0, |(stage, _)| stage)
+    }
+}
+
 impl Race<Vec<QualifyingResult>> {
     /// Returns a reference to the field [`Race::payload`], a list of [`QualifyingResult`]s.
     pub fn qualifying_results(&self) -> &[QualifyingResult] {
@@ -1541,6 +2375,8 @@ impl Race<QualifyingResult> {
 }
 
 impl PayloadInnerList for QualifyingResult {
+    const KIND: PayloadKind = PayloadKind::QualifyingResults;
+
     fn try_into_inner_from(payload: Payload) -> Result<InnerList<Self>> {
         payload.into_qualifying_results().map_err(into)
     }
@@ -1558,19 +2394,20 @@ pub type Points = f32;
 #[serde_as]
 #[derive(Deserialize, PartialEq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct SprintResult {
     /// Driver's car number during the sprint.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub number: u32,
     /// Driver's classified position in the sprint, even if they did not finish.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub position: u32,
     /// Indicates the driver's result in the sprint, e.g. [`Position::Finished`] containing
     /// the finishing position if the driver finished, or any of other possible outcomes, e.g.
     /// [`Position::Retired`], [`Position::Disqualified`], [`Position::Withdrawn`],etc.
     pub position_text: Position,
     /// Points awarded to the driver for their result in the sprint.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub points: Points,
     /// The driver that this sprint result corresponds to.
     #[serde(rename = "Driver")]
@@ -1579,10 +2416,10 @@ pub struct SprintResult {
     #[serde(rename = "Constructor")]
     pub constructor: Constructor,
     /// Driver's starting grid position for the sprint.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub grid: u32,
     /// Number of laps completed by the driver during the sprint.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub laps: u32,
     /// Driver's status at the end of the sprint, e.g. `"Finished"`, `"Retired"`, etc.
     pub status: String,
@@ -1607,6 +2444,19 @@ impl Race<Vec<SprintResult>> {
     pub fn into_sprint_results(self) -> Vec<SprintResult> {
         self.payload
     }
+
+    /// Returns the drivers' [`FastestLap`]s of the sprint, sorted by [`FastestLap::rank`], i.e. with
+    /// the overall fastest lap of the sprint first.
+    ///
+    /// Results without a [`SprintResult::fastest_lap`] are excluded. Fastest laps without a
+    /// [`FastestLap::rank`] are placed last, in the order of the results.
+    pub fn fastest_laps(&self) -> Vec<(&Driver, &FastestLap)> {
+        sorted_fastest_laps(
+            self.payload
+                .iter()
+                .map(|result| (&result.driver, result.fastest_lap.as_ref())),
+        )
+    }
 }
 
 impl Race<SprintResult> {
@@ -1622,6 +2472,8 @@ impl Race<SprintResult> {
 }
 
 impl PayloadInnerList for SprintResult {
+    const KIND: PayloadKind = PayloadKind::SprintResults;
+
     fn try_into_inner_from(payload: Payload) -> Result<InnerList<Self>> {
         payload.into_sprint_results().map_err(into)
     }
@@ -1633,19 +2485,24 @@ impl PayloadInnerList for SprintResult {
 #[serde_as]
 #[derive(Deserialize, PartialEq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct RaceResult {
     /// Driver's car number during the race.
+    ///
+    /// **Note:** This may hold the sentinel value [`RaceResult::NO_NUMBER`], i.e. `u32::MAX`, for
+    /// the few entries that were not assigned a car number. Prefer [`RaceResult::car_number`] for
+    /// display purposes, which returns [`None`] in such cases.
     #[serde(deserialize_with = "deserialize_possible_no_number")]
     pub number: u32,
     /// Driver's classified position in the race, even if they did not finish.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub position: u32,
     /// Indicates the driver's result in the race, e.g. [`Position::Finished`] containing the
     /// finishing position if the driver finished, or any of other possible outcomes, e.g.
     /// [`Position::Retired`], [`Position::Disqualified`], [`Position::Withdrawn`],etc.
     pub position_text: Position,
     /// Points awarded to the driver for their result in the race, including any fastest lap points.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub points: Points,
     /// The driver that this race result corresponds to.
     #[serde(rename = "Driver")]
@@ -1654,10 +2511,10 @@ pub struct RaceResult {
     #[serde(rename = "Constructor")]
     pub constructor: Constructor,
     /// Driver's starting grid position for the race.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub grid: u32,
     /// Number of laps completed by the driver during the race.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub laps: u32,
     /// Driver's status at the end of the race, e.g. `"Finished"`, `"Retired"`, etc.
     pub status: String,
@@ -1682,6 +2539,34 @@ impl RaceResult {
     ///   - 1962, round 4 (French Grand Prix): P19-22
     ///   - 1963, round 10 (South African Grand Prix): P23
     pub const NO_NUMBER: u32 = u32::MAX;
+
+    /// Returns the driver's car number during the race, i.e. [`RaceResult::number`], or [`None`]
+    /// if no car number was assigned, i.e. it holds [`RaceResult::NO_NUMBER`].
+    pub const fn car_number(&self) -> Option<u32> {
+        if self.number == Self::NO_NUMBER {
+            None
+        } else {
+            Some(self.number)
+        }
+    }
+
+    /// Returns the number of positions gained by the driver from their [`RaceResult::grid`] to
+    /// their classified [`RaceResult::position`], negative if they lost positions.
+    ///
+    /// Returns [`None`] if the driver has a [`RaceResult::grid`] of `0`, e.g. if they started from
+    /// the pit lane, as there is no grid position to compare against.
+    pub fn positions_gained(&self) -> Option<i64> {
+        (self.grid != 0).then(|| i64::from(self.grid) - i64::from(self.position))
+    }
+
+    /// Returns `true` if the driver did not finish the race, i.e. if their
+    /// [`RaceResult::position_text`] is [`Position::Retired`] or [`Position::NotClassified`].
+    ///
+    /// Other non-finishing outcomes, e.g. [`Position::Disqualified`] or [`Position::Withdrawn`],
+    /// are not considered a DNF, as they are not the result of failing to complete the race.
+    pub const fn is_dnf(&self) -> bool {
+        matches!(self.position_text, Position::Retired | Position::NotClassified)
+    }
 }
 
 impl Race<Vec<RaceResult>> {
@@ -1694,6 +2579,77 @@ impl Race<Vec<RaceResult>> {
     pub fn into_race_results(self) -> Vec<RaceResult> {
         self.payload
     }
+
+    /// Returns the drivers' [`FastestLap`]s of the race, sorted by [`FastestLap::rank`], i.e. with
+    /// the overall fastest lap of the race first.
+    ///
+    /// Results without a [`RaceResult::fastest_lap`], e.g. drivers who retired on the first lap or
+    /// races before the 2004 season, are excluded. Fastest laps without a [`FastestLap::rank`] are
+    /// placed last, in the order of the results.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::{id::DriverID, jolpica::{agent::Agent, resource::Filters}};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let race = jolpica
+    ///     .get_race_results_for_event(Filters::new().season(2021).round(22))
+    ///     .unwrap();
+    ///
+    /// let (driver, fastest_lap) = race.fastest_laps()[0];
+    /// assert_eq!(driver.driver_id, DriverID::from("max_verstappen"));
+    /// assert_eq!(fastest_lap.rank, Some(1));
+    /// ```
+    pub fn fastest_laps(&self) -> Vec<(&Driver, &FastestLap)> {
+        sorted_fastest_laps(
+            self.payload
+                .iter()
+                .map(|result| (&result.driver, result.fastest_lap.as_ref())),
+        )
+    }
+
+    /// Returns the absolute finishing time of each driver, in the order of the results, by
+    /// resolving each [`RaceTime::delta`] against the [`RaceTime::total`] of the leader/P1, via
+    /// [`RaceTime::resolve_total`], e.g. to compute the real gaps between midfield finishers.
+    ///
+    /// Results without a [`RaceResult::time`], e.g. lapped drivers whose status is `"+1 Lap"`, or
+    /// drivers who did not finish, have no finishing time, i.e. [`None`]. If no result is for the
+    /// leader, as per [`RaceTime::is_lead`], then each [`RaceTime::total`] is used as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use f1_data::jolpica::{agent::Agent, resource::Filters};
+    /// # let jolpica = Agent::default();
+    /// #
+    /// let race = jolpica
+    ///     .get_race_results_for_event(Filters::new().season(2023).round(4))
+    ///     .unwrap();
+    ///
+    /// let times = race.finishing_times();
+    /// let (p1, p2) = (times[0].1.unwrap(), times[1].1.unwrap());
+    /// assert_eq!((p2 - p1).whole_milliseconds(), 2137);
+    /// assert!(times.last().unwrap().1.is_none());
+    /// ```
+    pub fn finishing_times(&self) -> Vec<(&Driver, Option<Duration>)> {
+        let leader_total = self
+            .payload
+            .iter()
+            .filter_map(|result| result.time.as_ref())
+            .find(|time| time.is_lead())
+            .map(|time| *time.total());
+
+        self.payload
+            .iter()
+            .map(|result| {
+                let finishing_time = result.time.as_ref().map(|time| {
+                    leader_total.map_or_else(|| *time.total(), |leader_total| time.resolve_total(leader_total))
+                });
+                (&result.driver, finishing_time)
+            })
+            .collect()
+    }
 }
 
 impl Race<RaceResult> {
@@ -1709,12 +2665,16 @@ impl Race<RaceResult> {
 }
 
 impl PayloadInnerList for RaceResult {
+    const KIND: PayloadKind = PayloadKind::RaceResults;
+
     fn try_into_inner_from(payload: Payload) -> Result<InnerList<Self>> {
         payload.into_race_results().map_err(into)
     }
 }
 
 /// Deserialize a `u32` from a string, where empty is represented by [`RaceResult::NO_NUMBER`].
+///
+/// With the `lenient-parsing` feature, any other invalid number is also set to the sentinel value.
 fn deserialize_possible_no_number<'de, D>(deserializer: D) -> std::result::Result<u32, D::Error>
 where
     D: Deserializer<'de>,
@@ -1723,11 +2683,66 @@ where
         if str == "None" {
             Ok(RaceResult::NO_NUMBER)
         } else {
-            str.parse::<u32>().map_err(serde::de::Error::custom)
+            str.parse::<u32>()
+                .or_else(|err| lenient_or_error(&str, err).map(|()| RaceResult::NO_NUMBER))
         }
     })
 }
 
+/// A [`serde_with`] adapter for numeric values represented as strings, e.g. `"25"`.
+///
+/// This is equivalent to [`DisplayFromStr`], unless the `lenient-parsing` feature is enabled, in
+/// which case values that fail to parse are set to [`Default::default`], i.e. `0`. See the
+/// [module-level documentation](self#lenient-parsing) for more details.
+struct NumberFromStr;
+
+impl<'de, T> DeserializeAs<'de, T> for NumberFromStr
+where
+    T: FromStr + Default,
+    T::Err: Display,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<T, D::Error> {
+        let str = String::deserialize(deserializer)?;
+        str.parse()
+            .or_else(|err| lenient_or_error(&str, err).map(|()| T::default()))
+    }
+}
+
+/// A [`serde_with`] adapter for optional numeric values represented as strings, e.g. `"33"`.
+///
+/// This is equivalent to `Option<DisplayFromStr>`, unless the `lenient-parsing` feature is enabled,
+/// in which case values that fail to parse are set to [`None`]. See the
+/// [module-level documentation](self#lenient-parsing) for more details.
+struct OptionNumberFromStr;
+
+impl<'de, T> DeserializeAs<'de, Option<T>> for OptionNumberFromStr
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<T>, D::Error> {
+        let Some(str) = Option::<String>::deserialize(deserializer)? else {
+            return Ok(None);
+        };
+
+        str.parse()
+            .map(Some)
+            .or_else(|err| lenient_or_error(&str, err).map(|()| None))
+    }
+}
+
+/// Returns `Ok(())` after logging a warning if the `lenient-parsing` feature is enabled, indicating
+/// that a default value should be used in place of `str`, which failed to parse with `err`.
+/// Otherwise, returns `err` as a deserialization error.
+fn lenient_or_error<E: serde::de::Error>(str: &str, err: impl Display) -> std::result::Result<(), E> {
+    if cfg!(feature = "lenient-parsing") {
+        log::warn!("Failed to parse number from \"{str}\", using default value instead: {err}");
+        Ok(())
+    } else {
+        Err(E::custom(err))
+    }
+}
+
 /// Represents a driver's result outcome in a Formula 1 sprint or race session.
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Position {
@@ -1762,19 +2777,189 @@ impl Position {
     pub const N: Self = Self::NotClassified;
 }
 
-impl<'de> Deserialize<'de> for Position {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
-        match String::deserialize(deserializer)?.as_str() {
+impl Display for Position {
+    /// Formats the [`Position`] as it is represented in the jolpica-f1 API, i.e. `"R"` for
+    /// [`Position::Retired`], etc., or the finishing position number for [`Position::Finished`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Finished(pos) => write!(f, "{pos}"),
+            Self::Retired => write!(f, "R"),
+            Self::Disqualified => write!(f, "D"),
+            Self::Excluded => write!(f, "E"),
+            Self::Withdrawn => write!(f, "W"),
+            Self::FailedToQualify => write!(f, "F"),
+            Self::NotClassified => write!(f, "N"),
+        }
+    }
+}
+
+impl FromStr for Position {
+    type Err = Error;
+
+    /// Parses a [`Position`] from its jolpica-f1 API representation, the inverse of [`Display`].
+    /// Returns [`Error::UnexpectedData`] if `s` is neither a known shorthand nor a `u32` number.
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
             "R" => Ok(Self::R),
             "D" => Ok(Self::D),
             "E" => Ok(Self::E),
             "W" => Ok(Self::W),
             "F" => Ok(Self::F),
             "N" => Ok(Self::N),
-            num => Ok(Self::Finished(
-                num.parse::<u32>()
-                    .map_err(|err| serde::de::Error::custom(err.to_string()))?,
-            )),
+            num => num
+                .parse::<u32>()
+                .map(Self::Finished)
+                .map_err(|err| Error::UnexpectedData(format!("Invalid position \"{num}\": {err}"))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Holds the schedule and all available session results for a single Formula 1 race weekend event.
+///
+/// This is assembled from multiple requests, e.g. by [`Agent::get_weekend`], and is not directly
+/// returned in any [`Response`]. Each of the session results is [`None`] if the session has not
+/// taken place yet, did not take place at all, e.g. sprint sessions, or has no data available.
+///
+/// **Note:** The jolpica-f1 API does not provide results for free-practice sessions, so only their
+/// dates and times, if any, are available via [`Schedule`] in [`Weekend::schedule`].
+#[derive(PartialEq, Clone, Debug)]
+pub struct Weekend {
+    /// The race weekend event and its [`Schedule`], which contains the dates and times of sessions.
+    pub schedule: Race<Schedule>,
+    /// The list of [`QualifyingResult`]s for the qualifying session, if available.
+    pub qualifying_results: Option<Vec<QualifyingResult>>,
+    /// The list of [`SprintResult`]s for the sprint session, if available.
+    pub sprint_results: Option<Vec<SprintResult>>,
+    /// The list of [`RaceResult`]s for the race session, if available.
+    pub race_results: Option<Vec<RaceResult>>,
+}
+
+/// Holds a single driver's qualifying and race results for every round of a single season.
+///
+/// This is assembled from multiple requests, e.g. by [`Agent::get_driver_season`], and is not
+/// directly returned in any [`Response`].
+#[derive(PartialEq, Clone, Debug)]
+pub struct DriverSeason {
+    /// The [`DriverRound`]s in which the driver took part, in round order.
+    pub rounds: Vec<DriverRound>,
+}
+
+/// Holds a single driver's qualifying and race results for a single round of a [`DriverSeason`].
+///
+/// Each of the results is [`None`] if the driver has no result for that session, e.g. qualifying
+/// results before the 2003 season, or a race entry after failing to qualify.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DriverRound {
+    /// The information about the race weekend event, e.g. its [`RaceInfo::round`].
+    pub race: RaceInfo,
+    /// The driver's [`QualifyingResult`] for this round, if available.
+    pub qualifying_result: Option<QualifyingResult>,
+    /// The driver's [`RaceResult`] for this round, if available.
+    pub race_result: Option<RaceResult>,
+}
+
+/// Holds every driver's qualifying, sprint, and race results for a single race weekend event,
+/// joined by [`DriverID`] into a single [`DriverWeekend`] row per driver.
+///
+/// This is assembled from a [`Weekend`], e.g. by [`Agent::get_weekend_summary`], and is not
+/// directly returned in any [`Response`]. The rows are in race finishing order, followed by any
+/// drivers without a [`RaceResult`], in sprint and then qualifying order, e.g. drivers that took
+/// part in qualifying but did not start the race.
+#[derive(PartialEq, Clone, Debug)]
+pub struct WeekendSummary {
+    /// The information about the race weekend event, e.g. its [`RaceInfo::race_name`].
+    pub race: RaceInfo,
+    /// The [`DriverWeekend`] row for each driver with a result in any of the sessions.
+    pub drivers: Vec<DriverWeekend>,
+}
+
+/// Holds a single driver's qualifying, sprint, and race results for a [`WeekendSummary`].
+///
+/// Each of the results is [`None`] if the driver has no result for that session, e.g. in a
+/// weekend without a sprint, or in the race after not starting it.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DriverWeekend {
+    /// The [`Driver`] whose results are held in this row.
+    pub driver: Driver,
+    /// The driver's [`QualifyingResult`] for this weekend, if available.
+    pub qualifying_result: Option<QualifyingResult>,
+    /// The driver's [`SprintResult`] for this weekend, if available.
+    pub sprint_result: Option<SprintResult>,
+    /// The driver's [`RaceResult`] for this weekend, if available.
+    pub race_result: Option<RaceResult>,
+}
+
+impl DriverWeekend {
+    /// Returns the driver's [`QualifyingResult::position`], if available.
+    pub fn qualifying_position(&self) -> Option<u32> {
+        self.qualifying_result.as_ref().map(|result| result.position)
+    }
+
+    /// Returns the driver's [`SprintResult::position_text`], if available.
+    pub fn sprint_position(&self) -> Option<Position> {
+        self.sprint_result.as_ref().map(|result| result.position_text)
+    }
+
+    /// Returns the driver's starting grid position for the race, i.e. [`RaceResult::grid`], if
+    /// available.
+    pub fn grid(&self) -> Option<u32> {
+        self.race_result.as_ref().map(|result| result.grid)
+    }
+
+    /// Returns the driver's [`RaceResult::position_text`], if available.
+    pub fn race_position(&self) -> Option<Position> {
+        self.race_result.as_ref().map(|result| result.position_text)
+    }
+}
+
+impl From<Weekend> for WeekendSummary {
+    /// Joins the session results of a [`Weekend`] by [`DriverID`], as per [`WeekendSummary`].
+    fn from(weekend: Weekend) -> Self {
+        fn row<'a>(drivers: &'a mut Vec<DriverWeekend>, driver: &Driver) -> &'a mut DriverWeekend {
+            let idx = drivers
+                .iter()
+                .position(|row| row.driver.driver_id == driver.driver_id)
+                .unwrap_or_else(|| {
+                    drivers.push(DriverWeekend {
+                        driver: driver.clone(),
+                        qualifying_result: None,
+                        sprint_result: None,
+                        race_result: None,
+                    });
+                    drivers.len() - 1
+                });
+
+            &mut drivers[idx]
+        }
+
+        let mut drivers = Vec::new();
+
+        for result in weekend.race_results.unwrap_or_default() {
+            let entry = row(&mut drivers, &result.driver);
+            entry.race_result = Some(result);
+        }
+
+        for result in weekend.sprint_results.unwrap_or_default() {
+            let entry = row(&mut drivers, &result.driver);
+            entry.sprint_result = Some(result);
+        }
+
+        for result in weekend.qualifying_results.unwrap_or_default() {
+            let entry = row(&mut drivers, &result.driver);
+            entry.qualifying_result = Some(result);
+        }
+
+        Self {
+            race: weekend.schedule.to_info(),
+            drivers,
         }
     }
 }
@@ -1789,6 +2974,8 @@ pub struct DriverLap {
     pub position: u32,
     /// Directly maps to [`Timing::time`] for a given driver's [`Timing`] in a given [`Lap`].
     pub time: Duration,
+    /// Directly maps to [`Timing::sectors`] for a given driver's [`Timing`] in a given [`Lap`].
+    pub sectors: Option<[Duration; 3]>,
 }
 
 impl DriverLap {
@@ -1809,8 +2996,21 @@ impl DriverLap {
             number: lap.number,
             position: timing.position,
             time: timing.time,
+            sectors: timing.sectors,
         })
     }
+
+    /// Returns the theoretical best lap time from a list of [`DriverLap`]s, i.e. the sum of the
+    /// fastest time in each of the three sectors, which may have been set on different laps.
+    ///
+    /// Laps without [`DriverLap::sectors`] are ignored. Returns [`None`] if none of the laps have
+    /// sector times, e.g. for all data currently available from the jolpica-f1 API.
+    pub fn theoretical_best_from_sectors(laps: &[Self]) -> Option<Duration> {
+        laps.iter()
+            .filter_map(|lap| lap.sectors)
+            .reduce(|best, sectors| std::array::from_fn(|idx| best[idx].min(sectors[idx])))
+            .map(|best| best.into_iter().sum())
+    }
 }
 
 /// Holds information about a single lap in a Formula 1 sprint or race session.
@@ -1818,6 +3018,7 @@ impl DriverLap {
 /// Requested via [`Resource::LapTimes`] and returned in [`Payload::Laps`].
 #[serde_as]
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Lap {
     /// Lap number within the session, starting from `1` for the first lap.
     #[serde_as(as = "DisplayFromStr")]
@@ -1831,15 +3032,112 @@ pub struct Lap {
 #[serde_as]
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Timing {
     /// Unique identifier for the driver that this timing corresponds to.
     pub driver_id: DriverID,
     /// Position of the driver at the end of the lap.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub position: u32,
     /// Lap time for the driver in this lap.
     #[serde(deserialize_with = "deserialize_duration")]
     pub time: Duration,
+    /// Sector times for the driver in this lap, i.e. sectors 1, 2, and 3, if available.
+    ///
+    /// **Note:** The jolpica-f1 API does not currently provide sector times, so this is always
+    /// [`None`]. It is modeled in anticipation of richer timing data, without a breaking change.
+    #[serde(default, deserialize_with = "deserialize_optional_sectors")]
+    pub sectors: Option<[Duration; 3]>,
+}
+
+/// Collects the drivers that have a [`FastestLap`], sorted by [`FastestLap::rank`], with unranked
+/// fastest laps last, for [`Race::fastest_laps`].
+fn sorted_fastest_laps<'a>(
+    results: impl Iterator<Item = (&'a Driver, Option<&'a FastestLap>)>,
+) -> Vec<(&'a Driver, &'a FastestLap)> {
+    let mut fastest_laps: Vec<_> = results
+        .filter_map(|(driver, fastest_lap)| fastest_lap.map(|fastest_lap| (driver, fastest_lap)))
+        .collect();
+
+    fastest_laps.sort_by_key(|(_, fastest_lap)| fastest_lap.rank.unwrap_or(u32::MAX));
+    fastest_laps
+}
+
+/// Returns the fastest [`Timing`] from a list, i.e. the one with the shortest [`Timing::time`].
+///
+/// Returns [`None`] if the list is empty. If several timings have the same shortest time, the first
+/// one in the list is returned. This is typically used with the [`Lap::timings`] of a single lap,
+/// e.g. as returned by [`Agent::get_lap_timings`], to find the fastest driver on that lap.
+pub fn fastest_timing(timings: &[Timing]) -> Option<&Timing> {
+    timings.iter().min_by_key(|timing| timing.time)
+}
+
+/// Returns the numbers of the laps, from `1` to `total_laps`, for which the specified driver has no
+/// [`Timing`] in the list of [`Lap`]s, e.g. as returned by [`Agent::get_laps`].
+///
+/// **Note:** This includes laps after a driver retired, if `total_laps` is the race distance, e.g.
+/// [`RaceResult::laps`] of the winner. Use the driver's own [`RaceResult::laps`] to only detect
+/// gaps in the laps that they completed.
+pub fn missing_laps(laps: &[Lap], driver_id: &DriverID, total_laps: u32) -> Vec<u32> {
+    (1..=total_laps)
+        .filter(|&number| {
+            !laps
+                .iter()
+                .filter(|lap| lap.number == number)
+                .any(|lap| lap.timings.iter().any(|timing| &timing.driver_id == driver_id))
+        })
+        .collect()
+}
+
+/// A [`DriverLap`] returned by [`interpolate_driver_laps`], which may have been estimated.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct InterpolatedDriverLap {
+    /// The lap data, either as provided or, if [`estimated`](Self::estimated), interpolated.
+    pub lap: DriverLap,
+    /// Whether this lap was missing and [`lap`](Self::lap) was estimated, rather than provided.
+    pub estimated: bool,
+}
+
+/// Fills gaps in a driver's list of [`DriverLap`]s, e.g. as returned by [`Agent::get_driver_laps`],
+/// with laps whose times are linearly interpolated from the laps surrounding each gap.
+///
+/// The returned laps are sorted by [`DriverLap::number`], and the estimated ones are flagged with
+/// [`InterpolatedDriverLap::estimated`]. Estimated laps take the [`DriverLap::position`] of the
+/// preceding lap, and have no [`DriverLap::sectors`]. Only gaps between two provided laps are
+/// filled, i.e. missing laps before the first or after the last provided lap are not added.
+pub fn interpolate_driver_laps(laps: &[DriverLap]) -> Vec<InterpolatedDriverLap> {
+    let mut laps = laps.to_vec();
+    laps.sort_by_key(|lap| lap.number);
+
+    let mut interpolated = Vec::with_capacity(laps.len());
+
+    for (idx, lap) in laps.iter().enumerate() {
+        if let Some(prev) = idx.checked_sub(1).map(|prev| &laps[prev]) {
+            let gap = f64::from(lap.number - prev.number);
+            let delta = (lap.time - prev.time).as_seconds_f64();
+
+            interpolated.extend((prev.number + 1..lap.number).map(|number| {
+                let fraction = f64::from(number - prev.number) / gap;
+
+                InterpolatedDriverLap {
+                    lap: DriverLap {
+                        number,
+                        position: prev.position,
+                        time: prev.time + duration_from_secs_f64(delta * fraction),
+                        sectors: None,
+                    },
+                    estimated: true,
+                }
+            }));
+        }
+
+        interpolated.push(InterpolatedDriverLap {
+            lap: *lap,
+            estimated: false,
+        });
+    }
+
+    interpolated
 }
 
 /// Holds information about a single pit stop made by a driver in a Formula 1 sprint or race.
@@ -1848,49 +3146,256 @@ pub struct Timing {
 #[serde_as]
 #[derive(Deserialize, PartialEq, Eq, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct PitStop {
     /// Unique identifier for the driver that made this pit stop.
     pub driver_id: DriverID,
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     /// Lap number during which the pit stop was made.
     pub lap: u32,
     /// Pit stop index for the driver during the session, starting from `1` for their first stop.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub stop: u32,
     /// Time from the start of the race at which the pit stop was made.
     #[serde(deserialize_with = "deserialize_time")]
     pub time: Time,
     /// Duration of the pit stop from pit entry to pit exit.
-    // @todo Double-check if it's actually from pit entry to pit exit.
+    ///
+    /// **Note:** This is the total time spent in the pit lane, including the transit through it,
+    /// and not the stationary time in the pit box, e.g. ~20s rather than ~2s for a typical stop.
     #[serde(deserialize_with = "deserialize_duration")]
     pub duration: Duration,
 }
 
+impl PitStop {
+    /// Returns an estimate of the total time lost by making this pit stop, compared to staying on
+    /// track, e.g. for strategy analysis such as undercut windows.
+    ///
+    /// Since [`PitStop::duration`] is measured from pit entry to pit exit, it already includes both
+    /// the stationary time in the pit box and the transit through the pit lane. The time lost is
+    /// therefore estimated as [`PitStop::duration`] minus `pit_lane_delta`, the time it would have
+    /// taken to cover the same stretch of track, from pit entry to pit exit, at racing speed. The
+    /// result saturates at [`Duration::ZERO`].
+    ///
+    /// **Note:** `pit_lane_delta` is circuit-specific and must be supplied by the caller, e.g. from
+    /// a lap of the same driver without a pit stop. The estimate does not account for time lost to
+    /// slower in/out laps outside of the pit lane, e.g. due to cold tyres, or for traffic.
+    ///
+    /// A bundled table of per-circuit defaults is not provided, since the jolpica-f1 API has no
+    /// data on pit lane lengths or speed limits, and both have changed over the years for many
+    /// circuits, e.g. with layout revisions, so a single value per circuit would be misleading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::id::DriverID;
+    /// # use f1_data::jolpica::{response::PitStop, time::{duration_s_ms, macros::time}};
+    /// let pit_stop = PitStop {
+    ///     driver_id: DriverID::from("max_verstappen"),
+    ///     lap: 10,
+    ///     stop: 1,
+    ///     time: time!(15:22:00),
+    ///     duration: duration_s_ms(20, 707),
+    /// };
+    ///
+    /// assert_eq!(pit_stop.time_lost_estimate(duration_s_ms(4, 500)), duration_s_ms(16, 207));
+    /// ```
+    pub fn time_lost_estimate(&self, pit_lane_delta: Duration) -> Duration {
+        self.duration.saturating_sub(pit_lane_delta).max(Duration::ZERO)
+    }
+}
+
+/// Returns the fastest [`PitStop`] from a list, i.e. the one with the shortest [`PitStop::duration`].
+///
+/// Returns [`None`] if the list is empty. If several pit stops have the same shortest duration, the
+/// first one in the list is returned.
+///
+/// **Note:** [`PitStop::duration`] includes the pit lane transit, so the fastest pit stop by this
+/// measure is not necessarily the one with the shortest stationary time.
+pub fn fastest_pit_stop(stops: &[PitStop]) -> Option<&PitStop> {
+    stops.iter().min_by_key(|stop| stop.duration)
+}
+
+/// Holds the championship standings of a Formula 1 season after a given round.
+///
+/// Requested via [`Resource::DriverStandings`] or [`Resource::ConstructorStandings`], and returned
+/// in [`Table::StandingsLists`]. The generic type parameter `T` represents the type of standings.
+/// The default <code>T = [Standings]</code> accepts both drivers' and constructors' standings, but
+/// methods like [`Agent::get_driver_standings`] return the already extracted inner list, e.g. a
+/// [`Vec<DriverStanding>`] for the drivers' standings.
+#[serde_as]
+#[derive(Deserialize, Eq, PartialEq, Clone, Debug)]
+pub struct StandingsList<T = Standings> {
+    /// Season that these standings are for, e.g. `2021`. See [`Season::season`].
+    #[serde_as(as = "DisplayFromStr")]
+    pub season: SeasonID,
+    /// Round of the season after which these standings apply, e.g. `22` for the final round of the
+    /// 2021 season. See [`Race::round`].
+    #[serde_as(as = "DisplayFromStr")]
+    pub round: RoundID,
+    /// Standings of generic type `T`, e.g. [`Standings`] or [`Vec<DriverStanding>`].
+    #[serde(flatten)]
+    pub standings: T,
+}
+
+impl<T> StandingsList<T> {
+    /// Maps a [`StandingsList<T>`] to a [`StandingsList<U>`] by applying a type `T` -> `U`
+    /// conversion function, which may fail with error `E`, to the standings, keeping the other
+    /// fields.
+    pub fn try_map<U, F, E>(self, op: F) -> std::result::Result<StandingsList<U>, E>
+    where
+        F: FnOnce(T) -> std::result::Result<U, E>,
+        E: std::error::Error,
+    {
+        Ok(StandingsList::<U> {
+            season: self.season,
+            round: self.round,
+            standings: op(self.standings)?,
+        })
+    }
+
+    /// Maps a [`StandingsList<T>`] to a [`StandingsList<U>`] by applying a type `T` -> `U`
+    /// conversion function to the standings and keeping all other fields unchanged.
+    pub fn map<U, F>(self, op: F) -> StandingsList<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.try_map(|standings| Ok::<_, Infallible>(op(standings)))
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl TableInnerList for StandingsList<Standings> {
+    fn try_into_inner_from(table: Table) -> Result<InnerList<Self>> {
+        table
+            .into_standings_lists()
+            .map_err(bad_table_variant(TableKind::StandingsLists))
+    }
+
+    fn try_as_inner_from(table: &Table) -> Result<&InnerList<Self>> {
+        table.as_standings_lists().ok_or_else(|| Error::BadTableVariant {
+            expected: TableKind::StandingsLists,
+            actual: table.kind(),
+        })
+    }
+}
+
+/// Represents the standings held in a [`StandingsList`], i.e. either drivers' or constructors'.
+///
+/// The variant is determined by the requested [`Resource`], i.e. [`Standings::DriverStandings`]
+/// for [`Resource::DriverStandings`], and [`Standings::ConstructorStandings`] for
+/// [`Resource::ConstructorStandings`].
+#[derive(Deserialize, EnumAsInner, PartialEq, Clone, Debug)]
+pub enum Standings {
+    /// Contains a list of [`DriverStanding`]s, and corresponds to the `"DriverStandings"` property
+    /// key in the JSON response from the jolpica-f1 API.
+    DriverStandings(Vec<DriverStanding>),
+    /// Contains a list of [`ConstructorStanding`]s, and corresponds to the `"ConstructorStandings"`
+    /// property key in the JSON response from the jolpica-f1 API.
+    ConstructorStandings(Vec<ConstructorStanding>),
+}
+
+/// Holds information about a driver's position in the drivers' championship standings.
+///
+/// Requested via [`Resource::DriverStandings`] and returned in [`Standings::DriverStandings`].
+#[serde_as]
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
+pub struct DriverStanding {
+    /// Position in the championship, e.g. `1` for the leader. This is [`None`] if the driver holds
+    /// no position, e.g. if they were excluded from the championship.
+    #[serde_as(as = "OptionNumberFromStr")]
+    #[serde(default)]
+    pub position: Option<u32>,
+    /// Textual representation of the position in the championship, e.g. `"1"`, or `"-"` if the
+    /// driver holds no position.
+    pub position_text: String,
+    /// Total points scored in the championship, e.g. `395.5`.
+    #[serde_as(as = "NumberFromStr")]
+    pub points: Points,
+    /// Number of race wins in the championship.
+    #[serde_as(as = "NumberFromStr")]
+    pub wins: u32,
+    /// The driver in this position.
+    #[serde(rename = "Driver")]
+    pub driver: Driver,
+    /// All the constructors that the driver has driven for in the championship, in order.
+    #[serde(rename = "Constructors")]
+    pub constructors: Vec<Constructor>,
+}
+
+/// Holds information about a constructor's position in the constructors' championship standings.
+///
+/// Requested via [`Resource::ConstructorStandings`] and returned in
+/// [`Standings::ConstructorStandings`].
+#[serde_as]
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
+pub struct ConstructorStanding {
+    /// Position in the championship, e.g. `1` for the leader. This is [`None`] if the constructor
+    /// holds no position, e.g. if they were excluded from the championship.
+    #[serde_as(as = "OptionNumberFromStr")]
+    #[serde(default)]
+    pub position: Option<u32>,
+    /// Textual representation of the position in the championship, e.g. `"1"`, or `"-"` if the
+    /// constructor holds no position.
+    pub position_text: String,
+    /// Total points scored in the championship, e.g. `613.5`.
+    #[serde_as(as = "NumberFromStr")]
+    pub points: Points,
+    /// Number of race wins in the championship.
+    #[serde_as(as = "NumberFromStr")]
+    pub wins: u32,
+    /// The constructor in this position.
+    #[serde(rename = "Constructor")]
+    pub constructor: Constructor,
+}
+
 /// Holds geographical location information, typically about a Formula 1 circuit/track.
 #[serde_as]
 #[derive(Deserialize, Hash, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct Location {
     /// Latitude of the location, e.g. `"50.4372"` for 50°26′14″N of Circuit de Spa-Francorchamps.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub lat: OrderedFloat<f64>,
     /// Longitude of the location, e.g. `"5.97139"` for 5°58′17″E of Circuit de Spa-Francorchamps.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub long: OrderedFloat<f64>,
     /// Locality (city/town) of the location, e.g. `"Spa"`, `"Monte-Carlo"`, `"Montreal"`, etc.
     pub locality: String,
     /// Country of the location, e.g. `"Belgium"`, `"Monaco"`, `"Canada"`, `"UK"`, etc.
     pub country: String,
+    /// Altitude of the location in meters, e.g. `"2227"` for Autódromo Hermanos Rodríguez.
+    ///
+    /// **Note:** This is only present in some extended datasets, and is [`None`] otherwise.
+    #[serde_as(as = "OptionNumberFromStr")]
+    #[serde(default)]
+    pub alt: Option<OrderedFloat<f64>>,
+}
+
+impl Location {
+    /// Returns the ISO 3166-1 alpha-2 country code for this [`Location`]'s
+    /// [`country`](Self::country), e.g. `"GB"` for `"UK"`, as per [`country_code`], or `None` if
+    /// it is not known.
+    pub fn country_code(&self) -> Option<&'static str> {
+        country_code(&self.country)
+    }
 }
 
 /// Holds information about a driver's fastest lap in a Formula 1 sprint or race session.
 #[serde_as]
 #[derive(Deserialize, PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct FastestLap {
     /// The rank of the fastest lap, e.g. `1` for the overall fastest lap in the session.
-    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde_as(as = "OptionNumberFromStr")]
+    #[serde(default)]
     pub rank: Option<u32>,
     /// The lap number during which the fastest lap was set.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub lap: u32,
     /// The lap time of the fastest lap.
     #[serde(rename = "Time", deserialize_with = "extract_nested_time")]
@@ -1912,11 +3417,12 @@ fn extract_nested_time<'de, D: Deserializer<'de>>(deserializer: D) -> std::resul
 /// Holds information about the average speed during a lap.
 #[serde_as]
 #[derive(Deserialize, PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct AverageSpeed {
     /// The units used for the speed measurement, e.g. kilometers per hour, [`SpeedUnits::Kph`].
     pub units: SpeedUnits,
     /// The average speed value.
-    #[serde_as(as = "DisplayFromStr")]
+    #[serde_as(as = "NumberFromStr")]
     pub speed: f32,
 }
 
@@ -1931,9 +3437,9 @@ pub enum SpeedUnits {
 /// Check that there is exactly one element `T` in a slice `&[T]`, and return a
 /// <code>[Result<&\[T\]>]</code> containing the slice if so, [`Error::NotFound`] if it contained no
 /// elements, or [`Error::TooMany`] if it contained more than one.
-pub(crate) const fn verify_has_one_element<T>(sequence: &[T]) -> Result<&[T]> {
+pub(crate) fn verify_has_one_element<T>(sequence: &[T]) -> Result<&[T]> {
     match sequence.len() {
-        0 => Err(Error::NotFound),
+        0 => Err(Error::NotFound(String::from("expected one element, found none"))),
         1 => Ok(sequence),
         _ => Err(Error::TooMany),
     }
@@ -1944,12 +3450,26 @@ pub(crate) const fn verify_has_one_element<T>(sequence: &[T]) -> Result<&[T]> {
 /// [`Error::TooMany`] if it contained more than one.
 pub(crate) fn verify_has_one_element_and_extract<T>(mut sequence: Vec<T>) -> Result<T> {
     match sequence.len() {
-        0 => Err(Error::NotFound),
+        0 => Err(Error::NotFound(String::from("expected one element, found none"))),
         1 => Ok(sequence.remove(0)),
         _ => Err(Error::TooMany),
     }
 }
 
+/// Extract the inner list of <code>T = [PayloadInnerList]</code> from a [`Payload`], returning an
+/// [`Error::UnexpectedData`] with an explanatory message if the [`Payload`] is an empty schedule,
+/// as per [`Payload::is_empty_schedule`], or [`Error::BadPayloadVariant`] if it's another variant.
+fn try_into_session_results<T: PayloadInnerList>(payload: Payload) -> Result<InnerList<T>> {
+    if payload.is_empty_schedule() {
+        return Err(Error::UnexpectedData(format!(
+            "expected {:?} but response contained only schedule data, likely an empty result set",
+            T::KIND
+        )));
+    }
+
+    T::try_into_inner_from(payload)
+}
+
 /// Extract single [`Race`] from a [`Response`], into [`Result<Race>`], enforcing that there is only
 /// one race in the [`Response`], returning [`Error::NotFound`] if the it contained no races, or
 /// [`Error::TooMany`] if it contained more than one.
@@ -1957,7 +3477,7 @@ pub(crate) fn verify_has_one_race_and_extract(response: Response) -> Result<Race
     response
         .table
         .into_races()
-        .map_err(into)
+        .map_err(bad_table_variant(TableKind::Races))
         .and_then(verify_has_one_element_and_extract)
 }
 
@@ -1968,14 +3488,25 @@ fn into<T: Into<U>, U>(t: T) -> U {
     t.into()
 }
 
+/// Returns a closure mapping an unexpected [`Table`] to an [`Error::BadTableVariant`], reporting the
+/// `expected` [`TableKind`] along with the actual one, e.g. for [`Table::into_seasons`].
+fn bad_table_variant(expected: TableKind) -> impl FnOnce(Table) -> Error {
+    move |table| Error::BadTableVariant {
+        expected,
+        actual: table.kind(),
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage, coverage(off))]
 mod tests {
-    use std::sync::LazyLock;
+    use std::{collections::HashSet, sync::LazyLock};
 
     use const_format::formatcp;
 
+    use crate::jolpica::resource::Filters;
     use crate::jolpica::tests::assets::*;
+    use crate::jolpica::time::macros::{date, time};
     use crate::tests::asserts::*;
     use shadow_asserts::{assert_eq, assert_ne};
 
@@ -2003,26 +3534,174 @@ mod tests {
     }
 
     #[test]
-    fn circuit_table() {
-        let table: Table = serde_json::from_str(CIRCUIT_TABLE_STR).unwrap();
-        assert_false!(table.as_circuits().unwrap().is_empty());
-        assert_eq!(table, *CIRCUIT_TABLE);
+    fn circuit_table() {
+        let table: Table = serde_json::from_str(CIRCUIT_TABLE_STR).unwrap();
+        assert_false!(table.as_circuits().unwrap().is_empty());
+        assert_eq!(table, *CIRCUIT_TABLE);
+    }
+
+    #[test]
+    fn location() {
+        let location: Location = serde_json::from_str(
+            r#"{"lat": "19.4042", "long": "-99.0907", "locality": "Mexico City", "country": "Mexico"}"#,
+        )
+        .unwrap();
+        assert_eq!(location.lat, OrderedFloat(19.4042));
+        assert_eq!(location.long, OrderedFloat(-99.0907));
+        assert_true!(location.alt.is_none());
+
+        let with_alt: Location = serde_json::from_str(
+            r#"{"lat": "19.4042", "long": "-99.0907", "alt": "2227", "locality": "Mexico City", "country": "Mexico"}"#,
+        )
+        .unwrap();
+        assert_eq!(with_alt.alt, Some(OrderedFloat(2227.0)));
+        assert_eq!(
+            with_alt,
+            Location {
+                alt: Some(OrderedFloat(2227.0)),
+                ..location
+            }
+        );
+    }
+
+    #[test]
+    fn driver_standings_table() {
+        let table: Table = serde_json::from_str(DRIVER_STANDINGS_TABLE_2021_22_STR).unwrap();
+        assert_eq!(table.kind(), TableKind::StandingsLists);
+        assert_eq!(table, *DRIVER_STANDINGS_TABLE_2021_22);
+    }
+
+    #[test]
+    fn constructor_standings_table() {
+        let table: Table = serde_json::from_str(CONSTRUCTOR_STANDINGS_TABLE_2021_22_STR).unwrap();
+        assert_eq!(table.kind(), TableKind::StandingsLists);
+        assert_eq!(table, *CONSTRUCTOR_STANDINGS_TABLE_2021_22);
+    }
+
+    #[test]
+    fn standings_list_map() {
+        let list = DRIVER_STANDINGS_2021_22
+            .clone()
+            .map(|standings| standings.into_driver_standings().unwrap());
+
+        assert_eq!(list.season, 2021);
+        assert_eq!(list.round, 22);
+        assert_eq!(list.standings, vec![DRIVER_STANDING_2021_22_P1.clone(), DRIVER_STANDING_2021_22_P2.clone()]);
+
+        assert_true!(
+            CONSTRUCTOR_STANDINGS_2021_22
+                .clone()
+                .try_map(|standings| standings.into_driver_standings().map_err(|_| Error::BadPayloadVariant))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn race_table_schedule() {
+        let table: Table = serde_json::from_str(RACE_TABLE_SCHEDULE_STR).unwrap();
+        assert_false!(table.as_races().unwrap().is_empty());
+        assert_eq!(table, *RACE_TABLE_SCHEDULE);
+    }
+
+    #[test]
+    fn driver_full_name() {
+        assert_eq!(DRIVER_KIMI.full_name(), "Kimi Räikkönen");
+        assert_eq!(DRIVER_PEREZ.full_name(), "Sergio Pérez");
+        assert_eq!(DRIVER_DE_VRIES.full_name(), "Nyck de Vries");
+        assert_eq!(DRIVER_MAX.full_name(), "Max Verstappen");
+        assert_eq!(DRIVER_LECLERC.full_name(), "Charles Leclerc");
+    }
+
+    #[test]
+    fn driver_age_on() {
+        // Max Verstappen was born on 1997-09-30, and won his first race on 2016-05-15
+        assert_eq!(DRIVER_MAX.age_on(date!(2016 - 05 - 15)), Some(18));
+        assert_eq!(DRIVER_MAX.age_on_days(date!(2016 - 05 - 15)), Some(6802));
+
+        assert_eq!(DRIVER_MAX.age_on(date!(1997 - 09 - 30)), Some(0));
+        assert_eq!(DRIVER_MAX.age_on_days(date!(1997 - 09 - 30)), Some(0));
+        assert_eq!(DRIVER_MAX.age_on(date!(2023 - 09 - 29)), Some(25));
+        assert_eq!(DRIVER_MAX.age_on(date!(2023 - 09 - 30)), Some(26));
+
+        assert_eq!(DRIVER_MAX.age_on(date!(1997 - 09 - 29)), None);
+        assert_eq!(DRIVER_MAX.age_on_days(date!(1997 - 09 - 29)), None);
+
+        let no_date_of_birth = Driver {
+            date_of_birth: None,
+            ..DRIVER_MAX.clone()
+        };
+        assert_eq!(no_date_of_birth.age_on(date!(2016 - 05 - 15)), None);
+        assert_eq!(no_date_of_birth.age_on_days(date!(2016 - 05 - 15)), None);
+
+        // Birthdays on February 29th fall on March 1st in non-leap years
+        let leap_day = Driver {
+            date_of_birth: Some(date!(2000 - 02 - 29)),
+            ..DRIVER_MAX.clone()
+        };
+        assert_eq!(leap_day.age_on(date!(2001 - 02 - 28)), Some(0));
+        assert_eq!(leap_day.age_on(date!(2001 - 03 - 01)), Some(1));
+        assert_eq!(leap_day.age_on(date!(2004 - 02 - 28)), Some(3));
+        assert_eq!(leap_day.age_on(date!(2004 - 02 - 29)), Some(4));
+        assert_eq!(leap_day.age_on_days(date!(2001 - 03 - 01)), Some(366));
+    }
+
+    #[test]
+    fn driver_car_number() {
+        assert_eq!(DRIVER_MAX.car_number(), Some(CarNumber::Permanent(3)));
+        assert_false!(DRIVER_MAX.is_reigning_champion_number());
+
+        let champion = Driver {
+            permanent_number: Some(1),
+            ..DRIVER_MAX.clone()
+        };
+        assert_eq!(champion.car_number(), Some(CarNumber::Champion));
+        assert_true!(champion.is_reigning_champion_number());
+
+        let no_number = Driver {
+            permanent_number: None,
+            ..DRIVER_MAX.clone()
+        };
+        assert_eq!(no_number.car_number(), None);
+        assert_false!(no_number.is_reigning_champion_number());
     }
 
     #[test]
-    fn race_table_schedule() {
-        let table: Table = serde_json::from_str(RACE_TABLE_SCHEDULE_STR).unwrap();
-        assert_false!(table.as_races().unwrap().is_empty());
-        assert_eq!(table, *RACE_TABLE_SCHEDULE);
+    fn country_codes() {
+        assert_eq!(DRIVER_MAX.country_code(), Some("NL"));
+        assert_eq!(DRIVER_LECLERC.country_code(), Some("MC"));
+        assert_eq!(
+            Driver {
+                nationality: None,
+                ..DRIVER_MAX.clone()
+            }
+            .country_code(),
+            None
+        );
+
+        assert_eq!(CONSTRUCTOR_RED_BULL.country_code(), Some("AT"));
+        assert_eq!(CONSTRUCTOR_MCLAREN.country_code(), Some("GB"));
+
+        // The location uses "UK", while nationalities use "British"
+        assert_eq!(CIRCUIT_SILVERSTONE.location.country, "UK");
+        assert_eq!(CIRCUIT_SILVERSTONE.location.country_code(), Some("GB"));
+        assert_eq!(CIRCUIT_SILVERSTONE.location.country_code(), CONSTRUCTOR_MCLAREN.country_code());
+        assert_eq!(CIRCUIT_SPA.location.country_code(), Some("BE"));
     }
 
     #[test]
-    fn driver_full_name() {
-        assert_eq!(DRIVER_KIMI.full_name(), "Kimi Räikkönen");
-        assert_eq!(DRIVER_PEREZ.full_name(), "Sergio Pérez");
-        assert_eq!(DRIVER_DE_VRIES.full_name(), "Nyck de Vries");
-        assert_eq!(DRIVER_MAX.full_name(), "Max Verstappen");
-        assert_eq!(DRIVER_LECLERC.full_name(), "Charles Leclerc");
+    fn car_number() {
+        assert_eq!(CarNumber::from(1), CarNumber::Champion);
+        assert_eq!(CarNumber::from(33), CarNumber::Permanent(33));
+
+        assert_eq!(CarNumber::Champion.number(), 1);
+        assert_eq!(CarNumber::Permanent(33).number(), 33);
+        assert_true!(CarNumber::Champion.is_champion());
+        assert_false!(CarNumber::Permanent(33).is_champion());
+
+        assert_eq!(CarNumber::Champion.to_string(), "1");
+        assert_eq!(CarNumber::Permanent(33).to_string(), "33");
+
+        assert_eq!(RACE_RESULT_2023_4_P1.car_number().map(CarNumber::from), Some(CarNumber::Permanent(11)));
     }
 
     #[test]
@@ -2049,6 +3728,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn qualifying_result_eliminated_in() {
+        // 2003 had a single-stage qualifying format, so no driver was eliminated early
+        let results_2003 = RACE_2003_4_QUALIFYING_RESULTS.payload.as_qualifying_results().unwrap();
+        assert_eq!(QualifyingResult::stage_count(results_2003), 1);
+
+        for result in results_2003 {
+            assert_eq!(result.eliminated_in(1), None);
+        }
+
+        // 2023 had a three-stage qualifying format
+        let results_2023 = RACE_2023_4_QUALIFYING_RESULTS.payload.as_qualifying_results().unwrap();
+        assert_eq!(QualifyingResult::stage_count(results_2023), 3);
+
+        for result in results_2023 {
+            assert_eq!(result.eliminated_in(3), None);
+        }
+
+        let eliminated_in_q2 = QualifyingResult {
+            q3: None,
+            ..QUALIFYING_RESULT_2023_4_P1.clone()
+        };
+        let eliminated_in_q1 = QualifyingResult {
+            q2: None,
+            ..eliminated_in_q2.clone()
+        };
+        let no_time_set_in_q2 = QualifyingResult {
+            q2: Some(QualifyingTime::NoTimeSet),
+            ..eliminated_in_q2.clone()
+        };
+
+        assert_eq!(eliminated_in_q2.eliminated_in(3), Some(2));
+        assert_eq!(eliminated_in_q1.eliminated_in(3), Some(1));
+        assert_eq!(no_time_set_in_q2.eliminated_in(3), Some(2));
+
+        // Two-stage qualifying format, e.g. 2005
+        assert_eq!(eliminated_in_q2.eliminated_in(2), None);
+        assert_eq!(eliminated_in_q1.eliminated_in(2), Some(1));
+
+        // No qualifying times, e.g. prior to 1994
+        let no_times = QualifyingResult {
+            q1: None,
+            ..eliminated_in_q1
+        };
+        assert_eq!(QualifyingResult::stage_count(std::slice::from_ref(&no_times)), 0);
+        assert_eq!(no_times.eliminated_in(0), None);
+        assert_eq!(no_times.eliminated_in(3), None);
+        assert_eq!(QualifyingResult::stage_count(&[]), 0);
+    }
+
+    #[test]
+    fn qualifying_result_best_time() {
+        use crate::jolpica::time::duration_m_s_ms;
+
+        assert_eq!(QUALIFYING_RESULT_2023_4_P1.best_time(), Some(duration_m_s_ms(1, 40, 203)));
+        assert_eq!(QUALIFYING_RESULT_2003_4_P1.best_time(), Some(duration_m_s_ms(1, 22, 327)));
+        assert_eq!(QUALIFYING_RESULT_2003_4_P20.best_time(), None);
+
+        // The best time is not necessarily from the last stage
+        let faster_in_q2 = QualifyingResult {
+            q3: Some(QualifyingTime::Time(duration_m_s_ms(1, 42, 0))),
+            ..QUALIFYING_RESULT_2023_4_P1.clone()
+        };
+        assert_eq!(faster_in_q2.best_time(), Some(duration_m_s_ms(1, 41, 37)));
+
+        let no_time_set_in_q3 = QualifyingResult {
+            q3: Some(QualifyingTime::NoTimeSet),
+            ..QUALIFYING_RESULT_2023_4_P1.clone()
+        };
+        assert_eq!(no_time_set_in_q3.best_time(), Some(duration_m_s_ms(1, 41, 37)));
+    }
+
     #[test]
     fn sprint_result() {
         let from_str = |result_str| serde_json::from_str::<SprintResult>(result_str).unwrap();
@@ -2091,6 +3842,147 @@ mod tests {
         }
     }
 
+    #[test]
+    fn race_finishing_times() {
+        let race = map_race_multi_results::<RaceResult>(RACE_2023_4_RACE_RESULTS.clone());
+        let times = race.finishing_times();
+
+        let p1_total = *RACE_RESULT_2023_4_P1.time.unwrap().total();
+        assert_eq!(times.len(), 3);
+        assert_eq!(times[0], (&RACE_RESULT_2023_4_P1.driver, Some(p1_total)));
+        assert_eq!(times[1], (&RACE_RESULT_2023_4_P2.driver, Some(p1_total + Duration::milliseconds(2137))));
+        assert_eq!(times[1].1.as_ref(), RACE_RESULT_2023_4_P2.time.as_ref().map(RaceTime::total));
+        assert_eq!(times[2], (&RACE_RESULT_2023_4_P20.driver, None));
+
+        // Without the leader, the totals are used as-is
+        let race = race.clone().map(|results| results[1..].to_vec());
+        let times = race.finishing_times();
+        assert_eq!(times[0].1.as_ref(), RACE_RESULT_2023_4_P2.time.as_ref().map(RaceTime::total));
+        assert_eq!(times[1].1, None);
+    }
+
+    #[test]
+    fn weekend_summary_from_weekend() {
+        let weekend = Weekend {
+            schedule: RACE_2023_4_SCHEDULE
+                .clone()
+                .map(|payload| payload.into_schedule().unwrap()),
+            qualifying_results: Some(
+                RACE_2023_4_QUALIFYING_RESULTS
+                    .clone()
+                    .payload
+                    .into_qualifying_results()
+                    .unwrap(),
+            ),
+            sprint_results: Some(
+                RACE_2023_4_SPRINT_RESULTS
+                    .clone()
+                    .payload
+                    .into_sprint_results()
+                    .unwrap(),
+            ),
+            race_results: Some(RACE_2023_4_RACE_RESULTS.clone().payload.into_race_results().unwrap()),
+        };
+
+        let summary = WeekendSummary::from(weekend.clone());
+        assert_eq!(summary.race, RACE_2023_4.to_info());
+
+        let driver_ids: Vec<_> = summary
+            .drivers
+            .iter()
+            .map(|row| row.driver.driver_id.as_str())
+            .collect();
+        assert_eq!(driver_ids, ["perez", "max_verstappen", "de_vries", "leclerc"]);
+
+        let perez = &summary.drivers[0];
+        assert_eq!(perez.qualifying_result.as_ref(), Some(&*QUALIFYING_RESULT_2023_4_P3));
+        assert_eq!(perez.sprint_result.as_ref(), Some(&*SPRINT_RESULT_2023_4_P1));
+        assert_eq!(perez.race_result.as_ref(), Some(&*RACE_RESULT_2023_4_P1));
+        assert_eq!(perez.qualifying_position(), Some(3));
+        assert_eq!(perez.sprint_position(), Some(Position::Finished(1)));
+        assert_eq!(perez.grid(), Some(RACE_RESULT_2023_4_P1.grid));
+        assert_eq!(perez.race_position(), Some(Position::Finished(1)));
+
+        // Drivers may be missing from any of the sessions
+        let de_vries = &summary.drivers[2];
+        assert_eq!(de_vries.qualifying_position(), None);
+        assert_eq!(de_vries.sprint_position(), None);
+        assert_eq!(de_vries.race_result.as_ref(), Some(&*RACE_RESULT_2023_4_P20));
+
+        let leclerc = &summary.drivers[3];
+        assert_eq!(leclerc.qualifying_position(), Some(1));
+        assert_eq!(leclerc.sprint_position(), None);
+        assert_eq!(leclerc.grid(), None);
+        assert_eq!(leclerc.race_position(), None);
+
+        // A weekend without a sprint, or without any results, yields no sprint results, or no rows
+        let no_sprint = WeekendSummary::from(Weekend {
+            sprint_results: None,
+            ..weekend.clone()
+        });
+        assert_eq!(no_sprint.drivers.len(), 4);
+        assert_true!(no_sprint.drivers.iter().all(|row| row.sprint_result.is_none()));
+
+        let no_results = WeekendSummary::from(Weekend {
+            qualifying_results: None,
+            sprint_results: None,
+            race_results: None,
+            ..weekend
+        });
+        assert_true!(no_results.drivers.is_empty());
+    }
+
+    #[test]
+    fn race_fastest_laps() {
+        let no_fastest_lap = RaceResult {
+            fastest_lap: None,
+            ..RACE_RESULT_2023_4_P20.clone()
+        };
+        let unranked = RaceResult {
+            fastest_lap: Some(FastestLap {
+                rank: None,
+                ..RACE_RESULT_2023_4_P20.fastest_lap.clone().unwrap()
+            }),
+            ..RACE_RESULT_2023_4_P20.clone()
+        };
+
+        let race = RACE_2023_4.clone().map(|_| {
+            vec![
+                no_fastest_lap.clone(),
+                RACE_RESULT_2023_4_P1.clone(),
+                unranked.clone(),
+                RACE_RESULT_2023_4_P20.clone(),
+                no_fastest_lap.clone(),
+                RACE_RESULT_2023_4_P2.clone(),
+            ]
+        });
+
+        let fastest_laps = race.fastest_laps();
+        assert_eq!(fastest_laps.len(), 4);
+
+        assert_eq!(fastest_laps[0].0, &RACE_RESULT_2023_4_P2.driver);
+        assert_eq!(fastest_laps[0].1.rank, Some(2));
+        assert_eq!(fastest_laps[1].0, &RACE_RESULT_2023_4_P1.driver);
+        assert_eq!(fastest_laps[1].1.rank, Some(5));
+        assert_eq!(fastest_laps[2].1.rank, Some(20));
+        assert_eq!(fastest_laps[3].1, unranked.fastest_lap.as_ref().unwrap());
+
+        assert_true!(
+            RACE_2023_4
+                .clone()
+                .map(|_| vec![no_fastest_lap])
+                .fastest_laps()
+                .is_empty()
+        );
+
+        let sprint = RACE_2023_4_SPRINT_RESULTS
+            .clone()
+            .map(|payload| payload.into_sprint_results().unwrap());
+        // The sprint results' fastest laps are unranked, so they are kept in the order of the results
+        let drivers: Vec<_> = sprint.fastest_laps().into_iter().map(|(driver, _)| driver).collect();
+        assert_eq!(drivers, vec![&SPRINT_RESULT_2023_4_P1.driver, &SPRINT_RESULT_2023_4_P3.driver]);
+    }
+
     #[test]
     fn finishing_status() {
         let table: Table = serde_json::from_str(STATUS_TABLE_2022_STR).unwrap();
@@ -2108,6 +4000,104 @@ mod tests {
         assert_eq!(from_str(TIMING_2023_4_L2_P2_STR), *TIMING_2023_4_L2_P2);
     }
 
+    #[test]
+    fn timing_sectors() {
+        use crate::jolpica::time::duration_m_s_ms;
+
+        let timing: Timing = serde_json::from_str(
+            r#"{
+                "driverId": "leclerc",
+                "position": "1",
+                "time": "1:47.656",
+                "sectors": ["31.123", "40.456", "36.077"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            timing,
+            Timing {
+                sectors: Some([
+                    duration_m_s_ms(0, 31, 123),
+                    duration_m_s_ms(0, 40, 456),
+                    duration_m_s_ms(0, 36, 77)
+                ]),
+                ..TIMING_2023_4_L2_P1.clone()
+            }
+        );
+    }
+
+    #[test]
+    fn missing_laps() {
+        let laps = vec![LAP_2023_4_L1.clone(), LAP_2023_4_L2.clone()];
+        let driver_id = &LAP_2023_4_L1.timings[0].driver_id;
+
+        assert_true!(super::missing_laps(&laps, driver_id, 2).is_empty());
+        assert_eq!(super::missing_laps(&laps, driver_id, 4), vec![3, 4]);
+        assert_eq!(super::missing_laps(&laps[1..], driver_id, 2), vec![1]);
+        assert_eq!(super::missing_laps(&laps, &DriverID::from("unknown"), 2), vec![1, 2]);
+    }
+
+    #[test]
+    fn interpolate_driver_laps() {
+        use crate::jolpica::time::duration_m_s_ms;
+
+        let lap = |number, position, ms| DriverLap {
+            number,
+            position,
+            time: duration_m_s_ms(1, 40, ms),
+            sectors: None,
+        };
+
+        assert_true!(super::interpolate_driver_laps(&[]).is_empty());
+
+        let laps = [lap(1, 3, 0), lap(2, 2, 500), lap(5, 1, 200), lap(3, 2, 800)];
+        let interpolated = super::interpolate_driver_laps(&laps);
+
+        let estimated = |lap| InterpolatedDriverLap { lap, estimated: true };
+        let provided = |lap| InterpolatedDriverLap { lap, estimated: false };
+
+        assert_eq!(
+            interpolated,
+            vec![
+                provided(lap(1, 3, 0)),
+                provided(lap(2, 2, 500)),
+                provided(lap(3, 2, 800)),
+                estimated(lap(4, 2, 500)),
+                provided(lap(5, 1, 200)),
+            ]
+        );
+    }
+
+    #[test]
+    fn driver_lap_theoretical_best_from_sectors() {
+        use crate::jolpica::time::duration_m_s_ms;
+
+        let lap = |number, sectors: Option<[i64; 3]>| DriverLap {
+            number,
+            position: 1,
+            time: duration_m_s_ms(1, 50, 0),
+            sectors: sectors.map(|sectors| sectors.map(|ms| duration_m_s_ms(0, 30, ms))),
+        };
+
+        assert_true!(DriverLap::theoretical_best_from_sectors(&[]).is_none());
+        assert_true!(DriverLap::theoretical_best_from_sectors(&[lap(1, None), lap(2, None)]).is_none());
+
+        assert_eq!(
+            DriverLap::theoretical_best_from_sectors(&[lap(1, Some([100, 200, 300]))]),
+            Some(duration_m_s_ms(1, 30, 600))
+        );
+        assert_eq!(
+            DriverLap::theoretical_best_from_sectors(&[
+                lap(1, Some([100, 500, 300])),
+                lap(2, None),
+                lap(3, Some([400, 200, 600])),
+                lap(4, Some([300, 300, 50]))
+            ]),
+            Some(duration_m_s_ms(1, 30, 350))
+        );
+    }
+
     #[test]
     fn lap() {
         let from_str = |lap_str| serde_json::from_str::<Lap>(lap_str).unwrap();
@@ -2142,6 +4132,74 @@ mod tests {
         assert_eq!(race, *RACE_2023_4_PIT_STOPS);
     }
 
+    #[test]
+    fn pit_stop_time_lost_estimate() {
+        let pit_stop = &*PIT_STOP_2023_4_L10_MAX;
+        assert_eq!(pit_stop.time_lost_estimate(Duration::ZERO), pit_stop.duration);
+        assert_eq!(pit_stop.time_lost_estimate(Duration::seconds(5)), pit_stop.duration - Duration::seconds(5));
+        assert_eq!(pit_stop.time_lost_estimate(pit_stop.duration), Duration::ZERO);
+        assert_eq!(pit_stop.time_lost_estimate(Duration::seconds(30)), Duration::ZERO);
+        assert_eq!(pit_stop.time_lost_estimate(Duration::milliseconds(4_500)), Duration::milliseconds(16_207));
+    }
+
+    #[test]
+    fn fastest_pit_stop() {
+        let pit_stops = RACE_2023_4_PIT_STOPS.payload.as_pit_stops().unwrap();
+        assert_eq!(super::fastest_pit_stop(pit_stops), Some(&*PIT_STOP_2023_4_L10_MAX));
+
+        let reversed: Vec<_> = pit_stops.iter().rev().cloned().collect();
+        assert_eq!(super::fastest_pit_stop(&reversed), Some(&*PIT_STOP_2023_4_L10_MAX));
+
+        let tied = vec![PIT_STOP_2023_4_L11_LECLERC.clone(), PIT_STOP_2023_4_L11_LECLERC.clone()];
+        assert_true!(std::ptr::eq(super::fastest_pit_stop(&tied).unwrap(), &tied[0]));
+
+        assert_true!(super::fastest_pit_stop(&[]).is_none());
+    }
+
+    #[test]
+    fn dedupe_races_by_id() {
+        let races = vec![
+            Race::from(RACE_2023_4.clone(), vec![RACE_RESULT_2023_4_P1.clone(), RACE_RESULT_2023_4_P2.clone()]),
+            Race::from(RACE_2023_3.clone(), vec![RACE_RESULT_2023_3_P15.clone()]),
+            Race::from(RACE_2023_4.clone(), vec![RACE_RESULT_2023_4_P2.clone(), RACE_RESULT_2023_4_P20.clone()]),
+        ];
+
+        let deduped = super::dedupe_races_by_id(races);
+        assert_eq!(deduped.len(), 2);
+
+        assert_eq!(deduped[0].id(), RACE_2023_4.id());
+        assert_eq!(
+            deduped[0].payload,
+            vec![
+                RACE_RESULT_2023_4_P1.clone(),
+                RACE_RESULT_2023_4_P2.clone(),
+                RACE_RESULT_2023_4_P20.clone()
+            ]
+        );
+
+        assert_eq!(deduped[1].id(), RACE_2023_3.id());
+        assert_eq!(deduped[1].payload, vec![RACE_RESULT_2023_3_P15.clone()]);
+
+        assert_true!(super::dedupe_races_by_id::<RaceResult>(vec![]).is_empty());
+    }
+
+    #[test]
+    fn fastest_timing() {
+        let timings = &LAP_2023_4_L1.timings;
+        let fastest = super::fastest_timing(timings).unwrap();
+        timings.iter().for_each(|timing| assert_le!(fastest.time, timing.time));
+
+        let mut faster = TIMING_2023_4_L1_P2.clone();
+        faster.time = TIMING_2023_4_L1_P1.time - Duration::milliseconds(1);
+        let timings = vec![TIMING_2023_4_L1_P1.clone(), faster.clone()];
+        assert_eq!(super::fastest_timing(&timings), Some(&faster));
+
+        let tied = vec![TIMING_2023_4_L1_P1.clone(), TIMING_2023_4_L1_P1.clone()];
+        assert_true!(std::ptr::eq(super::fastest_timing(&tied).unwrap(), &tied[0]));
+
+        assert_true!(super::fastest_timing(&[]).is_none());
+    }
+
     #[test]
     fn pagination_is_last_page() {
         assert_true!(
@@ -2321,6 +4379,23 @@ mod tests {
         assert_eq!(map[&RACE_2023_4.to_info()], *RACE_2023_4);
     }
 
+    #[test]
+    fn race_info_from() {
+        let race = RACE_2023_4.clone();
+        let info = RaceInfo::from(&race);
+
+        assert_eq!(info.season, race.season);
+        assert_eq!(info.round, race.round);
+        assert_eq!(info.url, race.url);
+        assert_eq!(info.race_name, race.race_name);
+        assert_eq!(info.circuit, race.circuit);
+        assert_eq!(info.date, race.date);
+        assert_eq!(info.time, race.time);
+
+        assert_eq!(info, race.to_info());
+        assert_eq!(info, Race::from(race, ()).to_info());
+    }
+
     #[test]
     fn race_try_map() {
         let from = Race::from(RACE_2023_4.clone(), true);
@@ -2332,7 +4407,7 @@ mod tests {
         #[derive(Debug)]
         struct DummyError;
 
-        impl std::fmt::Display for DummyError {
+        impl Display for DummyError {
             fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 Ok(())
             }
@@ -2366,6 +4441,42 @@ mod tests {
         race.map(|payload| payload.into_schedule().unwrap())
     }
 
+    #[test]
+    fn payload_kind() {
+        assert_eq!(RACE_2023_4_QUALIFYING_RESULTS.payload_kind(), PayloadKind::QualifyingResults);
+        assert_eq!(RACE_2023_4_SPRINT_RESULTS.payload_kind(), PayloadKind::SprintResults);
+        assert_eq!(RACE_2023_4_RACE_RESULTS.payload_kind(), PayloadKind::RaceResults);
+        assert_eq!(RACE_2023_4_LAPS.payload_kind(), PayloadKind::Laps);
+        assert_eq!(RACE_2023_4_PIT_STOPS.payload_kind(), PayloadKind::PitStops);
+        assert_eq!(RACE_2023_4_SCHEDULE.payload_kind(), PayloadKind::Schedule);
+
+        assert_eq!(RACE_2023_4_RACE_RESULTS.payload.kind(), PayloadKind::RaceResults);
+        assert_eq!(Payload::Schedule(SCHEDULE_NONE.clone()).kind(), PayloadKind::Schedule);
+    }
+
+    #[test]
+    fn payload_from_inner_list() {
+        let from_payload = |race: &Race| race.payload.clone();
+
+        let results = RACE_2023_4_QUALIFYING_RESULTS.payload.as_qualifying_results().unwrap();
+        assert_eq!(
+            Payload::from_qualifying_results(results.iter().cloned()),
+            from_payload(&RACE_2023_4_QUALIFYING_RESULTS)
+        );
+
+        let results = RACE_2023_4_SPRINT_RESULTS.payload.as_sprint_results().unwrap();
+        assert_eq!(Payload::from_sprint_results(results.clone()), from_payload(&RACE_2023_4_SPRINT_RESULTS));
+
+        let results = RACE_2023_4_RACE_RESULTS.payload.as_race_results().unwrap();
+        assert_eq!(Payload::from_race_results(results.clone()), from_payload(&RACE_2023_4_RACE_RESULTS));
+
+        let laps = RACE_2023_4_LAPS.payload.as_laps().unwrap();
+        assert_eq!(Payload::from_laps(laps.clone()), from_payload(&RACE_2023_4_LAPS));
+
+        let pit_stops = RACE_2023_4_PIT_STOPS.payload.as_pit_stops().unwrap();
+        assert_eq!(Payload::from_pit_stops(pit_stops.clone()), from_payload(&RACE_2023_4_PIT_STOPS));
+    }
+
     #[test]
     fn payload_deserialize_helpful_errors() {
         static GOOD_STR: &str = formatcp!(r#"{{"QualifyingResults": [{QUALIFYING_RESULT_2023_4_P1_STR}]}}"#);
@@ -2374,8 +4485,12 @@ mod tests {
         let p = serde_json::from_str::<Payload>(GOOD_STR);
         assert_eq!(p.unwrap().as_qualifying_results().unwrap()[0], *QUALIFYING_RESULT_2023_4_P1);
 
-        let p = serde_json::from_str::<Payload>(BAD_STR);
-        assert_true!(p.unwrap_err().to_string().contains("missing field `number`"));
+        let err = serde_json::from_str::<Payload>(BAD_STR).unwrap_err().to_string();
+        if cfg!(feature = "strict-parse") {
+            assert_true!(err.contains("unknown field `key`"));
+        } else {
+            assert_true!(err.contains("missing field `number`"));
+        }
     }
 
     #[test]
@@ -2438,6 +4553,102 @@ mod tests {
         assert_eq!(actual.into_race_result(), expected[0]);
     }
 
+    #[test]
+    fn race_result_car_number() {
+        assert_eq!(RACE_RESULT_2023_4_P1.car_number(), Some(11));
+        assert_eq!(RACE_RESULT_1963_10_P23.number, RaceResult::NO_NUMBER);
+        assert_true!(RACE_RESULT_1963_10_P23.car_number().is_none());
+    }
+
+    #[test]
+    fn race_result_positions_gained() {
+        assert_eq!(RACE_RESULT_2023_4_P1.positions_gained(), Some(2));
+        assert_eq!(RACE_RESULT_2023_4_P2.positions_gained(), Some(0));
+        assert_eq!(RACE_RESULT_2023_4_P20.positions_gained(), Some(-2));
+        assert_true!(RACE_RESULT_1963_10_P23.positions_gained().is_none());
+    }
+
+    #[test]
+    fn race_result_is_dnf() {
+        assert_false!(RACE_RESULT_2023_4_P1.is_dnf());
+        assert_true!(RACE_RESULT_2023_4_P20.is_dnf());
+        assert_true!(RACE_RESULT_2003_4_P19.is_dnf());
+        assert_false!(RACE_RESULT_1963_10_P23.is_dnf());
+
+        let with_position = |position_text| RaceResult {
+            position_text,
+            ..RACE_RESULT_2023_4_P1.clone()
+        };
+        assert_true!(with_position(Position::N).is_dnf());
+        assert_false!(with_position(Position::D).is_dnf());
+        assert_false!(with_position(Position::E).is_dnf());
+        assert_false!(with_position(Position::F).is_dnf());
+    }
+
+    #[test]
+    fn lenient_parsing() {
+        let from_str = |str: &str| serde_json::from_str::<RaceResult>(str);
+
+        let bad_grid = RACE_RESULT_2023_4_P1_STR.replace(r#""grid": "3""#, r#""grid": """#);
+        let bad_number = RACE_RESULT_2023_4_P1_STR.replace(r#""number": "11""#, r#""number": "x""#);
+        let bad_rank = RACE_RESULT_2023_4_P1_STR.replace(r#""rank": "5""#, r#""rank": "-""#);
+        let bad_permanent_number =
+            RACE_RESULT_2023_4_P1_STR.replace(r#""permanentNumber": "11""#, r#""permanentNumber": """#);
+
+        if cfg!(feature = "lenient-parsing") {
+            assert_eq!(from_str(&bad_grid).unwrap().grid, 0);
+            assert_eq!(from_str(&bad_number).unwrap().number, RaceResult::NO_NUMBER);
+            assert_true!(from_str(&bad_rank).unwrap().fastest_lap.unwrap().rank.is_none());
+            assert_true!(
+                from_str(&bad_permanent_number)
+                    .unwrap()
+                    .driver
+                    .permanent_number
+                    .is_none()
+            );
+        } else {
+            assert_true!(from_str(&bad_grid).is_err());
+            assert_true!(from_str(&bad_number).is_err());
+            assert_true!(from_str(&bad_rank).is_err());
+            assert_true!(from_str(&bad_permanent_number).is_err());
+        }
+
+        // Fields needed to process the response are always parsed strictly
+        let bad_season = RACE_2023_4_RACE_RESULTS_STR.replace(r#""season": "2023""#, r#""season": """#);
+        assert_true!(serde_json::from_str::<Race>(&bad_season).is_err());
+    }
+
+    #[test]
+    fn strict_parse() {
+        let with_extra = |str: &str, field: &str| str.replacen(field, &format!(r#"{field}, "extra": "x""#), 1);
+
+        let season = with_extra(SEASON_1950_STR, r#""season": "1950""#);
+        let driver = with_extra(RACE_RESULT_2023_4_P1_STR, r#""permanentNumber": "11""#);
+        let result = with_extra(RACE_RESULT_2023_4_P1_STR, r#""grid": "3""#);
+        let schedule = RACE_2023_4_SCHEDULE_STR.replacen(
+            r#""FirstPractice""#,
+            r#""FourthPractice": {"date": "2023-04-28"}, "FirstPractice""#,
+            1,
+        );
+
+        let season = serde_json::from_str::<Season>(&season);
+        let driver = serde_json::from_str::<RaceResult>(&driver);
+        let result = serde_json::from_str::<RaceResult>(&result);
+        let schedule = serde_json::from_str::<Race>(&schedule);
+
+        if cfg!(feature = "strict-parse") {
+            assert_true!(season.unwrap_err().to_string().contains("unknown field `extra`"));
+            assert_true!(driver.unwrap_err().to_string().contains("unknown field `extra`"));
+            assert_true!(result.unwrap_err().to_string().contains("unknown field `extra`"));
+            assert_true!(schedule.is_err());
+        } else {
+            assert_eq!(season.unwrap(), *SEASON_1950);
+            assert_eq!(driver.unwrap(), *RACE_RESULT_2023_4_P1);
+            assert_eq!(result.unwrap(), *RACE_RESULT_2023_4_P1);
+            assert_eq!(schedule.unwrap(), *RACE_2023_4_SCHEDULE);
+        }
+    }
+
     #[test]
     fn deserialize_possible_no_number() {
         #[derive(Deserialize, Debug)]
@@ -2482,6 +4693,31 @@ mod tests {
         assert_true!(serde_json::from_str::<Position>("\"unknown\"").is_err());
     }
 
+    #[test]
+    fn position_display_from_str() {
+        let positions = [
+            (Position::Finished(1), "1"),
+            (Position::Finished(20), "20"),
+            (Position::R, "R"),
+            (Position::D, "D"),
+            (Position::E, "E"),
+            (Position::W, "W"),
+            (Position::F, "F"),
+            (Position::N, "N"),
+        ];
+
+        for (position, str) in positions {
+            assert_eq!(position.to_string(), str);
+            assert_eq!(str.parse::<Position>().unwrap(), position);
+            assert_eq!(serde_json::from_str::<Position>(&format!("\"{str}\"")).unwrap(), position);
+        }
+
+        assert!(matches!("unknown".parse::<Position>(), Err(Error::UnexpectedData(_))));
+        assert!(matches!("".parse::<Position>(), Err(Error::UnexpectedData(_))));
+        assert!(matches!("-1".parse::<Position>(), Err(Error::UnexpectedData(_))));
+        assert!(matches!("r".parse::<Position>(), Err(Error::UnexpectedData(_))));
+    }
+
     // Response tests
     // --------------
 
@@ -2556,6 +4792,21 @@ mod tests {
         assert_eq!(lhs.to_info(), rhs.to_info());
     }
 
+    #[test]
+    fn response_info_from() {
+        let response = RESPONSE_SEASONS_NONE.clone();
+        let info = ResponseInfo::from(&response);
+
+        assert_eq!(info.xmlns, response.xmlns);
+        assert_eq!(info.series, response.series);
+        assert_eq!(info.url, response.url);
+        assert_eq!(info, response.to_info());
+
+        let mut set = HashSet::new();
+        assert_true!(set.insert(info.clone()));
+        assert_false!(set.insert(RESPONSE_DRIVERS_ONE.to_info()));
+    }
+
     #[test]
     fn response_as_to_info() {
         let lhs = RESPONSE_SEASONS_NONE.clone();
@@ -2598,7 +4849,13 @@ mod tests {
 
     #[test]
     fn response_into_seasons_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_DRIVERS_NONE.clone().into_seasons(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_DRIVERS_NONE.clone().into_seasons(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Seasons,
+                actual: TableKind::Drivers
+            })
+        ));
     }
 
     #[test]
@@ -2608,12 +4865,18 @@ mod tests {
 
     #[test]
     fn response_into_season_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_DRIVERS_NONE.clone().into_season(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_DRIVERS_NONE.clone().into_season(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Seasons,
+                actual: TableKind::Drivers
+            })
+        ));
     }
 
     #[test]
     fn response_into_season_error_not_found() {
-        assert!(matches!(RESPONSE_SEASONS_NONE.clone().into_season(), Err(Error::NotFound)));
+        assert!(matches!(RESPONSE_SEASONS_NONE.clone().into_season(), Err(Error::NotFound(_))));
     }
 
     #[test]
@@ -2630,7 +4893,13 @@ mod tests {
 
     #[test]
     fn response_as_seasons_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_DRIVERS_NONE.as_seasons(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_DRIVERS_NONE.as_seasons(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Seasons,
+                actual: TableKind::Drivers
+            })
+        ));
     }
 
     #[test]
@@ -2642,12 +4911,18 @@ mod tests {
 
     #[test]
     fn response_as_season_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_DRIVERS_NONE.as_season(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_DRIVERS_NONE.as_season(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Seasons,
+                actual: TableKind::Drivers
+            })
+        ));
     }
 
     #[test]
     fn response_as_season_error_not_found() {
-        assert!(matches!(RESPONSE_SEASONS_NONE.as_season(), Err(Error::NotFound)));
+        assert!(matches!(RESPONSE_SEASONS_NONE.as_season(), Err(Error::NotFound(_))));
     }
 
     #[test]
@@ -2668,7 +4943,13 @@ mod tests {
 
     #[test]
     fn response_into_drivers_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_NONE.clone().into_drivers(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_NONE.clone().into_drivers(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Drivers,
+                actual: TableKind::Seasons
+            })
+        ));
     }
 
     #[test]
@@ -2678,12 +4959,18 @@ mod tests {
 
     #[test]
     fn response_into_driver_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_NONE.clone().into_driver(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_NONE.clone().into_driver(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Drivers,
+                actual: TableKind::Seasons
+            })
+        ));
     }
 
     #[test]
     fn response_into_driver_error_not_found() {
-        assert!(matches!(RESPONSE_DRIVERS_NONE.clone().into_driver(), Err(Error::NotFound)));
+        assert!(matches!(RESPONSE_DRIVERS_NONE.clone().into_driver(), Err(Error::NotFound(_))));
     }
 
     #[test]
@@ -2700,7 +4987,13 @@ mod tests {
 
     #[test]
     fn response_as_drivers_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_NONE.as_drivers(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_NONE.as_drivers(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Drivers,
+                actual: TableKind::Seasons
+            })
+        ));
     }
 
     #[test]
@@ -2712,16 +5005,236 @@ mod tests {
 
     #[test]
     fn response_as_driver_error_bad_table_variant() {
-        assert!(matches!(RESPONSE_NONE.as_driver(), Err(Error::BadTableVariant)));
+        assert!(matches!(
+            RESPONSE_NONE.as_driver(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Drivers,
+                actual: TableKind::Seasons
+            })
+        ));
     }
 
     #[test]
     fn response_as_driver_error_not_found() {
-        assert!(matches!(RESPONSE_DRIVERS_NONE.as_driver(), Err(Error::NotFound)));
+        assert!(matches!(RESPONSE_DRIVERS_NONE.as_driver(), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn table_kind() {
+        assert_eq!(RESPONSE_SEASONS_NONE.table.kind(), TableKind::Seasons);
+        assert_eq!(RESPONSE_DRIVERS_NONE.table.kind(), TableKind::Drivers);
+        assert_eq!(Table::Races { races: vec![] }.kind(), TableKind::Races);
+        assert_eq!(Table::Status { status: vec![] }.kind(), TableKind::Status);
+    }
+
+    #[test]
+    fn table_from_inner_list() {
+        let seasons = SEASON_TABLE.as_seasons().unwrap();
+        assert_eq!(Table::from_seasons(seasons.iter().cloned()), *SEASON_TABLE);
+
+        let drivers = DRIVER_TABLE.as_drivers().unwrap();
+        assert_eq!(Table::from_drivers(drivers.clone()), *DRIVER_TABLE);
+
+        let constructors = CONSTRUCTOR_TABLE.as_constructors().unwrap();
+        assert_eq!(Table::from_constructors(constructors.clone()), *CONSTRUCTOR_TABLE);
+
+        let circuits = CIRCUIT_TABLE.as_circuits().unwrap();
+        assert_eq!(Table::from_circuits(circuits.clone()), *CIRCUIT_TABLE);
+
+        assert_eq!(
+            Table::from_races([RACE_2023_4_LAPS.clone()]),
+            Table::Races {
+                races: vec![RACE_2023_4_LAPS.clone()]
+            }
+        );
+        assert_eq!(Table::from_status([]), Table::Status { status: vec![] });
+    }
+
+    #[test]
+    fn response_verify_matches() {
+        assert_true!(
+            RESPONSE_DRIVERS_ONE
+                .verify_matches(&Resource::DriverInfo(Filters::none()))
+                .is_ok()
+        );
+        assert_true!(
+            RESPONSE_SEASONS_NONE
+                .verify_matches(&Resource::SeasonList(Filters::none()))
+                .is_ok()
+        );
+
+        assert!(matches!(
+            RESPONSE_DRIVERS_ONE.verify_matches(&Resource::SeasonList(Filters::none())),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Seasons,
+                actual: TableKind::Drivers
+            })
+        ));
+        assert!(matches!(
+            RESPONSE_SEASONS_NONE.verify_matches(&Resource::RaceResults(Filters::none())),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Races,
+                actual: TableKind::Seasons
+            })
+        ));
     }
 
     #[test]
     fn response_as_driver_error_too_many() {
         assert!(matches!(RESPONSE_DRIVERS_TWO.as_driver(), Err(Error::TooMany)));
     }
+
+    #[test]
+    fn payload_is_empty_schedule() {
+        assert_true!(RACE_2003_4.payload.is_empty_schedule());
+        assert_false!(RACE_2023_4_SCHEDULE.payload.is_empty_schedule());
+        assert_false!(RACE_2023_4_RACE_RESULTS.payload.is_empty_schedule());
+        assert_false!(Payload::RaceResults(vec![]).is_empty_schedule());
+    }
+
+    #[test]
+    fn schedule_has_sprint() {
+        let has_sprint = |race: &Race| race.payload.as_schedule().unwrap().has_sprint();
+
+        assert_false!(SCHEDULE_NONE.has_sprint());
+        assert_false!(has_sprint(&RACE_2003_4_SCHEDULE));
+        assert_false!(has_sprint(&RACE_2015_11_SCHEDULE));
+
+        assert_true!(has_sprint(&RACE_2022_4_SCHEDULE));
+        assert_true!(has_sprint(&RACE_2023_4_SCHEDULE));
+        assert_true!(has_sprint(&RACE_2024_5_SCHEDULE));
+
+        let sprint_time = RACE_2024_5_SCHEDULE.payload.as_schedule().unwrap().sprint;
+        assert_true!(
+            Schedule {
+                sprint_shootout: sprint_time,
+                ..*SCHEDULE_NONE
+            }
+            .has_sprint()
+        );
+        assert_true!(
+            Schedule {
+                sprint_qualifying: sprint_time,
+                ..*SCHEDULE_NONE
+            }
+            .has_sprint()
+        );
+    }
+
+    #[test]
+    fn schedule_sessions() {
+        let kinds = |race: &Race| {
+            race.payload
+                .as_schedule()
+                .unwrap()
+                .sessions()
+                .map(|(kind, _)| kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_true!(SCHEDULE_NONE.sessions().next().is_none());
+        assert_true!(kinds(&RACE_2003_4_SCHEDULE).is_empty());
+
+        assert_eq!(
+            kinds(&RACE_2015_11_SCHEDULE),
+            vec![
+                SessionKind::FirstPractice,
+                SessionKind::SecondPractice,
+                SessionKind::ThirdPractice,
+                SessionKind::Qualifying
+            ]
+        );
+
+        // Qualifying on Friday, before the second practice and sprint on Saturday
+        assert_eq!(
+            kinds(&RACE_2022_4_SCHEDULE),
+            vec![
+                SessionKind::FirstPractice,
+                SessionKind::Qualifying,
+                SessionKind::SecondPractice,
+                SessionKind::Sprint
+            ]
+        );
+
+        // Sprint shootout in 2023, and sprint qualifying in 2024, map to the same session kind
+        let sprint_weekend = vec![
+            SessionKind::FirstPractice,
+            SessionKind::Qualifying,
+            SessionKind::SprintQualifying,
+            SessionKind::Sprint,
+        ];
+        assert_eq!(kinds(&RACE_2023_4_SCHEDULE), sprint_weekend);
+
+        let sprint_weekend = vec![
+            SessionKind::FirstPractice,
+            SessionKind::SprintQualifying,
+            SessionKind::Sprint,
+            SessionKind::Qualifying,
+        ];
+        assert_eq!(kinds(&RACE_2024_5_SCHEDULE), sprint_weekend);
+
+        let sessions: Vec<_> = RACE_2024_5_SCHEDULE.payload.as_schedule().unwrap().sessions().collect();
+        assert_eq!(
+            sessions[1],
+            (
+                SessionKind::SprintQualifying,
+                DateTime {
+                    date: date!(2024 - 04 - 19),
+                    time: Some(time!(07:30:00)),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn response_into_session_results_empty_schedule_error() {
+        let response = make_response_with_table(Table::Races {
+            races: vec![RACE_2003_4.clone()],
+        });
+
+        let Err(Error::UnexpectedData(message)) = response.into_many_races_with_many_session_results::<RaceResult>()
+        else {
+            panic!("Expected Error::UnexpectedData");
+        };
+        assert_true!(message.contains("RaceResults"));
+        assert_true!(message.contains("empty result set"));
+
+        let response = make_response_with_table(Table::Races {
+            races: vec![RACE_2023_4_SCHEDULE.clone()],
+        });
+        assert!(matches!(
+            response.into_one_race_with_many_session_results::<RaceResult>(),
+            Err(Error::BadPayloadVariant)
+        ));
+    }
+
+    #[test]
+    fn response_into_laps() {
+        let response = make_response_with_table(Table::Races {
+            races: vec![RACE_2023_4_LAPS.clone()],
+        });
+        assert_eq!(response.into_laps().unwrap(), vec![LAP_2023_4_L1.clone(), LAP_2023_4_L2.clone()]);
+    }
+
+    #[test]
+    fn response_into_laps_error() {
+        assert!(matches!(
+            RESPONSE_NONE.clone().into_laps(),
+            Err(Error::BadTableVariant {
+                expected: TableKind::Races,
+                actual: TableKind::Seasons
+            })
+        ));
+        assert!(matches!(
+            make_response_with_table(Table::Races { races: vec![] }).into_laps(),
+            Err(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            make_response_with_table(Table::Races {
+                races: vec![RACE_2023_4_PIT_STOPS.clone()]
+            })
+            .into_laps(),
+            Err(Error::BadPayloadVariant)
+        ));
+    }
 }
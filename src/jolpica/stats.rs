@@ -0,0 +1,1089 @@
+//! Types and functions for computing statistics from data fetched from the jolpica-f1 API.
+//!
+//! These operate purely on the types from the [`response`] module, e.g. as returned by the
+//! [`Agent`]'s `get_*` methods, and do not make any requests themselves.
+
+use std::{cmp::Ordering, collections::HashMap, ops::RangeInclusive};
+
+use crate::{
+    id::{ConstructorID, DriverID, RoundID},
+    jolpica::{
+        response::{
+            Lap, PitStop, Points, Position, QualifyingResult, Race, RaceInfo, RaceResult, Schedule, SprintResult,
+            fastest_pit_stop,
+        },
+        time::Duration,
+    },
+};
+
+#[cfg(doc)]
+use crate::jolpica::{agent::Agent, resource::Filters, response, response::Timing};
+
+/// A lap-by-lap view of the running order of a race, built from a list of [`Lap`]s.
+///
+/// The [`Lap`]s are typically as returned by [`Agent::get_laps`]. This allows lookups of each
+/// driver's position at the end of each lap, the leader of each lap, etc.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LapMatrix {
+    laps: Vec<Lap>,
+}
+
+impl LapMatrix {
+    /// Creates a new [`LapMatrix`] from a list of [`Lap`]s, which are sorted by [`Lap::number`].
+    pub fn new(mut laps: Vec<Lap>) -> Self {
+        laps.sort_by_key(|lap| lap.number);
+        Self { laps }
+    }
+
+    /// Returns the underlying list of [`Lap`]s, sorted by [`Lap::number`].
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+
+    /// Returns the number of [`Lap`]s in this [`LapMatrix`].
+    pub const fn lap_count(&self) -> usize {
+        self.laps.len()
+    }
+
+    /// Returns the [`Lap`] with the given `lap` number, if present.
+    pub fn lap(&self, lap: u32) -> Option<&Lap> {
+        self.laps.iter().find(|l| l.number == lap)
+    }
+
+    /// Returns the position of the driver with `driver_id` at the end of the given `lap`, if the
+    /// driver has a [`Timing`] for that lap.
+    pub fn position(&self, driver_id: &DriverID, lap: u32) -> Option<u32> {
+        self.lap(lap)?
+            .timings
+            .iter()
+            .find(|timing| &timing.driver_id == driver_id)
+            .map(|timing| timing.position)
+    }
+
+    /// Returns the [`DriverID`] of the driver in P1 at the end of the given `lap`, if any.
+    pub fn leader(&self, lap: u32) -> Option<&DriverID> {
+        self.lap(lap)?
+            .timings
+            .iter()
+            .find(|timing| timing.position == 1)
+            .map(|timing| &timing.driver_id)
+    }
+
+    /// Returns `true` if the driver with `driver_id` was in P1 at the end of every lap in this
+    /// [`LapMatrix`]. Returns `false` if there are no laps.
+    pub fn led_every_lap(&self, driver_id: &DriverID) -> bool {
+        !self.laps.is_empty() && self.laps.iter().all(|lap| self.leader(lap.number) == Some(driver_id))
+    }
+
+    /// Returns each driver's position trajectory through the race, i.e. a list of `(lap, position)`
+    /// pairs, in lap order, for every lap in which the driver has a [`Timing`], e.g. to draw a race
+    /// position chart.
+    ///
+    /// A driver that retired during the race has no [`Timing`]s after their last completed lap, so
+    /// their trajectory simply ends there, i.e. it may be shorter than [`lap_count`](Self::lap_count).
+    pub fn position_trajectories(&self) -> HashMap<DriverID, Vec<(u32, u32)>> {
+        let mut trajectories: HashMap<DriverID, Vec<(u32, u32)>> = HashMap::new();
+
+        for lap in &self.laps {
+            for timing in &lap.timings {
+                trajectories
+                    .entry(timing.driver_id.clone())
+                    .or_default()
+                    .push((lap.number, timing.position));
+            }
+        }
+
+        trajectories
+    }
+}
+
+/// A season summary table of the results of each driver in each round, built from a list of races.
+///
+/// The races are typically as returned by [`Agent::get_race_results`] with a [`Filters::season`],
+/// and can be built via [`season_position_matrix`]. Rows are drivers and columns are rounds, with
+/// each cell holding the driver's [`RaceResult::position_text`] in that round, if any.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct PositionMatrix {
+    drivers: Vec<DriverID>,
+    rounds: Vec<RoundID>,
+    positions: HashMap<(DriverID, RoundID), Position>,
+}
+
+impl PositionMatrix {
+    /// Returns the list of drivers, i.e. the rows, in order of their first appearance in the
+    /// results, i.e. by round, and then by [`RaceResult::position`] within that round.
+    pub fn drivers(&self) -> &[DriverID] {
+        &self.drivers
+    }
+
+    /// Returns the list of rounds, i.e. the columns, in ascending order.
+    pub fn rounds(&self) -> &[RoundID] {
+        &self.rounds
+    }
+
+    /// Returns the [`Position`] of the driver with `driver_id` in the given `round`, or [`None`] if
+    /// the driver did not take part in that round, or if there are no results for that round.
+    pub fn position(&self, driver_id: &DriverID, round: RoundID) -> Option<Position> {
+        self.positions.get(&(driver_id.clone(), round)).copied()
+    }
+}
+
+/// Builds a [`PositionMatrix`] of drivers by rounds from a list of races, e.g. a season's results.
+///
+/// **Note:** The `races` are expected to be from a single season, e.g. as returned by
+/// [`Agent::get_race_results`] with a [`Filters::season`], as they are indexed by [`Race::round`].
+pub fn season_position_matrix(races: &[Race<Vec<RaceResult>>]) -> PositionMatrix {
+    let mut races: Vec<_> = races.iter().collect();
+    races.sort_by_key(|race| race.round);
+
+    let mut matrix = PositionMatrix::default();
+
+    for race in races {
+        matrix.rounds.push(race.round);
+
+        let mut results: Vec<_> = race.race_results().iter().collect();
+        results.sort_by_key(|result| result.position);
+
+        for result in results {
+            let driver_id = &result.driver.driver_id;
+
+            if !matrix.drivers.contains(driver_id) {
+                matrix.drivers.push(driver_id.clone());
+            }
+
+            let _unused = matrix
+                .positions
+                .insert((driver_id.clone(), race.round), result.position_text);
+        }
+    }
+
+    matrix
+}
+
+/// Returns `true` if the driver with `driver_id` achieved a "grand slam" in a race, i.e. they took
+/// pole position, won the race, set the fastest lap, and led every lap.
+///
+/// Pole position is taken from `qualifying`, the win and fastest lap, i.e. [`FastestLap::rank`] of
+/// `Some(1)`, are taken from `race_result`, and leading every lap is checked via `lap_matrix`, which
+/// must also cover all of the [`RaceResult::laps`] completed by the driver. Returns `false` if
+/// `race_result` or `qualifying` do not belong to `driver_id`.
+///
+/// [`FastestLap::rank`]: crate::jolpica::response::FastestLap::rank
+pub fn is_grand_slam(
+    race_result: &RaceResult,
+    qualifying: &QualifyingResult,
+    lap_matrix: &LapMatrix,
+    driver: &DriverID,
+) -> bool {
+    &race_result.driver.driver_id == driver
+        && &qualifying.driver.driver_id == driver
+        && qualifying.position == 1
+        && race_result.position == 1
+        && race_result.fastest_lap.and_then(|fastest_lap| fastest_lap.rank) == Some(1)
+        && usize::try_from(race_result.laps).is_ok_and(|laps| laps == lap_matrix.lap_count())
+        && lap_matrix.led_every_lap(driver)
+}
+
+/// Returns the drivers' championship standings after a given `round`, derived from the results.
+///
+/// The points from all `races` and `sprints` with a [`Race::round`] up to and including `round`
+/// are summed for each driver, and the resulting list is sorted by points in descending order.
+/// Drivers tied on points are ordered by count-back of their race finishing positions, i.e. the
+/// driver with the most wins is ahead, then the most second places, and so on. Any drivers still
+/// tied are ordered by their [`DriverID`], so that the result is deterministic.
+///
+/// **Note:** The `races` and `sprints` are expected to be from a single season, e.g. as returned by
+/// [`Agent::get_race_results`] and [`Agent::get_sprint_results`] with a [`Filters::season`]. This
+/// does not account for any points deductions, shared drives, or other special cases that may be
+/// reflected in the official standings.
+pub fn standings_after_round(
+    races: &[Race<Vec<RaceResult>>],
+    sprints: &[Race<Vec<SprintResult>>],
+    round: RoundID,
+) -> Vec<(DriverID, Points)> {
+    let mut points: HashMap<&DriverID, Points> = HashMap::new();
+    let mut finishes: HashMap<&DriverID, Vec<u32>> = HashMap::new();
+
+    for race in races.iter().filter(|race| race.round <= round) {
+        for result in race.race_results() {
+            *points.entry(&result.driver.driver_id).or_default() += result.points;
+            finishes
+                .entry(&result.driver.driver_id)
+                .or_default()
+                .push(result.position);
+        }
+    }
+
+    for sprint in sprints.iter().filter(|sprint| sprint.round <= round) {
+        for result in sprint.sprint_results() {
+            *points.entry(&result.driver.driver_id).or_default() += result.points;
+        }
+    }
+
+    for positions in finishes.values_mut() {
+        positions.sort_unstable();
+    }
+
+    let mut standings: Vec<_> = points.into_iter().collect();
+    standings.sort_by(|(lhs_id, lhs_points), (rhs_id, rhs_points)| {
+        rhs_points
+            .total_cmp(lhs_points)
+            .then_with(|| compare_count_back(finishes.get(lhs_id), finishes.get(rhs_id)))
+            .then_with(|| lhs_id.cmp(rhs_id))
+    });
+
+    standings
+        .into_iter()
+        .map(|(driver_id, points)| (driver_id.clone(), points))
+        .collect()
+}
+
+/// Compares two drivers' sorted finishing positions by count-back, where [`Ordering::Less`] means
+/// that `lhs` is ahead, i.e. it has more wins, or more second places if tied on wins, and so on.
+fn compare_count_back(lhs: Option<&Vec<u32>>, rhs: Option<&Vec<u32>>) -> Ordering {
+    let (lhs, rhs) = (lhs.map_or(&[][..], Vec::as_slice), rhs.map_or(&[][..], Vec::as_slice));
+
+    lhs.iter()
+        .zip(rhs)
+        .map(|(lhs, rhs)| lhs.cmp(rhs))
+        .find(|ordering| ordering.is_ne())
+        // If one is a prefix of the other, the one with more finishes is ahead on count-back
+        .unwrap_or_else(|| rhs.len().cmp(&lhs.len()))
+}
+
+/// Returns the `races` in which the [`RaceResult`] is a finish within the inclusive range of
+/// `positions`, e.g. `1..=3` for podium finishes, or `1..=10` for points finishes.
+///
+/// The `races` are typically as returned by [`Agent::get_race_result_for_events`] for a driver, via
+/// [`Filters::driver_id`]. Since [`Filters::finish_pos`] can only filter by a single position,
+/// this can be used to instead filter by a range of positions after the results are fetched.
+///
+/// **Note:** Consistent with [`Filters::finish_pos`], only results where the driver finished the
+/// race match, i.e. where [`RaceResult::position_text`] is [`Position::Finished`]. Non-finishing
+/// results, e.g. [`Position::Retired`], never match, even though their [`RaceResult::position`]
+/// may be within the range.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use f1_data::jolpica::{agent::Agent, resource::Filters, stats::finishes_in_range};
+/// # let jolpica = Agent::default();
+/// #
+/// let races = jolpica
+///     .get_race_result_for_events(Filters::new().season(2023).driver_id("max_verstappen".into()))
+///     .unwrap();
+///
+/// let podiums = finishes_in_range(&races, 1..=3);
+/// assert_eq!(podiums.len(), 21);
+/// ```
+pub fn finishes_in_range(races: &[Race<RaceResult>], positions: RangeInclusive<u32>) -> Vec<&Race<RaceResult>> {
+    races
+        .iter()
+        .filter(|race| matches!(race.race_result().position_text, Position::Finished(pos) if positions.contains(&pos)))
+        .collect()
+}
+
+/// Returns the "did not finish" rate of each constructor across all `races`, i.e. the fraction of
+/// their entries that did not finish, from `0.0` to `1.0`.
+///
+/// For each constructor, the denominator is the number of [`RaceResult`]s across all `races`,
+/// i.e. one per car entered in each race, and the numerator is the number of those for which
+/// [`RaceResult::is_dnf`] is `true`. Constructors with no entries are not included.
+///
+/// **Note:** All entries are counted in the denominator, including those that did not start, e.g.
+/// [`Position::Withdrawn`], which may slightly lower the rate for some historical seasons.
+///
+/// [`Position::Withdrawn`]: crate::jolpica::response::Position::Withdrawn
+pub fn dnf_rate(races: &[Race<Vec<RaceResult>>]) -> HashMap<ConstructorID, f32> {
+    let mut counts: HashMap<&ConstructorID, (u32, u32)> = HashMap::new();
+
+    for result in races.iter().flat_map(Race::race_results) {
+        let (entries, dnfs) = counts.entry(&result.constructor.constructor_id).or_default();
+        *entries += 1;
+        *dnfs += u32::from(result.is_dnf());
+    }
+
+    counts
+        .into_iter()
+        .map(|(constructor_id, (entries, dnfs))| {
+            #[allow(clippy::cast_precision_loss)]
+            (constructor_id.clone(), dnfs as f32 / entries as f32)
+        })
+        .collect()
+}
+
+/// Returns the `top_n` results with the most positions gained across all `races`, e.g. the best
+/// comeback drives of a season.
+///
+/// Positions gained are computed via [`RaceResult::positions_gained`]. Each result is returned
+/// along with its [`Race`], without the payload, sorted by positions gained in descending order.
+/// Results with the same number of positions gained are kept in the order of the `races` and their
+/// results. Results that did not gain any positions are excluded.
+///
+/// Results with a [`RaceResult::grid`] of `0`, e.g. pit lane starts, are excluded, unless
+/// `include_pit_starts` is `true`, in which case they are treated as starting from the back of the
+/// grid, i.e. a grid position equal to the number of results in that race.
+///
+/// **Note:** Positions gained are based on the classified [`RaceResult::position`], so a driver who
+/// did not finish may still be included, e.g. if they were classified ahead of their grid position.
+pub fn best_comebacks(
+    races: &[Race<Vec<RaceResult>>],
+    top_n: usize,
+    include_pit_starts: bool,
+) -> Vec<(Race<()>, RaceResult)> {
+    let mut comebacks: Vec<_> = races
+        .iter()
+        .flat_map(|race| {
+            let back_of_grid = i64::try_from(race.race_results().len()).unwrap_or(i64::MAX);
+
+            race.race_results().iter().filter_map(move |result| {
+                let gained = result
+                    .positions_gained()
+                    .or_else(|| include_pit_starts.then(|| back_of_grid - i64::from(result.position)))?;
+                (gained > 0).then_some((gained, race, result))
+            })
+        })
+        .collect();
+
+    comebacks.sort_by_key(|(gained, ..)| std::cmp::Reverse(*gained));
+
+    comebacks
+        .into_iter()
+        .take(top_n)
+        .map(|(_, race, result)| (without_payload(race), result.clone()))
+        .collect()
+}
+
+/// Returns a copy of the `race` with an empty `()` payload, i.e. only its event information.
+fn without_payload<T>(race: &Race<T>) -> Race<()> {
+    let RaceInfo {
+        season,
+        round,
+        url,
+        race_name,
+        circuit,
+        date,
+        time,
+    } = race.to_info();
+
+    Race {
+        season,
+        round,
+        url,
+        race_name,
+        circuit,
+        date,
+        time,
+        payload: (),
+    }
+}
+
+/// Aggregate statistics of the [`PitStop`]s of a race, e.g. the fastest stop, and the minimum,
+/// maximum, and mean [`PitStop::duration`].
+///
+/// The [`PitStop`]s are typically as returned by [`Agent::get_pit_stops`] for a single race, and
+/// the statistics can be computed via [`pit_stop_stats`].
+///
+/// **Note:** [`PitStop::duration`] includes the pit lane transit, not just the stationary time in
+/// the pit box, so these statistics reflect the total time spent in the pit lane.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct PitStopStats {
+    fastest: PitStop,
+    max_duration: Duration,
+    mean_duration: Duration,
+    stop_counts: HashMap<DriverID, u32>,
+}
+
+impl PitStopStats {
+    /// Returns the fastest [`PitStop`], as per [`fastest_pit_stop`].
+    pub const fn fastest(&self) -> &PitStop {
+        &self.fastest
+    }
+
+    /// Returns the shortest [`PitStop::duration`], i.e. that of [`PitStopStats::fastest`].
+    pub const fn min_duration(&self) -> Duration {
+        self.fastest.duration
+    }
+
+    /// Returns the longest [`PitStop::duration`].
+    pub const fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// Returns the mean [`PitStop::duration`] across all pit stops.
+    pub const fn mean_duration(&self) -> Duration {
+        self.mean_duration
+    }
+
+    /// Returns the total number of pit stops across all drivers.
+    pub fn stop_count(&self) -> u32 {
+        self.stop_counts.values().sum()
+    }
+
+    /// Returns the number of pit stops made by each driver, keyed by [`PitStop::driver_id`].
+    pub const fn stop_counts(&self) -> &HashMap<DriverID, u32> {
+        &self.stop_counts
+    }
+
+    /// Returns the number of pit stops made by the driver with `driver_id`, i.e. `0` if none.
+    pub fn driver_stop_count(&self, driver_id: &DriverID) -> u32 {
+        self.stop_counts.get(driver_id).copied().unwrap_or_default()
+    }
+}
+
+/// Computes the [`PitStopStats`] of a list of [`PitStop`]s, e.g. of a single race, or returns
+/// [`None`] if the list is empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use f1_data::{id::DriverID, jolpica::{agent::Agent, resource::PitStopFilters, stats}};
+/// # let jolpica = Agent::default();
+/// #
+/// let stops = jolpica.get_pit_stops(PitStopFilters::new(2023, 4)).unwrap();
+/// let stats = stats::pit_stop_stats(&stops).unwrap();
+///
+/// assert!(stats.min_duration() <= stats.mean_duration());
+/// assert!(stats.mean_duration() <= stats.max_duration());
+/// assert_eq!(stats.driver_stop_count(&DriverID::from("max_verstappen")), 1);
+/// assert_eq!(stats.fastest().duration, stats.min_duration());
+/// ```
+pub fn pit_stop_stats(stops: &[PitStop]) -> Option<PitStopStats> {
+    let fastest = fastest_pit_stop(stops)?.clone();
+    let max_duration = stops.iter().map(|stop| stop.duration).max()?;
+
+    let total: Duration = stops.iter().map(|stop| stop.duration).sum();
+    let mean_duration = total / u32::try_from(stops.len()).unwrap_or(u32::MAX);
+
+    let mut stop_counts = HashMap::new();
+    for stop in stops {
+        *stop_counts.entry(stop.driver_id.clone()).or_default() += 1;
+    }
+
+    Some(PitStopStats {
+        fastest,
+        max_duration,
+        mean_duration,
+        stop_counts,
+    })
+}
+
+/// Returns the average signed gap between the best qualifying lap times of two drivers, e.g.
+/// teammates, as `a` minus `b`, across the rounds in which both drivers set a lap time.
+///
+/// The `a` and `b` lists are typically as returned by [`Agent::get_qualifying_result_for_events`]
+/// for each driver, via [`Filters::driver_id`]. Rounds are matched by [`Race::id`], and each
+/// driver's lap time is their [`QualifyingResult::best_time`]. A negative gap means that `a` was
+/// faster on average. Returns [`None`] if there are no rounds in which both drivers set a time.
+///
+/// **Note:** The best times may be from different stages of a session, e.g. if one driver was
+/// eliminated in Q2 while the other went on to improve in Q3, which may inflate the gap.
+pub fn avg_qualifying_delta(a: &[Race<QualifyingResult>], b: &[Race<QualifyingResult>]) -> Option<Duration> {
+    let deltas: Vec<Duration> = a
+        .iter()
+        .filter_map(|race_a| {
+            let race_b = b.iter().find(|race_b| race_b.id() == race_a.id())?;
+            Some(race_a.payload.best_time()? - race_b.payload.best_time()?)
+        })
+        .collect();
+
+    let count = i32::try_from(deltas.len()).ok().filter(|count| *count > 0)?;
+    Some(deltas.into_iter().sum::<Duration>() / count)
+}
+
+/// Returns the qualifying head-to-head record of two drivers, e.g. teammates, as the number of
+/// rounds in which `a` and `b`, respectively, qualified ahead of the other.
+///
+/// The `a` and `b` lists are typically as returned by [`Agent::get_qualifying_result_for_events`]
+/// for each driver, via [`Filters::driver_id`]. Rounds are matched by [`Race::id`], and compared by
+/// [`QualifyingResult::position`]. Rounds in which either driver did not take part, e.g. due to an
+/// injury, or did not set a lap time, as per [`QualifyingResult::best_time`], don't count for
+/// either driver.
+pub fn qualifying_head_to_head(a: &[Race<QualifyingResult>], b: &[Race<QualifyingResult>]) -> (u32, u32) {
+    a.iter()
+        .filter_map(|race_a| {
+            let race_b = b.iter().find(|race_b| race_b.id() == race_a.id())?;
+            (race_a.payload.best_time().is_some() && race_b.payload.best_time().is_some())
+                .then(|| race_a.payload.position.cmp(&race_b.payload.position))
+        })
+        .fold((0, 0), |(wins_a, wins_b), ordering| match ordering {
+            Ordering::Less => (wins_a + 1, wins_b),
+            Ordering::Greater => (wins_a, wins_b + 1),
+            Ordering::Equal => (wins_a, wins_b),
+        })
+}
+
+/// The differences between two seasons' calendars, as computed by [`calendar_diff`].
+///
+/// Events are matched between the calendars by [`Race::race_name`] and [`Circuit::circuit_id`],
+/// so an event that was renamed, e.g. `"Mexican Grand Prix"` to `"Mexico City Grand Prix"`, or
+/// that changed circuits, e.g. `"Spanish Grand Prix"` moving to a new venue, is still matched.
+/// Matched events are reported as pairs of `(a, b)`, and may appear in more than one of the lists.
+///
+/// [`Circuit::circuit_id`]: crate::jolpica::response::Circuit::circuit_id
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct CalendarDiff {
+    /// Events in `b` that have no matching event in `a`.
+    pub added: Vec<Race<Schedule>>,
+    /// Events in `a` that have no matching event in `b`.
+    pub removed: Vec<Race<Schedule>>,
+    /// Matched events that took place in a different [`Race::round`].
+    pub moved: Vec<(Race<Schedule>, Race<Schedule>)>,
+    /// Matched events at the same circuit, but with a different [`Race::race_name`].
+    pub renamed: Vec<(Race<Schedule>, Race<Schedule>)>,
+    /// Matched events with the same [`Race::race_name`], but at a different circuit.
+    pub relocated: Vec<(Race<Schedule>, Race<Schedule>)>,
+}
+
+impl CalendarDiff {
+    /// Returns `true` if there are no differences between the two calendars.
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.moved.is_empty()
+            && self.renamed.is_empty()
+            && self.relocated.is_empty()
+    }
+}
+
+/// Computes the differences between two seasons' calendars, e.g. to find what changed from one
+/// season to the next.
+///
+/// The calendars are typically as returned by [`Agent::get_race_schedules`] for two seasons. Events
+/// are first matched by both [`Race::race_name`] and circuit, then by circuit only, i.e. renamed
+/// events, and finally by [`Race::race_name`] only, i.e. relocated events. Each event is matched
+/// at most once, in order of [`Race::round`], which handles multiple events at the same circuit.
+/// See [`CalendarDiff`] for how the differences are reported.
+pub fn calendar_diff(a: &[Race<Schedule>], b: &[Race<Schedule>]) -> CalendarDiff {
+    let sorted = |races: &[Race<Schedule>]| {
+        let mut races = races.to_vec();
+        races.sort_by_key(|race| race.round);
+        races.into_iter().map(Some).collect::<Vec<_>>()
+    };
+
+    let (mut a, mut b) = (sorted(a), sorted(b));
+    let mut matched = Vec::new();
+
+    let passes: [RaceMatcher; 3] = [is_same_event, is_same_circuit, is_same_name];
+
+    for is_match in passes {
+        for lhs in &mut a {
+            let Some(lhs_race) = lhs.as_ref() else { continue };
+
+            if let Some(rhs) = b
+                .iter_mut()
+                .find(|rhs| rhs.as_ref().is_some_and(|rhs_race| is_match(lhs_race, rhs_race)))
+            {
+                matched
+                    .push((lhs.take().unwrap_or_else(|| unreachable!()), rhs.take().unwrap_or_else(|| unreachable!())));
+            }
+        }
+    }
+
+    matched.sort_by_key(|(_, rhs)| rhs.round);
+
+    let mut diff = CalendarDiff {
+        added: b.into_iter().flatten().collect(),
+        removed: a.into_iter().flatten().collect(),
+        ..CalendarDiff::default()
+    };
+
+    for (lhs, rhs) in matched {
+        if lhs.round != rhs.round {
+            diff.moved.push((lhs.clone(), rhs.clone()));
+        }
+        if !is_same_name(&lhs, &rhs) {
+            diff.renamed.push((lhs.clone(), rhs.clone()));
+        }
+        if !is_same_circuit(&lhs, &rhs) {
+            diff.relocated.push((lhs, rhs));
+        }
+    }
+
+    diff
+}
+
+/// A predicate for matching the same event between two seasons' calendars, see [`calendar_diff`].
+type RaceMatcher = fn(&Race<Schedule>, &Race<Schedule>) -> bool;
+
+/// Returns `true` if both races have the same [`Race::race_name`].
+fn is_same_name(lhs: &Race<Schedule>, rhs: &Race<Schedule>) -> bool {
+    lhs.race_name == rhs.race_name
+}
+
+/// Returns `true` if both races took place at the same circuit.
+fn is_same_circuit(lhs: &Race<Schedule>, rhs: &Race<Schedule>) -> bool {
+    lhs.circuit.circuit_id == rhs.circuit.circuit_id
+}
+
+/// Returns `true` if both races have the same [`Race::race_name`] and took place at the same circuit.
+fn is_same_event(lhs: &Race<Schedule>, rhs: &Race<Schedule>) -> bool {
+    is_same_name(lhs, rhs) && is_same_circuit(lhs, rhs)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use std::sync::LazyLock;
+
+    use crate::jolpica::{
+        response::{FastestLap, Position, Timing},
+        time::{QualifyingTime, duration_m_s_ms},
+    };
+
+    use crate::jolpica::tests::assets::*;
+    use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
+
+    use super::*;
+
+    static LAP_MATRIX_2023_4: LazyLock<LapMatrix> =
+        LazyLock::new(|| LapMatrix::new(vec![LAP_2023_4_L2.clone(), LAP_2023_4_L1.clone()]));
+
+    // Hypothetical grand slam for Leclerc in 2023, R4, over the first two laps
+    static RACE_RESULT_LECLERC_GRAND_SLAM: LazyLock<RaceResult> = LazyLock::new(|| RaceResult {
+        number: 16,
+        position: 1,
+        position_text: Position::Finished(1),
+        driver: DRIVER_LECLERC.clone(),
+        constructor: CONSTRUCTOR_FERRARI.clone(),
+        grid: 1,
+        laps: 2,
+        fastest_lap: Some(FastestLap {
+            rank: Some(1),
+            lap: 2,
+            time: duration_m_s_ms(1, 47, 656),
+            average_speed: None,
+        }),
+        ..RACE_RESULT_2023_4_P1.clone()
+    });
+
+    #[test]
+    fn lap_matrix() {
+        let matrix = &*LAP_MATRIX_2023_4;
+
+        assert_eq!(matrix.lap_count(), 2);
+        assert_eq!(matrix.laps(), &[LAP_2023_4_L1.clone(), LAP_2023_4_L2.clone()]);
+        assert_eq!(matrix.lap(2), Some(&*LAP_2023_4_L2));
+        assert_true!(matrix.lap(3).is_none());
+
+        let leclerc = DriverID::from("leclerc");
+        let max = DriverID::from("max_verstappen");
+
+        assert_eq!(matrix.position(&leclerc, 1), Some(1));
+        assert_eq!(matrix.position(&max, 2), Some(2));
+        assert_true!(matrix.position(&max, 3).is_none());
+        assert_true!(matrix.position(&DriverID::from("perez"), 1).is_none());
+
+        assert_eq!(matrix.leader(1), Some(&leclerc));
+        assert_eq!(matrix.leader(2), Some(&leclerc));
+        assert_true!(matrix.leader(3).is_none());
+
+        assert_true!(matrix.led_every_lap(&leclerc));
+        assert_false!(matrix.led_every_lap(&max));
+        assert_false!(LapMatrix::new(vec![]).led_every_lap(&leclerc));
+    }
+
+    #[test]
+    fn lap_matrix_position_trajectories() {
+        let leclerc = DriverID::from("leclerc");
+        let max = DriverID::from("max_verstappen");
+
+        let trajectories = LAP_MATRIX_2023_4.position_trajectories();
+        assert_eq!(trajectories.len(), 2);
+        assert_eq!(trajectories[&leclerc], [(1, 1), (2, 1)]);
+        assert_eq!(trajectories[&max], [(1, 2), (2, 2)]);
+
+        // A driver that retires has no further timings, so their trajectory ends early
+        let mut laps = LAP_MATRIX_2023_4.laps().to_vec();
+        laps.push(Lap {
+            number: 3,
+            timings: vec![TIMING_2023_4_L2_P1.clone()],
+        });
+
+        let trajectories = LapMatrix::new(laps).position_trajectories();
+        assert_eq!(trajectories[&leclerc], [(1, 1), (2, 1), (3, 1)]);
+        assert_eq!(trajectories[&max], [(1, 2), (2, 2)]);
+
+        assert_true!(LapMatrix::new(vec![]).position_trajectories().is_empty());
+    }
+
+    #[test]
+    fn is_grand_slam() {
+        let leclerc = DriverID::from("leclerc");
+        let quali = &*QUALIFYING_RESULT_2023_4_P1;
+
+        assert_true!(super::is_grand_slam(&RACE_RESULT_LECLERC_GRAND_SLAM, quali, &LAP_MATRIX_2023_4, &leclerc));
+
+        // Wrong driver
+        assert_false!(super::is_grand_slam(
+            &RACE_RESULT_LECLERC_GRAND_SLAM,
+            quali,
+            &LAP_MATRIX_2023_4,
+            &DriverID::from("max_verstappen")
+        ));
+
+        // Not on pole
+        let not_pole = QualifyingResult {
+            position: 2,
+            ..quali.clone()
+        };
+        assert_false!(super::is_grand_slam(&RACE_RESULT_LECLERC_GRAND_SLAM, &not_pole, &LAP_MATRIX_2023_4, &leclerc));
+
+        // Did not win
+        let not_win = RaceResult {
+            position: 2,
+            ..RACE_RESULT_LECLERC_GRAND_SLAM.clone()
+        };
+        assert_false!(super::is_grand_slam(&not_win, quali, &LAP_MATRIX_2023_4, &leclerc));
+
+        // No fastest lap
+        let mut not_fastest = RACE_RESULT_LECLERC_GRAND_SLAM.clone();
+        not_fastest.fastest_lap.as_mut().unwrap().rank = Some(2);
+        assert_false!(super::is_grand_slam(&not_fastest, quali, &LAP_MATRIX_2023_4, &leclerc));
+
+        // Did not lead every lap
+        let mut not_led = LAP_MATRIX_2023_4.laps().to_vec();
+        not_led[1].timings = vec![
+            Timing {
+                position: 1,
+                ..TIMING_2023_4_L2_P2.clone()
+            },
+            Timing {
+                position: 2,
+                ..TIMING_2023_4_L2_P1.clone()
+            },
+        ];
+        let not_led = LapMatrix::new(not_led);
+        assert_false!(super::is_grand_slam(&RACE_RESULT_LECLERC_GRAND_SLAM, quali, &not_led, &leclerc));
+
+        // Lap matrix does not cover all laps
+        let partial = LapMatrix::new(vec![LAP_2023_4_L1.clone()]);
+        assert_false!(super::is_grand_slam(&RACE_RESULT_LECLERC_GRAND_SLAM, quali, &partial, &leclerc));
+    }
+
+    #[test]
+    fn standings_after_round() {
+        let races: Vec<Race<Vec<RaceResult>>> = [RACE_2023_3_RACE_RESULTS.clone(), RACE_2023_4_RACE_RESULTS.clone()]
+            .into_iter()
+            .map(|race| race.map(|payload| payload.into_race_results().unwrap()))
+            .collect();
+        let sprints: Vec<Race<Vec<SprintResult>>> = vec![
+            RACE_2023_4_SPRINT_RESULTS
+                .clone()
+                .map(|payload| payload.into_sprint_results().unwrap()),
+        ];
+
+        let (perez, max, de_vries) =
+            (DriverID::from("perez"), DriverID::from("max_verstappen"), DriverID::from("de_vries"));
+
+        assert_true!(super::standings_after_round(&races, &sprints, 2).is_empty());
+        assert_eq!(super::standings_after_round(&races, &sprints, 3), vec![(de_vries.clone(), 0.0)]);
+        assert_eq!(
+            super::standings_after_round(&races, &sprints, 4),
+            vec![(perez.clone(), 33.0), (max.clone(), 24.0), (de_vries.clone(), 0.0)]
+        );
+        assert_eq!(
+            super::standings_after_round(&races, &[], 4),
+            vec![(perez.clone(), 25.0), (max.clone(), 18.0), (de_vries, 0.0)]
+        );
+
+        // Tied on points, broken by count-back of race finishing positions, i.e. Perez's win
+        let mut sprints_tied = sprints.clone();
+        sprints_tied[0].payload[0].points = 0.0;
+        sprints_tied[0].payload[1].points = 7.0;
+        assert_eq!(super::standings_after_round(&races, &sprints_tied, 4)[..2], [(perez, 25.0), (max, 25.0)]);
+    }
+
+    #[test]
+    fn compare_count_back() {
+        use Ordering::{Equal, Greater, Less};
+
+        assert_eq!(super::compare_count_back(Some(&vec![1, 3]), Some(&vec![2, 2])), Less);
+        assert_eq!(super::compare_count_back(Some(&vec![1, 2]), Some(&vec![1, 1])), Greater);
+        assert_eq!(super::compare_count_back(Some(&vec![1, 2]), Some(&vec![1, 2])), Equal);
+        assert_eq!(super::compare_count_back(Some(&vec![1]), Some(&vec![1, 20])), Greater);
+        assert_eq!(super::compare_count_back(Some(&vec![20]), None), Less);
+        assert_eq!(super::compare_count_back(None, None), Equal);
+    }
+
+    fn into_race_results(race: &Race) -> Race<Vec<RaceResult>> {
+        race.clone().map(|payload| payload.into_race_results().unwrap())
+    }
+
+    #[test]
+    fn finishes_in_range() {
+        let races: Vec<_> = [
+            (&*RACE_2021_12, &*RACE_RESULT_2021_12_P1),
+            (&*RACE_2021_12, &*RACE_RESULT_2021_12_P2),
+            (&*RACE_2021_12, &*RACE_RESULT_2021_12_P3),
+            (&*RACE_2021_12, &*RACE_RESULT_2021_12_P10),
+            (&*RACE_2023_4, &*RACE_RESULT_2023_4_P1),
+            (&*RACE_2023_4, &*RACE_RESULT_2023_4_P2),
+            (&*RACE_2023_4, &*RACE_RESULT_2023_4_P20),
+        ]
+        .into_iter()
+        .map(|(race, result)| race.clone().map(|_| result.clone()))
+        .collect();
+
+        let positions = |range| {
+            super::finishes_in_range(&races, range)
+                .into_iter()
+                .map(|race| race.race_result().position)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(positions(1..=1), vec![1, 1]);
+        assert_eq!(positions(1..=3), vec![1, 2, 3, 1, 2]);
+        assert_eq!(positions(1..=10), vec![1, 2, 3, 10, 1, 2]);
+        assert_eq!(positions(4..=9), Vec::<u32>::new());
+
+        // A retired driver's classified position is not a finish
+        assert_eq!(RACE_RESULT_2023_4_P20.position, 20);
+        assert_eq!(super::finishes_in_range(&races, 1..=20).len(), 6);
+        assert_true!(super::finishes_in_range(&[], 1..=3).is_empty());
+    }
+
+    #[test]
+    fn pit_stop_stats() {
+        let stops = RACE_2023_4_PIT_STOPS.payload.as_pit_stops().unwrap().clone();
+
+        let stats = super::pit_stop_stats(&stops).unwrap();
+        assert_eq!(stats.fastest(), &*PIT_STOP_2023_4_L10_MAX);
+        assert_eq!(stats.min_duration(), duration_m_s_ms(0, 20, 707));
+        assert_eq!(stats.max_duration(), duration_m_s_ms(0, 21, 126));
+        assert_eq!(stats.mean_duration(), duration_m_s_ms(0, 20, 916) + Duration::microseconds(500));
+
+        assert_eq!(stats.stop_count(), 2);
+        assert_eq!(stats.stop_counts().len(), 2);
+        assert_eq!(stats.driver_stop_count(&DriverID::from("max_verstappen")), 1);
+        assert_eq!(stats.driver_stop_count(&DriverID::from("leclerc")), 1);
+        assert_eq!(stats.driver_stop_count(&DriverID::from("perez")), 0);
+
+        let second_stop = PitStop {
+            lap: 30,
+            stop: 2,
+            duration: duration_m_s_ms(0, 19, 907),
+            ..PIT_STOP_2023_4_L10_MAX.clone()
+        };
+        let stats = super::pit_stop_stats(&[stops, vec![second_stop.clone()]].concat()).unwrap();
+        assert_eq!(stats.fastest(), &second_stop);
+        assert_eq!(stats.mean_duration(), duration_m_s_ms(0, 20, 580));
+        assert_eq!(stats.driver_stop_count(&DriverID::from("max_verstappen")), 2);
+        assert_eq!(stats.stop_count(), 3);
+
+        assert_true!(super::pit_stop_stats(&[]).is_none());
+    }
+
+    #[test]
+    fn best_comebacks() {
+        let races: Vec<_> = [
+            &RACE_2023_3_RACE_RESULTS,
+            &RACE_2023_4_RACE_RESULTS,
+            &RACE_2021_12_RACE_RESULTS,
+        ]
+        .into_iter()
+        .map(|race| into_race_results(race))
+        .collect();
+
+        let comebacks = super::best_comebacks(&races, 10, false);
+        assert_eq!(comebacks.len(), 2);
+
+        let (race, result) = &comebacks[0];
+        assert_eq!(race.as_info(), races[1].as_info());
+        assert_eq!(result, &*RACE_RESULT_2023_4_P1);
+
+        let (race, result) = &comebacks[1];
+        assert_eq!(race.as_info(), races[2].as_info());
+        assert_eq!(result, &*RACE_RESULT_2021_12_P10);
+
+        assert_eq!(super::best_comebacks(&races, 1, false), comebacks[..1]);
+        assert_true!(super::best_comebacks(&races, 0, false).is_empty());
+        assert_true!(super::best_comebacks(&[], 10, false).is_empty());
+
+        // Pit lane starts are treated as starting from the back of the grid, if included
+        let mut races_with_pit_start = races.clone();
+        races_with_pit_start[1].payload[1].grid = 0;
+        let pit_start = races_with_pit_start[1].payload[1].clone();
+
+        assert_eq!(super::best_comebacks(&races_with_pit_start, 10, false), comebacks);
+
+        let comebacks = super::best_comebacks(&races_with_pit_start, 10, true);
+        assert_eq!(comebacks.len(), 3);
+        assert_eq!(comebacks[0].1, *RACE_RESULT_2023_4_P1);
+        assert_eq!(comebacks[1].1, pit_start);
+        assert_eq!(comebacks[2].1, *RACE_RESULT_2021_12_P10);
+    }
+
+    #[test]
+    fn dnf_rate() {
+        let races: Vec<_> = [&RACE_2023_3_RACE_RESULTS, &RACE_2023_4_RACE_RESULTS]
+            .into_iter()
+            .map(|race| into_race_results(race))
+            .collect();
+
+        let rates = super::dnf_rate(&races);
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[&ConstructorID::from("red_bull")], 0.0);
+        assert_eq!(rates[&ConstructorID::from("alphatauri")], 0.5);
+
+        assert_eq!(super::dnf_rate(&races[1..])[&ConstructorID::from("alphatauri")], 1.0);
+        assert_true!(super::dnf_rate(&[]).is_empty());
+    }
+
+    #[test]
+    fn season_position_matrix() {
+        let races: Vec<_> = [&RACE_2023_4_RACE_RESULTS, &RACE_2023_3_RACE_RESULTS]
+            .into_iter()
+            .map(|race| into_race_results(race))
+            .collect();
+
+        let matrix = super::season_position_matrix(&races);
+
+        let (perez, max, de_vries) =
+            (DriverID::from("perez"), DriverID::from("max_verstappen"), DriverID::from("de_vries"));
+
+        assert_eq!(matrix.rounds(), &[3, 4]);
+        assert_eq!(matrix.drivers(), &[de_vries.clone(), perez.clone(), max.clone()]);
+
+        assert_eq!(matrix.position(&de_vries, 3), Some(Position::Finished(15)));
+        assert_eq!(matrix.position(&de_vries, 4), Some(Position::R));
+        assert_eq!(matrix.position(&perez, 4), Some(Position::Finished(1)));
+        assert_eq!(matrix.position(&max, 4), Some(Position::Finished(2)));
+
+        assert_true!(matrix.position(&perez, 3).is_none());
+        assert_true!(matrix.position(&max, 5).is_none());
+        assert_true!(matrix.position(&DriverID::from("leclerc"), 4).is_none());
+
+        assert_eq!(super::season_position_matrix(&[]), PositionMatrix::default());
+    }
+
+    #[test]
+    fn avg_qualifying_delta() {
+        let with_result = |race: &Race, result: &QualifyingResult| race.clone().map(|_| result.clone());
+
+        let a = vec![
+            with_result(&RACE_2023_4, &QUALIFYING_RESULT_2023_4_P1),
+            with_result(&RACE_2023_10, &QUALIFYING_RESULT_2023_10_P4),
+            with_result(&RACE_2023_12, &QUALIFYING_RESULT_2023_12_P2),
+        ];
+
+        let b_2023_10 = QualifyingResult {
+            q3: Some(QualifyingTime::Time(duration_m_s_ms(1, 27, 36))),
+            ..QUALIFYING_RESULT_2023_4_P2.clone()
+        };
+        let b_2023_12 = QualifyingResult {
+            q1: Some(QualifyingTime::NoTimeSet),
+            q2: None,
+            q3: None,
+            ..QUALIFYING_RESULT_2023_4_P2.clone()
+        };
+
+        // Only R4, -0.188s, and R10, +0.100s, are comparable; R3 is missing in `a`
+        let b = vec![
+            with_result(&RACE_2023_3, &QUALIFYING_RESULT_2023_4_P2),
+            with_result(&RACE_2023_4, &QUALIFYING_RESULT_2023_4_P2),
+            with_result(&RACE_2023_10, &b_2023_10),
+            with_result(&RACE_2023_12, &b_2023_12),
+        ];
+
+        assert_eq!(super::avg_qualifying_delta(&a, &b), Some(Duration::milliseconds(-44)));
+        assert_eq!(super::avg_qualifying_delta(&b, &a), Some(Duration::milliseconds(44)));
+        assert_eq!(super::avg_qualifying_delta(&a, &a), Some(Duration::ZERO));
+
+        assert_eq!(super::avg_qualifying_delta(&a, &b[3..]), None);
+        assert_eq!(super::avg_qualifying_delta(&a, &[]), None);
+    }
+
+    #[test]
+    fn qualifying_head_to_head() {
+        let with_result = |race: &Race, result: &QualifyingResult| race.clone().map(|_| result.clone());
+
+        let a = vec![
+            with_result(&RACE_2023_4, &QUALIFYING_RESULT_2023_4_P1),
+            with_result(&RACE_2023_10, &QUALIFYING_RESULT_2023_10_P4),
+            with_result(&RACE_2023_12, &QUALIFYING_RESULT_2023_12_P2),
+        ];
+
+        let b_2023_12 = QualifyingResult {
+            q1: Some(QualifyingTime::NoTimeSet),
+            q2: None,
+            q3: None,
+            ..QUALIFYING_RESULT_2023_4_P2.clone()
+        };
+
+        // `a` is ahead in R4, and `b` in R10; R3 is missing in `a`, and `b` set no time in R12
+        let b = vec![
+            with_result(&RACE_2023_3, &QUALIFYING_RESULT_2023_4_P2),
+            with_result(&RACE_2023_4, &QUALIFYING_RESULT_2023_4_P2),
+            with_result(&RACE_2023_10, &QUALIFYING_RESULT_2023_4_P2),
+            with_result(&RACE_2023_12, &b_2023_12),
+        ];
+
+        assert_eq!(super::qualifying_head_to_head(&a, &b), (1, 1));
+        assert_eq!(super::qualifying_head_to_head(&a[..1], &b), (1, 0));
+        assert_eq!(super::qualifying_head_to_head(&b, &a[1..]), (1, 0));
+        assert_eq!(super::qualifying_head_to_head(&a, &a), (0, 0));
+        assert_eq!(super::qualifying_head_to_head(&a, &[]), (0, 0));
+    }
+
+    fn into_schedule(race: &Race) -> Race<Schedule> {
+        race.clone().map(|payload| payload.into_schedule().unwrap())
+    }
+
+    #[test]
+    fn calendar_diff() {
+        let a: Vec<_> = [
+            &RACE_2023_3_SCHEDULE,
+            &RACE_2023_4_SCHEDULE,
+            &RACE_2023_10_SCHEDULE,
+            &RACE_2023_12_SCHEDULE,
+        ]
+        .into_iter()
+        .map(|race| into_schedule(race))
+        .collect();
+
+        assert_true!(super::calendar_diff(&a, &a).is_empty());
+        assert_true!(super::calendar_diff(&a, &a.iter().rev().cloned().collect::<Vec<_>>()).is_empty());
+
+        let moved = Race {
+            round: 6,
+            ..a[1].clone()
+        };
+        let renamed = Race {
+            race_name: "Silverstone Grand Prix".to_string(),
+            ..a[2].clone()
+        };
+        let relocated = Race {
+            circuit: CIRCUIT_MAGNY_COURS.clone(),
+            ..a[3].clone()
+        };
+        let added = into_schedule(&RACE_2024_5_SCHEDULE);
+
+        let b = vec![added.clone(), moved.clone(), renamed.clone(), relocated.clone()];
+        let diff = super::calendar_diff(&a, &b);
+
+        assert_eq!(diff.added, vec![added]);
+        assert_eq!(diff.removed, vec![a[0].clone()]);
+        assert_eq!(diff.moved, vec![(a[1].clone(), moved)]);
+        assert_eq!(diff.renamed, vec![(a[2].clone(), renamed)]);
+        assert_eq!(diff.relocated, vec![(a[3].clone(), relocated)]);
+
+        let reverse = super::calendar_diff(&b, &a);
+        assert_eq!(reverse.added, diff.removed);
+        assert_eq!(reverse.removed, diff.added);
+        assert_eq!(reverse.moved.len(), 1);
+
+        // Multiple events at the same circuit are matched in order of rounds
+        let double_header = vec![
+            a[3].clone(),
+            Race {
+                round: 13,
+                ..a[3].clone()
+            },
+        ];
+        let diff = super::calendar_diff(&double_header, &double_header[..1]);
+        assert_eq!(diff.removed, vec![double_header[1].clone()]);
+        assert_true!(diff.moved.is_empty() && diff.added.is_empty());
+    }
+}
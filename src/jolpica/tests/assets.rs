@@ -101,6 +101,29 @@ pub(crate) static SEASON_TABLE: LazyLock<Table> = LazyLock::new(|| Table::Season
     ],
 });
 
+pub(crate) const RESPONSE_SEASON_TABLE_STR: &str = formatcp!(
+    r#"{{
+  "MRData": {{
+    "xmlns": "",
+    "series": "f1",
+    "url": "https://api.jolpi.ca/ergast/f1/seasons.json",
+    "limit": "30",
+    "offset": "0",
+    "total": "6",
+    "SeasonTable": {{
+        "Seasons": [
+            {SEASON_1950_STR},
+            {SEASON_1979_STR},
+            {SEASON_1980_STR},
+            {SEASON_1981_STR},
+            {SEASON_2000_STR},
+            {SEASON_2023_STR}
+        ]
+    }}
+  }}
+}}"#
+);
+
 // https://api.jolpi.ca/ergast/f1/drivers/
 // ---------------------------------------
 
@@ -792,6 +815,7 @@ pub(crate) static CIRCUIT_GEORGE: LazyLock<Circuit> = LazyLock::new(|| Circuit {
         long: OrderedFloat(27.8736),
         locality: "Eastern Cape Province".to_string(),
         country: "South Africa".to_string(),
+        alt: None,
     },
 });
 
@@ -804,6 +828,7 @@ pub(crate) static CIRCUIT_MAGNY_COURS: LazyLock<Circuit> = LazyLock::new(|| Circ
         long: OrderedFloat(3.16361),
         locality: "Magny Cours".to_string(),
         country: "France".to_string(),
+        alt: None,
     },
 });
 
@@ -816,6 +841,7 @@ pub(crate) static CIRCUIT_ALBERT_PARK: LazyLock<Circuit> = LazyLock::new(|| Circ
         long: OrderedFloat(144.968),
         locality: "Melbourne".to_string(),
         country: "Australia".to_string(),
+        alt: None,
     },
 });
 
@@ -828,6 +854,7 @@ pub(crate) static CIRCUIT_SPA: LazyLock<Circuit> = LazyLock::new(|| Circuit {
         long: OrderedFloat(5.97139),
         locality: "Spa".to_string(),
         country: "Belgium".to_string(),
+        alt: None,
     },
 });
 
@@ -840,6 +867,7 @@ pub(crate) static CIRCUIT_SILVERSTONE: LazyLock<Circuit> = LazyLock::new(|| Circ
         long: OrderedFloat(-1.01694),
         locality: "Silverstone".to_string(),
         country: "UK".to_string(),
+        alt: None,
     },
 });
 
@@ -852,6 +880,7 @@ pub(crate) static CIRCUIT_IMOLA: LazyLock<Circuit> = LazyLock::new(|| Circuit {
         long: OrderedFloat(11.7167),
         locality: "Imola".to_string(),
         country: "Italy".to_string(),
+        alt: None,
     },
 });
 
@@ -864,6 +893,7 @@ pub(crate) static CIRCUIT_MUGELLO: LazyLock<Circuit> = LazyLock::new(|| Circuit
         long: OrderedFloat(11.3719),
         locality: "Mugello".to_string(),
         country: "Italy".to_string(),
+        alt: None,
     },
 });
 
@@ -876,6 +906,7 @@ pub(crate) static CIRCUIT_BAKU: LazyLock<Circuit> = LazyLock::new(|| Circuit {
         long: OrderedFloat(49.8533),
         locality: "Baku".to_string(),
         country: "Azerbaijan".to_string(),
+        alt: None,
     },
 });
 
@@ -888,6 +919,7 @@ pub(crate) static CIRCUIT_SHANGHAI: LazyLock<Circuit> = LazyLock::new(|| Circuit
         long: OrderedFloat(121.22),
         locality: "Shanghai".to_string(),
         country: "China".to_string(),
+        alt: None,
     },
 });
 
@@ -1126,6 +1158,7 @@ pub(crate) const RACE_NONE: LazyLock<Race> = LazyLock::new(|| Race {
             long: OrderedFloat(f64::NAN),
             locality: "".to_string(),
             country: "".to_string(),
+            alt: None,
         },
     },
     date: Date::MIN,
@@ -3240,24 +3273,28 @@ pub(crate) const TIMING_2023_4_L1_P1: LazyLock<Timing> = LazyLock::new(|| Timing
     driver_id: "leclerc".into(),
     position: 1,
     time: duration_m_s_ms(1, 50, 109),
+    sectors: None,
 });
 
 pub(crate) const TIMING_2023_4_L1_P2: LazyLock<Timing> = LazyLock::new(|| Timing {
     driver_id: "max_verstappen".into(),
     position: 2,
     time: duration_m_s_ms(1, 50, 456),
+    sectors: None,
 });
 
 pub(crate) const TIMING_2023_4_L2_P1: LazyLock<Timing> = LazyLock::new(|| Timing {
     driver_id: "leclerc".into(),
     position: 1,
     time: duration_m_s_ms(1, 47, 656),
+    sectors: None,
 });
 
 pub(crate) const TIMING_2023_4_L2_P2: LazyLock<Timing> = LazyLock::new(|| Timing {
     driver_id: "max_verstappen".into(),
     position: 2,
     time: duration_m_s_ms(1, 47, 707),
+    sectors: None,
 });
 
 pub(crate) const LAP_2023_4_L1_STR: &str = formatcp!(
@@ -3359,6 +3396,153 @@ pub(crate) static RACE_2023_4_PIT_STOPS: LazyLock<Race> = LazyLock::new(|| Race
     ..RACE_2023_4.clone()
 });
 
+// https://api.jolpi.ca/ergast/f1/driverstandings/
+// -----------------------------------------------
+
+pub(crate) const DRIVER_STANDING_2021_22_P1_STR: &str = formatcp!(
+    r#"{{
+    "position": "1",
+    "positionText": "1",
+    "points": "395.5",
+    "wins": "10",
+    "Driver": {DRIVER_MAX_STR},
+    "Constructors": [
+        {CONSTRUCTOR_RED_BULL_STR}
+    ]
+  }}"#
+);
+
+pub(crate) const DRIVER_STANDING_2021_22_P2_STR: &str = formatcp!(
+    r#"{{
+    "position": "2",
+    "positionText": "2",
+    "points": "387.5",
+    "wins": "8",
+    "Driver": {DRIVER_HAMILTON_STR},
+    "Constructors": [
+        {CONSTRUCTOR_MERCEDES_STR}
+    ]
+  }}"#
+);
+
+pub(crate) static DRIVER_STANDING_2021_22_P1: LazyLock<DriverStanding> = LazyLock::new(|| DriverStanding {
+    position: Some(1),
+    position_text: "1".to_string(),
+    points: 395.5,
+    wins: 10,
+    driver: DRIVER_MAX.clone(),
+    constructors: vec![CONSTRUCTOR_RED_BULL.clone()],
+});
+
+pub(crate) static DRIVER_STANDING_2021_22_P2: LazyLock<DriverStanding> = LazyLock::new(|| DriverStanding {
+    position: Some(2),
+    position_text: "2".to_string(),
+    points: 387.5,
+    wins: 8,
+    driver: DRIVER_HAMILTON.clone(),
+    constructors: vec![CONSTRUCTOR_MERCEDES.clone()],
+});
+
+pub(crate) const DRIVER_STANDINGS_TABLE_2021_22_STR: &str = formatcp!(
+    r#"{{
+    "StandingsTable": {{
+        "season": "2021",
+        "round": "22",
+        "StandingsLists": [
+            {{
+                "season": "2021",
+                "round": "22",
+                "DriverStandings": [
+                    {DRIVER_STANDING_2021_22_P1_STR},
+                    {DRIVER_STANDING_2021_22_P2_STR}
+                ]
+            }}
+        ]
+    }}}}"#
+);
+
+pub(crate) static DRIVER_STANDINGS_2021_22: LazyLock<StandingsList> = LazyLock::new(|| StandingsList {
+    season: 2021,
+    round: 22,
+    standings: Standings::DriverStandings(vec![DRIVER_STANDING_2021_22_P1.clone(), DRIVER_STANDING_2021_22_P2.clone()]),
+});
+
+pub(crate) static DRIVER_STANDINGS_TABLE_2021_22: LazyLock<Table> = LazyLock::new(|| Table::StandingsLists {
+    standings_lists: vec![DRIVER_STANDINGS_2021_22.clone()],
+});
+
+// https://api.jolpi.ca/ergast/f1/constructorstandings/
+// ----------------------------------------------------
+
+pub(crate) const CONSTRUCTOR_STANDING_2021_22_P1_STR: &str = formatcp!(
+    r#"{{
+    "position": "1",
+    "positionText": "1",
+    "points": "613.5",
+    "wins": "9",
+    "Constructor": {CONSTRUCTOR_MERCEDES_STR}
+  }}"#
+);
+
+pub(crate) const CONSTRUCTOR_STANDING_2021_22_P2_STR: &str = formatcp!(
+    r#"{{
+    "position": "2",
+    "positionText": "2",
+    "points": "585.5",
+    "wins": "11",
+    "Constructor": {CONSTRUCTOR_RED_BULL_STR}
+  }}"#
+);
+
+pub(crate) static CONSTRUCTOR_STANDING_2021_22_P1: LazyLock<ConstructorStanding> =
+    LazyLock::new(|| ConstructorStanding {
+        position: Some(1),
+        position_text: "1".to_string(),
+        points: 613.5,
+        wins: 9,
+        constructor: CONSTRUCTOR_MERCEDES.clone(),
+    });
+
+pub(crate) static CONSTRUCTOR_STANDING_2021_22_P2: LazyLock<ConstructorStanding> =
+    LazyLock::new(|| ConstructorStanding {
+        position: Some(2),
+        position_text: "2".to_string(),
+        points: 585.5,
+        wins: 11,
+        constructor: CONSTRUCTOR_RED_BULL.clone(),
+    });
+
+pub(crate) const CONSTRUCTOR_STANDINGS_TABLE_2021_22_STR: &str = formatcp!(
+    r#"{{
+    "StandingsTable": {{
+        "season": "2021",
+        "round": "22",
+        "StandingsLists": [
+            {{
+                "season": "2021",
+                "round": "22",
+                "ConstructorStandings": [
+                    {CONSTRUCTOR_STANDING_2021_22_P1_STR},
+                    {CONSTRUCTOR_STANDING_2021_22_P2_STR}
+                ]
+            }}
+        ]
+    }}}}"#
+);
+
+pub(crate) static CONSTRUCTOR_STANDINGS_2021_22: LazyLock<StandingsList> = LazyLock::new(|| StandingsList {
+    season: 2021,
+    round: 22,
+    standings: Standings::ConstructorStandings(vec![
+        CONSTRUCTOR_STANDING_2021_22_P1.clone(),
+        CONSTRUCTOR_STANDING_2021_22_P2.clone(),
+    ]),
+});
+
+pub(crate) static CONSTRUCTOR_STANDINGS_TABLE_2021_22: LazyLock<Table> = LazyLock::new(|| Table::StandingsLists {
+    standings_lists: vec![CONSTRUCTOR_STANDINGS_2021_22.clone()],
+});
+
 // [`Driver`]s by season, helpful for testing
 // ------------------------------------------
 
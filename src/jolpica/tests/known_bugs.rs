@@ -211,7 +211,7 @@ mod tests {
 
         assert!(matches!(
             JOLPICA_MP.get_race_result(Filters::new().season(2023).round(4).finish_pos(20)),
-            Err(Error::NotFound)
+            Err(Error::NotFound(_))
         ));
     }
 
@@ -1,3 +1,8 @@
+// Only a subset of the assets is used by `test_util`, under the `test-util` feature. The assets are
+// written as test code, so lints that are not enforced for test code are allowed in that case too.
+#[cfg_attr(not(test), allow(dead_code, clippy::all, clippy::pedantic, clippy::nursery))]
 pub(crate) mod assets;
+#[cfg(test)]
 mod known_bugs;
+#[cfg(test)]
 pub(crate) mod util;
@@ -1,11 +1,18 @@
-use std::sync::LazyLock;
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{Arc, LazyLock},
+    thread::JoinHandle,
+};
 
 use crate::{
     error::Result,
     jolpica::{
-        agent::{Agent, AgentConfigs, MultiPageOption, RateLimiterOption},
+        agent::{Agent, AgentConfigs, CacheExpiry, CacheOption, MultiPageOption, RateLimiterOption},
         api::{JOLPICA_API_BASE_URL, JOLPICA_API_RATE_LIMIT_QUOTA},
-        get::retry_on_http_error,
+        backend::HttpBackend,
+        get::{RetryPolicy, retry_on_http_error},
     },
     rate_limiter::RateLimiter,
 };
@@ -74,8 +81,17 @@ pub(crate) fn get_jolpica_test_rate_limiter_option() -> RateLimiterOption<'stati
 pub(crate) static JOLPICA_SP: LazyLock<Agent<'_>> = LazyLock::new(|| {
     Agent::new(AgentConfigs {
         base_url: get_jolpica_test_base_url(),
+        backend: Arc::new(HttpBackend),
+        user_agent: None,
+        extra_headers: BTreeMap::new(),
         multi_page: MultiPageOption::Disabled,
-        http_retries: Some(TESTS_DEFAULT_HTTP_RETRIES),
+        multi_page_concurrency: 1,
+        http_retries: Some(RetryPolicy::immediate(TESTS_DEFAULT_HTTP_RETRIES)),
+        retry_predicate: None,
+        conditional_requests: false,
+        verify_table_variant: false,
+        cache: CacheOption::None,
+        cache_expiry: CacheExpiry::Never,
         rate_limiter: get_jolpica_test_rate_limiter_option(),
     })
 });
@@ -87,8 +103,17 @@ pub(crate) static JOLPICA_SP: LazyLock<Agent<'_>> = LazyLock::new(|| {
 pub(crate) static JOLPICA_MP: LazyLock<Agent<'_>> = LazyLock::new(|| {
     Agent::new(AgentConfigs {
         base_url: get_jolpica_test_base_url(),
+        backend: Arc::new(HttpBackend),
+        user_agent: None,
+        extra_headers: BTreeMap::new(),
         multi_page: MultiPageOption::Enabled(None),
-        http_retries: Some(TESTS_DEFAULT_HTTP_RETRIES),
+        multi_page_concurrency: 1,
+        http_retries: Some(RetryPolicy::immediate(TESTS_DEFAULT_HTTP_RETRIES)),
+        retry_predicate: None,
+        conditional_requests: false,
+        verify_table_variant: false,
+        cache: CacheOption::None,
+        cache_expiry: CacheExpiry::Never,
         rate_limiter: get_jolpica_test_rate_limiter_option(),
     })
 });
@@ -104,3 +129,95 @@ pub(crate) static JOLPICA_MP: LazyLock<Agent<'_>> = LazyLock::new(|| {
 pub(crate) fn get_request_avg_duration_ms() -> u64 {
     if is_using_local_jolpica() { 15 } else { 350 }
 }
+
+/// `ETag` header value sent by [`spawn_conditional_mock_server`].
+pub(crate) const MOCK_ETAG: &str = "\"f1-data-mock\"";
+
+/// Spawn a minimal local HTTP server that supports conditional requests, for tests without network.
+///
+/// It handles exactly `request_count` requests, responding to each with `body` and an `ETag` of
+/// [`MOCK_ETAG`], unless the request has a matching `If-None-Match` header, in which case it
+/// responds with `304 Not Modified`. It returns the base URL of the server, and a handle to the
+/// server thread, which returns the number of `304 Not Modified` responses sent.
+pub(crate) fn spawn_conditional_mock_server(body: &'static str, request_count: usize) -> (String, JoinHandle<usize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = std::thread::spawn(move || {
+        let mut not_modified_count = 0;
+
+        for stream in listener.incoming().take(request_count) {
+            let mut stream = stream.unwrap();
+
+            let is_not_modified = BufReader::new(&stream)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .any(|line| line.eq_ignore_ascii_case(&format!("if-none-match: {MOCK_ETAG}")));
+
+            let response = if is_not_modified {
+                not_modified_count += 1;
+                format!("HTTP/1.1 304 Not Modified\r\nETag: {MOCK_ETAG}\r\nConnection: close\r\n\r\n")
+            } else {
+                format!(
+                    "HTTP/1.1 200 OK\r\nETag: {MOCK_ETAG}\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            };
+
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+
+        not_modified_count
+    });
+
+    (base_url, handle)
+}
+
+/// Formats the JSON of a response with a `RaceTable` holding the given `races`, e.g. for mocking
+/// responses with [`MockBackend`](crate::jolpica::backend::MockBackend) in tests.
+///
+/// The `races` must be comma-separated JSON race objects, e.g. `RACE_2023_4_RACE_RESULTS_STR`, and
+/// `url`, `limit`, `offset`, and `total` are used as-is for the top-level response fields.
+pub(crate) fn race_table_json(url: &str, limit: u32, offset: u32, total: u32, races: &str) -> String {
+    format!(
+        r#"{{"MRData": {{
+            "xmlns": "", "series": "f1", "url": "{url}",
+            "limit": "{limit}", "offset": "{offset}", "total": "{total}",
+            "RaceTable": {{ "Races": [{races}] }}
+        }}}}"#
+    )
+}
+
+/// Spawn a minimal local HTTP server that responds to each request with the next of `responses`.
+///
+/// Each element of `responses` is a full raw HTTP response, e.g. `"HTTP/1.1 200 OK\r\n..."`, and
+/// the server handles exactly as many requests as there are `responses`. It returns the base URL of
+/// the server, and a handle to the server thread, which returns the header lines of each request.
+pub(crate) fn spawn_mock_server(responses: Vec<String>) -> (String, JoinHandle<Vec<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+
+        for (response, stream) in responses.into_iter().zip(listener.incoming()) {
+            let mut stream = stream.unwrap();
+
+            requests.push(
+                BufReader::new(&stream)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect(),
+            );
+
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+
+        requests
+    });
+
+    (base_url, handle)
+}
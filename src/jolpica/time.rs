@@ -45,9 +45,115 @@ pub fn duration_s_ms(seconds: i64, milliseconds: i64) -> Duration {
     Duration::seconds(seconds) + Duration::milliseconds(milliseconds)
 }
 
-#[cfg(test)]
+/// Construct a [`Duration`] from a number of decimal seconds, e.g. `92.109`, rounded to the nearest
+/// millisecond, which is the precision of durations in the jolpica-f1 API.
+///
+/// Unlike [`Duration::seconds_f64`], the rounding means that the result compares equal to durations
+/// parsed from the API, e.g. `duration_from_secs_f64(92.109) == duration_m_s_ms(1, 32, 109)`.
+///
+/// # Panics
+///
+/// Panics if `seconds` is not finite, or is too large to be represented in milliseconds.
+pub fn duration_from_secs_f64(seconds: f64) -> Duration {
+    assert!(seconds.is_finite(), "Invalid number of seconds: {seconds}");
+
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::milliseconds((seconds * 1000.0).round() as i64)
+}
+
+/// Parses a [`Duration`] from a string in either the jolpica-f1 API format or as decimal seconds.
+///
+/// The jolpica-f1 API format is `H:MM:SS.SSS`, e.g. `"1:32.109"`, where `[hour]` and `[minute]` may
+/// be omitted. Decimal seconds, e.g. `"92.109"`, are common in telemetry feeds, CSVs, etc. Returns
+/// [`None`] if the string is in neither format.
+///
+/// # Examples
+///
+/// ```
+/// use f1_data::jolpica::time::{duration_from_str, duration_m_s_ms};
+///
+/// assert_eq!(duration_from_str("1:32.109"), Some(duration_m_s_ms(1, 32, 109)));
+/// assert_eq!(duration_from_str("92.109"), Some(duration_m_s_ms(1, 32, 109)));
+/// assert_eq!(duration_from_str("1:32"), None);
+/// ```
+pub fn duration_from_str(raw_str: &str) -> Option<Duration> {
+    const SECONDS_REGEX_STR: &str = r"^(\d+)(?:\.(\d{1,3}))?$";
+    static RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(SECONDS_REGEX_STR).unwrap());
+
+    parse_duration(raw_str).ok().or_else(|| {
+        let matches = RE.captures(raw_str)?;
+
+        let seconds = matches[1].parse::<i64>().ok()?;
+        let milliseconds = matches
+            .get(2)
+            .map_or(0, |mtch| parse_subsecond_into_milli(mtch.as_str()));
+
+        Some(duration_s_ms(seconds, milliseconds))
+    })
+}
+
+/// Formats a [`Duration`] in the jolpica-f1 API format, i.e. the inverse of [`duration_from_str`].
+///
+/// The format is `H:MM:SS.SSS`, e.g. `"1:32:42.436"`, where `[hour]` and `[minute]` are omitted if
+/// zero, e.g. `"1:32.109"` or `"2.137"`. Sub-millisecond precision is truncated.
+///
+/// # Examples
+///
+/// ```
+/// use f1_data::jolpica::time::{duration_hms_ms, duration_m_s_ms, duration_to_string};
+///
+/// assert_eq!(duration_to_string(duration_hms_ms(1, 32, 42, 436)), "1:32:42.436");
+/// assert_eq!(duration_to_string(duration_m_s_ms(1, 32, 109)), "1:32.109");
+/// assert_eq!(duration_to_string(duration_m_s_ms(0, 2, 137)), "2.137");
+/// ```
+pub fn duration_to_string(duration: Duration) -> String {
+    let sign = if duration.is_negative() { "-" } else { "" };
+    let duration = duration.abs();
+
+    let hours = duration.whole_hours();
+    let minutes = duration.whole_minutes() % 60;
+    let seconds = duration.whole_seconds() % 60;
+    let milliseconds = duration.subsec_milliseconds();
+
+    if hours > 0 {
+        format!("{sign}{hours}:{minutes:02}:{seconds:02}.{milliseconds:03}")
+    } else if minutes > 0 {
+        format!("{sign}{minutes}:{seconds:02}.{milliseconds:03}")
+    } else {
+        format!("{sign}{seconds}.{milliseconds:03}")
+    }
+}
+
+/// Formats a [`Duration`] as a lap time, i.e. `M:SS.SSS`, e.g. `"1:32.109"` or `"0:59.037"`.
+///
+/// Unlike [`duration_to_string`], `[minute]` is always included, so that lap times line up when
+/// printed in a column. Durations of an hour or more, e.g. full race times, are formatted as
+/// `H:MM:SS.SSS`, as per [`duration_to_string`], instead of overflowing `[minute]`. The result can
+/// be parsed back into a [`Duration`] via [`duration_from_str`].
+///
+/// # Examples
+///
+/// ```
+/// use f1_data::jolpica::time::{duration_hms_ms, duration_m_s_ms, duration_to_lap_string};
+///
+/// assert_eq!(duration_to_lap_string(duration_m_s_ms(1, 32, 109)), "1:32.109");
+/// assert_eq!(duration_to_lap_string(duration_m_s_ms(0, 59, 37)), "0:59.037");
+/// assert_eq!(duration_to_lap_string(duration_hms_ms(1, 32, 42, 436)), "1:32:42.436");
+/// ```
+pub fn duration_to_lap_string(duration: Duration) -> String {
+    if duration.whole_minutes() != 0 {
+        return duration_to_string(duration);
+    }
+
+    let sign = if duration.is_negative() { "-" } else { "" };
+    let duration = duration.abs();
+
+    format!("{sign}0:{:02}.{:03}", duration.whole_seconds(), duration.subsec_milliseconds())
+}
+
+#[cfg(any(test, feature = "test-util"))]
 /// Construct a [`Duration`] from a number of milliseconds.
-pub(crate) fn duration_millis(milliseconds: i64) -> Duration {
+pub(crate) const fn duration_millis(milliseconds: i64) -> Duration {
     Duration::milliseconds(milliseconds)
 }
 
@@ -161,10 +267,25 @@ where
     parse_duration(&String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
 }
 
+/// Deserialize an optional list of three [`Duration`]s, e.g. sector times, via [`parse_duration`].
+pub(crate) fn deserialize_optional_sectors<'de, D>(deserializer: D) -> Result<Option<[Duration; 3]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<[String; 3]>::deserialize(deserializer)?
+        .map(|sectors| {
+            let [s1, s2, s3] = sectors.map(|s| parse_duration(&s));
+            Ok::<_, String>([s1?, s2?, s3?])
+        })
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
 #[derive(Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
 /// Represents a date and optional time in the jolpica-f1 API, e.g. the date and start time of an
 /// event. This is similar to, say [`time::PrimitiveDateTime`], but the time may not always be
 /// present.
+#[cfg_attr(feature = "strict-parse", serde(deny_unknown_fields))]
 pub struct DateTime {
     /// The date component of the date-time.
     pub date: Date,
@@ -173,12 +294,28 @@ pub struct DateTime {
     pub time: Option<Time>,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
 /// Represents the duration of the best qualifying lap set by a driver in a qualifying session, e.g.
 /// Q1, Q2, etc.
 ///
 /// A lap time is represented by the [`QualifyingTime::Time`]. If a driver took part in a qualifying
 /// session but did not set a lap time, then [`QualifyingTime::NoTimeSet`].
+///
+/// [`QualifyingTime`]s are ordered by lap time, with [`QualifyingTime::NoTimeSet`] ordered after
+/// all lap times, so that e.g. the minimum of a driver's Q1, Q2, and Q3 times is their best lap.
+///
+/// # Examples
+///
+/// ```
+/// # use f1_data::jolpica::time::{QualifyingTime, duration_m_s_ms};
+/// #
+/// let q1 = QualifyingTime::Time(duration_m_s_ms(1, 41, 269));
+/// let q2 = QualifyingTime::Time(duration_m_s_ms(1, 40, 826));
+/// let q3 = QualifyingTime::NoTimeSet;
+///
+/// assert_eq!([q1, q2, q3].into_iter().min(), Some(q2));
+/// assert!(q1 < q3);
+/// ```
 pub enum QualifyingTime {
     /// The duration of the best qualifying lap set by a driver in a qualifying session.
     Time(Duration),
@@ -208,6 +345,42 @@ impl QualifyingTime {
             Self::NoTimeSet => panic!("Cannot get time of NoTimeSet"),
         }
     }
+
+    /// Returns the lap time as a [`Duration`], if the driver set a lap time in a qualifying
+    /// session, or [`None`] otherwise, e.g. to compare it against a race lap time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use f1_data::jolpica::time::{QualifyingTime, duration_m_s_ms};
+    /// #
+    /// let pole_lap = QualifyingTime::Time(duration_m_s_ms(1, 40, 203));
+    /// let race_fastest_lap = duration_m_s_ms(1, 43, 370);
+    ///
+    /// assert!(pole_lap.as_duration().unwrap() < race_fastest_lap);
+    /// assert_eq!(QualifyingTime::NoTimeSet.as_duration(), None);
+    /// ```
+    pub const fn as_duration(&self) -> Option<Duration> {
+        match self {
+            Self::Time(time) => Some(*time),
+            Self::NoTimeSet => None,
+        }
+    }
+}
+
+impl From<Duration> for QualifyingTime {
+    fn from(duration: Duration) -> Self {
+        Self::Time(duration)
+    }
+}
+
+impl TryFrom<QualifyingTime> for Duration {
+    /// The original [`QualifyingTime`] is returned if it's [`QualifyingTime::NoTimeSet`].
+    type Error = QualifyingTime;
+
+    fn try_from(quali: QualifyingTime) -> Result<Self, Self::Error> {
+        quali.as_duration().ok_or(quali)
+    }
 }
 
 impl<'de> Deserialize<'de> for QualifyingTime {
@@ -224,9 +397,14 @@ impl<'de> Deserialize<'de> for QualifyingTime {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
 /// Represents the full race duration for a single driver, including a delta to the race leader/P1.
 /// This is only present if a driver finished in the lead lap, if their race status is `"Finished"`.
+///
+/// [`RaceTime`]s are ordered by [`RaceTime::total`], then by [`RaceTime::delta`]. Within a single
+/// race, this is the same as ordering by delta, i.e. by finishing order. The ordering is still
+/// defined between [`RaceTime`]s from different races, but it is only meaningful when comparing
+/// like kinds, e.g. total race durations, since their deltas are relative to different leaders.
 pub struct RaceTime {
     /// Total race duration for the driver.
     total: Duration,
@@ -268,6 +446,24 @@ impl RaceTime {
     pub const fn delta(&self) -> &Duration {
         &self.delta
     }
+
+    /// Get the total race duration for a driver by resolving its [`RaceTime::delta`] against the
+    /// total race duration of the leader/P1, i.e. `leader_total + delta`.
+    ///
+    /// This is normally the same as [`RaceTime::total`], but it is derived from the same delta
+    /// that's displayed for the driver, e.g. `"+2.137"`, so that it's consistent with it.
+    pub fn resolve_total(&self, leader_total: Duration) -> Duration {
+        leader_total + self.delta
+    }
+
+    /// Get the interval from the [`RaceTime`] of a driver that finished `ahead` to this one, i.e.
+    /// the difference between their [`RaceTime::delta`]s, e.g. between two midfield finishers.
+    ///
+    /// This is only meaningful for [`RaceTime`]s from the same race, and it's negative if `ahead`
+    /// actually finished behind this one.
+    pub fn interval_to(&self, ahead: &Self) -> Duration {
+        self.delta - ahead.delta
+    }
 }
 
 impl<'de> Deserialize<'de> for RaceTime {
@@ -393,6 +589,101 @@ where
     }
 }
 
+// Interoperability with `chrono`, behind the `chrono` feature
+// ------------------------------------------------------------
+//
+// Since [`Date`], [`Time`], and [`Duration`] are aliases of types from the underlying library, the
+// orphan rule prevents implementing [`From`] conversions between them and the `chrono` types, so
+// free functions are provided instead. [`DateTime`] is defined in this crate, so it implements
+// [`From`] and [`TryFrom`] directly.
+
+/// Converts a [`Date`] into a [`chrono::NaiveDate`].
+#[cfg(feature = "chrono")]
+pub fn date_to_chrono(date: Date) -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(date.year(), u32::from(u8::from(date.month())), u32::from(date.day()))
+        .unwrap_or_else(|| unreachable!("the range of `chrono::NaiveDate` is a superset of `Date`"))
+}
+
+/// Converts a [`chrono::NaiveDate`] into a [`Date`].
+///
+/// # Errors
+///
+/// Returns an error if the date is outside of the range supported by [`Date`], e.g. year `10000`.
+#[cfg(feature = "chrono")]
+pub fn date_from_chrono(date: chrono::NaiveDate) -> Result<Date, underlying::error::ComponentRange> {
+    use chrono::Datelike;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let month = underlying::Month::try_from(date.month() as u8)?;
+
+    #[allow(clippy::cast_possible_truncation)]
+    Date::from_calendar_date(date.year(), month, date.day() as u8)
+}
+
+/// Converts a [`Time`] into a [`chrono::NaiveTime`].
+#[cfg(feature = "chrono")]
+pub fn time_to_chrono(time: Time) -> chrono::NaiveTime {
+    chrono::NaiveTime::from_hms_nano_opt(
+        u32::from(time.hour()),
+        u32::from(time.minute()),
+        u32::from(time.second()),
+        time.nanosecond(),
+    )
+    .unwrap_or_else(|| unreachable!("the range of `chrono::NaiveTime` is a superset of `Time`"))
+}
+
+/// Converts a [`chrono::NaiveTime`] into a [`Time`].
+///
+/// # Errors
+///
+/// Returns an error if the time is a leap second, which is not supported by [`Time`].
+#[cfg(feature = "chrono")]
+pub fn time_from_chrono(time: chrono::NaiveTime) -> Result<Time, underlying::error::ComponentRange> {
+    use chrono::Timelike;
+
+    #[allow(clippy::cast_possible_truncation)]
+    Time::from_hms_nano(time.hour() as u8, time.minute() as u8, time.second() as u8, time.nanosecond())
+}
+
+/// Converts a [`Duration`] into a [`chrono::TimeDelta`].
+///
+/// Returns [`None`] if the duration is outside of the range supported by [`chrono::TimeDelta`].
+#[cfg(feature = "chrono")]
+pub fn duration_to_chrono(duration: Duration) -> Option<chrono::TimeDelta> {
+    chrono::TimeDelta::try_seconds(duration.whole_seconds())?
+        .checked_add(&chrono::TimeDelta::nanoseconds(i64::from(duration.subsec_nanoseconds())))
+}
+
+/// Converts a [`chrono::TimeDelta`] into a [`Duration`].
+#[cfg(feature = "chrono")]
+pub const fn duration_from_chrono(duration: chrono::TimeDelta) -> Duration {
+    Duration::new(duration.num_seconds(), duration.subsec_nanos())
+}
+
+/// Converts a [`DateTime`] into a [`chrono::DateTime<chrono::Utc>`], assuming midnight if
+/// [`DateTime::time`] is [`None`]. Times from the jolpica-f1 API are in UTC.
+#[cfg(feature = "chrono")]
+impl From<DateTime> for chrono::DateTime<chrono::Utc> {
+    fn from(date_time: DateTime) -> Self {
+        let time = date_time.time.map_or(chrono::NaiveTime::MIN, time_to_chrono);
+        chrono::NaiveDateTime::new(date_to_chrono(date_time.date), time).and_utc()
+    }
+}
+
+/// Converts a [`chrono::DateTime<chrono::Utc>`] into a [`DateTime`], always with a
+/// [`DateTime::time`], via [`date_from_chrono`] and [`time_from_chrono`].
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for DateTime {
+    type Error = underlying::error::ComponentRange;
+
+    fn try_from(date_time: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            date: date_from_chrono(date_time.date_naive())?,
+            time: Some(time_from_chrono(date_time.time())?),
+        })
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage, coverage(off))]
 mod tests {
@@ -547,6 +838,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn duration_from_str() {
+        let str_value_pairs = vec![
+            ("1:32.109", "92.109", super::duration_m_s_ms(1, 32, 109)),
+            ("59.037", "59.037", super::duration_m_s_ms(0, 59, 37)),
+            ("1:07.769", "67.769", super::duration_m_s_ms(1, 7, 769)),
+            ("2:01.3", "121.3", super::duration_m_s_ms(2, 1, 300)),
+            ("1:28:12.058", "5292.058", super::duration_hms_ms(1, 28, 12, 58)),
+            ("1:30.0", "90", super::duration_m_s_ms(1, 30, 0)),
+        ];
+
+        for (api_str, seconds_str, expected) in str_value_pairs {
+            assert_eq!(super::duration_from_str(api_str), Some(expected));
+            assert_eq!(super::duration_from_str(seconds_str), Some(expected));
+        }
+
+        for bad_str in ["", "abc", "1:32", "92.1093", "-92.109", "+21.217", "92.", ".109"] {
+            assert_true!(super::duration_from_str(bad_str).is_none());
+        }
+    }
+
+    #[test]
+    fn duration_from_secs_f64() {
+        assert_eq!(super::duration_from_secs_f64(92.109), super::duration_m_s_ms(1, 32, 109));
+        assert_eq!(super::duration_from_secs_f64(0.4), super::duration_m_s_ms(0, 0, 400));
+        assert_eq!(super::duration_from_secs_f64(5292.0584), super::duration_hms_ms(1, 28, 12, 58));
+        assert_eq!(super::duration_from_secs_f64(92.109), super::duration_from_str("92.109").unwrap());
+
+        assert_true!(catch_unwind(|| super::duration_from_secs_f64(f64::NAN)).is_err());
+        assert_true!(catch_unwind(|| super::duration_from_secs_f64(f64::INFINITY)).is_err());
+    }
+
+    #[test]
+    fn duration_to_string() {
+        assert_eq!(super::duration_to_string(super::duration_hms_ms(1, 32, 42, 436)), "1:32:42.436");
+        assert_eq!(super::duration_to_string(super::duration_hms_ms(2, 0, 5, 7)), "2:00:05.007");
+        assert_eq!(super::duration_to_string(super::duration_m_s_ms(1, 32, 109)), "1:32.109");
+        assert_eq!(super::duration_to_string(super::duration_m_s_ms(1, 2, 0)), "1:02.000");
+        assert_eq!(super::duration_to_string(super::duration_s_ms(2, 137)), "2.137");
+        assert_eq!(super::duration_to_string(Duration::ZERO), "0.000");
+        assert_eq!(super::duration_to_string(-super::duration_s_ms(2, 137)), "-2.137");
+        assert_eq!(super::duration_to_string(Duration::microseconds(1_999)), "0.001");
+
+        for duration in [
+            super::duration_hms_ms(1, 32, 42, 436),
+            super::duration_m_s_ms(1, 32, 109),
+            super::duration_s_ms(2, 137),
+        ] {
+            assert_eq!(super::duration_from_str(&super::duration_to_string(duration)), Some(duration));
+        }
+    }
+
+    #[test]
+    fn duration_to_lap_string() {
+        assert_eq!(super::duration_to_lap_string(super::duration_m_s_ms(1, 32, 109)), "1:32.109");
+        assert_eq!(super::duration_to_lap_string(super::duration_m_s_ms(33, 17, 667)), "33:17.667");
+        assert_eq!(super::duration_to_lap_string(super::duration_m_s_ms(0, 59, 37)), "0:59.037");
+        assert_eq!(super::duration_to_lap_string(super::duration_s_ms(2, 137)), "0:02.137");
+        assert_eq!(super::duration_to_lap_string(Duration::ZERO), "0:00.000");
+        assert_eq!(super::duration_to_lap_string(-super::duration_s_ms(2, 137)), "-0:02.137");
+        assert_eq!(super::duration_to_lap_string(-super::duration_m_s_ms(1, 14, 240)), "-1:14.240");
+        assert_eq!(super::duration_to_lap_string(super::duration_hms_ms(1, 32, 42, 436)), "1:32:42.436");
+        assert_eq!(super::duration_to_lap_string(super::duration_hms_ms(2, 0, 5, 7)), "2:00:05.007");
+
+        for duration in [
+            super::duration_hms_ms(1, 32, 42, 436),
+            super::duration_m_s_ms(1, 32, 109),
+            super::duration_s_ms(2, 137),
+            Duration::ZERO,
+        ] {
+            assert_eq!(super::duration_from_str(&super::duration_to_lap_string(duration)), Some(duration));
+        }
+    }
+
     #[test]
     fn parse_duration_err() {
         let bad_strings = make_bad_duration_strings(&[
@@ -651,6 +1016,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_optional_sectors() {
+        #[derive(Deserialize)]
+        struct Proxy {
+            #[serde(default, deserialize_with = "super::deserialize_optional_sectors")]
+            sectors: Option<[Duration; 3]>,
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Proxy>(r#"{"sectors": ["28.123", "40.456", "39.001"]}"#)
+                .unwrap()
+                .sectors,
+            Some([
+                super::duration_m_s_ms(0, 28, 123),
+                super::duration_m_s_ms(0, 40, 456),
+                super::duration_m_s_ms(0, 39, 1)
+            ])
+        );
+        assert_true!(serde_json::from_str::<Proxy>(r#"{}"#).unwrap().sectors.is_none());
+        assert_true!(
+            serde_json::from_str::<Proxy>(r#"{"sectors": null}"#)
+                .unwrap()
+                .sectors
+                .is_none()
+        );
+
+        assert_true!(serde_json::from_str::<Proxy>(r#"{"sectors": ["28.123", "40.456"]}"#).is_err());
+        assert_true!(serde_json::from_str::<Proxy>(r#"{"sectors": ["28.123", "40.456", "abc"]}"#).is_err());
+    }
+
     #[test]
     fn date_time_deserialize() {
         let dt: DateTime = serde_json::from_str(
@@ -719,6 +1114,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn qualifying_time_as_duration() {
+        let lap_time = super::duration_m_s_ms(1, 23, 456);
+        let quali = QualifyingTime::from(lap_time);
+        assert_eq!(quali, QualifyingTime::Time(lap_time));
+        assert_eq!(quali.as_duration(), Some(lap_time));
+        assert_eq!(Duration::try_from(quali), Ok(lap_time));
+
+        assert_eq!(QualifyingTime::NoTimeSet.as_duration(), None);
+        assert_eq!(Duration::try_from(QualifyingTime::NoTimeSet), Err(QualifyingTime::NoTimeSet));
+    }
+
+    #[test]
+    fn qualifying_time_ord() {
+        let fast = QualifyingTime::Time(super::duration_m_s_ms(1, 40, 203));
+        let slow = QualifyingTime::Time(super::duration_m_s_ms(1, 41, 269));
+
+        assert_true!(fast < slow);
+        assert_true!(slow < QualifyingTime::NoTimeSet);
+        assert_true!(fast < QualifyingTime::NoTimeSet);
+
+        assert_eq!([slow, QualifyingTime::NoTimeSet, fast].into_iter().min(), Some(fast));
+        assert_eq!([QualifyingTime::NoTimeSet, slow].into_iter().min(), Some(slow));
+        assert_eq!([QualifyingTime::NoTimeSet].into_iter().min(), Some(QualifyingTime::NoTimeSet));
+
+        let mut times = vec![QualifyingTime::NoTimeSet, slow, fast];
+        times.sort();
+        assert_eq!(times, [fast, slow, QualifyingTime::NoTimeSet]);
+    }
+
     #[test]
     fn qualifying_time_deserialize_err() {
         assert_true!(serde_json::from_str::<QualifyingTime>("1").is_err());
@@ -742,6 +1167,32 @@ mod tests {
         assert_eq!(p2, *RACE_TIME_2023_4_P2);
     }
 
+    #[test]
+    fn race_time_resolve_total_and_interval_to() {
+        let (p1, p2, p3) = (*RACE_TIME_2023_4_P1, *RACE_TIME_2023_4_P2, *RACE_TIME_2023_4_P3);
+
+        for race_time in [p1, p2, p3] {
+            assert_eq!(race_time.resolve_total(*p1.total()), *race_time.total());
+        }
+        assert_eq!(p2.resolve_total(Duration::ZERO), *p2.delta());
+
+        assert_eq!(p2.interval_to(&p1), *p2.delta());
+        assert_eq!(p3.interval_to(&p2), *p3.total() - *p2.total());
+        assert_eq!(p2.interval_to(&p3), -p3.interval_to(&p2));
+        assert_eq!(p1.interval_to(&p1), Duration::ZERO);
+    }
+
+    #[test]
+    fn race_time_ord() {
+        assert_true!(*RACE_TIME_2023_4_P1 < *RACE_TIME_2023_4_P2);
+        assert_true!(*RACE_TIME_2023_4_P2 < *RACE_TIME_2023_4_P3);
+
+        let mut times = vec![*RACE_TIME_2023_4_P3, *RACE_TIME_2023_4_P1, *RACE_TIME_2023_4_P2];
+        times.sort();
+        assert_eq!(times, [*RACE_TIME_2023_4_P1, *RACE_TIME_2023_4_P2, *RACE_TIME_2023_4_P3]);
+        assert_eq!(times.iter().max(), Some(&*RACE_TIME_2023_4_P3));
+    }
+
     #[test]
     fn race_time_deserialize() {
         let str_value_pairs = vec![
@@ -802,4 +1253,57 @@ mod tests {
         validate_race_times(&RACE_TIMES_2021_12[..]);
         validate_race_times(&RACE_TIMES_2023_4[..]);
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_date_and_time() {
+        let chrono_date = chrono::NaiveDate::from_ymd_opt(2023, 4, 30).unwrap();
+        assert_eq!(date_to_chrono(date!(2023 - 04 - 30)), chrono_date);
+        assert_eq!(date_from_chrono(chrono_date).unwrap(), date!(2023 - 04 - 30));
+        assert_true!(date_from_chrono(chrono::NaiveDate::from_ymd_opt(10000, 1, 1).unwrap()).is_err());
+
+        let chrono_time = chrono::NaiveTime::from_hms_milli_opt(11, 0, 0, 123).unwrap();
+        assert_eq!(time_to_chrono(time!(11:00:00.123)), chrono_time);
+        assert_eq!(time_from_chrono(chrono_time).unwrap(), time!(11:00:00.123));
+        assert_true!(time_from_chrono(chrono::NaiveTime::from_hms_milli_opt(23, 59, 59, 1500).unwrap()).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_duration() {
+        let durations = [
+            super::duration_m_s_ms(1, 50, 109),
+            Duration::ZERO,
+            -super::duration_s_ms(1, 500),
+            Duration::nanoseconds(1),
+        ];
+
+        for duration in durations {
+            let chrono_duration = duration_to_chrono(duration).unwrap();
+            assert_eq!(chrono_duration.num_nanoseconds().unwrap(), duration.whole_nanoseconds() as i64);
+            assert_eq!(duration_from_chrono(chrono_duration), duration);
+        }
+
+        assert_true!(duration_to_chrono(Duration::MAX).is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_date_time() {
+        let date_time = DateTime {
+            date: date!(2023 - 04 - 30),
+            time: Some(time!(11:00:00)),
+        };
+        let chrono_date_time = chrono::DateTime::<chrono::Utc>::from(date_time);
+        assert_eq!(chrono_date_time.to_rfc3339(), "2023-04-30T11:00:00+00:00");
+        assert_eq!(DateTime::try_from(chrono_date_time).unwrap(), date_time);
+
+        let date_only = DateTime {
+            date: date!(2023 - 04 - 30),
+            time: None,
+        };
+        let chrono_date_time = chrono::DateTime::<chrono::Utc>::from(date_only);
+        assert_eq!(chrono_date_time.to_rfc3339(), "2023-04-30T00:00:00+00:00");
+        assert_eq!(DateTime::try_from(chrono_date_time).unwrap().time, Some(time!(00:00:00)));
+    }
 }
@@ -60,10 +60,16 @@ multi-page response handling, etc. [`AgentConfigs::default()`](jolpica::AgentCon
 provides sensible defaults that respect the API's Terms of Use and should work for most use cases.
 
 ```no_run
+# use std::{collections::BTreeMap, sync::Arc, time::Duration};
+#
 # use nonzero_ext::nonzero;
 #
 # use f1_data::{
-#     jolpica::{Agent, AgentConfigs, MultiPageOption, RateLimiterOption},
+#     jolpica::{
+#         Agent, AgentConfigs, CacheExpiry, CacheOption, MultiPageOption, RateLimiterOption,
+#         backend::HttpBackend,
+#         get::RetryPolicy,
+#     },
 #     rate_limiter::{Quota, RateLimiter},
 # };
 #
@@ -72,8 +78,17 @@ let jolpica = Agent::default();
 // The above is equivalent to:
 let jolpica = Agent::new(AgentConfigs {
     base_url: "https://api.jolpi.ca/ergast/f1/".into(),
+    backend: Arc::new(HttpBackend),
+    user_agent: None,
+    extra_headers: BTreeMap::new(),
     multi_page: MultiPageOption::Enabled(None),
-    http_retries: Some(2),
+    multi_page_concurrency: 1,
+    http_retries: Some(RetryPolicy::exponential(2, Duration::from_millis(500))),
+    retry_predicate: None,
+    conditional_requests: false,
+    verify_table_variant: false,
+    cache: CacheOption::None,
+    cache_expiry: CacheExpiry::ImmutablePastSeasons(Duration::from_hours(1)),
     rate_limiter: RateLimiterOption::Internal(RateLimiter::new(
         Quota::per_hour(nonzero!(500u32)).allow_burst(nonzero!(4u32)),
     )),
@@ -208,7 +223,7 @@ mod _lint {
     use colored as _;
     use criterion as _;
     use env_logger as _;
-    use log as _;
+    use tokio as _;
 }
 
 pub mod error;
@@ -216,6 +231,12 @@ pub mod id;
 pub mod jolpica;
 pub mod rate_limiter;
 
+#[cfg(feature = "teams")]
+pub mod teams;
+
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
 // @todo Make this public if and when a solution for sourcing the data is found.
 #[allow(unreachable_pub, unused, rustdoc::private_doc_tests)]
 #[cfg(feature = "fantasy")]
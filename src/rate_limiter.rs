@@ -1,44 +1,155 @@
 //! A simple rate limiter providing a minimal interface required by the [`f1_data`](crate) crate.
 
+use std::{
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
 use governor::DefaultDirectRateLimiter;
 pub use governor::Quota;
 pub use nonzero_ext::nonzero;
 
+#[cfg(doc)]
+use crate::jolpica::agent::{Agent, RateLimiterOption};
+
 /// A simple rate limiter providing a minimal interface required by this crate.
 ///
 /// This is a thin wrapper around [`governor`](https://crates.io/crates/governor)'s rate limiter,
 /// although the underlying implementation is not part of the public API and is subject to change.
+///
+/// [`RateLimiter`] is [`Send`] and [`Sync`], and [`wait_until_ready`](Self::wait_until_ready) takes
+/// `&self`, so a single instance can be shared across threads, e.g. via an `Arc<RateLimiter>`, or
+/// between several [`Agent`]s via [`RateLimiterOption::External`], to enforce one shared quota.
+///
+/// The current state of the quota can be inspected via [`available`](Self::available) and
+/// [`time_until_next`](Self::time_until_next), e.g. to decide whether a large number of requests
+/// can be made without waiting, without consuming any of the quota.
 #[derive(Debug)]
 pub struct RateLimiter {
     quota: Quota,
-    rate_limiter: DefaultDirectRateLimiter,
+    limiter: DefaultDirectRateLimiter,
+    /// The theoretical arrival time of the next request, as per the Generic Cell Rate Algorithm
+    /// used by [`governor`], updated whenever a request is allowed. This mirrors the internal state
+    /// of `limiter`, which cannot be inspected without consuming the quota.
+    next_arrival: Mutex<Option<Instant>>,
 }
 
 impl RateLimiter {
     /// Create a new rate limiter with the given [`Quota`].
     pub fn new(quota: Quota) -> Self {
-        let rate_limiter = DefaultDirectRateLimiter::direct(quota);
-        Self { quota, rate_limiter }
+        let limiter = DefaultDirectRateLimiter::direct(quota);
+        Self {
+            quota,
+            limiter,
+            next_arrival: Mutex::new(None),
+        }
+    }
+
+    /// Returns the [`Quota`] that this rate limiter was created with.
+    pub const fn quota(&self) -> Quota {
+        self.quota
+    }
+
+    /// Returns the number of requests that the rate limiter currently allows without waiting, i.e.
+    /// the remaining burst allowance, between `0` and [`Quota::burst_size`].
+    ///
+    /// This does not consume any of the quota, and may be outdated as soon as it is returned, e.g.
+    /// if the rate limiter is shared with other threads.
+    pub fn available(&self) -> u32 {
+        let (interval, burst) = self.interval_and_burst();
+        let Some(next_arrival) = *self.lock_next_arrival() else {
+            return self.quota.burst_size().get();
+        };
+
+        let allowance = (Instant::now() + burst).saturating_duration_since(next_arrival);
+        u32::try_from(allowance.as_nanos() / interval.as_nanos())
+            .unwrap_or(u32::MAX)
+            .min(self.quota.burst_size().get())
+    }
+
+    /// Returns the [`Duration`] until the rate limiter allows another request, i.e. [`Duration::ZERO`]
+    /// if [`available`](Self::available) is non-zero, or otherwise the time until the next request
+    /// in the quota is replenished, at most [`Quota::replenish_interval`].
+    ///
+    /// This does not consume any of the quota, and may be outdated as soon as it is returned, e.g.
+    /// if the rate limiter is shared with other threads.
+    pub fn time_until_next(&self) -> Duration {
+        let (interval, burst) = self.interval_and_burst();
+        let Some(next_arrival) = *self.lock_next_arrival() else {
+            return Duration::ZERO;
+        };
+
+        (next_arrival + interval)
+            .checked_sub(burst)
+            .map_or(Duration::ZERO, |ready_at| ready_at.saturating_duration_since(Instant::now()))
     }
 
     /// Synchronously wait until the rate limiter allows another request.
+    ///
+    /// If the quota is exhausted, i.e. if waiting is required, the wait and its duration are
+    /// logged at the `debug` level via the [`log`](https://docs.rs/log) crate.
     pub fn wait_until_ready(&self) {
-        while self.rate_limiter.check().is_err() {
+        if self.limiter.check().is_ok() {
+            self.record_allowed();
+            return;
+        }
+
+        log::debug!("Rate limiter quota exhausted, waiting until ready");
+        let start = Instant::now();
+
+        while self.limiter.check().is_err() {
             std::thread::sleep(self.quota.replenish_interval() / 100);
         }
+        self.record_allowed();
+
+        log::debug!("Rate limiter ready after waiting {:?}", start.elapsed());
+    }
+
+    /// Asynchronously wait until the rate limiter allows another request, without blocking the
+    /// calling thread, e.g. from within an async runtime.
+    ///
+    /// This consumes the same quota as [`wait_until_ready`](Self::wait_until_ready), so synchronous
+    /// and asynchronous callers can share a single [`RateLimiter`]. It does not depend on any
+    /// particular async runtime.
+    pub async fn wait_until_ready_async(&self) {
+        self.limiter.until_ready().await;
+        self.record_allowed();
+    }
+
+    /// Returns the [`Quota::replenish_interval`] and the total duration of the burst allowance.
+    fn interval_and_burst(&self) -> (Duration, Duration) {
+        let interval = self.quota.replenish_interval();
+        (interval, interval * self.quota.burst_size().get())
+    }
+
+    /// Locks the theoretical arrival time of the next request, recovering from a poisoned lock,
+    /// since it is always left in a consistent state.
+    fn lock_next_arrival(&self) -> MutexGuard<'_, Option<Instant>> {
+        self.next_arrival.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Records that a request was allowed, advancing the theoretical arrival time of the next
+    /// request by one [`Quota::replenish_interval`], as per the Generic Cell Rate Algorithm.
+    fn record_allowed(&self) {
+        let now = Instant::now();
+        let mut next_arrival = self.lock_next_arrival();
+        *next_arrival = Some(next_arrival.map_or(now, |next| next.max(now)) + self.quota.replenish_interval());
     }
 }
 
 #[cfg(test)]
 #[cfg_attr(coverage, coverage(off))]
 mod tests {
+    use std::pin::pin;
     use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
     use std::thread;
     use std::time::{Duration, Instant};
 
     use nonzero_ext::nonzero;
 
     use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
 
     use super::*;
 
@@ -48,6 +159,28 @@ mod tests {
         }
     }
 
+    /// Drive a future to completion on the current thread, parking it while the future is pending.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct ThreadWaker(thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut context = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
     fn spawn_and_join_threads(limiter: &Arc<RateLimiter>, thread_count: usize, requests_per_thread: usize) {
         (0..thread_count)
             .map(|_| {
@@ -63,6 +196,54 @@ mod tests {
             });
     }
 
+    #[test]
+    fn rate_limiter_is_send_and_sync() {
+        // Compile-time check; this fails to build if the underlying rate limiter loses thread-safety
+        const fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<RateLimiter>();
+    }
+
+    #[test]
+    fn available_and_time_until_next() {
+        let quota = Quota::per_second(nonzero!(10u32)).allow_burst(nonzero!(5u32));
+        let limiter = RateLimiter::new(quota);
+        assert_eq!(limiter.quota(), quota);
+
+        // The full burst is initially available, without any waiting
+        assert_eq!(limiter.available(), 5);
+        assert_eq!(limiter.time_until_next(), Duration::ZERO);
+
+        wait_until_n_ready(&limiter, 3);
+        assert_eq!(limiter.available(), 2);
+        assert_eq!(limiter.time_until_next(), Duration::ZERO);
+
+        wait_until_n_ready(&limiter, 2);
+        assert_eq!(limiter.available(), 0);
+
+        // The next request is allowed after ~100ms
+        let time_until_next = limiter.time_until_next();
+        assert_gt!(time_until_next, Duration::from_millis(90));
+        assert_le!(time_until_next, Duration::from_millis(100));
+
+        let start = Instant::now();
+        limiter.wait_until_ready();
+        assert_ge!(start.elapsed(), time_until_next - Duration::from_millis(10));
+        assert_eq!(limiter.available(), 0);
+
+        // Replenishes one request per ~100ms, up to the burst size
+        thread::sleep(Duration::from_millis(250));
+        assert_eq!(limiter.available(), 2);
+        assert_eq!(limiter.time_until_next(), Duration::ZERO);
+
+        thread::sleep(Duration::from_millis(1000));
+        assert_eq!(limiter.available(), 5);
+
+        // Asynchronous waits are also reflected
+        block_on(limiter.wait_until_ready_async());
+        assert_eq!(limiter.available(), 4);
+    }
+
     #[test]
     fn basic_rate_limiting_and_burst() {
         let quota = Quota::per_second(nonzero!(10u32)).allow_burst(nonzero!(5u32));
@@ -146,4 +327,38 @@ mod tests {
         assert_ge!(elapsed, Duration::from_millis(100 * (10 - 1)));
         assert_lt!(elapsed, Duration::from_millis(100 * (10 + 1)));
     }
+
+    #[test]
+    fn async_rate_limiting_and_burst() {
+        let quota = Quota::per_second(nonzero!(10u32)).allow_burst(nonzero!(5u32));
+        let limiter = RateLimiter::new(quota);
+
+        let wait_until_n_ready_async = |n| {
+            block_on(async {
+                for _ in 0..n {
+                    limiter.wait_until_ready_async().await;
+                }
+            });
+        };
+
+        let start = Instant::now();
+        wait_until_n_ready_async(5);
+        let elapsed = start.elapsed();
+
+        // First 5 requests should complete immediately
+        assert_lt!(elapsed, Duration::from_millis(50)); // + some margin
+
+        let start = Instant::now();
+        wait_until_n_ready_async(5);
+        let elapsed = start.elapsed();
+
+        // Subsequent requests should wait, ~100ms each, ~500ms total, +/- some margin
+        assert_ge!(elapsed, Duration::from_millis(100 * (5 - 1)));
+        assert_lt!(elapsed, Duration::from_millis(100 * (5 + 1)));
+
+        // The quota is shared with synchronous waits, which now also have to wait
+        let start = Instant::now();
+        limiter.wait_until_ready();
+        assert_ge!(start.elapsed(), Duration::from_millis(50));
+    }
 }
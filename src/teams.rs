@@ -0,0 +1,147 @@
+//! Team colors for Formula 1 constructors, e.g. for use in charts and other visualizations.
+//!
+//! The colors are looked up from a bundled table of recent seasons' team colors, which change
+//! yearly, keyed by [`ConstructorID`] and [`SeasonID`]. Historical seasons and teams that are not
+//! in the table are not supported, i.e. [`None`] is returned for them.
+//!
+//! ```
+//! use f1_data::teams::{Rgb, team_color};
+//!
+//! assert_eq!(team_color(&"ferrari".into(), 2024), Some(Rgb::new(0xE8, 0x00, 0x2D)));
+//! assert_eq!(team_color(&"ferrari".into(), 2024).unwrap().to_string(), "#E8002D");
+//! assert_eq!(team_color(&"ferrari".into(), 1950), None);
+//! ```
+
+use std::fmt::Display;
+
+use crate::{
+    id::{ConstructorID, SeasonID},
+    jolpica::response::RaceResult,
+};
+
+/// A color in the RGB color space, with 8 bits per channel.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Rgb {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+}
+
+impl Rgb {
+    /// Creates a new [`Rgb`] color from its red, green, and blue channels.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Creates a new [`Rgb`] color from a `0xRRGGBB` value, ignoring the most significant byte.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_hex(hex: u32) -> Self {
+        Self::new((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+}
+
+impl Display for Rgb {
+    /// Formats the color as a hex string, e.g. `"#E8002D"`, as used in HTML/CSS.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+/// Bundled table of team colors, by season and constructor ID.
+const TEAM_COLORS: &[(SeasonID, &str, Rgb)] = &[
+    // 2022
+    (2022, "red_bull", Rgb::from_hex(0x36_71_C6)),
+    (2022, "ferrari", Rgb::from_hex(0xF9_15_36)),
+    (2022, "mercedes", Rgb::from_hex(0x6C_D3_BF)),
+    (2022, "mclaren", Rgb::from_hex(0xF5_80_20)),
+    (2022, "alpine", Rgb::from_hex(0x22_93_D1)),
+    (2022, "alphatauri", Rgb::from_hex(0x5E_8F_AA)),
+    (2022, "aston_martin", Rgb::from_hex(0x35_8C_75)),
+    (2022, "williams", Rgb::from_hex(0x37_BE_DD)),
+    (2022, "alfa", Rgb::from_hex(0xC9_2D_4B)),
+    (2022, "haas", Rgb::from_hex(0xB6_BA_BD)),
+    // 2023
+    (2023, "red_bull", Rgb::from_hex(0x36_71_C6)),
+    (2023, "ferrari", Rgb::from_hex(0xF9_15_36)),
+    (2023, "mercedes", Rgb::from_hex(0x6C_D3_BF)),
+    (2023, "mclaren", Rgb::from_hex(0xF5_80_20)),
+    (2023, "alpine", Rgb::from_hex(0x22_93_D1)),
+    (2023, "alphatauri", Rgb::from_hex(0x5E_8F_AA)),
+    (2023, "aston_martin", Rgb::from_hex(0x35_8C_75)),
+    (2023, "williams", Rgb::from_hex(0x37_BE_DD)),
+    (2023, "alfa", Rgb::from_hex(0xC9_2D_4B)),
+    (2023, "haas", Rgb::from_hex(0xB6_BA_BD)),
+    // 2024
+    (2024, "red_bull", Rgb::from_hex(0x36_71_C6)),
+    (2024, "ferrari", Rgb::from_hex(0xE8_00_2D)),
+    (2024, "mercedes", Rgb::from_hex(0x27_F4_D2)),
+    (2024, "mclaren", Rgb::from_hex(0xFF_80_00)),
+    (2024, "alpine", Rgb::from_hex(0xFF_87_BC)),
+    (2024, "rb", Rgb::from_hex(0x66_92_FF)),
+    (2024, "aston_martin", Rgb::from_hex(0x22_99_71)),
+    (2024, "williams", Rgb::from_hex(0x64_C4_FF)),
+    (2024, "sauber", Rgb::from_hex(0x52_E2_52)),
+    (2024, "haas", Rgb::from_hex(0xB6_BA_BD)),
+];
+
+/// Returns the team color of a constructor in a given season, if it's in the bundled table.
+///
+/// Returns [`None`] for unknown constructors, and for seasons that are not in the table, e.g.
+/// historical seasons.
+pub fn team_color(constructor_id: &ConstructorID, season: SeasonID) -> Option<Rgb> {
+    TEAM_COLORS
+        .iter()
+        .find(|(s, id, _)| *s == season && id == constructor_id)
+        .map(|(_, _, color)| *color)
+}
+
+/// Returns the color of a driver for a [`RaceResult`] in a given season, i.e. the [`team_color`] of
+/// the [`RaceResult::constructor`] that they drove for.
+pub fn driver_color(race_result: &RaceResult, season: SeasonID) -> Option<Rgb> {
+    team_color(&race_result.constructor.constructor_id, season)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::jolpica::tests::assets::*;
+    use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn rgb() {
+        assert_eq!(Rgb::from_hex(0x36_71_C6), Rgb::new(0x36, 0x71, 0xC6));
+        assert_eq!(Rgb::from_hex(0xFF_36_71_C6), Rgb::new(0x36, 0x71, 0xC6));
+        assert_eq!(Rgb::new(0, 10, 255).to_string(), "#000AFF");
+    }
+
+    #[test]
+    fn team_colors_table_is_unique() {
+        let keys: HashSet<_> = TEAM_COLORS.iter().map(|(season, id, _)| (season, id)).collect();
+        assert_eq!(keys.len(), TEAM_COLORS.len());
+    }
+
+    #[test]
+    fn team_color_lookup() {
+        assert_eq!(team_color(&"red_bull".into(), 2023), Some(Rgb::from_hex(0x36_71_C6)));
+        assert_eq!(team_color(&"alfa".into(), 2023), Some(Rgb::from_hex(0xC9_2D_4B)));
+        assert_eq!(team_color(&"sauber".into(), 2024), Some(Rgb::from_hex(0x52_E2_52)));
+
+        assert_eq!(team_color(&"sauber".into(), 2023), None);
+        assert_eq!(team_color(&"unknown".into(), 2024), None);
+        assert_eq!(team_color(&"ferrari".into(), 1950), None);
+    }
+
+    #[test]
+    fn driver_color_lookup() {
+        assert_eq!(driver_color(&RACE_RESULT_2023_4_P1, 2023), Some(Rgb::from_hex(0x36_71_C6)));
+        assert_true!(driver_color(&RACE_RESULT_1950_5_P1, 1950).is_none());
+    }
+}
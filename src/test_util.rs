@@ -0,0 +1,129 @@
+//! Sample data for testing code that builds on top of `f1_data`, under the `test-util` feature.
+//!
+//! These functions return realistic values of the types from the [`jolpica::response`] module,
+//! e.g. [`Race<Vec<RaceResult>>`], matching what would be returned by the [`Agent`]'s `get_*`
+//! methods for the same requests. They are intended for use in dependents' unit tests, so that
+//! they don't need to hand-build fixtures or make network requests.
+//!
+//! **Note:** The sample data is not comprehensive, e.g. [`sample_race_results_2023_4`] only
+//! contains a few of the results of that race, and the sample lists only contain a few entries.
+//!
+//! ```
+//! use f1_data::test_util::sample_race_results_2023_4;
+//!
+//! let race = sample_race_results_2023_4();
+//! assert_eq!(race.race_name, "Azerbaijan Grand Prix");
+//! assert_eq!(race.payload[0].driver.full_name(), "Sergio Pérez");
+//! ```
+
+use crate::jolpica::{
+    response::{Circuit, Constructor, Driver, Lap, PitStop, QualifyingResult, Race, RaceResult, Schedule, Season},
+    response::{SprintResult, Status},
+    tests::assets::{
+        CIRCUIT_TABLE, CONSTRUCTOR_TABLE, DRIVER_TABLE, RACE_2023_4_LAPS, RACE_2023_4_PIT_STOPS,
+        RACE_2023_4_QUALIFYING_RESULTS, RACE_2023_4_RACE_RESULTS, RACE_2023_4_SCHEDULE, RACE_2023_4_SPRINT_RESULTS,
+        SEASON_TABLE, STATUS_TABLE_2022,
+    },
+};
+
+#[cfg(doc)]
+use crate::jolpica::{self, agent::Agent};
+
+/// Returns a list of sample [`Season`]s, e.g. `1950`, `1979`, `2023`, etc.
+pub fn sample_seasons() -> Vec<Season> {
+    SEASON_TABLE.clone().into_seasons().unwrap_or_else(|_| unreachable!())
+}
+
+/// Returns a list of sample [`Driver`]s, e.g. `"fangio"`, `"michael_schumacher"`, `"leclerc"`, etc.
+pub fn sample_drivers() -> Vec<Driver> {
+    DRIVER_TABLE.clone().into_drivers().unwrap_or_else(|_| unreachable!())
+}
+
+/// Returns a list of sample [`Constructor`]s, e.g. `"mclaren"`, `"ferrari"`, `"red_bull"`, etc.
+pub fn sample_constructors() -> Vec<Constructor> {
+    CONSTRUCTOR_TABLE
+        .clone()
+        .into_constructors()
+        .unwrap_or_else(|_| unreachable!())
+}
+
+/// Returns a list of sample [`Circuit`]s, e.g. `"spa"`, `"silverstone"`, `"imola"`, etc.
+pub fn sample_circuits() -> Vec<Circuit> {
+    CIRCUIT_TABLE.clone().into_circuits().unwrap_or_else(|_| unreachable!())
+}
+
+/// Returns a list of sample [`Status`]es from the 2022 season, e.g. `"Finished"`, `"Engine"`, etc.
+pub fn sample_statuses_2022() -> Vec<Status> {
+    STATUS_TABLE_2022
+        .clone()
+        .into_status()
+        .unwrap_or_else(|_| unreachable!())
+}
+
+/// Returns the sample [`Schedule`] of the 2023 Azerbaijan Grand Prix, i.e. 2023, round 4.
+#[allow(clippy::borrow_interior_mutable_const)]
+pub fn sample_race_schedule_2023_4() -> Race<Schedule> {
+    RACE_2023_4_SCHEDULE
+        .clone()
+        .map(|payload| payload.into_schedule().unwrap_or_else(|_| unreachable!()))
+}
+
+/// Returns sample [`QualifyingResult`]s of the 2023 Azerbaijan Grand Prix, i.e. 2023, round 4.
+pub fn sample_qualifying_results_2023_4() -> Race<Vec<QualifyingResult>> {
+    RACE_2023_4_QUALIFYING_RESULTS
+        .clone()
+        .map(|payload| payload.into_qualifying_results().unwrap_or_else(|_| unreachable!()))
+}
+
+/// Returns sample [`SprintResult`]s of the 2023 Azerbaijan Grand Prix, i.e. 2023, round 4.
+pub fn sample_sprint_results_2023_4() -> Race<Vec<SprintResult>> {
+    RACE_2023_4_SPRINT_RESULTS
+        .clone()
+        .map(|payload| payload.into_sprint_results().unwrap_or_else(|_| unreachable!()))
+}
+
+/// Returns sample [`RaceResult`]s of the 2023 Azerbaijan Grand Prix, i.e. 2023, round 4.
+pub fn sample_race_results_2023_4() -> Race<Vec<RaceResult>> {
+    RACE_2023_4_RACE_RESULTS
+        .clone()
+        .map(|payload| payload.into_race_results().unwrap_or_else(|_| unreachable!()))
+}
+
+/// Returns sample [`Lap`]s of the 2023 Azerbaijan Grand Prix, i.e. 2023, round 4.
+pub fn sample_laps_2023_4() -> Race<Vec<Lap>> {
+    RACE_2023_4_LAPS
+        .clone()
+        .map(|payload| payload.into_laps().unwrap_or_else(|_| unreachable!()))
+}
+
+/// Returns sample [`PitStop`]s of the 2023 Azerbaijan Grand Prix, i.e. 2023, round 4.
+pub fn sample_pit_stops_2023_4() -> Race<Vec<PitStop>> {
+    RACE_2023_4_PIT_STOPS
+        .clone()
+        .map(|payload| payload.into_pit_stops().unwrap_or_else(|_| unreachable!()))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage, coverage(off))]
+mod tests {
+    use crate::tests::asserts::*;
+    use shadow_asserts::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn samples() {
+        assert_false!(sample_seasons().is_empty());
+        assert_false!(sample_drivers().is_empty());
+        assert_false!(sample_constructors().is_empty());
+        assert_false!(sample_circuits().is_empty());
+        assert_false!(sample_statuses_2022().is_empty());
+
+        assert_eq!(sample_race_schedule_2023_4().round, 4);
+        assert_false!(sample_qualifying_results_2023_4().payload.is_empty());
+        assert_false!(sample_sprint_results_2023_4().payload.is_empty());
+        assert_false!(sample_race_results_2023_4().payload.is_empty());
+        assert_false!(sample_laps_2023_4().payload.is_empty());
+        assert_false!(sample_pit_stops_2023_4().payload.is_empty());
+    }
+}